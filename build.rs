@@ -0,0 +1,35 @@
+//! Symbol naming convention of BLAS functions.
+//!
+//! BLAS symbol is `{prefix}{name}{suffix}`, where `name` is lowercase routine name (such as `dgemm`). By crate
+//! features:
+//! - default: `dgemm_`;
+//! - `suffix64_`: `dgemm_64_` (OpenBLAS built with `INTERFACE64=1 SYMBOLSUFFIX=64_`, Debian `libopenblas64`);
+//! - `suffix_64`: `dgemm_64` (Reference-LAPACK built with 64-bit index API).
+//!
+//! Environment variables `BLAS_ARRAY2_SYMBOL_PREFIX` and `BLAS_ARRAY2_SYMBOL_SUFFIX` override the above.
+//!
+//! CBLAS symbol (crate feature `cblas`) is `cblas_{name}{cblas_suffix}`: `cblas_dgemm` by default,
+//! `cblas_dgemm64_` with `suffix64_`, and `cblas_dgemm_64` with `suffix_64`. Environment variable
+//! `BLAS_ARRAY2_CBLAS_SYMBOL_SUFFIX` overrides it (it is not derived from `BLAS_ARRAY2_SYMBOL_SUFFIX`).
+
+use std::env;
+
+fn main() {
+    let (prefix, suffix, cblas_suffix) = if env::var_os("CARGO_FEATURE_SUFFIX64_").is_some() {
+        ("", "_64_", "64_")
+    } else if env::var_os("CARGO_FEATURE_SUFFIX_64").is_some() {
+        ("", "_64", "_64")
+    } else {
+        ("", "_", "")
+    };
+    let prefix = env::var("BLAS_ARRAY2_SYMBOL_PREFIX").unwrap_or(prefix.into());
+    let suffix = env::var("BLAS_ARRAY2_SYMBOL_SUFFIX").unwrap_or(suffix.into());
+    let cblas_suffix = env::var("BLAS_ARRAY2_CBLAS_SYMBOL_SUFFIX").unwrap_or(cblas_suffix.into());
+    println!("cargo:rustc-env=BLAS_ARRAY2_SYMBOL_PREFIX={prefix}");
+    println!("cargo:rustc-env=BLAS_ARRAY2_SYMBOL_SUFFIX={suffix}");
+    println!("cargo:rustc-env=BLAS_ARRAY2_CBLAS_SYMBOL_SUFFIX={cblas_suffix}");
+    println!("cargo:rerun-if-env-changed=BLAS_ARRAY2_SYMBOL_PREFIX");
+    println!("cargo:rerun-if-env-changed=BLAS_ARRAY2_SYMBOL_SUFFIX");
+    println!("cargo:rerun-if-env-changed=BLAS_ARRAY2_CBLAS_SYMBOL_SUFFIX");
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,2006 @@
+//! Exported CBLAS functions, with the same signatures as `cblas.h`.
+//!
+//! Automatically generated by `scripts/cblas_gen.py`.
+
+use crate::*;
+use blas_array2::ffi::blas_int;
+use blas_array2::util::{c32, c64};
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sdsdot(
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+) -> f32 {
+    level1::sdsdot("cblas_sdsdot", n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsdot(
+    n: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+) -> f64 {
+    level1::dsdot("cblas_dsdot", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sdot(
+    n: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+) -> f32 {
+    level1::dotu::<f32>("cblas_sdot", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ddot(
+    n: blas_int,
+    x: *const f64,
+    incx: blas_int,
+    y: *const f64,
+    incy: blas_int,
+) -> f64 {
+    level1::dotu::<f64>("cblas_ddot", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cdotu_sub(
+    n: blas_int,
+    x: *const c32,
+    incx: blas_int,
+    y: *const c32,
+    incy: blas_int,
+    dotu: *mut c32,
+) {
+    *dotu = level1::dotu::<c32>("cblas_cdotu_sub", n, x, incx, y, incy);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdotu_sub(
+    n: blas_int,
+    x: *const c64,
+    incx: blas_int,
+    y: *const c64,
+    incy: blas_int,
+    dotu: *mut c64,
+) {
+    *dotu = level1::dotu::<c64>("cblas_zdotu_sub", n, x, incx, y, incy);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cdotc_sub(
+    n: blas_int,
+    x: *const c32,
+    incx: blas_int,
+    y: *const c32,
+    incy: blas_int,
+    dotc: *mut c32,
+) {
+    *dotc = level1::dotc::<c32>("cblas_cdotc_sub", n, x, incx, y, incy);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdotc_sub(
+    n: blas_int,
+    x: *const c64,
+    incx: blas_int,
+    y: *const c64,
+    incy: blas_int,
+    dotc: *mut c64,
+) {
+    *dotc = level1::dotc::<c64>("cblas_zdotc_sub", n, x, incx, y, incy);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_snrm2(n: blas_int, x: *const f32, incx: blas_int) -> f32 {
+    level1::nrm2::<f32>("cblas_snrm2", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dnrm2(n: blas_int, x: *const f64, incx: blas_int) -> f64 {
+    level1::nrm2::<f64>("cblas_dnrm2", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_scnrm2(n: blas_int, x: *const c32, incx: blas_int) -> f32 {
+    level1::nrm2::<c32>("cblas_scnrm2", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dznrm2(n: blas_int, x: *const c64, incx: blas_int) -> f64 {
+    level1::nrm2::<c64>("cblas_dznrm2", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sasum(n: blas_int, x: *const f32, incx: blas_int) -> f32 {
+    level1::asum::<f32>("cblas_sasum", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dasum(n: blas_int, x: *const f64, incx: blas_int) -> f64 {
+    level1::asum::<f64>("cblas_dasum", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_scasum(n: blas_int, x: *const c32, incx: blas_int) -> f32 {
+    level1::asum::<c32>("cblas_scasum", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dzasum(n: blas_int, x: *const c64, incx: blas_int) -> f64 {
+    level1::asum::<c64>("cblas_dzasum", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_isamax(n: blas_int, x: *const f32, incx: blas_int) -> CBLAS_INDEX {
+    level1::iamax::<f32>("cblas_isamax", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_idamax(n: blas_int, x: *const f64, incx: blas_int) -> CBLAS_INDEX {
+    level1::iamax::<f64>("cblas_idamax", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_icamax(n: blas_int, x: *const c32, incx: blas_int) -> CBLAS_INDEX {
+    level1::iamax::<c32>("cblas_icamax", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_izamax(n: blas_int, x: *const c64, incx: blas_int) -> CBLAS_INDEX {
+    level1::iamax::<c64>("cblas_izamax", n, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sswap(n: blas_int, x: *mut f32, incx: blas_int, y: *mut f32, incy: blas_int) {
+    level1::swap::<f32>("cblas_sswap", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dswap(n: blas_int, x: *mut f64, incx: blas_int, y: *mut f64, incy: blas_int) {
+    level1::swap::<f64>("cblas_dswap", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cswap(n: blas_int, x: *mut c32, incx: blas_int, y: *mut c32, incy: blas_int) {
+    level1::swap::<c32>("cblas_cswap", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zswap(n: blas_int, x: *mut c64, incx: blas_int, y: *mut c64, incy: blas_int) {
+    level1::swap::<c64>("cblas_zswap", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_scopy(
+    n: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    y: *mut f32,
+    incy: blas_int,
+) {
+    level1::copy::<f32>("cblas_scopy", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dcopy(
+    n: blas_int,
+    x: *const f64,
+    incx: blas_int,
+    y: *mut f64,
+    incy: blas_int,
+) {
+    level1::copy::<f64>("cblas_dcopy", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ccopy(
+    n: blas_int,
+    x: *const c32,
+    incx: blas_int,
+    y: *mut c32,
+    incy: blas_int,
+) {
+    level1::copy::<c32>("cblas_ccopy", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zcopy(
+    n: blas_int,
+    x: *const c64,
+    incx: blas_int,
+    y: *mut c64,
+    incy: blas_int,
+) {
+    level1::copy::<c64>("cblas_zcopy", n, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_saxpy(
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    y: *mut f32,
+    incy: blas_int,
+) {
+    level1::axpy::<f32>("cblas_saxpy", n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_daxpy(
+    n: blas_int,
+    alpha: f64,
+    x: *const f64,
+    incx: blas_int,
+    y: *mut f64,
+    incy: blas_int,
+) {
+    level1::axpy::<f64>("cblas_daxpy", n, alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_caxpy(
+    n: blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: blas_int,
+    y: *mut c32,
+    incy: blas_int,
+) {
+    level1::axpy::<c32>("cblas_caxpy", n, *alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zaxpy(
+    n: blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: blas_int,
+    y: *mut c64,
+    incy: blas_int,
+) {
+    level1::axpy::<c64>("cblas_zaxpy", n, *alpha, x, incx, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    level1::rotg::<f32>("cblas_srotg", a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotg(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64) {
+    level1::rotg::<f64>("cblas_drotg", a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_crotg(a: *mut c32, b: *mut c32, c: *mut f32, s: *mut c32) {
+    level1::rotg_complex::<c32>("cblas_crotg", a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zrotg(a: *mut c64, b: *mut c64, c: *mut f64, s: *mut c64) {
+    level1::rotg_complex::<c64>("cblas_zrotg", a, b, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotmg(d1: *mut f32, d2: *mut f32, b1: *mut f32, b2: f32, p: *mut f32) {
+    level1::rotmg::<f32>("cblas_srotmg", d1, d2, b1, b2, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotmg(d1: *mut f64, d2: *mut f64, b1: *mut f64, b2: f64, p: *mut f64) {
+    level1::rotmg::<f64>("cblas_drotmg", d1, d2, b1, b2, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srot(
+    n: blas_int,
+    x: *mut f32,
+    incx: blas_int,
+    y: *mut f32,
+    incy: blas_int,
+    c: f32,
+    s: f32,
+) {
+    level1::rot::<f32>("cblas_srot", n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drot(
+    n: blas_int,
+    x: *mut f64,
+    incx: blas_int,
+    y: *mut f64,
+    incy: blas_int,
+    c: f64,
+    s: f64,
+) {
+    level1::rot::<f64>("cblas_drot", n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csrot(
+    n: blas_int,
+    x: *mut c32,
+    incx: blas_int,
+    y: *mut c32,
+    incy: blas_int,
+    c: f32,
+    s: f32,
+) {
+    level1::rot::<c32>("cblas_csrot", n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdrot(
+    n: blas_int,
+    x: *mut c64,
+    incx: blas_int,
+    y: *mut c64,
+    incy: blas_int,
+    c: f64,
+    s: f64,
+) {
+    level1::rot::<c64>("cblas_zdrot", n, x, incx, y, incy, c, s)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotm(
+    n: blas_int,
+    x: *mut f32,
+    incx: blas_int,
+    y: *mut f32,
+    incy: blas_int,
+    p: *const f32,
+) {
+    level1::rotm::<f32>("cblas_srotm", n, x, incx, y, incy, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotm(
+    n: blas_int,
+    x: *mut f64,
+    incx: blas_int,
+    y: *mut f64,
+    incy: blas_int,
+    p: *const f64,
+) {
+    level1::rotm::<f64>("cblas_drotm", n, x, incx, y, incy, p)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sscal(n: blas_int, alpha: f32, x: *mut f32, incx: blas_int) {
+    level1::scal::<f32>("cblas_sscal", n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dscal(n: blas_int, alpha: f64, x: *mut f64, incx: blas_int) {
+    level1::scal::<f64>("cblas_dscal", n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cscal(n: blas_int, alpha: *const c32, x: *mut c32, incx: blas_int) {
+    level1::scal::<c32>("cblas_cscal", n, *alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zscal(n: blas_int, alpha: *const c64, x: *mut c64, incx: blas_int) {
+    level1::scal::<c64>("cblas_zscal", n, *alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csscal(n: blas_int, alpha: f32, x: *mut c32, incx: blas_int) {
+    level1::rscal::<c32>("cblas_csscal", n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zdscal(n: blas_int, alpha: f64, x: *mut c64, incx: blas_int) {
+    level1::rscal::<c64>("cblas_zdscal", n, alpha, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgemv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    beta: f32,
+    y: *mut f32,
+    incy: blas_int,
+) {
+    level2::gemv::<f32>("cblas_sgemv", order, transa, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgemv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    x: *const f64,
+    incx: blas_int,
+    beta: f64,
+    y: *mut f64,
+    incy: blas_int,
+) {
+    level2::gemv::<f64>("cblas_dgemv", order, transa, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgemv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    x: *const c32,
+    incx: blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: blas_int,
+) {
+    level2::gemv::<c32>("cblas_cgemv", order, transa, m, n, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgemv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    x: *const c64,
+    incx: blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: blas_int,
+) {
+    level2::gemv::<c64>("cblas_zgemv", order, transa, m, n, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgbmv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    kl: blas_int,
+    ku: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    beta: f32,
+    y: *mut f32,
+    incy: blas_int,
+) {
+    level2::gbmv::<f32>("cblas_sgbmv", order, transa, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgbmv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    kl: blas_int,
+    ku: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    x: *const f64,
+    incx: blas_int,
+    beta: f64,
+    y: *mut f64,
+    incy: blas_int,
+) {
+    level2::gbmv::<f64>("cblas_dgbmv", order, transa, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgbmv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    kl: blas_int,
+    ku: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    x: *const c32,
+    incx: blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: blas_int,
+) {
+    level2::gbmv::<c32>("cblas_cgbmv", order, transa, m, n, kl, ku, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgbmv(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    kl: blas_int,
+    ku: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    x: *const c64,
+    incx: blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: blas_int,
+) {
+    level2::gbmv::<c64>("cblas_zgbmv", order, transa, m, n, kl, ku, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const f32,
+    lda: blas_int,
+    x: *mut f32,
+    incx: blas_int,
+) {
+    level2::trmv::<f32>("cblas_strmv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const f64,
+    lda: blas_int,
+    x: *mut f64,
+    incx: blas_int,
+) {
+    level2::trmv::<f64>("cblas_dtrmv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const c32,
+    lda: blas_int,
+    x: *mut c32,
+    incx: blas_int,
+) {
+    level2::trmv::<c32>("cblas_ctrmv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const c64,
+    lda: blas_int,
+    x: *mut c64,
+    incx: blas_int,
+) {
+    level2::trmv::<c64>("cblas_ztrmv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const f32,
+    lda: blas_int,
+    x: *mut f32,
+    incx: blas_int,
+) {
+    level2::tbmv::<f32>("cblas_stbmv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const f64,
+    lda: blas_int,
+    x: *mut f64,
+    incx: blas_int,
+) {
+    level2::tbmv::<f64>("cblas_dtbmv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const c32,
+    lda: blas_int,
+    x: *mut c32,
+    incx: blas_int,
+) {
+    level2::tbmv::<c32>("cblas_ctbmv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const c64,
+    lda: blas_int,
+    x: *mut c64,
+    incx: blas_int,
+) {
+    level2::tbmv::<c64>("cblas_ztbmv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: blas_int,
+) {
+    level2::tpmv::<f32>("cblas_stpmv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: blas_int,
+) {
+    level2::tpmv::<f64>("cblas_dtpmv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const c32,
+    x: *mut c32,
+    incx: blas_int,
+) {
+    level2::tpmv::<c32>("cblas_ctpmv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const c64,
+    x: *mut c64,
+    incx: blas_int,
+) {
+    level2::tpmv::<c64>("cblas_ztpmv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const f32,
+    lda: blas_int,
+    x: *mut f32,
+    incx: blas_int,
+) {
+    level2::trsv::<f32>("cblas_strsv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const f64,
+    lda: blas_int,
+    x: *mut f64,
+    incx: blas_int,
+) {
+    level2::trsv::<f64>("cblas_dtrsv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const c32,
+    lda: blas_int,
+    x: *mut c32,
+    incx: blas_int,
+) {
+    level2::trsv::<c32>("cblas_ctrsv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const c64,
+    lda: blas_int,
+    x: *mut c64,
+    incx: blas_int,
+) {
+    level2::trsv::<c64>("cblas_ztrsv", order, uplo, transa, diag, n, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const f32,
+    lda: blas_int,
+    x: *mut f32,
+    incx: blas_int,
+) {
+    level2::tbsv::<f32>("cblas_stbsv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const f64,
+    lda: blas_int,
+    x: *mut f64,
+    incx: blas_int,
+) {
+    level2::tbsv::<f64>("cblas_dtbsv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const c32,
+    lda: blas_int,
+    x: *mut c32,
+    incx: blas_int,
+) {
+    level2::tbsv::<c32>("cblas_ctbsv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztbsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const c64,
+    lda: blas_int,
+    x: *mut c64,
+    incx: blas_int,
+) {
+    level2::tbsv::<c64>("cblas_ztbsv", order, uplo, transa, diag, n, k, a, lda, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: blas_int,
+) {
+    level2::tpsv::<f32>("cblas_stpsv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: blas_int,
+) {
+    level2::tpsv::<f64>("cblas_dtpsv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const c32,
+    x: *mut c32,
+    incx: blas_int,
+) {
+    level2::tpsv::<c32>("cblas_ctpsv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztpsv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const c64,
+    x: *mut c64,
+    incx: blas_int,
+) {
+    level2::tpsv::<c64>("cblas_ztpsv", order, uplo, transa, diag, n, ap, x, incx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssymv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    beta: f32,
+    y: *mut f32,
+    incy: blas_int,
+) {
+    level2::hemv::<f32>("cblas_ssymv", order, uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsymv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    x: *const f64,
+    incx: blas_int,
+    beta: f64,
+    y: *mut f64,
+    incy: blas_int,
+) {
+    level2::hemv::<f64>("cblas_dsymv", order, uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chemv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    x: *const c32,
+    incx: blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: blas_int,
+) {
+    level2::hemv::<c32>("cblas_chemv", order, uplo, n, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhemv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    x: *const c64,
+    incx: blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: blas_int,
+) {
+    level2::hemv::<c64>("cblas_zhemv", order, uplo, n, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    k: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    beta: f32,
+    y: *mut f32,
+    incy: blas_int,
+) {
+    level2::hbmv::<f32>("cblas_ssbmv", order, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    k: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    x: *const f64,
+    incx: blas_int,
+    beta: f64,
+    y: *mut f64,
+    incy: blas_int,
+) {
+    level2::hbmv::<f64>("cblas_dsbmv", order, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    x: *const c32,
+    incx: blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: blas_int,
+) {
+    level2::hbmv::<c32>("cblas_chbmv", order, uplo, n, k, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhbmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    x: *const c64,
+    incx: blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: blas_int,
+) {
+    level2::hbmv::<c64>("cblas_zhbmv", order, uplo, n, k, *alpha, a, lda, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: blas_int,
+    beta: f32,
+    y: *mut f32,
+    incy: blas_int,
+) {
+    level2::hpmv::<f32>("cblas_sspmv", order, uplo, n, alpha, ap, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: blas_int,
+    beta: f64,
+    y: *mut f64,
+    incy: blas_int,
+) {
+    level2::hpmv::<f64>("cblas_dspmv", order, uplo, n, alpha, ap, x, incx, beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c32,
+    ap: *const c32,
+    x: *const c32,
+    incx: blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: blas_int,
+) {
+    level2::hpmv::<c32>("cblas_chpmv", order, uplo, n, *alpha, ap, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhpmv(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c64,
+    ap: *const c64,
+    x: *const c64,
+    incx: blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: blas_int,
+) {
+    level2::hpmv::<c64>("cblas_zhpmv", order, uplo, n, *alpha, ap, x, incx, *beta, y, incy)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sger(
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+    a: *mut f32,
+    lda: blas_int,
+) {
+    level2::ger::<f32>("cblas_sger", order, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dger(
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: f64,
+    x: *const f64,
+    incx: blas_int,
+    y: *const f64,
+    incy: blas_int,
+    a: *mut f64,
+    lda: blas_int,
+) {
+    level2::ger::<f64>("cblas_dger", order, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgeru(
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: blas_int,
+    y: *const c32,
+    incy: blas_int,
+    a: *mut c32,
+    lda: blas_int,
+) {
+    level2::ger::<c32>("cblas_cgeru", order, m, n, *alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgeru(
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: blas_int,
+    y: *const c64,
+    incy: blas_int,
+    a: *mut c64,
+    lda: blas_int,
+) {
+    level2::ger::<c64>("cblas_zgeru", order, m, n, *alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgerc(
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: blas_int,
+    y: *const c32,
+    incy: blas_int,
+    a: *mut c32,
+    lda: blas_int,
+) {
+    level2::gerc::<c32>("cblas_cgerc", order, m, n, *alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgerc(
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: blas_int,
+    y: *const c64,
+    incy: blas_int,
+    a: *mut c64,
+    lda: blas_int,
+) {
+    level2::gerc::<c64>("cblas_zgerc", order, m, n, *alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    a: *mut f32,
+    lda: blas_int,
+) {
+    level2::her::<f32>("cblas_ssyr", order, uplo, n, alpha, x, incx, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    x: *const f64,
+    incx: blas_int,
+    a: *mut f64,
+    lda: blas_int,
+) {
+    level2::her::<f64>("cblas_dsyr", order, uplo, n, alpha, x, incx, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cher(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    x: *const c32,
+    incx: blas_int,
+    a: *mut c32,
+    lda: blas_int,
+) {
+    level2::her::<c32>("cblas_cher", order, uplo, n, alpha, x, incx, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zher(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    x: *const c64,
+    incx: blas_int,
+    a: *mut c64,
+    lda: blas_int,
+) {
+    level2::her::<c64>("cblas_zher", order, uplo, n, alpha, x, incx, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    ap: *mut f32,
+) {
+    level2::hpr::<f32>("cblas_sspr", order, uplo, n, alpha, x, incx, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    x: *const f64,
+    incx: blas_int,
+    ap: *mut f64,
+) {
+    level2::hpr::<f64>("cblas_dspr", order, uplo, n, alpha, x, incx, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chpr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    x: *const c32,
+    incx: blas_int,
+    ap: *mut c32,
+) {
+    level2::hpr::<c32>("cblas_chpr", order, uplo, n, alpha, x, incx, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhpr(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    x: *const c64,
+    incx: blas_int,
+    ap: *mut c64,
+) {
+    level2::hpr::<c64>("cblas_zhpr", order, uplo, n, alpha, x, incx, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+    a: *mut f32,
+    lda: blas_int,
+) {
+    level2::her2::<f32>("cblas_ssyr2", order, uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    x: *const f64,
+    incx: blas_int,
+    y: *const f64,
+    incy: blas_int,
+    a: *mut f64,
+    lda: blas_int,
+) {
+    level2::her2::<f64>("cblas_dsyr2", order, uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cher2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: blas_int,
+    y: *const c32,
+    incy: blas_int,
+    a: *mut c32,
+    lda: blas_int,
+) {
+    level2::her2::<c32>("cblas_cher2", order, uplo, n, *alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zher2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: blas_int,
+    y: *const c64,
+    incy: blas_int,
+    a: *mut c64,
+    lda: blas_int,
+) {
+    level2::her2::<c64>("cblas_zher2", order, uplo, n, *alpha, x, incx, y, incy, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+    ap: *mut f32,
+) {
+    level2::hpr2::<f32>("cblas_sspr2", order, uplo, n, alpha, x, incx, y, incy, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: f64,
+    x: *const f64,
+    incx: blas_int,
+    y: *const f64,
+    incy: blas_int,
+    ap: *mut f64,
+) {
+    level2::hpr2::<f64>("cblas_dspr2", order, uplo, n, alpha, x, incx, y, incy, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chpr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: blas_int,
+    y: *const c32,
+    incy: blas_int,
+    ap: *mut c32,
+) {
+    level2::hpr2::<c32>("cblas_chpr2", order, uplo, n, *alpha, x, incx, y, incy, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhpr2(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: blas_int,
+    y: *const c64,
+    incy: blas_int,
+    ap: *mut c64,
+) {
+    level2::hpr2::<c64>("cblas_zhpr2", order, uplo, n, *alpha, x, incx, y, incy, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgemm(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    k: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    b: *const f32,
+    ldb: blas_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: blas_int,
+) {
+    level3::gemm::<f32>("cblas_sgemm", order, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgemm(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    k: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    b: *const f64,
+    ldb: blas_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: blas_int,
+) {
+    level3::gemm::<f64>("cblas_dgemm", order, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cgemm(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    b: *const c32,
+    ldb: blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: blas_int,
+) {
+    level3::gemm::<c32>("cblas_cgemm", order, transa, transb, m, n, k, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zgemm(
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    b: *const c64,
+    ldb: blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: blas_int,
+) {
+    level3::gemm::<c64>("cblas_zgemm", order, transa, transb, m, n, k, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    b: *const f32,
+    ldb: blas_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: blas_int,
+) {
+    level3::symm::<f32>("cblas_ssymm", order, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    b: *const f64,
+    ldb: blas_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: blas_int,
+) {
+    level3::symm::<f64>("cblas_dsymm", order, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    b: *const c32,
+    ldb: blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: blas_int,
+) {
+    level3::symm::<c32>("cblas_csymm", order, side, uplo, m, n, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zsymm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    b: *const c64,
+    ldb: blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: blas_int,
+) {
+    level3::symm::<c64>("cblas_zsymm", order, side, uplo, m, n, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_chemm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    b: *const c32,
+    ldb: blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: blas_int,
+) {
+    level3::hemm::<c32>("cblas_chemm", order, side, uplo, m, n, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zhemm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    b: *const c64,
+    ldb: blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: blas_int,
+) {
+    level3::hemm::<c64>("cblas_zhemm", order, side, uplo, m, n, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: blas_int,
+) {
+    level3::syrk::<f32>("cblas_ssyrk", order, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: blas_int,
+) {
+    level3::syrk::<f64>("cblas_dsyrk", order, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: blas_int,
+) {
+    level3::syrk::<c32>("cblas_csyrk", order, uplo, trans, n, k, *alpha, a, lda, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zsyrk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: blas_int,
+) {
+    level3::syrk::<c64>("cblas_zsyrk", order, uplo, trans, n, k, *alpha, a, lda, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cherk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: f32,
+    a: *const c32,
+    lda: blas_int,
+    beta: f32,
+    c: *mut c32,
+    ldc: blas_int,
+) {
+    level3::herk::<c32>("cblas_cherk", order, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zherk(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: f64,
+    a: *const c64,
+    lda: blas_int,
+    beta: f64,
+    c: *mut c64,
+    ldc: blas_int,
+) {
+    level3::herk::<c64>("cblas_zherk", order, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    b: *const f32,
+    ldb: blas_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: blas_int,
+) {
+    level3::syr2k::<f32>("cblas_ssyr2k", order, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    b: *const f64,
+    ldb: blas_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: blas_int,
+) {
+    level3::syr2k::<f64>("cblas_dsyr2k", order, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_csyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    b: *const c32,
+    ldb: blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: blas_int,
+) {
+    level3::syr2k::<c32>("cblas_csyr2k", order, uplo, trans, n, k, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zsyr2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    b: *const c64,
+    ldb: blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: blas_int,
+) {
+    level3::syr2k::<c64>("cblas_zsyr2k", order, uplo, trans, n, k, *alpha, a, lda, b, ldb, *beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_cher2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    b: *const c32,
+    ldb: blas_int,
+    beta: f32,
+    c: *mut c32,
+    ldc: blas_int,
+) {
+    level3::her2k::<c32>("cblas_cher2k", order, uplo, trans, n, k, *alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_zher2k(
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    b: *const c64,
+    ldb: blas_int,
+    beta: f64,
+    c: *mut c64,
+    ldc: blas_int,
+) {
+    level3::her2k::<c64>("cblas_zher2k", order, uplo, trans, n, k, *alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    b: *mut f32,
+    ldb: blas_int,
+) {
+    level3::trmm::<f32>("cblas_strmm", order, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    b: *mut f64,
+    ldb: blas_int,
+) {
+    level3::trmm::<f64>("cblas_dtrmm", order, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    b: *mut c32,
+    ldb: blas_int,
+) {
+    level3::trmm::<c32>("cblas_ctrmm", order, side, uplo, transa, diag, m, n, *alpha, a, lda, b, ldb)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrmm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    b: *mut c64,
+    ldb: blas_int,
+) {
+    level3::trmm::<c64>("cblas_ztrmm", order, side, uplo, transa, diag, m, n, *alpha, a, lda, b, ldb)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: f32,
+    a: *const f32,
+    lda: blas_int,
+    b: *mut f32,
+    ldb: blas_int,
+) {
+    level3::trsm::<f32>("cblas_strsm", order, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: f64,
+    a: *const f64,
+    lda: blas_int,
+    b: *mut f64,
+    ldb: blas_int,
+) {
+    level3::trsm::<f64>("cblas_dtrsm", order, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ctrsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: blas_int,
+    b: *mut c32,
+    ldb: blas_int,
+) {
+    level3::trsm::<c32>("cblas_ctrsm", order, side, uplo, transa, diag, m, n, *alpha, a, lda, b, ldb)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ztrsm(
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: blas_int,
+    b: *mut c64,
+    ldb: blas_int,
+) {
+    level3::trsm::<c64>("cblas_ztrsm", order, side, uplo, transa, diag, m, n, *alpha, a, lda, b, ldb)
+}
@@ -0,0 +1,253 @@
+//! Level 1 CBLAS: vector operations.
+
+use crate::*;
+use blas_array2::prelude::*;
+use num_traits::{Float, Zero};
+
+/* #region dot */
+
+pub unsafe fn sdsdot(
+    name: &str,
+    n: blas_int,
+    alpha: f32,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+) -> f32 {
+    if n <= 0 {
+        return alpha;
+    }
+    let (x, y) = (vector(n, x, incx), vector(n, y, incy));
+    return report(name, SDSDOT::default().x(x).y(y).sb(alpha).run()).unwrap_or(alpha);
+}
+
+pub unsafe fn dsdot(
+    name: &str,
+    n: blas_int,
+    x: *const f32,
+    incx: blas_int,
+    y: *const f32,
+    incy: blas_int,
+) -> f64 {
+    if n <= 0 {
+        return 0.0;
+    }
+    let (x, y) = (vector(n, x, incx), vector(n, y, incy));
+    return report(name, DSDOT::default().x(x).y(y).run()).unwrap_or(0.0);
+}
+
+/// `x^T y`; also `sdot` and `ddot` for real types.
+pub unsafe fn dotu<F: DOTUNum>(
+    name: &str,
+    n: blas_int,
+    x: *const F,
+    incx: blas_int,
+    y: *const F,
+    incy: blas_int,
+) -> F {
+    if n <= 0 {
+        return F::zero();
+    }
+    let (x, y) = (vector(n, x, incx), vector(n, y, incy));
+    return report(name, DOTU::<F>::default().x(x).y(y).run()).unwrap_or(F::zero());
+}
+
+pub unsafe fn dotc<F: DOTCNum>(
+    name: &str,
+    n: blas_int,
+    x: *const F,
+    incx: blas_int,
+    y: *const F,
+    incy: blas_int,
+) -> F {
+    if n <= 0 {
+        return F::zero();
+    }
+    let (x, y) = (vector(n, x, incx), vector(n, y, incy));
+    return report(name, DOTC::<F>::default().x(x).y(y).run()).unwrap_or(F::zero());
+}
+
+/* #endregion */
+
+/* #region norm and index */
+
+pub unsafe fn nrm2<F: NRM2Num>(name: &str, n: blas_int, x: *const F, incx: blas_int) -> F::RealFloat {
+    if n <= 0 || incx <= 0 {
+        return F::RealFloat::zero();
+    }
+    let x = vector(n, x, incx);
+    return report(name, NRM2::<F>::default().x(x).run()).unwrap_or(F::RealFloat::zero());
+}
+
+pub unsafe fn asum<F: ASUMNum>(name: &str, n: blas_int, x: *const F, incx: blas_int) -> F::RealFloat {
+    if n <= 0 || incx <= 0 {
+        return F::RealFloat::zero();
+    }
+    let x = vector(n, x, incx);
+    return report(name, ASUM::<F>::default().x(x).run()).unwrap_or(F::RealFloat::zero());
+}
+
+/// 0-based index of the first element of maximum `|re| + |im|`; 0 for empty vector.
+pub unsafe fn iamax<F: IAMAXNum>(name: &str, n: blas_int, x: *const F, incx: blas_int) -> CBLAS_INDEX {
+    if n <= 0 || incx <= 0 {
+        return 0;
+    }
+    let x = vector(n, x, incx);
+    return report(name, IAMAX::<F>::default().x(x).run()).unwrap_or(0);
+}
+
+/* #endregion */
+
+/* #region vector update */
+
+pub unsafe fn swap<F: SWAPNum>(
+    name: &str,
+    n: blas_int,
+    x: *mut F,
+    incx: blas_int,
+    y: *mut F,
+    incy: blas_int,
+) {
+    if !check_args(name, &[(incx != 0, 3), (incy != 0, 5)]) || n <= 0 {
+        return;
+    }
+    let (x, y) = (vector_mut(n, x, incx), vector_mut(n, y, incy));
+    report(name, SWAP::<F>::default().x(x).y(y).run());
+}
+
+pub unsafe fn copy<F: COPYNum>(
+    name: &str,
+    n: blas_int,
+    x: *const F,
+    incx: blas_int,
+    y: *mut F,
+    incy: blas_int,
+) {
+    if !check_args(name, &[(incy != 0, 5)]) || n <= 0 {
+        return;
+    }
+    let (x, y) = (vector(n, x, incx), vector_mut(n, y, incy));
+    report(name, COPY::<F>::default().x(x).y(y).run());
+}
+
+pub unsafe fn axpy<F: AXPYNum>(
+    name: &str,
+    n: blas_int,
+    alpha: F,
+    x: *const F,
+    incx: blas_int,
+    y: *mut F,
+    incy: blas_int,
+) {
+    if !check_args(name, &[(incy != 0, 6)]) || n <= 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, y) = (vector(n, x, incx), vector_mut(n, y, incy));
+    report(name, AXPY::<F>::default().x(x).y(y).alpha(alpha).run());
+}
+
+pub unsafe fn scal<F: SCALNum>(name: &str, n: blas_int, alpha: F, x: *mut F, incx: blas_int) {
+    if n <= 0 || incx <= 0 {
+        return;
+    }
+    let x = vector_mut(n, x, incx);
+    report(name, SCAL::<F>::default().x(x).alpha(alpha).run());
+}
+
+/// Scale complex vector by real scalar (`csscal`, `zdscal`).
+pub unsafe fn rscal<F: SCALNum>(name: &str, n: blas_int, alpha: F::RealFloat, x: *mut F, incx: blas_int) {
+    if n <= 0 || incx <= 0 {
+        return;
+    }
+    let x = vector_mut(n, x, incx);
+    report(name, RSCAL::<F>::default().x(x).alpha(alpha).run());
+}
+
+/* #endregion */
+
+/* #region rotation */
+
+/// Real Givens rotation; `a` is overwritten by `r`, and `b` by `z` (for reconstruction of `c` and `s`).
+pub unsafe fn rotg<F: ROTGNum<RealFloat = F> + Float>(
+    name: &str,
+    a: *mut F,
+    b: *mut F,
+    c: *mut F,
+    s: *mut F,
+) {
+    let (abs_a, abs_b) = ((*a).abs(), (*b).abs());
+    let Some(ROTGResult { c: c_, s: s_, r }) = report(name, ROTG::<F>::default().a(*a).b(*b).run()) else {
+        return;
+    };
+    // z = s if |a| > |b|; 1 / c if c != 0; 1 otherwise (0 if b == 0)
+    let z = if s_.is_zero() {
+        F::zero()
+    } else if abs_a > abs_b {
+        s_
+    } else if !c_.is_zero() {
+        F::one() / c_
+    } else {
+        F::one()
+    };
+    (*a, *b, *c, *s) = (r, z, c_, s_);
+}
+
+/// Complex Givens rotation; `a` is overwritten by `r`, and `b` is not changed.
+pub unsafe fn rotg_complex<F: ROTGNum>(name: &str, a: *mut F, b: *mut F, c: *mut F::RealFloat, s: *mut F) {
+    let Some(ROTGResult { c: c_, s: s_, r }) = report(name, ROTG::<F>::default().a(*a).b(*b).run()) else {
+        return;
+    };
+    (*a, *c, *s) = (r, c_, s_);
+}
+
+pub unsafe fn rotmg<F: ROTMGNum + Float>(name: &str, d1: *mut F, d2: *mut F, b1: *mut F, b2: F, p: *mut F) {
+    let result = ROTMG::<F>::default().d1(*d1).d2(*d2).x1(*b1).y1(b2).run();
+    let Some(ROTMGResult { d1: d1_, d2: d2_, x1, param }) = report(name, result) else {
+        return;
+    };
+    (*d1, *d2, *b1) = (d1_, d2_, x1);
+    core::slice::from_raw_parts_mut(p, 5).copy_from_slice(&param.to_array());
+}
+
+/// Plane rotation; also `csrot` and `zdrot` of complex vectors with real `c` and `s`.
+pub unsafe fn rot<F: ROTNum>(
+    name: &str,
+    n: blas_int,
+    x: *mut F,
+    incx: blas_int,
+    y: *mut F,
+    incy: blas_int,
+    c: F::RealFloat,
+    s: F::RealFloat,
+) {
+    if !check_args(name, &[(incx != 0, 3), (incy != 0, 5)]) || n <= 0 {
+        return;
+    }
+    let (x, y) = (vector_mut(n, x, incx), vector_mut(n, y, incy));
+    report(name, ROT::<F>::default().x(x).y(y).c(c).s(s).run());
+}
+
+pub unsafe fn rotm<F: ROTMNum + Float>(
+    name: &str,
+    n: blas_int,
+    x: *mut F,
+    incx: blas_int,
+    y: *mut F,
+    incy: blas_int,
+    p: *const F,
+) {
+    if !check_args(name, &[(incx != 0, 3), (incy != 0, 5)]) || n <= 0 {
+        return;
+    }
+    let Some(param) = report(name, RotmParam::from_array(*(p as *const [F; 5]))) else {
+        return;
+    };
+    if param == RotmParam::Identity {
+        return;
+    }
+    let (x, y) = (vector_mut(n, x, incx), vector_mut(n, y, incy));
+    report(name, ROTM::<F>::default().x(x).y(y).param(param).run());
+}
+
+/* #endregion */
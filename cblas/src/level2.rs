@@ -0,0 +1,531 @@
+//! Level 2 CBLAS: matrix-vector operations.
+//!
+//! Functions of real symmetric matrices (`ssymv`, `dsyr`, etc.) are the same to Hermitian ones, as wrappers of
+//! blas-array2 do.
+
+use crate::*;
+use blas_array2::prelude::*;
+use num_traits::Zero;
+
+/// Length of packed triangular matrix of dimension `n`.
+fn packed_len(n: blas_int) -> blas_int {
+    return n * (n + 1) / 2;
+}
+
+/* #region general */
+
+pub unsafe fn gemv<F: GEMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    x: *const F,
+    incx: blas_int,
+    beta: F,
+    y: *mut F,
+    incy: blas_int,
+) {
+    let (layout, trans) = (parse_order(order), parse_trans(trans));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (trans.is_some(), 2), (m >= 0, 3), (n >= 0, 4),
+        (lda >= ld_min(layout, m, n), 7), (incx != 0, 9), (incy != 0, 12),
+    ]);
+    let (true, Some(layout), Some(trans)) = (legal, layout, trans) else { return };
+    if m == 0 || n == 0 || (alpha.is_zero() && beta == F::one()) {
+        return;
+    }
+    let (len_x, len_y) = if trans == BLASNoTrans { (n, m) } else { (m, n) };
+    let a = matrix(layout, m, n, a, lda);
+    let (x, y) = (vector(len_x, x, incx), vector_mut(len_y, y, incy));
+    report(name, GEMV::<F>::default().a(a).x(x).y(y).alpha(alpha).beta(beta).trans(trans).run());
+}
+
+/// Band matrix is stored as `(kl + ku + 1) x n` in col-major, or `m x (kl + ku + 1)` in row-major.
+pub unsafe fn gbmv<F: GBMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    trans: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    kl: blas_int,
+    ku: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    x: *const F,
+    incx: blas_int,
+    beta: F,
+    y: *mut F,
+    incy: blas_int,
+) {
+    let (layout, trans) = (parse_order(order), parse_trans(trans));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (trans.is_some(), 2), (m >= 0, 3), (n >= 0, 4), (kl >= 0, 5), (ku >= 0, 6),
+        (lda > kl + ku, 9), (incx != 0, 11), (incy != 0, 14),
+    ]);
+    let (true, Some(layout), Some(trans)) = (legal, layout, trans) else { return };
+    if m == 0 || n == 0 || (alpha.is_zero() && beta == F::one()) {
+        return;
+    }
+    let (len_x, len_y) = if trans == BLASNoTrans { (n, m) } else { (m, n) };
+    // wrapper takes number of rows of the col-major band matrix, which is `n` in row-major
+    let (a, m_band) = match layout {
+        BLASRowMajor => (matrix(layout, m, kl + ku + 1, a, lda), n),
+        _ => (matrix(layout, kl + ku + 1, n, a, lda), m),
+    };
+    let (x, y) = (vector(len_x, x, incx), vector_mut(len_y, y, incy));
+    let task = GBMV::<F>::default().a(a).x(x).y(y).m(m_band as usize).kl(kl as usize);
+    report(name, task.alpha(alpha).beta(beta).trans(trans).layout(layout).run());
+}
+
+pub unsafe fn ger<F: GERNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: F,
+    x: *const F,
+    incx: blas_int,
+    y: *const F,
+    incy: blas_int,
+    a: *mut F,
+    lda: blas_int,
+) {
+    let layout = parse_order(order);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (m >= 0, 2), (n >= 0, 3), (incx != 0, 6), (incy != 0, 8),
+        (lda >= ld_min(layout, m, n), 10),
+    ]);
+    let (true, Some(layout)) = (legal, layout) else { return };
+    if m == 0 || n == 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, y) = (vector(m, x, incx), vector(n, y, incy));
+    let a = matrix_mut(layout, m, n, a, lda);
+    report(name, GER::<F>::default().x(x).y(y).a(a).alpha(alpha).run());
+}
+
+pub unsafe fn gerc<F: GERCNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    m: blas_int,
+    n: blas_int,
+    alpha: F,
+    x: *const F,
+    incx: blas_int,
+    y: *const F,
+    incy: blas_int,
+    a: *mut F,
+    lda: blas_int,
+) {
+    let layout = parse_order(order);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (m >= 0, 2), (n >= 0, 3), (incx != 0, 6), (incy != 0, 8),
+        (lda >= ld_min(layout, m, n), 10),
+    ]);
+    let (true, Some(layout)) = (legal, layout) else { return };
+    if m == 0 || n == 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, y) = (vector(m, x, incx), vector(n, y, incy));
+    let a = matrix_mut(layout, m, n, a, lda);
+    report(name, GERC::<F>::default().x(x).y(y).a(a).alpha(alpha).run());
+}
+
+/* #endregion */
+
+/* #region triangular */
+
+pub unsafe fn trmv<F: TRMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const F,
+    lda: blas_int,
+    x: *mut F,
+    incx: blas_int,
+) {
+    let (layout, uplo, trans, diag) =
+        (parse_order(order), parse_uplo(uplo), parse_trans(trans), parse_diag(diag));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (diag.is_some(), 4), (n >= 0, 5),
+        (lda >= n.max(1), 7), (incx != 0, 9),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans), Some(diag)) = (legal, layout, uplo, trans, diag) else {
+        return;
+    };
+    if n == 0 {
+        return;
+    }
+    let (a, x) = (matrix(layout, n, n, a, lda), vector_mut(n, x, incx));
+    report(name, TRMV::<F>::default().a(a).x(x).uplo(uplo).trans(trans).diag(diag).run());
+}
+
+/// Band matrix is stored as `(k + 1) x n` in col-major, or `n x (k + 1)` in row-major.
+pub unsafe fn tbmv<F: TBMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const F,
+    lda: blas_int,
+    x: *mut F,
+    incx: blas_int,
+) {
+    let (layout, uplo, trans, diag) =
+        (parse_order(order), parse_uplo(uplo), parse_trans(trans), parse_diag(diag));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (diag.is_some(), 4), (n >= 0, 5),
+        (k >= 0, 6), (lda > k, 8), (incx != 0, 10),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans), Some(diag)) = (legal, layout, uplo, trans, diag) else {
+        return;
+    };
+    if n == 0 {
+        return;
+    }
+    let a = match layout {
+        BLASRowMajor => matrix(layout, n, k + 1, a, lda),
+        _ => matrix(layout, k + 1, n, a, lda),
+    };
+    let x = vector_mut(n, x, incx);
+    let task = TBMV::<F>::default().a(a).x(x).uplo(uplo).trans(trans).diag(diag);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn tpmv<F: TPMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const F,
+    x: *mut F,
+    incx: blas_int,
+) {
+    let (layout, uplo, trans, diag) =
+        (parse_order(order), parse_uplo(uplo), parse_trans(trans), parse_diag(diag));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (diag.is_some(), 4), (n >= 0, 5),
+        (incx != 0, 8),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans), Some(diag)) = (legal, layout, uplo, trans, diag) else {
+        return;
+    };
+    if n == 0 {
+        return;
+    }
+    let (ap, x) = (vector(packed_len(n), ap, 1), vector_mut(n, x, incx));
+    let task = TPMV::<F>::default().ap(ap).x(x).uplo(uplo).trans(trans).diag(diag);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn trsv<F: TRSVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    a: *const F,
+    lda: blas_int,
+    x: *mut F,
+    incx: blas_int,
+) {
+    let (layout, uplo, trans, diag) =
+        (parse_order(order), parse_uplo(uplo), parse_trans(trans), parse_diag(diag));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (diag.is_some(), 4), (n >= 0, 5),
+        (lda >= n.max(1), 7), (incx != 0, 9),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans), Some(diag)) = (legal, layout, uplo, trans, diag) else {
+        return;
+    };
+    if n == 0 {
+        return;
+    }
+    let (a, x) = (matrix(layout, n, n, a, lda), vector_mut(n, x, incx));
+    report(name, TRSV::<F>::default().a(a).x(x).uplo(uplo).trans(trans).diag(diag).run());
+}
+
+pub unsafe fn tbsv<F: TBSVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    k: blas_int,
+    a: *const F,
+    lda: blas_int,
+    x: *mut F,
+    incx: blas_int,
+) {
+    let (layout, uplo, trans, diag) =
+        (parse_order(order), parse_uplo(uplo), parse_trans(trans), parse_diag(diag));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (diag.is_some(), 4), (n >= 0, 5),
+        (k >= 0, 6), (lda > k, 8), (incx != 0, 10),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans), Some(diag)) = (legal, layout, uplo, trans, diag) else {
+        return;
+    };
+    if n == 0 {
+        return;
+    }
+    let a = match layout {
+        BLASRowMajor => matrix(layout, n, k + 1, a, lda),
+        _ => matrix(layout, k + 1, n, a, lda),
+    };
+    let x = vector_mut(n, x, incx);
+    let task = TBSV::<F>::default().a(a).x(x).uplo(uplo).trans(trans).diag(diag);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn tpsv<F: TPSVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: blas_int,
+    ap: *const F,
+    x: *mut F,
+    incx: blas_int,
+) {
+    let (layout, uplo, trans, diag) =
+        (parse_order(order), parse_uplo(uplo), parse_trans(trans), parse_diag(diag));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (diag.is_some(), 4), (n >= 0, 5),
+        (incx != 0, 8),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans), Some(diag)) = (legal, layout, uplo, trans, diag) else {
+        return;
+    };
+    if n == 0 {
+        return;
+    }
+    let (ap, x) = (vector(packed_len(n), ap, 1), vector_mut(n, x, incx));
+    let task = TPSV::<F>::default().ap(ap).x(x).uplo(uplo).trans(trans).diag(diag);
+    report(name, task.layout(layout).run());
+}
+
+/* #endregion */
+
+/* #region hermitian */
+
+pub unsafe fn hemv<F: HEMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    x: *const F,
+    incx: blas_int,
+    beta: F,
+    y: *mut F,
+    incy: blas_int,
+) {
+    let (layout, uplo) = (parse_order(order), parse_uplo(uplo));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (n >= 0, 3), (lda >= n.max(1), 6), (incx != 0, 8),
+        (incy != 0, 11),
+    ]);
+    let (true, Some(layout), Some(uplo)) = (legal, layout, uplo) else { return };
+    if n == 0 || (alpha.is_zero() && beta == F::one()) {
+        return;
+    }
+    let a = matrix(layout, n, n, a, lda);
+    let (x, y) = (vector(n, x, incx), vector_mut(n, y, incy));
+    report(name, HEMV::<F>::default().a(a).x(x).y(y).alpha(alpha).beta(beta).uplo(uplo).run());
+}
+
+/// Band matrix is stored as `(k + 1) x n` in col-major, or `n x (k + 1)` in row-major.
+pub unsafe fn hbmv<F: HBMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    k: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    x: *const F,
+    incx: blas_int,
+    beta: F,
+    y: *mut F,
+    incy: blas_int,
+) {
+    let (layout, uplo) = (parse_order(order), parse_uplo(uplo));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (n >= 0, 3), (k >= 0, 4), (lda > k, 7), (incx != 0, 9),
+        (incy != 0, 12),
+    ]);
+    let (true, Some(layout), Some(uplo)) = (legal, layout, uplo) else { return };
+    if n == 0 || (alpha.is_zero() && beta == F::one()) {
+        return;
+    }
+    let a = match layout {
+        BLASRowMajor => matrix(layout, n, k + 1, a, lda),
+        _ => matrix(layout, k + 1, n, a, lda),
+    };
+    let (x, y) = (vector(n, x, incx), vector_mut(n, y, incy));
+    let task = HBMV::<F>::default().a(a).x(x).y(y).alpha(alpha).beta(beta).uplo(uplo);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn hpmv<F: HPMVNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: F,
+    ap: *const F,
+    x: *const F,
+    incx: blas_int,
+    beta: F,
+    y: *mut F,
+    incy: blas_int,
+) {
+    let (layout, uplo) = (parse_order(order), parse_uplo(uplo));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (n >= 0, 3), (incx != 0, 7), (incy != 0, 10),
+    ]);
+    let (true, Some(layout), Some(uplo)) = (legal, layout, uplo) else { return };
+    if n == 0 || (alpha.is_zero() && beta == F::one()) {
+        return;
+    }
+    let ap = vector(packed_len(n), ap, 1);
+    let (x, y) = (vector(n, x, incx), vector_mut(n, y, incy));
+    let task = HPMV::<F>::default().ap(ap).x(x).y(y).alpha(alpha).beta(beta).uplo(uplo);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn her<F: HERNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: F::RealFloat,
+    x: *const F,
+    incx: blas_int,
+    a: *mut F,
+    lda: blas_int,
+) {
+    let (layout, uplo) = (parse_order(order), parse_uplo(uplo));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (n >= 0, 3), (incx != 0, 6), (lda >= n.max(1), 8),
+    ]);
+    let (true, Some(layout), Some(uplo)) = (legal, layout, uplo) else { return };
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, a) = (vector(n, x, incx), matrix_mut(layout, n, n, a, lda));
+    report(name, HER::<F>::default().x(x).a(a).alpha(alpha).uplo(uplo).run());
+}
+
+pub unsafe fn hpr<F: HPRNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: F::RealFloat,
+    x: *const F,
+    incx: blas_int,
+    ap: *mut F,
+) {
+    let (layout, uplo) = (parse_order(order), parse_uplo(uplo));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (n >= 0, 3), (incx != 0, 6),
+    ]);
+    let (true, Some(layout), Some(uplo)) = (legal, layout, uplo) else { return };
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, ap) = (vector(n, x, incx), vector_mut(packed_len(n), ap, 1));
+    report(name, HPR::<F>::default().x(x).ap(ap).alpha(alpha).uplo(uplo).layout(layout).run());
+}
+
+pub unsafe fn her2<F: HER2Num>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: F,
+    x: *const F,
+    incx: blas_int,
+    y: *const F,
+    incy: blas_int,
+    a: *mut F,
+    lda: blas_int,
+) {
+    let (layout, uplo) = (parse_order(order), parse_uplo(uplo));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (n >= 0, 3), (incx != 0, 6), (incy != 0, 8),
+        (lda >= n.max(1), 10),
+    ]);
+    let (true, Some(layout), Some(uplo)) = (legal, layout, uplo) else { return };
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, y) = (vector(n, x, incx), vector(n, y, incy));
+    let a = matrix_mut(layout, n, n, a, lda);
+    report(name, HER2::<F>::default().x(x).y(y).a(a).alpha(alpha).uplo(uplo).run());
+}
+
+pub unsafe fn hpr2<F: HPR2Num>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    n: blas_int,
+    alpha: F,
+    x: *const F,
+    incx: blas_int,
+    y: *const F,
+    incy: blas_int,
+    ap: *mut F,
+) {
+    let (layout, uplo) = (parse_order(order), parse_uplo(uplo));
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (n >= 0, 3), (incx != 0, 6), (incy != 0, 8),
+    ]);
+    let (true, Some(layout), Some(uplo)) = (legal, layout, uplo) else { return };
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, y) = (vector(n, x, incx), vector(n, y, incy));
+    let ap = vector_mut(packed_len(n), ap, 1);
+    report(name, HPR2::<F>::default().x(x).y(y).ap(ap).alpha(alpha).uplo(uplo).layout(layout).run());
+}
+
+/* #endregion */
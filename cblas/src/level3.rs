@@ -0,0 +1,344 @@
+//! Level 3 CBLAS: matrix-matrix operations.
+
+use crate::*;
+use blas_array2::prelude::*;
+use num_traits::{One, Zero};
+
+/// Dimension of triangular or symmetric `A` given `side`, for `m x n` matrix `B`.
+fn dim_side(side: Option<BLASSide>, m: blas_int, n: blas_int) -> blas_int {
+    match side {
+        Some(BLASRight) => return n,
+        _ => return m,
+    }
+}
+
+/// Shape of `op(A)` as stored, where `op(A)` is `rows x cols`.
+fn shape_trans(trans: Option<BLASTranspose>, rows: blas_int, cols: blas_int) -> (blas_int, blas_int) {
+    match trans {
+        Some(BLASNoTrans) => return (rows, cols),
+        _ => return (cols, rows),
+    }
+}
+
+/* #region general */
+
+pub unsafe fn gemm<F: GEMMNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blas_int,
+    n: blas_int,
+    k: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    b: *const F,
+    ldb: blas_int,
+    beta: F,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (layout, transa, transb) = (parse_order(order), parse_trans(transa), parse_trans(transb));
+    let (rows_a, cols_a) = shape_trans(transa, m, k);
+    let (rows_b, cols_b) = shape_trans(transb, k, n);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (transa.is_some(), 2), (transb.is_some(), 3), (m >= 0, 4), (n >= 0, 5), (k >= 0, 6),
+        (lda >= ld_min(layout, rows_a, cols_a), 9), (ldb >= ld_min(layout, rows_b, cols_b), 11),
+        (ldc >= ld_min(layout, m, n), 14),
+    ]);
+    let (true, Some(layout), Some(transa), Some(transb)) = (legal, layout, transa, transb) else { return };
+    if m == 0 || n == 0 || ((alpha.is_zero() || k == 0) && beta == F::one()) {
+        return;
+    }
+    let a = matrix(layout, rows_a, cols_a, a, lda);
+    let b = matrix(layout, rows_b, cols_b, b, ldb);
+    let c = matrix_mut(layout, m, n, c, ldc);
+    let task = GEMM::<F>::default().a(a).b(b).c(c).alpha(alpha).beta(beta).transa(transa).transb(transb);
+    report(name, task.layout(layout).run());
+}
+
+/* #endregion */
+
+/* #region symmetric and hermitian */
+
+pub unsafe fn symm<F: SYMMNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    b: *const F,
+    ldb: blas_int,
+    beta: F,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (layout, side, uplo) = (parse_order(order), parse_side(side), parse_uplo(uplo));
+    let ka = dim_side(side, m, n);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (side.is_some(), 2), (uplo.is_some(), 3), (m >= 0, 4), (n >= 0, 5),
+        (lda >= ka.max(1), 8), (ldb >= ld_min(layout, m, n), 10), (ldc >= ld_min(layout, m, n), 13),
+    ]);
+    let (true, Some(layout), Some(side), Some(uplo)) = (legal, layout, side, uplo) else { return };
+    if m == 0 || n == 0 || (alpha.is_zero() && beta == F::one()) {
+        return;
+    }
+    let a = matrix(layout, ka, ka, a, lda);
+    let b = matrix(layout, m, n, b, ldb);
+    let c = matrix_mut(layout, m, n, c, ldc);
+    let task = SYMM::<F>::default().a(a).b(b).c(c).alpha(alpha).beta(beta).side(side).uplo(uplo);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn hemm<F: HEMMNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    m: blas_int,
+    n: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    b: *const F,
+    ldb: blas_int,
+    beta: F,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (layout, side, uplo) = (parse_order(order), parse_side(side), parse_uplo(uplo));
+    let ka = dim_side(side, m, n);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (side.is_some(), 2), (uplo.is_some(), 3), (m >= 0, 4), (n >= 0, 5),
+        (lda >= ka.max(1), 8), (ldb >= ld_min(layout, m, n), 10), (ldc >= ld_min(layout, m, n), 13),
+    ]);
+    let (true, Some(layout), Some(side), Some(uplo)) = (legal, layout, side, uplo) else { return };
+    if m == 0 || n == 0 || (alpha.is_zero() && beta == F::one()) {
+        return;
+    }
+    let a = matrix(layout, ka, ka, a, lda);
+    let b = matrix(layout, m, n, b, ldb);
+    let c = matrix_mut(layout, m, n, c, ldc);
+    let task = HEMM::<F>::default().a(a).b(b).c(c).alpha(alpha).beta(beta).side(side).uplo(uplo);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn syrk<F: SYRKNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    beta: F,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (layout, uplo, trans) = (parse_order(order), parse_uplo(uplo), parse_trans(trans));
+    let (rows_a, cols_a) = shape_trans(trans, n, k);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (n >= 0, 4), (k >= 0, 5),
+        (lda >= ld_min(layout, rows_a, cols_a), 8), (ldc >= n.max(1), 11),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans)) = (legal, layout, uplo, trans) else { return };
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta == F::one()) {
+        return;
+    }
+    let a = matrix(layout, rows_a, cols_a, a, lda);
+    let c = matrix_mut(layout, n, n, c, ldc);
+    let task = SYRK::<F>::default().a(a).c(c).alpha(alpha).beta(beta).uplo(uplo).trans(trans);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn herk<F: HERKNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: F::RealFloat,
+    a: *const F,
+    lda: blas_int,
+    beta: F::RealFloat,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (layout, uplo, trans) = (parse_order(order), parse_uplo(uplo), parse_trans(trans));
+    let (rows_a, cols_a) = shape_trans(trans, n, k);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (n >= 0, 4), (k >= 0, 5),
+        (lda >= ld_min(layout, rows_a, cols_a), 8), (ldc >= n.max(1), 11),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans)) = (legal, layout, uplo, trans) else { return };
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta == F::RealFloat::one()) {
+        return;
+    }
+    let a = matrix(layout, rows_a, cols_a, a, lda);
+    let c = matrix_mut(layout, n, n, c, ldc);
+    let task = HERK::<F>::default().a(a).c(c).alpha(alpha).beta(beta).uplo(uplo).trans(trans);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn syr2k<F: SYR2KNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    b: *const F,
+    ldb: blas_int,
+    beta: F,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (layout, uplo, trans) = (parse_order(order), parse_uplo(uplo), parse_trans(trans));
+    let (rows_a, cols_a) = shape_trans(trans, n, k);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (n >= 0, 4), (k >= 0, 5),
+        (lda >= ld_min(layout, rows_a, cols_a), 8), (ldb >= ld_min(layout, rows_a, cols_a), 10),
+        (ldc >= n.max(1), 13),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans)) = (legal, layout, uplo, trans) else { return };
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta == F::one()) {
+        return;
+    }
+    let a = matrix(layout, rows_a, cols_a, a, lda);
+    let b = matrix(layout, rows_a, cols_a, b, ldb);
+    let c = matrix_mut(layout, n, n, c, ldc);
+    let task = SYR2K::<F>::default().a(a).b(b).c(c).alpha(alpha).beta(beta).uplo(uplo).trans(trans);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn her2k<F: HER2KNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: blas_int,
+    k: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    b: *const F,
+    ldb: blas_int,
+    beta: F::RealFloat,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (layout, uplo, trans) = (parse_order(order), parse_uplo(uplo), parse_trans(trans));
+    let (rows_a, cols_a) = shape_trans(trans, n, k);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (uplo.is_some(), 2), (trans.is_some(), 3), (n >= 0, 4), (k >= 0, 5),
+        (lda >= ld_min(layout, rows_a, cols_a), 8), (ldb >= ld_min(layout, rows_a, cols_a), 10),
+        (ldc >= n.max(1), 13),
+    ]);
+    let (true, Some(layout), Some(uplo), Some(trans)) = (legal, layout, uplo, trans) else { return };
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta == F::RealFloat::one()) {
+        return;
+    }
+    let a = matrix(layout, rows_a, cols_a, a, lda);
+    let b = matrix(layout, rows_a, cols_a, b, ldb);
+    let c = matrix_mut(layout, n, n, c, ldc);
+    let task = HER2K::<F>::default().a(a).b(b).c(c).alpha(alpha).beta(beta).uplo(uplo).trans(trans);
+    report(name, task.layout(layout).run());
+}
+
+/* #endregion */
+
+/* #region triangular */
+
+pub unsafe fn trmm<F: TRMMNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    b: *mut F,
+    ldb: blas_int,
+) {
+    let (layout, side, uplo) = (parse_order(order), parse_side(side), parse_uplo(uplo));
+    let (transa, diag) = (parse_trans(transa), parse_diag(diag));
+    let ka = dim_side(side, m, n);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (side.is_some(), 2), (uplo.is_some(), 3), (transa.is_some(), 4),
+        (diag.is_some(), 5), (m >= 0, 6), (n >= 0, 7), (lda >= ka.max(1), 10), (ldb >= ld_min(layout, m, n), 12),
+    ]);
+    let (true, Some(layout), Some(side), Some(uplo), Some(transa), Some(diag)) =
+        (legal, layout, side, uplo, transa, diag)
+    else {
+        return;
+    };
+    if m == 0 || n == 0 {
+        return;
+    }
+    let a = matrix(layout, ka, ka, a, lda);
+    let b = matrix_mut(layout, m, n, b, ldb);
+    let task = TRMM::<F>::default().a(a).b(b).alpha(alpha).side(side).uplo(uplo).transa(transa).diag(diag);
+    report(name, task.layout(layout).run());
+}
+
+pub unsafe fn trsm<F: TRSMNum>(
+    name: &str,
+    order: CBLAS_ORDER,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blas_int,
+    n: blas_int,
+    alpha: F,
+    a: *const F,
+    lda: blas_int,
+    b: *mut F,
+    ldb: blas_int,
+) {
+    let (layout, side, uplo) = (parse_order(order), parse_side(side), parse_uplo(uplo));
+    let (transa, diag) = (parse_trans(transa), parse_diag(diag));
+    let ka = dim_side(side, m, n);
+    #[rustfmt::skip]
+    let legal = check_args(name, &[
+        (layout.is_some(), 1), (side.is_some(), 2), (uplo.is_some(), 3), (transa.is_some(), 4),
+        (diag.is_some(), 5), (m >= 0, 6), (n >= 0, 7), (lda >= ka.max(1), 10), (ldb >= ld_min(layout, m, n), 12),
+    ]);
+    let (true, Some(layout), Some(side), Some(uplo), Some(transa), Some(diag)) =
+        (legal, layout, side, uplo, transa, diag)
+    else {
+        return;
+    };
+    if m == 0 || n == 0 {
+        return;
+    }
+    let a = matrix(layout, ka, ka, a, lda);
+    let b = matrix_mut(layout, m, n, b, ldb);
+    let task = TRSM::<F>::default().a(a).b(b).alpha(alpha).side(side).uplo(uplo).transa(transa).diag(diag);
+    report(name, task.layout(layout).run());
+}
+
+/* #endregion */
@@ -0,0 +1,193 @@
+//! CBLAS-compatible C ABI of blas-array2.
+//!
+//! This crate builds a `cdylib`/`staticlib` exporting standard CBLAS functions (`cblas_dgemm`, `cblas_zherk`,
+//! `cblas_ddot`, etc.), declared in the generated header `include/cblas.h`. Functions are implemented by wrappers of
+//! blas-array2: row-major calls are performed by the same flipping logic of each `*_Builder::run`, so only a Fortran
+//! 77 BLAS (such as Debian's plain `libblas.so`) is required.
+//!
+//! Semantics follow netlib reference CBLAS:
+//! - illegal arguments (invalid enum values, negative dimensions, too small leading dimensions or zero increments
+//!   of output vectors) are reported as `Parameter {pos} to routine {name} was incorrect` on stderr, where `pos`
+//!   counts `Order` as the first parameter; the call then returns without touching output (the process is not
+//!   aborted);
+//! - quick returns (such as `m == 0`) do not touch output;
+//! - negative increments traverse vectors backwards from the last element;
+//! - `cblas_i?amax` returns 0-based index, and 0 for empty vector;
+//! - errors of wrappers that are not argument errors are reported as `Error in routine {name}: {error}`.
+//!
+//! Unlike reference CBLAS, zero increment of an output vector is rejected, since the output can not be written
+//! consistently.
+//!
+//! Integers are 32-bit `CBLAS_INT` by default; with crate feature `ilp64`, they are 64-bit, and C code should
+//! define `BLAS_ARRAY2_ILP64` before including `cblas.h`.
+//!
+//! Entry functions in `entry.rs` and header `include/cblas.h` are generated by `scripts/cblas_gen.py`.
+//!
+//! blas-array2 should not be built with crate feature `cblas` here, since the wrappers would then call the
+//! symbols exported by this crate recursively; this is checked at compile time.
+
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::missing_safety_doc)]
+
+pub mod entry;
+pub mod level1;
+pub mod level2;
+pub mod level3;
+
+use blas_array2::ffi::blas_int;
+use blas_array2::util::*;
+use core::ffi::c_int;
+use core::ptr::NonNull;
+use ndarray::prelude::*;
+
+const _: () = assert!(
+    !blas_array2::ffi::USE_CBLAS,
+    "blas-array2 with feature `cblas` would call this crate recursively"
+);
+
+/* #region enums */
+
+pub type CBLAS_ORDER = c_int;
+pub type CBLAS_LAYOUT = CBLAS_ORDER;
+pub type CBLAS_TRANSPOSE = c_int;
+pub type CBLAS_UPLO = c_int;
+pub type CBLAS_DIAG = c_int;
+pub type CBLAS_SIDE = c_int;
+pub type CBLAS_INDEX = usize;
+
+pub const CblasRowMajor: CBLAS_ORDER = 101;
+pub const CblasColMajor: CBLAS_ORDER = 102;
+pub const CblasNoTrans: CBLAS_TRANSPOSE = 111;
+pub const CblasTrans: CBLAS_TRANSPOSE = 112;
+pub const CblasConjTrans: CBLAS_TRANSPOSE = 113;
+pub const CblasUpper: CBLAS_UPLO = 121;
+pub const CblasLower: CBLAS_UPLO = 122;
+pub const CblasNonUnit: CBLAS_DIAG = 131;
+pub const CblasUnit: CBLAS_DIAG = 132;
+pub const CblasLeft: CBLAS_SIDE = 141;
+pub const CblasRight: CBLAS_SIDE = 142;
+
+// values of CBLAS enums are the same to those of flags in blas-array2
+
+pub(crate) fn parse_order(order: CBLAS_ORDER) -> Option<BLASLayout> {
+    return [BLASRowMajor, BLASColMajor].into_iter().find(|&f| f as c_int == order);
+}
+
+pub(crate) fn parse_trans(trans: CBLAS_TRANSPOSE) -> Option<BLASTranspose> {
+    return [BLASNoTrans, BLASTrans, BLASConjTrans].into_iter().find(|&f| f as c_int == trans);
+}
+
+pub(crate) fn parse_uplo(uplo: CBLAS_UPLO) -> Option<BLASUpLo> {
+    return [BLASUpper, BLASLower].into_iter().find(|&f| f as c_int == uplo);
+}
+
+pub(crate) fn parse_diag(diag: CBLAS_DIAG) -> Option<BLASDiag> {
+    return [BLASNonUnit, BLASUnit].into_iter().find(|&f| f as c_int == diag);
+}
+
+pub(crate) fn parse_side(side: CBLAS_SIDE) -> Option<BLASSide> {
+    return [BLASLeft, BLASRight].into_iter().find(|&f| f as c_int == side);
+}
+
+/* #endregion */
+
+/* #region error report */
+
+/// Check arguments by pairs of (legal, position); report the first illegal argument as `cblas_xerbla` does.
+///
+/// Returns whether all arguments are legal.
+pub(crate) fn check_args(name: &str, checks: &[(bool, usize)]) -> bool {
+    match checks.iter().find(|(legal, _)| !legal) {
+        Some((_, pos)) => {
+            eprintln!("Parameter {pos} to routine {name} was incorrect");
+            return false;
+        },
+        None => return true,
+    }
+}
+
+/// Report error of wrapper; returns the result if succeeded.
+pub(crate) fn report<T>(name: &str, result: Result<T, BLASError>) -> Option<T> {
+    match result {
+        Ok(result) => return Some(result),
+        Err(err) => {
+            eprintln!("Error in routine {name}: {err}");
+            return None;
+        },
+    }
+}
+
+/// Minimum leading dimension of `rows x cols` matrix stored in `layout`.
+pub(crate) fn ld_min(layout: Option<BLASLayout>, rows: blas_int, cols: blas_int) -> blas_int {
+    match layout {
+        Some(BLASRowMajor) => return cols.max(1),
+        _ => return rows.max(1),
+    }
+}
+
+/* #endregion */
+
+/* #region array views */
+
+/// Vector of `n` elements by pointer and increment of BLAS convention.
+///
+/// For negative increment, the first element is at `x + (n - 1) |inc|`.
+pub(crate) unsafe fn vector<'a, F>(n: blas_int, x: *const F, inc: blas_int) -> ArrayView1<'a, F> {
+    let n = n.max(0) as usize;
+    // pointer should not be null for ndarray, even if array is empty
+    let x = if n == 0 { NonNull::dangling().as_ptr() } else { x };
+    let mut view = ArrayView1::from_shape_ptr([n].strides([inc.unsigned_abs() as usize]), x);
+    if inc < 0 {
+        view.invert_axis(Axis(0));
+    }
+    return view;
+}
+
+/// Mutable vector of `n` elements by pointer and increment of BLAS convention.
+pub(crate) unsafe fn vector_mut<'a, F>(n: blas_int, x: *mut F, inc: blas_int) -> ArrayViewMut1<'a, F> {
+    let n = n.max(0) as usize;
+    let x = if n == 0 { NonNull::dangling().as_ptr() } else { x };
+    let mut view = ArrayViewMut1::from_shape_ptr([n].strides([inc.unsigned_abs() as usize]), x);
+    if inc < 0 {
+        view.invert_axis(Axis(0));
+    }
+    return view;
+}
+
+/// `rows x cols` matrix by pointer and leading dimension, stored in `layout` (row-major or col-major).
+pub(crate) unsafe fn matrix<'a, F>(
+    layout: BLASLayout,
+    rows: blas_int,
+    cols: blas_int,
+    a: *const F,
+    ld: blas_int,
+) -> ArrayView2<'a, F> {
+    let (rows, cols, ld) = (rows.max(0) as usize, cols.max(0) as usize, ld.max(0) as usize);
+    let a = if rows == 0 || cols == 0 { NonNull::dangling().as_ptr() } else { a };
+    let strides = match layout {
+        BLASRowMajor => [ld, 1],
+        _ => [1, ld],
+    };
+    return ArrayView2::from_shape_ptr([rows, cols].strides(strides), a);
+}
+
+/// Mutable `rows x cols` matrix by pointer and leading dimension, stored in `layout`.
+pub(crate) unsafe fn matrix_mut<'a, F>(
+    layout: BLASLayout,
+    rows: blas_int,
+    cols: blas_int,
+    a: *mut F,
+    ld: blas_int,
+) -> ArrayViewMut2<'a, F> {
+    let (rows, cols, ld) = (rows.max(0) as usize, cols.max(0) as usize, ld.max(0) as usize);
+    let a = if rows == 0 || cols == 0 { NonNull::dangling().as_ptr() } else { a };
+    let strides = match layout {
+        BLASRowMajor => [ld, 1],
+        _ => [1, ld],
+    };
+    return ArrayViewMut2::from_shape_ptr([rows, cols].strides(strides), a);
+}
+
+/* #endregion */
@@ -0,0 +1,416 @@
+//! Tests of exported CBLAS functions, against naive implementations in both row-major and col-major.
+//!
+//! Note that BLAS library linked may also export `cblas_*` symbols; functions of this crate are called through
+//! `blas_array2_cblas::entry`, so results are not compared to other CBLAS.
+
+#[path = "../../tests/util.rs"]
+#[allow(dead_code, unused_imports)]
+mod util;
+
+use blas_array2::prelude::*;
+use blas_array2_cblas::entry::*;
+use blas_array2_cblas::*;
+use itertools::iproduct;
+use ndarray::prelude::*;
+use util::*;
+
+/// Storage of `rows x cols` matrix with leading dimension `ld` (padded), in row-major or col-major.
+///
+/// The matrix is `storage.slice(s![..rows, ..cols])`, and `storage.as_ptr()` is pointer of CBLAS convention.
+fn storage<F: TestFloat>(rows: usize, cols: usize, ld: usize, layout: char) -> Array2<F> {
+    match layout {
+        'R' => random_matrix(rows, ld, 'R'.into()),
+        _ => random_matrix(ld, cols, 'C'.into()),
+    }
+}
+
+fn order(layout: char) -> CBLAS_ORDER {
+    match layout {
+        'R' => CblasRowMajor,
+        _ => CblasColMajor,
+    }
+}
+
+fn flag(c: char) -> core::ffi::c_int {
+    match c {
+        'N' => CblasNoTrans,
+        'T' => CblasTrans,
+        'C' => CblasConjTrans,
+        'U' => CblasUpper,
+        'L' => CblasLower,
+        'u' => CblasUnit,
+        'n' => CblasNonUnit,
+        'l' => CblasLeft,
+        'r' => CblasRight,
+        _ => panic!("invalid flag"),
+    }
+}
+
+#[cfg(test)]
+mod level1 {
+    use super::*;
+
+    #[test]
+    fn test_dot() {
+        let x_raw = random_array::<f64>(100);
+        let y_raw = random_array::<f64>(100);
+        let x = x_raw.slice(s![..21;2]);
+        let y = y_raw.slice(s![..31;-3]);
+        // negative increment starts from the last element
+        let y_ptr = y_raw.as_ptr();
+        let result = unsafe { cblas_ddot(11, x.as_ptr(), 2, y_ptr, -3) };
+        assert!((result - x.dot(&y)).abs() < 1e-12 * x.dot(&y).abs());
+
+        let x_raw = random_array::<c64>(100);
+        let y_raw = random_array::<c64>(100);
+        let x = x_raw.slice(s![..11;-1]);
+        let y = y_raw.slice(s![..33;3]);
+        let mut dotc = c64::new(0.0, 0.0);
+        unsafe { cblas_zdotc_sub(11, x_raw.as_ptr(), -1, y_raw.as_ptr(), 3, &mut dotc) };
+        let naive = x.mapv(|x| x.conj()).dot(&y);
+        assert!((dotc - naive).norm() < 1e-12 * naive.norm());
+
+        // quick return
+        assert_eq!(unsafe { cblas_ddot(0, x_raw.as_ptr() as *const f64, 1, y_ptr, 1) }, 0.0);
+    }
+
+    #[test]
+    fn test_norm_index() {
+        let mut x = random_array::<f64>(30);
+        x[[12]] = -10.0;
+        x[[14]] = 10.0;
+        assert_eq!(unsafe { cblas_idamax(30, x.as_ptr(), 1) }, 12);
+        assert_eq!(unsafe { cblas_idamax(15, x.as_ptr(), 2) }, 6);
+        assert_eq!(unsafe { cblas_idamax(0, x.as_ptr(), 1) }, 0);
+        assert_eq!(unsafe { cblas_idamax(30, x.as_ptr(), -1) }, 0);
+
+        let naive = x.slice(s![..;3]).mapv(|x| x * x).sum().sqrt();
+        assert!((unsafe { cblas_dnrm2(10, x.as_ptr(), 3) } - naive).abs() < 1e-12 * naive);
+        assert_eq!(unsafe { cblas_dnrm2(10, x.as_ptr(), 0) }, 0.0);
+        assert_eq!(unsafe { cblas_dasum(10, x.as_ptr(), -3) }, 0.0);
+    }
+
+    #[test]
+    fn test_axpy_scal() {
+        let alpha = c64::rand();
+        let x_raw = random_array::<c64>(100);
+        let mut y_raw = random_array::<c64>(100);
+        let x = x_raw.slice(s![..20;2]);
+        let y_naive = alpha * &x.slice(s![..;-1]) + y_raw.slice(s![..10]);
+        // x traversed backwards is equivalent to y traversed backwards
+        unsafe {
+            cblas_zaxpy(10, &alpha as *const c64 as *const _, x_raw.as_ptr(), 2, y_raw.as_mut_ptr(), -1)
+        };
+        check_same(&y_raw.slice(s![..10]), &y_naive.view(), 4.0 * f64::EPSILON);
+
+        let y_naive = c64::from(0.5) * &y_raw.slice(s![..30;3]);
+        unsafe { cblas_zdscal(10, 0.5, y_raw.as_mut_ptr(), 3) };
+        check_same(&y_raw.slice(s![..30;3]), &y_naive.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rotg() {
+        for (a, b) in [(3.0, 4.0), (4.0, 3.0), (0.0, 2.0), (2.0, 0.0), (-1.5, 0.5)] {
+            let (mut a_, mut b_, mut c, mut s) = (a, b, 0.0, 0.0);
+            unsafe { cblas_drotg(&mut a_, &mut b_, &mut c, &mut s) };
+            let r = a_;
+            assert!((c * a + s * b - r).abs() < 1e-14);
+            assert!((-s * a + c * b).abs() < 1e-14);
+            // reconstruction of c and s from z
+            let z = b_;
+            let (c_, s_) = if z == 1.0 {
+                (0.0, 1.0)
+            } else if z.abs() < 1.0 {
+                ((1.0 - z * z).sqrt(), z)
+            } else {
+                (1.0 / z, (1.0 - 1.0 / (z * z)).sqrt())
+            };
+            assert!((c - c_).abs() < 1e-14 && (s - s_).abs() < 1e-14);
+        }
+    }
+}
+
+#[cfg(test)]
+mod level2 {
+    use super::*;
+
+    #[test]
+    fn test_gemv() {
+        for (layout, trans) in iproduct!(['R', 'C'], ['N', 'T', 'C']) {
+            let (m, n) = (8, 6);
+            let alpha = c64::rand();
+            let beta = c64::rand();
+            let a_raw = storage::<c64>(m, n, 10, layout);
+            let a = a_raw.slice(s![..m, ..n]);
+            let (len_x, len_y) = if trans == 'N' { (n, m) } else { (m, n) };
+            let x_raw = random_array::<c64>(3 * len_x);
+            let mut y_raw = random_array::<c64>(2 * len_y);
+            let x = x_raw.slice(s![..3 * len_x - 2;-3]);
+            let y_naive =
+                alpha * gemv(&transpose(&a, trans.into()).view(), &x) + beta * &y_raw.slice(s![..;2]);
+            unsafe {
+                cblas_zgemv(
+                    order(layout),
+                    flag(trans),
+                    m as blas_int,
+                    n as blas_int,
+                    &alpha as *const c64 as *const _,
+                    a_raw.as_ptr() as *const _,
+                    10,
+                    x_raw.as_ptr() as *const _,
+                    -3,
+                    &beta as *const c64 as *const _,
+                    y_raw.as_mut_ptr() as *mut _,
+                    2,
+                )
+            };
+            check_same(&y_raw.slice(s![..;2]), &y_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_gbmv() {
+        for (layout, trans) in iproduct!(['R', 'C'], ['N', 'T']) {
+            let (m, n, kl, ku) = (8, 7, 2, 1);
+            // banded matrix, and band storage of CBLAS convention
+            let mut a = Array2::<f64>::zeros((m, n));
+            let mut ab = storage::<f64>(m.max(n), m.max(n), kl + ku + 2, layout);
+            for (i, j) in iproduct!(0..m, 0..n) {
+                if i + ku >= j && j + kl >= i {
+                    a[[i, j]] = f64::rand();
+                    match layout {
+                        'R' => ab[[i, kl + j - i]] = a[[i, j]],
+                        _ => ab[[ku + i - j, j]] = a[[i, j]],
+                    }
+                }
+            }
+            let (len_x, len_y) = if trans == 'N' { (n, m) } else { (m, n) };
+            let x = random_array::<f64>(len_x);
+            let mut y = random_array::<f64>(len_y);
+            let y_naive = 2.0 * gemv(&transpose(&a.view(), trans.into()).view(), &x.view()) + 0.5 * &y;
+            unsafe {
+                cblas_dgbmv(
+                    order(layout),
+                    flag(trans),
+                    m as blas_int,
+                    n as blas_int,
+                    kl as blas_int,
+                    ku as blas_int,
+                    2.0,
+                    ab.as_ptr(),
+                    (kl + ku + 2) as blas_int,
+                    x.as_ptr(),
+                    1,
+                    0.5,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            };
+            check_same(&y.view(), &y_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_tpmv() {
+        for (layout, uplo, trans, diag) in iproduct!(['R', 'C'], ['U', 'L'], ['N', 'T', 'C'], ['n', 'u']) {
+            let n = 7;
+            let ap = random_array::<c64>(n * (n + 1) / 2);
+            let mut a = Array2::<c64>::zeros((n, n));
+            unpack_tril(&ap.view(), &mut a.view_mut(), layout, uplo);
+            if diag == 'u' {
+                a.diag_mut().fill(c64::new(1.0, 0.0));
+            }
+            let mut x_raw = random_array::<c64>(2 * n);
+            let x_naive = gemv(&transpose(&a.view(), trans.into()).view(), &x_raw.slice(s![..;2]));
+            unsafe {
+                cblas_ztpmv(
+                    order(layout),
+                    flag(uplo),
+                    flag(trans),
+                    flag(diag),
+                    n as blas_int,
+                    ap.as_ptr() as *const _,
+                    x_raw.as_mut_ptr() as *mut _,
+                    2,
+                )
+            };
+            check_same(&x_raw.slice(s![..;2]), &x_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_her() {
+        for (layout, uplo) in iproduct!(['R', 'C'], ['U', 'L']) {
+            let n = 6;
+            let mut a_raw = storage::<c64>(n, n, 9, layout);
+            let x = random_array::<c64>(n);
+            let xxh = gemm(&x.view().insert_axis(Axis(1)), &x.mapv(|x| x.conj()).view().insert_axis(Axis(0)));
+            let mut a_naive = a_raw.slice(s![..n, ..n]).into_owned();
+            let a_updated = &a_naive + c64::from(0.5) * xxh;
+            tril_assign(&mut a_naive.view_mut(), &a_updated.view(), uplo);
+            unsafe {
+                cblas_zher(
+                    order(layout),
+                    flag(uplo),
+                    n as blas_int,
+                    0.5,
+                    x.as_ptr() as *const _,
+                    1,
+                    a_raw.as_mut_ptr() as *mut _,
+                    9,
+                )
+            };
+            // imaginary part of diagonal is not compared, which is implementation-defined
+            let mut a_out = a_raw.slice(s![..n, ..n]).into_owned();
+            a_out.diag_mut().mapv_inplace(|x| c64::new(x.re, 0.0));
+            a_naive.diag_mut().mapv_inplace(|x| c64::new(x.re, 0.0));
+            check_same(&a_out.view(), &a_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod level3 {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        for (layout, transa, transb) in iproduct!(['R', 'C'], ['N', 'T', 'C'], ['N', 'T', 'C']) {
+            let (m, n, k) = (7, 9, 5);
+            let alpha = c64::rand();
+            let beta = c64::rand();
+            let (rows_a, cols_a) = if transa == 'N' { (m, k) } else { (k, m) };
+            let (rows_b, cols_b) = if transb == 'N' { (k, n) } else { (n, k) };
+            let a_raw = storage::<c64>(rows_a, cols_a, 12, layout);
+            let b_raw = storage::<c64>(rows_b, cols_b, 13, layout);
+            let mut c_raw = storage::<c64>(m, n, 14, layout);
+            let a = a_raw.slice(s![..rows_a, ..cols_a]);
+            let b = b_raw.slice(s![..rows_b, ..cols_b]);
+            let ab = gemm(&transpose(&a, transa.into()).view(), &transpose(&b, transb.into()).view());
+            let c_naive = alpha * ab + beta * &c_raw.slice(s![..m, ..n]);
+            unsafe {
+                cblas_zgemm(
+                    order(layout),
+                    flag(transa),
+                    flag(transb),
+                    m as blas_int,
+                    n as blas_int,
+                    k as blas_int,
+                    &alpha as *const c64 as *const _,
+                    a_raw.as_ptr() as *const _,
+                    12,
+                    b_raw.as_ptr() as *const _,
+                    13,
+                    &beta as *const c64 as *const _,
+                    c_raw.as_mut_ptr() as *mut _,
+                    14,
+                )
+            };
+            check_same(&c_raw.slice(s![..m, ..n]), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_herk() {
+        for (layout, uplo, trans) in iproduct!(['R', 'C'], ['U', 'L'], ['N', 'C']) {
+            let (n, k) = (7, 5);
+            let (rows_a, cols_a) = if trans == 'N' { (n, k) } else { (k, n) };
+            let a_raw = storage::<c64>(rows_a, cols_a, 10, layout);
+            let mut c_raw = storage::<c64>(n, n, 11, layout);
+            let a = a_raw.slice(s![..rows_a, ..cols_a]);
+            let a = transpose(&a, trans.into());
+            let aah = gemm(&a.view(), &transpose(&a.view(), BLASConjTrans).view());
+            let mut c_naive = c_raw.slice(s![..n, ..n]).into_owned();
+            let c_updated = c64::from(2.0) * aah + c64::from(0.5) * &c_naive;
+            tril_assign(&mut c_naive.view_mut(), &c_updated.view(), uplo);
+            unsafe {
+                cblas_zherk(
+                    order(layout),
+                    flag(uplo),
+                    flag(trans),
+                    n as blas_int,
+                    k as blas_int,
+                    2.0,
+                    a_raw.as_ptr() as *const _,
+                    10,
+                    0.5,
+                    c_raw.as_mut_ptr() as *mut _,
+                    11,
+                )
+            };
+            let mut c_out = c_raw.slice(s![..n, ..n]).into_owned();
+            c_out.diag_mut().mapv_inplace(|x| c64::new(x.re, 0.0));
+            c_naive.diag_mut().mapv_inplace(|x| c64::new(x.re, 0.0));
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_trsm() {
+        for (layout, side, uplo, transa, diag) in
+            iproduct!(['R', 'C'], ['l', 'r'], ['U', 'L'], ['N', 'T'], ['n', 'u'])
+        {
+            let (m, n) = (6, 8);
+            let ka = if side == 'l' { m } else { n };
+            // well-conditioned triangular matrix
+            let mut a_raw = storage::<f64>(ka, ka, 10, layout);
+            a_raw.slice_mut(s![..ka, ..ka]).diag_mut().mapv_inplace(|x| x + ka as f64);
+            let mut a = Array2::<f64>::zeros((ka, ka));
+            tril_assign(&mut a.view_mut(), &a_raw.slice(s![..ka, ..ka]), uplo);
+            if diag == 'u' {
+                a.diag_mut().fill(1.0);
+            }
+            let mut b_raw = storage::<f64>(m, n, 11, layout);
+            let b = b_raw.slice(s![..m, ..n]).into_owned();
+            unsafe {
+                cblas_dtrsm(
+                    order(layout),
+                    flag(side),
+                    flag(uplo),
+                    flag(transa),
+                    flag(diag),
+                    m as blas_int,
+                    n as blas_int,
+                    2.0,
+                    a_raw.as_ptr(),
+                    10,
+                    b_raw.as_mut_ptr(),
+                    11,
+                )
+            };
+            // op(A) X = alpha B or X op(A) = alpha B
+            let x = b_raw.slice(s![..m, ..n]);
+            let op_a = transpose(&a.view(), transa.into());
+            let ax = if side == 'l' { gemm(&op_a.view(), &x) } else { gemm(&x, &op_a.view()) };
+            check_same(&ax.view(), &(2.0 * b).view(), 16.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_illegal_args() {
+        // output is not touched if any argument is illegal
+        let a = random_matrix::<f64>(5, 5, 'C'.into());
+        let mut c = random_matrix::<f64>(5, 5, 'C'.into());
+        let c_raw = c.clone();
+        let (pa, pc) = (a.as_ptr(), c.as_mut_ptr());
+        unsafe {
+            // lda too small for row-major
+            cblas_dgemm(CblasRowMajor, CblasNoTrans, CblasTrans, 5, 5, 5, 1.0, pa, 4, pa, 5, 0.0, pc, 5);
+            // invalid order
+            cblas_dgemm(0, CblasNoTrans, CblasNoTrans, 5, 5, 5, 1.0, pa, 5, pa, 5, 0.0, pc, 5);
+            // invalid trans
+            cblas_dgemm(CblasColMajor, CblasUpper, CblasNoTrans, 5, 5, 5, 1.0, pa, 5, pa, 5, 0.0, pc, 5);
+            // negative dimension
+            cblas_dsyrk(CblasColMajor, CblasUpper, CblasNoTrans, -1, 5, 1.0, pa, 5, 0.0, pc, 5);
+            // zero increment of output vector
+            cblas_daxpy(5, 1.0, pa, 1, pc, 0);
+            cblas_dgemv(CblasColMajor, CblasNoTrans, 5, 5, 1.0, pa, 5, pa, 1, 0.0, pc, 0);
+        }
+        assert_eq!(c, c_raw);
+    }
+}
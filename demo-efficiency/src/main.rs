@@ -2,11 +2,14 @@
 
 mod dgemm;
 mod dsyrk;
+mod transpose;
 
 fn main() {
     println!(">>> bench_dgemm <<<");
     dgemm::bench_dgemm();
     println!(">>> bench_dsyrk <<<");
     dsyrk::bench_dsyrk();
+    println!(">>> bench_transpose <<<");
+    transpose::bench_transpose();
 }
 
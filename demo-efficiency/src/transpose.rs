@@ -0,0 +1,53 @@
+#![allow(non_upper_case_globals)]
+
+use ndarray::prelude::*;
+use blas_array2::prelude::*;
+use std::time::Instant;
+use rand::{thread_rng, Rng};
+
+pub fn bench_transpose() {
+    let mut a_raw = Array2::<c64>::zeros((m, 2 * k));
+
+    // random initialize
+    a_raw.mapv_inplace(| _ | c64::new(thread_rng().gen(), thread_rng().gen()));
+    println!("random initialize finished");
+
+    // tall-skinny matrix, neither row-major nor col-major
+    let a = a_raw.slice(s![.., ..;2]);
+    let a_col = a.to_col_layout().unwrap().into_owned();
+
+    bench("copy_naive", 10, || { a.t().into_owned().reversed_axes().sum() });
+    bench("copy_conj_naive", 10, || { a.t().mapv(|x| x.conj()).reversed_axes().sum() });
+    bench("copy_blocked", 10, || { a.to_col_layout().unwrap().sum() });
+    bench("zgemm_contiguous", 10, || {
+        ZGEMM::default().a(a_col.view()).b(a_col.view()).transa('C').run().unwrap().into_owned().sum()
+    });
+    bench("zgemm_non_contiguous", 10, || {
+        ZGEMM::default().a(a.view()).b(a_col.view()).transa('C').run().unwrap().into_owned().sum()
+    });
+}
+
+/// matrix dimension (m x k), tall-skinny
+static m: usize = 1000000;
+static k: usize = 16;
+
+fn bench(name: &str, ntest: usize, f: impl Fn() -> c64) {
+    let mut time_list: Vec<f64> = vec![];
+    let mut check = c64::new(0.0, 0.0);
+
+    for _ in 0..ntest {
+        let now = Instant::now();
+
+        check += f();
+
+        let elapsed = now.elapsed();
+        time_list.push(elapsed.as_secs_f64());
+    }
+
+    let time_sum: f64 = time_list.iter().sum();
+    let time_avg: f64 = time_sum / ntest as f64;
+    let time_std: f64 = (time_list.iter().map(|x| (x - time_avg).powi(2)).sum::<f64>() / ntest as f64).sqrt();
+    println!("== bench_{name} ==");
+    println!("time for one test : {:.3} ± {:.3} msec", time_avg * 1000., time_std * 1000.);
+    println!("check_sum: {:.6}", check / ntest as f64);
+}
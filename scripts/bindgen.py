@@ -1,5 +1,6 @@
 # # Bindgen of `f77blas.h`
 
+import re
 import subprocess
 
 # ## Pre-process
@@ -61,9 +62,12 @@ with open("blas.rs", "r") as f:
 # hardcode blasint
 token = token.replace("pub type blas_int = ::core::ffi::c_int;\n", "")
 token = token.replace("::core::ffi::c_char", "c_char")
+# symbol naming convention (suffix `64_`, `_64`, custom prefix) is given by `link_name`
+token = re.sub(r"\n    pub fn (\w+)_\(", lambda m: f'\n    #[link_name = blas_symbol!("{m.group(1)}")]\n    pub fn {m.group(1)}_(', token)
 token = """
 #![allow(non_camel_case_types)]
 
+use super::blas_symbol;
 use num_complex::*;
 use core::ffi::c_char;
 
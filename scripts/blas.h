@@ -301,3 +301,34 @@ void zgemmt_(const char *uplo, const char *transa, const char *transb, const BLA
 
 #pragma endregion
 
+
+#pragma region Level3 BLAS gemm_batch_strided
+
+void sgemm_batch_strided_(const char *transa, const char *transb, const BLAS_INT *m, const BLAS_INT *n, const BLAS_INT *k, const float *alpha, const float *a, const BLAS_INT *lda, const BLAS_INT *stridea, const float *b, const BLAS_INT *ldb, const BLAS_INT *strideb, const float *beta, float *c, const BLAS_INT *ldc, const BLAS_INT *stridec, const BLAS_INT *batch_size);
+void cgemm_batch_strided_(const char *transa, const char *transb, const BLAS_INT *m, const BLAS_INT *n, const BLAS_INT *k, const BLAS_Complex8 *alpha, const BLAS_Complex8 *a, const BLAS_INT *lda, const BLAS_INT *stridea, const BLAS_Complex8 *b, const BLAS_INT *ldb, const BLAS_INT *strideb, const BLAS_Complex8 *beta, BLAS_Complex8 *c, const BLAS_INT *ldc, const BLAS_INT *stridec, const BLAS_INT *batch_size);
+void dgemm_batch_strided_(const char *transa, const char *transb, const BLAS_INT *m, const BLAS_INT *n, const BLAS_INT *k, const double *alpha, const double *a, const BLAS_INT *lda, const BLAS_INT *stridea, const double *b, const BLAS_INT *ldb, const BLAS_INT *strideb, const double *beta, double *c, const BLAS_INT *ldc, const BLAS_INT *stridec, const BLAS_INT *batch_size);
+void zgemm_batch_strided_(const char *transa, const char *transb, const BLAS_INT *m, const BLAS_INT *n, const BLAS_INT *k, const BLAS_Complex16 *alpha, const BLAS_Complex16 *a, const BLAS_INT *lda, const BLAS_INT *stridea, const BLAS_Complex16 *b, const BLAS_INT *ldb, const BLAS_INT *strideb, const BLAS_Complex16 *beta, BLAS_Complex16 *c, const BLAS_INT *ldc, const BLAS_INT *stridec, const BLAS_INT *batch_size);
+
+#pragma endregion
+
+#pragma region Level3 BLAS gemm_batch
+
+void sgemm_batch_(const char *transa_array, const char *transb_array, const BLAS_INT *m_array, const BLAS_INT *n_array, const BLAS_INT *k_array, const float *alpha_array, const float **a_array, const BLAS_INT *lda_array, const float **b_array, const BLAS_INT *ldb_array, const float *beta_array, float **c_array, const BLAS_INT *ldc_array, const BLAS_INT *group_count, const BLAS_INT *group_size);
+void cgemm_batch_(const char *transa_array, const char *transb_array, const BLAS_INT *m_array, const BLAS_INT *n_array, const BLAS_INT *k_array, const BLAS_Complex8 *alpha_array, const BLAS_Complex8 **a_array, const BLAS_INT *lda_array, const BLAS_Complex8 **b_array, const BLAS_INT *ldb_array, const BLAS_Complex8 *beta_array, BLAS_Complex8 **c_array, const BLAS_INT *ldc_array, const BLAS_INT *group_count, const BLAS_INT *group_size);
+void dgemm_batch_(const char *transa_array, const char *transb_array, const BLAS_INT *m_array, const BLAS_INT *n_array, const BLAS_INT *k_array, const double *alpha_array, const double **a_array, const BLAS_INT *lda_array, const double **b_array, const BLAS_INT *ldb_array, const double *beta_array, double **c_array, const BLAS_INT *ldc_array, const BLAS_INT *group_count, const BLAS_INT *group_size);
+void zgemm_batch_(const char *transa_array, const char *transb_array, const BLAS_INT *m_array, const BLAS_INT *n_array, const BLAS_INT *k_array, const BLAS_Complex16 *alpha_array, const BLAS_Complex16 **a_array, const BLAS_INT *lda_array, const BLAS_Complex16 **b_array, const BLAS_INT *ldb_array, const BLAS_Complex16 *beta_array, BLAS_Complex16 **c_array, const BLAS_INT *ldc_array, const BLAS_INT *group_count, const BLAS_INT *group_size);
+
+#pragma endregion
+
+#pragma region BLAS extension omatcopy/imatcopy
+
+void somatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const float *alpha, const float *a, const BLAS_INT *lda, float *b, const BLAS_INT *ldb);
+void comatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const BLAS_Complex8 *alpha, const BLAS_Complex8 *a, const BLAS_INT *lda, BLAS_Complex8 *b, const BLAS_INT *ldb);
+void domatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const double *alpha, const double *a, const BLAS_INT *lda, double *b, const BLAS_INT *ldb);
+void zomatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const BLAS_Complex16 *alpha, const BLAS_Complex16 *a, const BLAS_INT *lda, BLAS_Complex16 *b, const BLAS_INT *ldb);
+void simatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const float *alpha, float *ab, const BLAS_INT *lda, const BLAS_INT *ldb);
+void cimatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const BLAS_Complex8 *alpha, BLAS_Complex8 *ab, const BLAS_INT *lda, const BLAS_INT *ldb);
+void dimatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const double *alpha, double *ab, const BLAS_INT *lda, const BLAS_INT *ldb);
+void zimatcopy_(const char *ordering, const char *trans, const BLAS_INT *rows, const BLAS_INT *cols, const BLAS_Complex16 *alpha, BLAS_Complex16 *ab, const BLAS_INT *lda, const BLAS_INT *ldb);
+
+#pragma endregion
@@ -0,0 +1,29 @@
+# # Generate `blas_dynamic.rs` from `blas.rs`
+#
+# With crate feature `dynamic`, functions of `blas.rs` are declared by macro `blas_dynamic!`, which generates
+# table of function pointers (loaded by `dlopen`) and functions of the same signatures.
+
+import re
+
+with open("../src/ffi/blas.rs", "r") as f:
+    token = f.read()
+
+# header (type definitions) before the first extern block
+header = token[:token.index('extern "C" {')]
+header = header.replace("/* automatically generated by rust-bindgen 0.69.4 */", "").strip()
+
+# function declarations (with `link_name` of symbol), one per line
+decls = re.findall(r"#\[link_name = [^\]]*\]\s*pub fn [^;]*;", token)
+decls = [re.sub(r"\s+", " ", d).replace("( ", "(").replace(", )", ")") for d in decls]
+
+token = """
+//! BLAS functions by dynamic loading (crate feature `dynamic`).
+//!
+//! Automatically generated from `blas.rs` by `scripts/dynamic_gen.py`.
+
+""".lstrip() + header.replace(
+    "use core::ffi::c_char;", "use super::dynamic::blas_dynamic;\nuse core::ffi::c_char;"
+) + "\n\nblas_dynamic! {\n" + "\n".join("    " + d for d in decls) + "\n}\n"
+
+with open("../src/ffi/blas_dynamic.rs", "w") as f:
+    f.write(token)
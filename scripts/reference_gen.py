@@ -0,0 +1,78 @@
+# # Generate `reference/entry.rs` from `blas.rs`
+#
+# With crate feature `reference`, functions of `blas.rs` are implemented in Rust. Entry functions keep the
+# signatures of `blas.rs`, and call generic implementations of `reference/level{1,2,3}.rs` and
+# `reference/extension.rs`.
+
+import re
+import subprocess
+
+with open("../src/ffi/blas.rs", "r") as f:
+    token = f.read()
+
+TYPES = {"s": "f32", "d": "f64", "c": "c32", "z": "c64"}
+
+# generic implementation of routine, and whether routine reports illegal argument (takes routine name)
+MODULES = {
+    "level1": [
+        "cabs1", "asum", "nrm2", "iamax", "axpy", "copy", "swap", "scal", "rscal",
+        "dot", "dotu", "dotc", "rot", "rotg", "rotg_complex", "rotm", "rotmg",
+    ],
+    "level2": [
+        "gemv", "gbmv", "geru", "gerc", "symv", "hemv", "sbmv", "hbmv", "spmv", "hpmv",
+        "syr", "her", "spr", "hpr", "syr2", "her2", "spr2", "hpr2",
+        "trmv", "trsv", "tbmv", "tbsv", "tpmv", "tpsv",
+    ],
+    "level3": ["gemm", "gemmt", "symm", "hemm", "syrk", "herk", "syr2k", "her2k", "trmm", "trsm"],
+    "extension": ["gemm_batch", "gemm_batch_strided", "omatcopy", "imatcopy"],
+}
+MODULE_OF = {r: m for m, rs in MODULES.items() for r in rs}
+
+# routines whose names do not follow `{type}{routine}_`
+SPECIAL = {
+    "dcabs1_": ("cabs1", "c64"), "scabs1_": ("cabs1", "c32"),
+    "dzasum_": ("asum", "c64"), "scasum_": ("asum", "c32"),
+    "dznrm2_": ("nrm2", "c64"), "scnrm2_": ("nrm2", "c32"),
+    "csscal_": ("rscal", "c32"), "zdscal_": ("rscal", "c64"),
+    "csrot_": ("rot", "c32"), "zdrot_": ("rot", "c64"),
+    "crotg_": ("rotg_complex", "c32"), "zrotg_": ("rotg_complex", "c64"),
+    "sger_": ("geru", "f32"), "dger_": ("geru", "f64"),
+    "dsdot_": ("dsdot", None), "sdsdot_": ("sdsdot", None),
+}
+
+decls = re.findall(r"pub fn (\w+)\(([^)]*)\)\s*(->\s*[^;]+)?;", token)
+
+entries = []
+for name, args, ret in decls:
+    args = re.sub(r"\s+", " ", args).strip().rstrip(",")
+    arg_names = [a.split(":")[0].strip() for a in args.split(",")]
+    if name in SPECIAL:
+        routine, ty = SPECIAL[name]
+    elif name.startswith("i"):
+        routine, ty = "iamax", TYPES[name[1]]
+    else:
+        routine, ty = name[1:-1], TYPES[name[0]]
+    module = MODULE_OF.get(routine, "level1")
+    generic = "" if ty is None else f"::<{ty}>"
+    call_args = ([f'"{name[:-1].upper()}"'] if module != "level1" else []) + arg_names
+    ret = "" if not ret else " " + re.sub(r"\s+", " ", ret).strip()
+    entries.append(
+        f"pub unsafe extern \"C\" fn {name}({args}){ret} {{\n"
+        f"    {module}::{routine}{generic}({', '.join(call_args)})\n"
+        f"}}\n"
+    )
+
+token = """
+//! Entry functions of reference implementation, with the same signatures as `blas.rs`.
+//!
+//! Automatically generated from `blas.rs` by `scripts/reference_gen.py`.
+
+use super::*;
+use core::ffi::c_char;
+
+""".lstrip() + "\n".join(entries)
+
+with open("../src/ffi/reference/entry.rs", "w") as f:
+    f.write(token)
+
+subprocess.run(["rustfmt", "--edition", "2021", "../src/ffi/reference/entry.rs"])
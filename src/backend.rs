@@ -0,0 +1,228 @@
+//! Runtime discovery of the linked BLAS library.
+//!
+//! The same binary may be linked (or preloaded) against different BLAS libraries. [`backend_info`] reports
+//! - vendor and version, by vendor-specific functions (`openblas_get_config`, `mkl_get_version_string`,
+//!   `bli_info_get_version_str`) if found;
+//! - integer width of BLAS (LP64 or ILP64), by probing `idamax_`, and whether it agrees with crate feature
+//!   `ilp64`;
+//! - whether BLAS extensions (such as `?gemmt_`) are provided, under the symbol naming convention of
+//!   [`ffi::symbol_name`](crate::ffi::symbol_name).
+//!
+//! Symbols are looked up by `dlsym` in the global scope of the process (or in the loaded library with crate
+//! feature `dynamic`), so only unix targets are supported;
+//! on other targets, vendor is [`BLASVendor::Unknown`] and extensions are `None`. Symbols of statically linked
+//! BLAS may also be invisible to `dlsym`, unless exported by the executable.
+//!
+//! With crate feature `reference`, vendor is [`BLASVendor::Reference`], and all extensions are provided.
+//!
+//! ```rust
+//! let info = blas_array2::backend::backend_info();
+//! println!("{info}");
+//! ```
+
+extern crate alloc;
+
+use crate::ffi::{self, blas_int, c_char};
+use alloc::ffi::CString;
+use alloc::string::{String, ToString};
+use core::ffi::{c_int, c_void, CStr};
+use core::fmt::{Display, Formatter};
+
+/// Vendor of the linked BLAS library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BLASVendor {
+    OpenBLAS,
+    MKL,
+    BLIS,
+    /// AMD AOCL-BLAS (fork of BLIS).
+    AOCL,
+    /// Pure-Rust implementation of this crate (crate feature `reference`).
+    Reference,
+    /// Vendor without any known version function, such as netlib reference BLAS.
+    Unknown,
+}
+
+/// Report of the linked BLAS library, given by [`backend_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendInfo {
+    pub vendor: BLASVendor,
+    /// Version (or configuration) string reported by vendor, such as `"OpenBLAS 0.3.27 DYNAMIC_ARCH ..."`.
+    pub version: Option<String>,
+    /// Integer width of BLAS (32 for LP64, 64 for ILP64), detected by probing `idamax_`.
+    pub int_bits: Option<u32>,
+    /// Integer width of this crate (64 with crate feature `ilp64`, otherwise 32).
+    pub expected_int_bits: u32,
+    /// Whether `?gemmt_` is provided (crate feature `gemmt`).
+    pub gemmt: Option<bool>,
+    /// Whether `?gemm_batch_` is provided (crate feature `gemm_batch`).
+    pub gemm_batch: Option<bool>,
+    /// Whether `?gemm_batch_strided_` is provided (crate feature `gemm_batch`).
+    pub gemm_batch_strided: Option<bool>,
+    /// Whether `?omatcopy_` and `?imatcopy_` are provided (crate feature `matcopy`).
+    pub matcopy: Option<bool>,
+}
+
+impl BackendInfo {
+    /// Whether integer width of BLAS agrees with crate feature `ilp64`; `None` if not detected.
+    pub fn is_int_consistent(&self) -> Option<bool> {
+        self.int_bits.map(|bits| bits == self.expected_int_bits)
+    }
+}
+
+impl Display for BackendInfo {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        fn opt<T: Display>(value: &Option<T>) -> String {
+            value.as_ref().map_or("unknown".into(), |v| v.to_string())
+        }
+        writeln!(f, "vendor: {:?}", self.vendor)?;
+        writeln!(f, "version: {}", opt(&self.version))?;
+        let consistency = match self.is_int_consistent() {
+            Some(true) => "consistent",
+            Some(false) => "INCONSISTENT",
+            None => "unknown",
+        };
+        writeln!(
+            f,
+            "integer bits: {} (crate: {}, {consistency})",
+            opt(&self.int_bits),
+            self.expected_int_bits
+        )?;
+        writeln!(f, "gemmt: {}", opt(&self.gemmt))?;
+        writeln!(f, "gemm_batch: {}", opt(&self.gemm_batch))?;
+        writeln!(f, "gemm_batch_strided: {}", opt(&self.gemm_batch_strided))?;
+        write!(f, "matcopy: {}", opt(&self.matcopy))
+    }
+}
+
+/// Discover the linked BLAS library.
+pub fn backend_info() -> BackendInfo {
+    let (vendor, version) = vendor_version();
+    return BackendInfo {
+        vendor,
+        version,
+        int_bits: probe_int_bits(),
+        expected_int_bits: (core::mem::size_of::<blas_int>() * 8) as u32,
+        gemmt: has_blas_symbol("dgemmt"),
+        gemm_batch: has_blas_symbol("dgemm_batch"),
+        gemm_batch_strided: has_blas_symbol("dgemm_batch_strided"),
+        matcopy: has_blas_symbol("domatcopy").zip(has_blas_symbol("dimatcopy")).map(|(o, i)| o && i),
+    };
+}
+
+/* #region symbol lookup */
+
+#[cfg(all(unix, not(feature = "dynamic")))]
+extern "C" {
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+}
+
+/// Address of symbol in global scope of process (or in loaded library with crate feature `dynamic`); `None` if
+/// not found, or not supported on target.
+pub(crate) fn lookup(symbol: &CStr) -> Option<*mut c_void> {
+    #[cfg(feature = "dynamic")]
+    {
+        return ffi::dynamic::lookup(symbol);
+    }
+    #[cfg(all(unix, not(feature = "dynamic")))]
+    {
+        // RTLD_DEFAULT
+        #[cfg(any(target_vendor = "apple", target_os = "freebsd", target_os = "dragonfly"))]
+        let handle = -2isize as *mut c_void;
+        #[cfg(not(any(target_vendor = "apple", target_os = "freebsd", target_os = "dragonfly")))]
+        let handle = core::ptr::null_mut();
+        let ptr = unsafe { dlsym(handle, symbol.as_ptr()) };
+        return if ptr.is_null() { None } else { Some(ptr) };
+    }
+    #[cfg(all(not(unix), not(feature = "dynamic")))]
+    {
+        let _ = symbol;
+        return None;
+    }
+}
+
+/// Whether BLAS routine `name` is found under the symbol naming convention ([`ffi::symbol_name`]); `None` if
+/// lookup is not supported on target.
+fn has_blas_symbol(name: &str) -> Option<bool> {
+    if cfg!(feature = "reference") {
+        return Some(true);
+    }
+    let symbol = CString::new(ffi::symbol_name(name)).ok()?;
+    if cfg!(unix) {
+        Some(lookup(&symbol).is_some())
+    } else {
+        None
+    }
+}
+
+fn c_str_to_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let s = unsafe { CStr::from_ptr(ptr) };
+    return Some(String::from_utf8_lossy(s.to_bytes()).trim().into());
+}
+
+/* #endregion */
+
+/* #region vendor */
+
+fn vendor_version() -> (BLASVendor, Option<String>) {
+    if cfg!(feature = "reference") {
+        return (
+            BLASVendor::Reference,
+            Some(concat!("blas-array2 ", env!("CARGO_PKG_VERSION"), " reference").into()),
+        );
+    }
+    if let Some(f) = lookup(c"openblas_get_config") {
+        let f: unsafe extern "C" fn() -> *const c_char = unsafe { core::mem::transmute(f) };
+        return (BLASVendor::OpenBLAS, c_str_to_string(unsafe { f() }));
+    }
+    if let Some(f) = lookup(c"mkl_get_version_string") {
+        let f: unsafe extern "C" fn(*mut c_char, c_int) = unsafe { core::mem::transmute(f) };
+        let mut buf = [0 as c_char; 256];
+        unsafe { f(buf.as_mut_ptr(), buf.len() as c_int - 1) };
+        return (BLASVendor::MKL, c_str_to_string(buf.as_ptr()));
+    }
+    if let Some(f) = lookup(c"bli_info_get_version_str") {
+        let f: unsafe extern "C" fn() -> *const c_char = unsafe { core::mem::transmute(f) };
+        let version = c_str_to_string(unsafe { f() });
+        let is_aocl =
+            version.as_deref().is_some_and(|v| v.contains("AOCL")) || lookup(c"AOCL_get_version").is_some();
+        return (if is_aocl { BLASVendor::AOCL } else { BLASVendor::BLIS }, version);
+    }
+    return (BLASVendor::Unknown, None);
+}
+
+/* #endregion */
+
+/* #region integer width */
+
+/// Probe integer width of BLAS by `idamax_`, with 64-bit integer arguments.
+///
+/// Argument `n` is `0xffffffff_00000003`: LP64 BLAS reads its lower half (3 on little-endian targets) and gives
+/// index of maximum (3), while ILP64 BLAS reads a negative `n` and gives 0.
+fn probe_int_bits() -> Option<u32> {
+    if cfg!(feature = "reference") {
+        return Some((core::mem::size_of::<blas_int>() * 8) as u32);
+    }
+    if cfg!(target_endian = "big") {
+        return None;
+    }
+    let x = [1.0_f64, 2.0, 3.0];
+    let n: i64 = (0xffffffff_u64 << 32 | 3) as i64;
+    let incx: i64 = 1;
+    #[cfg(not(feature = "dynamic"))]
+    let idamax: unsafe extern "C" fn(*const blas_int, *const f64, *const blas_int) -> blas_int = ffi::idamax_;
+    #[cfg(feature = "dynamic")]
+    let idamax = ffi::dynamic::table().idamax_?;
+    let idamax: unsafe extern "C" fn(*const i64, *const f64, *const i64) -> i64 =
+        unsafe { core::mem::transmute(idamax) };
+    // only lower half of return value is reliable for LP64
+    return match unsafe { idamax(&n, x.as_ptr(), &incx) } as i32 {
+        3 => Some(32),
+        0 => Some(64),
+        _ => None,
+    };
+}
+
+/* #endregion */
@@ -18,6 +18,9 @@ macro_rules! impl_func {
                 x: *const Self,
                 incx: *const blas_int,
             ) -> <$type as BLASFloat>::RealFloat {
+                if blas_plan_call!($func, [], [n, incx]) {
+                    return Default::default();
+                }
                 ffi::$func(n, x, incx)
             }
         }
@@ -38,7 +41,7 @@ where
     F: ASUMNum,
 {
     n: blas_int,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
 }
 
@@ -48,11 +51,13 @@ where
 {
     pub fn run_blas(self) -> Result<F::RealFloat, BLASError> {
         let Self { n, x, incx } = self;
-        let x_ptr = x.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
         if n == 0 {
             return Ok(F::RealFloat::zero());
         } else {
-            return unsafe { Ok(F::asum(&n, x_ptr, &incx)) };
+            let result = unsafe { F::asum(&n, x_ptr, &incx) };
+            check_blas_call()?;
+            return Ok(result);
         }
     }
 }
@@ -68,6 +73,8 @@ where
     F: ASUMNum,
 {
     pub x: ArrayView1<'x, F>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, F> ASUM_<'x, F>
@@ -75,8 +82,11 @@ where
     F: ASUMNum,
 {
     pub fn driver(self) -> Result<ASUM_Driver<'x, F>, BLASError> {
-        let Self { x } = self;
-        let incx = x.stride_of(Axis(0));
+        let Self { x, .. } = self;
+        let x = to_nonzero_stride(x)?;
+        // order of elements is irrelevant, and BLAS gives zero for negative increment;
+        // so pass the element of lowest address with positive increment
+        let incx = get_vec_inc(&x.view()).abs();
         let n = x.len_of(Axis(0));
         let driver = ASUM_Driver { n: n.try_into()?, x, incx: incx.try_into()? };
         return Ok(driver);
@@ -96,8 +106,31 @@ where
     F: ASUMNum,
 {
     pub fn run(self) -> Result<F::RealFloat, BLASError> {
+        let _ctx = CopyContext::enter("ASUM", self.copy_policy.flatten());
         self.build()?.driver()?.run_blas()
     }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of ASUM; `run` is only available when all required arguments are given.
+        ASUM_Typed for ASUM_Builder ['x] [F] where [F: ASUMNum] => F::RealFloat;
+        required { x: ArrayView1<'x, F> as X }
+        optional { copy_policy: CopyPolicy }
+        aliases { ASUM, SASUM = f32, DASUM = f64, SCASUM = c32, DZASUM = c64 }
+    }
 }
 
 /* #endregion */
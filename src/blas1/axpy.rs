@@ -0,0 +1,167 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait AXPYNum: BLASFloat {
+    unsafe fn axpy(
+        n: *const blas_int,
+        alpha: *const Self,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl AXPYNum for $type {
+            unsafe fn axpy(
+                n: *const blas_int,
+                alpha: *const Self,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                if blas_plan_call!($func, [], [n, incx, incy]) {
+                    return;
+                }
+                ffi::$func(n, alpha, x, incx, y, incy);
+            }
+        }
+    };
+}
+
+impl_func!(f32, saxpy_);
+impl_func!(f64, daxpy_);
+impl_func!(c32, caxpy_);
+impl_func!(c64, zaxpy_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct AXPY_Driver<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    n: blas_int,
+    alpha: F,
+    x: ArrayView1<'x, F>,
+    incx: blas_int,
+    y: ArrayOut1<'y, F>,
+    incy: blas_int,
+}
+
+impl<'x, 'y, F> BLASDriver<'y, F, Ix1> for AXPY_Driver<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        let Self { n, alpha, x, incx, mut y, incy } = self;
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = y.get_base_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(y);
+        }
+
+        unsafe {
+            F::axpy(&n, &alpha, x_ptr, &incx, y_ptr, &incy);
+        }
+        check_blas_call()?;
+        return Ok(y);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct AXPY_<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    pub x: ArrayView1<'x, F>,
+    pub y: ArrayViewMut1<'y, F>,
+
+    #[builder(setter(into), default = "F::one()")]
+    pub alpha: F,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y, F> BLASBuilder_<'y, F, Ix1> for AXPY_<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    fn driver(self) -> Result<AXPY_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, alpha, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // perform check
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // prepare output
+        let y = ArrayOut1::ViewMut(y);
+
+        // finalize
+        let driver =
+            AXPY_Driver { n: n.try_into()?, alpha, x, incx: incx.try_into()?, y, incy: incy.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type AXPY<'x, 'y, F> = AXPY_Builder<'x, 'y, F>;
+pub type SAXPY<'x, 'y> = AXPY<'x, 'y, f32>;
+pub type DAXPY<'x, 'y> = AXPY<'x, 'y, f64>;
+pub type CAXPY<'x, 'y> = AXPY<'x, 'y, c32>;
+pub type ZAXPY<'x, 'y> = AXPY<'x, 'y, c64>;
+
+impl<'x, 'y, F> BLASBuilder<'y, F, Ix1> for AXPY_Builder<'x, 'y, F>
+where
+    F: AXPYNum,
+{
+    fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("AXPY", self.copy_policy.flatten());
+        let obj = self.build()?;
+
+        // y = alpha x + y; vectors have no layout to be flipped
+        return obj.driver()?.run_blas();
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of AXPY; `run` is only available when all required arguments are given.
+        AXPY_Typed for AXPY_Builder ['x, 'y] [F] where [F: AXPYNum] => ArrayOut1<'y, F>;
+        required { x: ArrayView1<'x, F> as X, y: ArrayViewMut1<'y, F> as Y }
+        optional { alpha: F, copy_policy: CopyPolicy }
+        aliases { AXPY, SAXPY = f32, DAXPY = f64, CAXPY = c32, ZAXPY = c64 }
+    }
+}
+
+/* #endregion */
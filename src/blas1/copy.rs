@@ -0,0 +1,165 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait COPYNum: BLASFloat {
+    unsafe fn copy(
+        n: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl COPYNum for $type {
+            unsafe fn copy(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                if blas_plan_call!($func, [], [n, incx, incy]) {
+                    return;
+                }
+                ffi::$func(n, x, incx, y, incy);
+            }
+        }
+    };
+}
+
+impl_func!(f32, scopy_);
+impl_func!(f64, dcopy_);
+impl_func!(c32, ccopy_);
+impl_func!(c64, zcopy_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct COPY_Driver<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    n: blas_int,
+    x: ArrayView1<'x, F>,
+    incx: blas_int,
+    y: ArrayOut1<'y, F>,
+    incy: blas_int,
+}
+
+impl<'x, 'y, F> BLASDriver<'y, F, Ix1> for COPY_Driver<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        let Self { n, x, incx, mut y, incy } = self;
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = y.get_base_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(y);
+        }
+
+        unsafe {
+            F::copy(&n, x_ptr, &incx, y_ptr, &incy);
+        }
+        check_blas_call()?;
+        return Ok(y);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct COPY_<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    pub x: ArrayView1<'x, F>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub y: Option<ArrayViewMut1<'y, F>>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y, F> BLASBuilder_<'y, F, Ix1> for COPY_<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    fn driver(self) -> Result<COPY_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+
+        // prepare output
+        let y = match y {
+            Some(y) => {
+                blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+                ArrayOut1::ViewMut(y)
+            },
+            None => ArrayOut1::Owned(Array1::zeros(n)),
+        };
+        let incy = get_vec_inc(&y.view());
+
+        // finalize
+        let driver = COPY_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type COPY<'x, 'y, F> = COPY_Builder<'x, 'y, F>;
+pub type SCOPY<'x, 'y> = COPY<'x, 'y, f32>;
+pub type DCOPY<'x, 'y> = COPY<'x, 'y, f64>;
+pub type CCOPY<'x, 'y> = COPY<'x, 'y, c32>;
+pub type ZCOPY<'x, 'y> = COPY<'x, 'y, c64>;
+
+impl<'x, 'y, F> BLASBuilder<'y, F, Ix1> for COPY_Builder<'x, 'y, F>
+where
+    F: COPYNum,
+{
+    fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("COPY", self.copy_policy.flatten());
+        let obj = self.build()?;
+
+        // y = x; vectors have no layout to be flipped
+        return obj.driver()?.run_blas();
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of COPY; `run` is only available when all required arguments are given.
+        COPY_Typed for COPY_Builder ['x, 'y] [F] where [F: COPYNum] => ArrayOut1<'y, F>;
+        required { x: ArrayView1<'x, F> as X }
+        optional { y: ArrayViewMut1<'y, F>, copy_policy: CopyPolicy }
+        aliases { COPY, SCOPY = f32, DCOPY = f64, CCOPY = c32, ZCOPY = c64 }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,181 @@
+use crate::ffi::{self, blas_int, c_char};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::*;
+
+/* #region BLAS func */
+
+pub trait DOTCNum: BLASFloat {
+    /// Conjugated dot product $\boldsymbol{x}^\dagger \boldsymbol{y}$.
+    ///
+    /// # Safety
+    ///
+    /// This function is evaluated by `?gemm_`, so `incx` and `incy` must be positive.
+    unsafe fn dotc(
+        n: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *const Self,
+        incy: *const blas_int,
+    ) -> Self;
+}
+
+// Return convention of `cdotc_`/`zdotc_` differs between gfortran (by value) and f2c/ifort (hidden first
+// argument), which can not be detected at link time. Evaluate by 1x1 gemm instead:
+// conj( x (1 x n, lda = incx) * y^H (n x 1, ldb = incy) ).
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl DOTCNum for $type {
+            unsafe fn dotc(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *const Self,
+                incy: *const blas_int,
+            ) -> Self {
+                let flags = [("transa", 'N' as c_char), ("transb", 'C' as c_char)];
+                let dims = [("m", 1), ("n", 1), ("k", *n), ("lda", *incx), ("ldb", *incy), ("ldc", 1)];
+                if plan_call(stringify!($func), &flags, &dims) {
+                    return <$type>::zero();
+                }
+                let mut res = <$type>::zero();
+                ffi::$func(
+                    &('N' as c_char),
+                    &('C' as c_char),
+                    &1,
+                    &1,
+                    n,
+                    &<$type>::one(),
+                    x,
+                    incx,
+                    y,
+                    incy,
+                    &<$type>::zero(),
+                    &mut res,
+                    &1,
+                );
+                res.conj()
+            }
+        }
+    };
+}
+
+impl_func!(c32, cgemm_);
+impl_func!(c64, zgemm_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct DOTC_Driver<'x, 'y, F>
+where
+    F: DOTCNum,
+{
+    n: blas_int,
+    x: CowArray<'x, F, Ix1>,
+    incx: blas_int,
+    y: CowArray<'y, F, Ix1>,
+    incy: blas_int,
+}
+
+impl<'x, 'y, F> DOTC_Driver<'x, 'y, F>
+where
+    F: DOTCNum,
+{
+    pub fn run_blas(self) -> Result<F, BLASError> {
+        let Self { n, x, incx, y, incy } = self;
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
+        if n == 0 {
+            return Ok(F::zero());
+        } else {
+            let result = unsafe { F::dotc(&n, x_ptr, &incx, y_ptr, &incy) };
+            check_blas_call()?;
+            return Ok(result);
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct DOTC_<'x, 'y, F>
+where
+    F: DOTCNum,
+{
+    pub x: ArrayView1<'x, F>,
+    pub y: ArrayView1<'y, F>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y, F> DOTC_<'x, 'y, F>
+where
+    F: DOTCNum,
+{
+    pub fn driver(self) -> Result<DOTC_Driver<'x, 'y, F>, BLASError> {
+        let Self { mut x, mut y, .. } = self;
+
+        // perform check
+        let n = x.len_of(Axis(0));
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // dot is evaluated by gemm, where leading dimension must be positive
+        invert_negative_stride_pair(&mut x, &mut y);
+        let (x, y) = (to_positive_stride(x)?, to_positive_stride(y)?);
+
+        // initialize intent(hide)
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // finalize
+        let driver = DOTC_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type DOTC<'x, 'y, F> = DOTC_Builder<'x, 'y, F>;
+pub type CDOTC<'x, 'y> = DOTC<'x, 'y, c32>;
+pub type ZDOTC<'x, 'y> = DOTC<'x, 'y, c64>;
+
+impl<'x, 'y, F> DOTC<'x, 'y, F>
+where
+    F: DOTCNum,
+{
+    pub fn run(self) -> Result<F, BLASError> {
+        let _ctx = CopyContext::enter("DOTC", self.copy_policy.flatten());
+        self.build()?.driver()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of DOTC; `run` is only available when all required arguments are given.
+        DOTC_Typed for DOTC_Builder ['x, 'y] [F] where [F: DOTCNum] => F;
+        required { x: ArrayView1<'x, F> as X, y: ArrayView1<'y, F> as Y }
+        optional { copy_policy: CopyPolicy }
+        aliases { DOTC, CDOTC = c32, ZDOTC = c64 }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,210 @@
+use crate::ffi::{self, blas_int, c_char};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::*;
+
+/* #region BLAS func */
+
+pub trait DOTUNum: BLASFloat {
+    /// Unconjugated dot product $\boldsymbol{x}^\mathrm{T} \boldsymbol{y}$.
+    ///
+    /// # Safety
+    ///
+    /// For complex types, this function is evaluated by `?gemm_`, so `incx` and `incy` must be positive.
+    unsafe fn dotu(
+        n: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        y: *const Self,
+        incy: *const blas_int,
+    ) -> Self;
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl DOTUNum for $type {
+            unsafe fn dotu(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *const Self,
+                incy: *const blas_int,
+            ) -> Self {
+                if blas_plan_call!($func, [], [n, incx, incy]) {
+                    return Default::default();
+                }
+                ffi::$func(n, x, incx, y, incy)
+            }
+        }
+    };
+}
+
+impl_func!(f32, sdot_);
+impl_func!(f64, ddot_);
+
+// Return convention of `cdotu_`/`zdotu_` differs between gfortran (by value) and f2c/ifort (hidden first
+// argument), which can not be detected at link time. Evaluate by 1x1 gemm instead:
+// x (1 x n, lda = incx) * y^T (n x 1, ldb = incy).
+macro_rules! impl_func_complex {
+    ($type: ty, $func: ident) => {
+        impl DOTUNum for $type {
+            unsafe fn dotu(
+                n: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                y: *const Self,
+                incy: *const blas_int,
+            ) -> Self {
+                let flags = [("transa", 'N' as c_char), ("transb", 'T' as c_char)];
+                let dims = [("m", 1), ("n", 1), ("k", *n), ("lda", *incx), ("ldb", *incy), ("ldc", 1)];
+                if plan_call(stringify!($func), &flags, &dims) {
+                    return <$type>::zero();
+                }
+                let mut res = <$type>::zero();
+                ffi::$func(
+                    &('N' as c_char),
+                    &('T' as c_char),
+                    &1,
+                    &1,
+                    n,
+                    &<$type>::one(),
+                    x,
+                    incx,
+                    y,
+                    incy,
+                    &<$type>::zero(),
+                    &mut res,
+                    &1,
+                );
+                res
+            }
+        }
+    };
+}
+
+impl_func_complex!(c32, cgemm_);
+impl_func_complex!(c64, zgemm_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct DOTU_Driver<'x, 'y, F>
+where
+    F: DOTUNum,
+{
+    n: blas_int,
+    x: CowArray<'x, F, Ix1>,
+    incx: blas_int,
+    y: CowArray<'y, F, Ix1>,
+    incy: blas_int,
+}
+
+impl<'x, 'y, F> DOTU_Driver<'x, 'y, F>
+where
+    F: DOTUNum,
+{
+    pub fn run_blas(self) -> Result<F, BLASError> {
+        let Self { n, x, incx, y, incy } = self;
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
+        if n == 0 {
+            return Ok(F::zero());
+        } else {
+            let result = unsafe { F::dotu(&n, x_ptr, &incx, y_ptr, &incy) };
+            check_blas_call()?;
+            return Ok(result);
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct DOTU_<'x, 'y, F>
+where
+    F: DOTUNum,
+{
+    pub x: ArrayView1<'x, F>,
+    pub y: ArrayView1<'y, F>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y, F> DOTU_<'x, 'y, F>
+where
+    F: DOTUNum,
+{
+    pub fn driver(self) -> Result<DOTU_Driver<'x, 'y, F>, BLASError> {
+        let Self { mut x, mut y, .. } = self;
+
+        // perform check
+        let n = x.len_of(Axis(0));
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // complex dot is evaluated by gemm, where leading dimension must be positive
+        let (x, y) = match F::is_complex() {
+            true => {
+                invert_negative_stride_pair(&mut x, &mut y);
+                (to_positive_stride(x)?, to_positive_stride(y)?)
+            },
+            false => (CowArray::from(x), CowArray::from(y)),
+        };
+
+        // initialize intent(hide)
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // finalize
+        let driver = DOTU_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type DOTU<'x, 'y, F> = DOTU_Builder<'x, 'y, F>;
+pub type SDOT<'x, 'y> = DOTU<'x, 'y, f32>;
+pub type DDOT<'x, 'y> = DOTU<'x, 'y, f64>;
+pub type CDOTU<'x, 'y> = DOTU<'x, 'y, c32>;
+pub type ZDOTU<'x, 'y> = DOTU<'x, 'y, c64>;
+
+impl<'x, 'y, F> DOTU<'x, 'y, F>
+where
+    F: DOTUNum,
+{
+    pub fn run(self) -> Result<F, BLASError> {
+        let _ctx = CopyContext::enter("DOTU", self.copy_policy.flatten());
+        self.build()?.driver()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of DOTU; `run` is only available when all required arguments are given.
+        DOTU_Typed for DOTU_Builder ['x, 'y] [F] where [F: DOTUNum] => F;
+        required { x: ArrayView1<'x, F> as X, y: ArrayView1<'y, F> as Y }
+        optional { copy_policy: CopyPolicy }
+        aliases { DOTU, SDOT = f32, DDOT = f64, CDOTU = c32, ZDOTU = c64 }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,182 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS driver */
+
+pub struct DSDOT_Driver<'x, 'y> {
+    n: blas_int,
+    x: ArrayView1<'x, f32>,
+    incx: blas_int,
+    y: ArrayView1<'y, f32>,
+    incy: blas_int,
+}
+
+impl<'x, 'y> DSDOT_Driver<'x, 'y> {
+    pub fn run_blas(self) -> Result<f64, BLASError> {
+        let Self { n, x, incx, y, incy } = self;
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
+        if n == 0 {
+            return Ok(0.0);
+        } else {
+            if plan_call("dsdot_", &[], &[("n", n), ("incx", incx), ("incy", incy)]) {
+                return Ok(0.0);
+            }
+            let result = unsafe { ffi::dsdot_(&n, x_ptr, &incx, y_ptr, &incy) };
+            check_blas_call()?;
+            return Ok(result);
+        }
+    }
+}
+
+pub struct SDSDOT_Driver<'x, 'y> {
+    n: blas_int,
+    sb: f32,
+    x: ArrayView1<'x, f32>,
+    incx: blas_int,
+    y: ArrayView1<'y, f32>,
+    incy: blas_int,
+}
+
+impl<'x, 'y> SDSDOT_Driver<'x, 'y> {
+    pub fn run_blas(self) -> Result<f32, BLASError> {
+        let Self { n, sb, x, incx, y, incy } = self;
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
+        if n == 0 {
+            return Ok(sb);
+        } else {
+            if plan_call("sdsdot_", &[], &[("n", n), ("incx", incx), ("incy", incy)]) {
+                return Ok(0.0);
+            }
+            let result = unsafe { ffi::sdsdot_(&n, &sb, x_ptr, &incx, y_ptr, &incy) };
+            check_blas_call()?;
+            return Ok(result);
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+/// Dot product of `f32` vectors, accumulated and returned in `f64`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct DSDOT_<'x, 'y> {
+    pub x: ArrayView1<'x, f32>,
+    pub y: ArrayView1<'y, f32>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y> DSDOT_<'x, 'y> {
+    pub fn driver(self) -> Result<DSDOT_Driver<'x, 'y>, BLASError> {
+        let Self { x, y, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // perform check
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // finalize
+        let driver = DSDOT_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/// Dot product of `f32` vectors plus scalar `sb`, accumulated in `f64` and returned in `f32`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct SDSDOT_<'x, 'y> {
+    pub x: ArrayView1<'x, f32>,
+    pub y: ArrayView1<'y, f32>,
+
+    #[builder(setter(into), default = "0.0")]
+    pub sb: f32,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y> SDSDOT_<'x, 'y> {
+    pub fn driver(self) -> Result<SDSDOT_Driver<'x, 'y>, BLASError> {
+        let Self { x, y, sb, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // perform check
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // finalize
+        let driver =
+            SDSDOT_Driver { n: n.try_into()?, sb, x, incx: incx.try_into()?, y, incy: incy.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type DSDOT<'x, 'y> = DSDOT_Builder<'x, 'y>;
+pub type SDSDOT<'x, 'y> = SDSDOT_Builder<'x, 'y>;
+
+impl<'x, 'y> DSDOT<'x, 'y> {
+    pub fn run(self) -> Result<f64, BLASError> {
+        let _ctx = CopyContext::enter("DSDOT", self.copy_policy.flatten());
+        self.build()?.driver()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+impl<'x, 'y> SDSDOT<'x, 'y> {
+    pub fn run(self) -> Result<f32, BLASError> {
+        let _ctx = CopyContext::enter("SDSDOT", self.copy_policy.flatten());
+        self.build()?.driver()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of DSDOT; `run` is only available when all required arguments are given.
+        DSDOT_Typed for DSDOT_Builder ['x, 'y] [] where [] => f64;
+        required { x: ArrayView1<'x, f32> as X, y: ArrayView1<'y, f32> as Y }
+        optional { copy_policy: CopyPolicy }
+        aliases { DSDOT }
+    }
+
+    blas_typestate! {
+        /// Typestate builder of SDSDOT; `run` is only available when all required arguments are given.
+        SDSDOT_Typed for SDSDOT_Builder ['x, 'y] [] where [] => f32;
+        required { x: ArrayView1<'x, f32> as X, y: ArrayView1<'y, f32> as Y }
+        optional { sb: f32, copy_policy: CopyPolicy }
+        aliases { SDSDOT }
+    }
+}
+
+/* #endregion */
@@ -16,6 +16,9 @@ macro_rules! impl_func {
             $type: BLASFloat,
         {
             unsafe fn iamax(n: *const blas_int, x: *const Self, incx: *const blas_int) -> blas_int {
+                if blas_plan_call!($func, [], [n, incx]) {
+                    return Default::default();
+                }
                 ffi::$func(n, x, incx)
             }
         }
@@ -36,7 +39,7 @@ where
     F: IAMAXNum,
 {
     n: blas_int,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
 }
 
@@ -51,7 +54,9 @@ where
             return Ok(0);
         } else {
             // 0-index for C/Rust v.s. 1-index for Fortran
-            return unsafe { Ok((F::iamax(&n, x_ptr, &incx) - 1).try_into()?) };
+            let result = unsafe { (F::iamax(&n, x_ptr, &incx) - 1).try_into()? };
+            check_blas_call()?;
+            return Ok(result);
         }
     }
 }
@@ -67,6 +72,8 @@ where
     F: IAMAXNum,
 {
     pub x: ArrayView1<'x, F>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, F> IAMAX_<'x, F>
@@ -74,8 +81,10 @@ where
     F: IAMAXNum,
 {
     pub fn driver(self) -> Result<IAMAX_Driver<'x, F>, BLASError> {
-        let Self { x } = self;
-        let incx = x.stride_of(Axis(0));
+        let Self { x, .. } = self;
+        // BLAS gives zero for negative increment, and index of first maximum depends on order of elements
+        let x = to_positive_stride(x)?;
+        let incx = get_vec_inc(&x.view());
         let n = x.len_of(Axis(0));
         let driver = IAMAX_Driver { n: n.try_into()?, x, incx: incx.try_into()? };
         return Ok(driver);
@@ -95,8 +104,31 @@ where
     F: IAMAXNum,
 {
     pub fn run(self) -> Result<usize, BLASError> {
+        let _ctx = CopyContext::enter("IAMAX", self.copy_policy.flatten());
         self.build()?.driver()?.run_blas()
     }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of IAMAX; `run` is only available when all required arguments are given.
+        IAMAX_Typed for IAMAX_Builder ['x] [F] where [F: IAMAXNum] => usize;
+        required { x: ArrayView1<'x, F> as X }
+        optional { copy_policy: CopyPolicy }
+        aliases { IAMAX, ISAMAX = f32, IDAMAX = f64, ICAMAX = c32, IZAMAX = c64 }
+    }
 }
 
 /* #endregion */
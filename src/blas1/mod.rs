@@ -1,3 +1,14 @@
 pub mod asum;
+pub mod axpy;
+pub mod copy;
+pub mod dotc;
+pub mod dotu;
+pub mod dsdot;
 pub mod iamax;
 pub mod nrm2;
+pub mod rot;
+pub mod rotg;
+pub mod rotm;
+pub mod rotmg;
+pub mod scal;
+pub mod swap;
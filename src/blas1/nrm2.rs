@@ -21,6 +21,9 @@ macro_rules! impl_func {
                 x: *const Self,
                 incx: *const blas_int,
             ) -> <$type as BLASFloat>::RealFloat {
+                if blas_plan_call!($func, [], [n, incx]) {
+                    return Default::default();
+                }
                 ffi::$func(n, x, incx)
             }
         }
@@ -41,7 +44,7 @@ where
     F: NRM2Num,
 {
     n: blas_int,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
 }
 
@@ -51,11 +54,13 @@ where
 {
     pub fn run_blas(self) -> Result<F::RealFloat, BLASError> {
         let Self { n, x, incx } = self;
-        let x_ptr = x.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
         if n == 0 {
             return Ok(F::RealFloat::zero());
         } else {
-            return unsafe { Ok(F::nrm2(&n, x_ptr, &incx)) };
+            let result = unsafe { F::nrm2(&n, x_ptr, &incx) };
+            check_blas_call()?;
+            return Ok(result);
         }
     }
 }
@@ -71,6 +76,8 @@ where
     F: NRM2Num,
 {
     pub x: ArrayView1<'x, F>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, F> NRM2_<'x, F>
@@ -78,8 +85,11 @@ where
     F: NRM2Num,
 {
     pub fn driver(self) -> Result<NRM2_Driver<'x, F>, BLASError> {
-        let Self { x } = self;
-        let incx = x.stride_of(Axis(0));
+        let Self { x, .. } = self;
+        let x = to_nonzero_stride(x)?;
+        // order of elements is irrelevant, and BLAS gives zero for negative increment;
+        // so pass the element of lowest address with positive increment
+        let incx = get_vec_inc(&x.view()).abs();
         let n = x.len_of(Axis(0));
         let driver = NRM2_Driver { n: n.try_into()?, x, incx: incx.try_into()? };
         return Ok(driver);
@@ -99,8 +109,31 @@ where
     F: NRM2Num,
 {
     pub fn run(self) -> Result<F::RealFloat, BLASError> {
+        let _ctx = CopyContext::enter("NRM2", self.copy_policy.flatten());
         self.build()?.driver()?.run_blas()
     }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of NRM2; `run` is only available when all required arguments are given.
+        NRM2_Typed for NRM2_Builder ['x] [F] where [F: NRM2Num] => F::RealFloat;
+        required { x: ArrayView1<'x, F> as X }
+        optional { copy_policy: CopyPolicy }
+        aliases { NRM2, SNRM2 = f32, DNRM2 = f64, SCNRM2 = c32, DZNRM2 = c64 }
+    }
 }
 
 /* #endregion */
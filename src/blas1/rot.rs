@@ -0,0 +1,179 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait ROTNum: BLASFloat {
+    unsafe fn rot(
+        n: *const blas_int,
+        x: *mut Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+        c: *const Self::RealFloat,
+        s: *const Self::RealFloat,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl ROTNum for $type {
+            unsafe fn rot(
+                n: *const blas_int,
+                x: *mut Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+                c: *const Self::RealFloat,
+                s: *const Self::RealFloat,
+            ) {
+                if blas_plan_call!($func, [], [n, incx, incy]) {
+                    return;
+                }
+                ffi::$func(n, x, incx, y, incy, c, s);
+            }
+        }
+    };
+}
+
+impl_func!(f32, srot_);
+impl_func!(f64, drot_);
+impl_func!(c32, csrot_);
+impl_func!(c64, zdrot_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct ROT_Driver<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    n: blas_int,
+    x: ArrayOut1<'x, F>,
+    incx: blas_int,
+    y: ArrayOut1<'y, F>,
+    incy: blas_int,
+    c: F::RealFloat,
+    s: F::RealFloat,
+}
+
+impl<'x, 'y, F> ROT_Driver<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub fn run_blas(self) -> Result<(ArrayOut1<'x, F>, ArrayOut1<'y, F>), BLASError> {
+        let Self { n, mut x, incx, mut y, incy, c, s } = self;
+        let x_ptr = x.get_base_mut_ptr();
+        let y_ptr = y.get_base_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok((x, y));
+        }
+
+        unsafe {
+            F::rot(&n, x_ptr, &incx, y_ptr, &incy, &c, &s);
+        }
+        check_blas_call()?;
+        return Ok((x, y));
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct ROT_<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub x: ArrayViewMut1<'x, F>,
+    pub y: ArrayViewMut1<'y, F>,
+    #[builder(setter(into))]
+    pub c: F::RealFloat,
+    #[builder(setter(into))]
+    pub s: F::RealFloat,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y, F> ROT_<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub fn driver(self) -> Result<ROT_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, c, s, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // perform check
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // prepare output
+        let x = ArrayOut1::ViewMut(x);
+        let y = ArrayOut1::ViewMut(y);
+
+        // finalize
+        let driver =
+            ROT_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()?, c, s };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type ROT<'x, 'y, F> = ROT_Builder<'x, 'y, F>;
+pub type SROT<'x, 'y> = ROT<'x, 'y, f32>;
+pub type DROT<'x, 'y> = ROT<'x, 'y, f64>;
+pub type CSROT<'x, 'y> = ROT<'x, 'y, c32>;
+pub type ZDROT<'x, 'y> = ROT<'x, 'y, c64>;
+
+impl<'x, 'y, F> ROT<'x, 'y, F>
+where
+    F: ROTNum,
+{
+    pub fn run(self) -> Result<(ArrayOut1<'x, F>, ArrayOut1<'y, F>), BLASError> {
+        let _ctx = CopyContext::enter("ROT", self.copy_policy.flatten());
+        self.build()?.driver()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of ROT; `run` is only available when all required arguments are given.
+        ROT_Typed for ROT_Builder ['x, 'y] [F] where [F: ROTNum] => (ArrayOut1<'x, F>, ArrayOut1<'y, F>);
+        required {
+            x: ArrayViewMut1<'x, F> as X,
+            y: ArrayViewMut1<'y, F> as Y,
+            c: F::RealFloat as C,
+            s: F::RealFloat as S,
+        }
+        optional { copy_policy: CopyPolicy }
+        aliases { ROT, SROT = f32, DROT = f64, CSROT = c32, ZDROT = c64 }
+    }
+}
+
+/* #endregion */
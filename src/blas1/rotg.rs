@@ -0,0 +1,118 @@
+use crate::ffi;
+use crate::util::*;
+use derive_builder::Builder;
+use num_traits::*;
+
+/* #region BLAS func */
+
+pub trait ROTGNum: BLASFloat {
+    unsafe fn rotg(a: *mut Self, b: *mut Self, c: *mut Self::RealFloat, s: *mut Self);
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl ROTGNum for $type {
+            unsafe fn rotg(a: *mut Self, b: *mut Self, c: *mut Self::RealFloat, s: *mut Self) {
+                if blas_plan_call!($func, [], []) {
+                    return;
+                }
+                ffi::$func(a, b, c, s);
+            }
+        }
+    };
+}
+
+impl_func!(f32, srotg_);
+impl_func!(f64, drotg_);
+impl_func!(c32, crotg_);
+impl_func!(c64, zrotg_);
+
+/* #endregion */
+
+/* #region BLAS result */
+
+/// Givens rotation $\begin{pmatrix} c & s \\\\ - \bar s & c \end{pmatrix}$, with
+/// $\begin{pmatrix} c & s \\\\ - \bar s & c \end{pmatrix} \begin{pmatrix} a \\\\ b \end{pmatrix} = \begin{pmatrix} r \\\\ 0 \end{pmatrix}$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ROTGResult<F>
+where
+    F: BLASFloat,
+{
+    pub c: F::RealFloat,
+    pub s: F,
+    pub r: F,
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct ROTG_<F>
+where
+    F: ROTGNum,
+{
+    pub a: F,
+    pub b: F,
+}
+
+impl<F> ROTG_<F>
+where
+    F: ROTGNum,
+{
+    pub fn run_blas(self) -> Result<ROTGResult<F>, BLASError> {
+        let Self { mut a, mut b } = self;
+        let mut c = F::RealFloat::zero();
+        let mut s = F::zero();
+        unsafe {
+            F::rotg(&mut a, &mut b, &mut c, &mut s);
+        }
+        check_blas_call()?;
+        return Ok(ROTGResult { c, s, r: a });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type ROTG<F> = ROTG_Builder<F>;
+pub type SROTG = ROTG<f32>;
+pub type DROTG = ROTG<f64>;
+pub type CROTG = ROTG<c32>;
+pub type ZROTG = ROTG<c64>;
+
+impl<F> ROTG<F>
+where
+    F: ROTGNum,
+{
+    pub fn run(self) -> Result<ROTGResult<F>, BLASError> {
+        let _ctx = CopyContext::enter("ROTG", None);
+        self.build()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of ROTG; `run` is only available when all required arguments are given.
+        ROTG_Typed for ROTG_Builder [] [F] where [F: ROTGNum] => ROTGResult<F>;
+        required { a: F as A, b: F as B }
+        optional {  }
+        aliases { ROTG, SROTG = f32, DROTG = f64, CROTG = c32, ZROTG = c64 }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,239 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::*;
+
+/* #region BLAS func */
+
+pub trait ROTMNum: BLASFloat {
+    unsafe fn rotm(
+        n: *const blas_int,
+        x: *mut Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+        param: *const Self,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl ROTMNum for $type {
+            unsafe fn rotm(
+                n: *const blas_int,
+                x: *mut Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+                param: *const Self,
+            ) {
+                if blas_plan_call!($func, [], [n, incx, incy]) {
+                    return;
+                }
+                ffi::$func(n, x, incx, y, incy, param);
+            }
+        }
+    };
+}
+
+impl_func!(f32, srotm_);
+impl_func!(f64, drotm_);
+
+/* #endregion */
+
+/* #region RotmParam */
+
+/// Modified Givens transformation $\mathbf{H}$ used by `?rotm` and generated by `?rotmg`.
+///
+/// Variants correspond to `flag = param[0]` of BLAS:
+///
+/// | flag | variant | $\mathbf{H}$ |
+/// |--|--|--|
+/// | -2 | `Identity`    | $\begin{pmatrix} 1 & 0 \\\\ 0 & 1 \end{pmatrix}$ |
+/// | -1 | `Full`        | $\begin{pmatrix} h_{11} & h_{12} \\\\ h_{21} & h_{22} \end{pmatrix}$ |
+/// |  0 | `OffDiagonal` | $\begin{pmatrix} 1 & h_{12} \\\\ h_{21} & 1 \end{pmatrix}$ |
+/// |  1 | `Diagonal`    | $\begin{pmatrix} h_{11} & 1 \\\\ -1 & h_{22} \end{pmatrix}$ |
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RotmParam<F> {
+    Identity,
+    Full { h11: F, h21: F, h12: F, h22: F },
+    OffDiagonal { h21: F, h12: F },
+    Diagonal { h11: F, h22: F },
+}
+
+impl<F> RotmParam<F>
+where
+    F: Float,
+{
+    /// Raw `param` array as BLAS convention (`[flag, h11, h21, h12, h22]`).
+    ///
+    /// Elements not referenced by BLAS for the corresponding flag are set to zero.
+    pub fn to_array(&self) -> [F; 5] {
+        let (zero, one) = (F::zero(), F::one());
+        match *self {
+            RotmParam::Identity => [-one - one, zero, zero, zero, zero],
+            RotmParam::Full { h11, h21, h12, h22 } => [-one, h11, h21, h12, h22],
+            RotmParam::OffDiagonal { h21, h12 } => [zero, zero, h21, h12, zero],
+            RotmParam::Diagonal { h11, h22 } => [one, h11, zero, zero, h22],
+        }
+    }
+
+    /// Parse raw `param` array of BLAS convention (`[flag, h11, h21, h12, h22]`).
+    pub fn from_array(param: [F; 5]) -> Result<Self, BLASError> {
+        let [flag, h11, h21, h12, h22] = param;
+        let (zero, one) = (F::zero(), F::one());
+        if flag == -one - one {
+            Ok(RotmParam::Identity)
+        } else if flag == -one {
+            Ok(RotmParam::Full { h11, h21, h12, h22 })
+        } else if flag == zero {
+            Ok(RotmParam::OffDiagonal { h21, h12 })
+        } else if flag == one {
+            Ok(RotmParam::Diagonal { h11, h22 })
+        } else {
+            blas_raise!(InvalidFlag, "flag of rotm param should be one of -2, -1, 0, 1")
+        }
+    }
+
+    /// Transformation matrix $\mathbf{H}$ (`[[h11, h12], [h21, h22]]`).
+    pub fn matrix(&self) -> [[F; 2]; 2] {
+        let (zero, one) = (F::zero(), F::one());
+        match *self {
+            RotmParam::Identity => [[one, zero], [zero, one]],
+            RotmParam::Full { h11, h21, h12, h22 } => [[h11, h12], [h21, h22]],
+            RotmParam::OffDiagonal { h21, h12 } => [[one, h12], [h21, one]],
+            RotmParam::Diagonal { h11, h22 } => [[h11, one], [-one, h22]],
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct ROTM_Driver<'x, 'y, F>
+where
+    F: ROTMNum,
+{
+    n: blas_int,
+    x: ArrayOut1<'x, F>,
+    incx: blas_int,
+    y: ArrayOut1<'y, F>,
+    incy: blas_int,
+    param: [F; 5],
+}
+
+impl<'x, 'y, F> ROTM_Driver<'x, 'y, F>
+where
+    F: ROTMNum,
+{
+    pub fn run_blas(self) -> Result<(ArrayOut1<'x, F>, ArrayOut1<'y, F>), BLASError> {
+        let Self { n, mut x, incx, mut y, incy, param } = self;
+        let x_ptr = x.get_base_mut_ptr();
+        let y_ptr = y.get_base_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok((x, y));
+        }
+
+        unsafe {
+            F::rotm(&n, x_ptr, &incx, y_ptr, &incy, param.as_ptr());
+        }
+        check_blas_call()?;
+        return Ok((x, y));
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct ROTM_<'x, 'y, F>
+where
+    F: ROTMNum + Float,
+{
+    pub x: ArrayViewMut1<'x, F>,
+    pub y: ArrayViewMut1<'y, F>,
+    pub param: RotmParam<F>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y, F> ROTM_<'x, 'y, F>
+where
+    F: ROTMNum + Float,
+{
+    pub fn driver(self) -> Result<ROTM_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, param, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // perform check
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // prepare output
+        let x = ArrayOut1::ViewMut(x);
+        let y = ArrayOut1::ViewMut(y);
+
+        // finalize
+        let driver = ROTM_Driver {
+            n: n.try_into()?,
+            x,
+            incx: incx.try_into()?,
+            y,
+            incy: incy.try_into()?,
+            param: param.to_array(),
+        };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type ROTM<'x, 'y, F> = ROTM_Builder<'x, 'y, F>;
+pub type SROTM<'x, 'y> = ROTM<'x, 'y, f32>;
+pub type DROTM<'x, 'y> = ROTM<'x, 'y, f64>;
+
+impl<'x, 'y, F> ROTM<'x, 'y, F>
+where
+    F: ROTMNum + Float,
+{
+    pub fn run(self) -> Result<(ArrayOut1<'x, F>, ArrayOut1<'y, F>), BLASError> {
+        let _ctx = CopyContext::enter("ROTM", self.copy_policy.flatten());
+        self.build()?.driver()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of ROTM; `run` is only available when all required arguments are given.
+        ROTM_Typed for ROTM_Builder ['x, 'y] [F] where [F: ROTMNum + Float] => (ArrayOut1<'x, F>, ArrayOut1<'y, F>);
+        required { x: ArrayViewMut1<'x, F> as X, y: ArrayViewMut1<'y, F> as Y, param: RotmParam<F> as PARAM }
+        optional { copy_policy: CopyPolicy }
+        aliases { ROTM, SROTM = f32, DROTM = f64 }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,115 @@
+use crate::blas1::rotm::RotmParam;
+use crate::ffi;
+use crate::util::*;
+use derive_builder::Builder;
+use num_traits::*;
+
+/* #region BLAS func */
+
+pub trait ROTMGNum: BLASFloat {
+    unsafe fn rotmg(d1: *mut Self, d2: *mut Self, x1: *mut Self, y1: *const Self, param: *mut Self);
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl ROTMGNum for $type {
+            unsafe fn rotmg(d1: *mut Self, d2: *mut Self, x1: *mut Self, y1: *const Self, param: *mut Self) {
+                if blas_plan_call!($func, [], []) {
+                    return;
+                }
+                ffi::$func(d1, d2, x1, y1, param);
+            }
+        }
+    };
+}
+
+impl_func!(f32, srotmg_);
+impl_func!(f64, drotmg_);
+
+/* #endregion */
+
+/* #region BLAS result */
+
+/// Modified Givens rotation, with
+/// $\mathbf{H} \begin{pmatrix} \sqrt{d_1} x_1 \\\\ \sqrt{d_2} y_1 \end{pmatrix} = \begin{pmatrix} \sqrt{d_1'} x_1' \\\\ 0 \end{pmatrix}$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ROTMGResult<F> {
+    pub d1: F,
+    pub d2: F,
+    pub x1: F,
+    pub param: RotmParam<F>,
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct ROTMG_<F>
+where
+    F: ROTMGNum + Float,
+{
+    pub d1: F,
+    pub d2: F,
+    pub x1: F,
+    pub y1: F,
+}
+
+impl<F> ROTMG_<F>
+where
+    F: ROTMGNum + Float,
+{
+    pub fn run_blas(self) -> Result<ROTMGResult<F>, BLASError> {
+        let Self { mut d1, mut d2, mut x1, y1 } = self;
+        let mut param = [F::zero(); 5];
+        unsafe {
+            F::rotmg(&mut d1, &mut d2, &mut x1, &y1, param.as_mut_ptr());
+        }
+        check_blas_call()?;
+        let param = RotmParam::from_array(param)?;
+        return Ok(ROTMGResult { d1, d2, x1, param });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type ROTMG<F> = ROTMG_Builder<F>;
+pub type SROTMG = ROTMG<f32>;
+pub type DROTMG = ROTMG<f64>;
+
+impl<F> ROTMG<F>
+where
+    F: ROTMGNum + Float,
+{
+    pub fn run(self) -> Result<ROTMGResult<F>, BLASError> {
+        let _ctx = CopyContext::enter("ROTMG", None);
+        self.build()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of ROTMG; `run` is only available when all required arguments are given.
+        ROTMG_Typed for ROTMG_Builder [] [F] where [F: ROTMGNum + Float] => ROTMGResult<F>;
+        required { d1: F as D1, d2: F as D2, x1: F as X1, y1: F as Y1 }
+        optional {  }
+        aliases { ROTMG, SROTMG = f32, DROTMG = f64 }
+    }
+}
+
+/* #endregion */
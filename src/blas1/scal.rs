@@ -0,0 +1,255 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::*;
+
+/* #region BLAS func */
+
+pub trait SCALNum: BLASFloat {
+    unsafe fn scal(n: *const blas_int, a: *const Self, x: *mut Self, incx: *const blas_int);
+    unsafe fn rscal(n: *const blas_int, a: *const Self::RealFloat, x: *mut Self, incx: *const blas_int);
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident, $rfunc: ident) => {
+        impl SCALNum for $type {
+            unsafe fn scal(n: *const blas_int, a: *const Self, x: *mut Self, incx: *const blas_int) {
+                if blas_plan_call!($func, [], [n, incx]) {
+                    return;
+                }
+                ffi::$func(n, a, x, incx);
+            }
+
+            unsafe fn rscal(
+                n: *const blas_int,
+                a: *const Self::RealFloat,
+                x: *mut Self,
+                incx: *const blas_int,
+            ) {
+                if blas_plan_call!($rfunc, [], [n, incx]) {
+                    return;
+                }
+                ffi::$rfunc(n, a, x, incx);
+            }
+        }
+    };
+}
+
+impl_func!(f32, sscal_, sscal_);
+impl_func!(f64, dscal_, dscal_);
+impl_func!(c32, cscal_, csscal_);
+impl_func!(c64, zscal_, zdscal_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct SCAL_Driver<'x, F>
+where
+    F: SCALNum,
+{
+    n: blas_int,
+    alpha: F,
+    x: ArrayOut1<'x, F>,
+    incx: blas_int,
+}
+
+impl<'x, F> BLASDriver<'x, F, Ix1> for SCAL_Driver<'x, F>
+where
+    F: SCALNum,
+{
+    fn run_blas(self) -> Result<ArrayOut1<'x, F>, BLASError> {
+        let Self { n, alpha, mut x, incx } = self;
+        let x_ptr = x.get_base_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(x);
+        }
+
+        unsafe {
+            F::scal(&n, &alpha, x_ptr, &incx);
+        }
+        check_blas_call()?;
+        return Ok(x);
+    }
+}
+
+pub struct RSCAL_Driver<'x, F>
+where
+    F: SCALNum,
+{
+    n: blas_int,
+    alpha: F::RealFloat,
+    x: ArrayOut1<'x, F>,
+    incx: blas_int,
+}
+
+impl<'x, F> BLASDriver<'x, F, Ix1> for RSCAL_Driver<'x, F>
+where
+    F: SCALNum,
+{
+    fn run_blas(self) -> Result<ArrayOut1<'x, F>, BLASError> {
+        let Self { n, alpha, mut x, incx } = self;
+        let x_ptr = x.get_base_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok(x);
+        }
+
+        unsafe {
+            F::rscal(&n, &alpha, x_ptr, &incx);
+        }
+        check_blas_call()?;
+        return Ok(x);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct SCAL_<'x, F>
+where
+    F: SCALNum,
+{
+    pub x: ArrayViewMut1<'x, F>,
+
+    #[builder(setter(into), default = "F::one()")]
+    pub alpha: F,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, F> BLASBuilder_<'x, F, Ix1> for SCAL_<'x, F>
+where
+    F: SCALNum,
+{
+    fn driver(self) -> Result<SCAL_Driver<'x, F>, BLASError> {
+        let Self { x, alpha, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        // order of elements is irrelevant, and BLAS does nothing for negative increment;
+        // so pass the element of lowest address with positive increment
+        let incx = get_vec_inc(&x.view()).abs();
+
+        // prepare output
+        let x = ArrayOut1::ViewMut(x);
+
+        // finalize
+        let driver = SCAL_Driver { n: n.try_into()?, alpha, x, incx: incx.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/// Scaling of vector by real number (`csscal`, `zdscal` for complex vectors).
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct RSCAL_<'x, F>
+where
+    F: SCALNum,
+{
+    pub x: ArrayViewMut1<'x, F>,
+
+    #[builder(setter(into), default = "F::RealFloat::one()")]
+    pub alpha: F::RealFloat,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, F> BLASBuilder_<'x, F, Ix1> for RSCAL_<'x, F>
+where
+    F: SCALNum,
+{
+    fn driver(self) -> Result<RSCAL_Driver<'x, F>, BLASError> {
+        let Self { x, alpha, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        // order of elements is irrelevant, and BLAS does nothing for negative increment;
+        // so pass the element of lowest address with positive increment
+        let incx = get_vec_inc(&x.view()).abs();
+
+        // prepare output
+        let x = ArrayOut1::ViewMut(x);
+
+        // finalize
+        let driver = RSCAL_Driver { n: n.try_into()?, alpha, x, incx: incx.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type SCAL<'x, F> = SCAL_Builder<'x, F>;
+pub type SSCAL<'x> = SCAL<'x, f32>;
+pub type DSCAL<'x> = SCAL<'x, f64>;
+pub type CSCAL<'x> = SCAL<'x, c32>;
+pub type ZSCAL<'x> = SCAL<'x, c64>;
+
+pub type RSCAL<'x, F> = RSCAL_Builder<'x, F>;
+pub type CSSCAL<'x> = RSCAL<'x, c32>;
+pub type ZDSCAL<'x> = RSCAL<'x, c64>;
+
+impl<'x, F> BLASBuilder<'x, F, Ix1> for SCAL_Builder<'x, F>
+where
+    F: SCALNum,
+{
+    fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("SCAL", self.copy_policy.flatten());
+        let obj = self.build()?;
+
+        // x = alpha x; vectors have no layout to be flipped
+        return obj.driver()?.run_blas();
+    }
+}
+
+impl<'x, F> BLASBuilder<'x, F, Ix1> for RSCAL_Builder<'x, F>
+where
+    F: SCALNum,
+{
+    fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("RSCAL", self.copy_policy.flatten());
+        let obj = self.build()?;
+
+        // x = alpha x; vectors have no layout to be flipped
+        return obj.driver()?.run_blas();
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of SCAL; `run` is only available when all required arguments are given.
+        SCAL_Typed for SCAL_Builder ['x] [F] where [F: SCALNum] => ArrayOut1<'x, F>;
+        required { x: ArrayViewMut1<'x, F> as X }
+        optional { alpha: F, copy_policy: CopyPolicy }
+        aliases { SCAL, SSCAL = f32, DSCAL = f64, CSCAL = c32, ZSCAL = c64 }
+    }
+
+    blas_typestate! {
+        /// Typestate builder of RSCAL; `run` is only available when all required arguments are given.
+        RSCAL_Typed for RSCAL_Builder ['x] [F] where [F: SCALNum] => ArrayOut1<'x, F>;
+        required { x: ArrayViewMut1<'x, F> as X }
+        optional { alpha: F::RealFloat, copy_policy: CopyPolicy }
+        aliases { RSCAL, CSSCAL = c32, ZDSCAL = c64 }
+    }
+}
+
+/* #endregion */
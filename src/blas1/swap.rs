@@ -0,0 +1,163 @@
+use crate::ffi::{self, blas_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait SWAPNum: BLASFloat {
+    unsafe fn swap(
+        n: *const blas_int,
+        x: *mut Self,
+        incx: *const blas_int,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl SWAPNum for $type {
+            unsafe fn swap(
+                n: *const blas_int,
+                x: *mut Self,
+                incx: *const blas_int,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                if blas_plan_call!($func, [], [n, incx, incy]) {
+                    return;
+                }
+                ffi::$func(n, x, incx, y, incy);
+            }
+        }
+    };
+}
+
+impl_func!(f32, sswap_);
+impl_func!(f64, dswap_);
+impl_func!(c32, cswap_);
+impl_func!(c64, zswap_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct SWAP_Driver<'x, 'y, F>
+where
+    F: SWAPNum,
+{
+    n: blas_int,
+    x: ArrayOut1<'x, F>,
+    incx: blas_int,
+    y: ArrayOut1<'y, F>,
+    incy: blas_int,
+}
+
+impl<'x, 'y, F> SWAP_Driver<'x, 'y, F>
+where
+    F: SWAPNum,
+{
+    pub fn run_blas(self) -> Result<(ArrayOut1<'x, F>, ArrayOut1<'y, F>), BLASError> {
+        let Self { n, mut x, incx, mut y, incy } = self;
+        let x_ptr = x.get_base_mut_ptr();
+        let y_ptr = y.get_base_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if n == 0 {
+            return Ok((x, y));
+        }
+
+        unsafe {
+            F::swap(&n, x_ptr, &incx, y_ptr, &incy);
+        }
+        check_blas_call()?;
+        return Ok((x, y));
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct SWAP_<'x, 'y, F>
+where
+    F: SWAPNum,
+{
+    pub x: ArrayViewMut1<'x, F>,
+    pub y: ArrayViewMut1<'y, F>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'x, 'y, F> SWAP_<'x, 'y, F>
+where
+    F: SWAPNum,
+{
+    pub fn driver(self) -> Result<SWAP_Driver<'x, 'y, F>, BLASError> {
+        let Self { x, y, .. } = self;
+
+        // initialize intent(hide)
+        let n = x.len_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
+
+        // perform check
+        blas_assert_eq!(y.len_of(Axis(0)), n, InvalidDim)?;
+
+        // prepare output
+        let x = ArrayOut1::ViewMut(x);
+        let y = ArrayOut1::ViewMut(y);
+
+        // finalize
+        let driver = SWAP_Driver { n: n.try_into()?, x, incx: incx.try_into()?, y, incy: incy.try_into()? };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type SWAP<'x, 'y, F> = SWAP_Builder<'x, 'y, F>;
+pub type SSWAP<'x, 'y> = SWAP<'x, 'y, f32>;
+pub type DSWAP<'x, 'y> = SWAP<'x, 'y, f64>;
+pub type CSWAP<'x, 'y> = SWAP<'x, 'y, c32>;
+pub type ZSWAP<'x, 'y> = SWAP<'x, 'y, c64>;
+
+impl<'x, 'y, F> SWAP<'x, 'y, F>
+where
+    F: SWAPNum,
+{
+    pub fn run(self) -> Result<(ArrayOut1<'x, F>, ArrayOut1<'y, F>), BLASError> {
+        let _ctx = CopyContext::enter("SWAP", self.copy_policy.flatten());
+        self.build()?.driver()?.run_blas()
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of SWAP; `run` is only available when all required arguments are given.
+        SWAP_Typed for SWAP_Builder ['x, 'y] [F] where [F: SWAPNum] => (ArrayOut1<'x, F>, ArrayOut1<'y, F>);
+        required { x: ArrayViewMut1<'x, F> as X, y: ArrayViewMut1<'y, F> as Y }
+        optional { copy_policy: CopyPolicy }
+        aliases { SWAP, SSWAP = f32, DSWAP = f64, CSWAP = c32, ZSWAP = c64 }
+    }
+}
+
+/* #endregion */
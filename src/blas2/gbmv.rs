@@ -21,10 +21,29 @@ pub trait GBMVNum: BLASFloat {
         y: *mut Self,
         incy: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`GBMVNum::gbmv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_gbmv(
+        layout: *const c_char,
+        trans: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        kl: *const blas_int,
+        ku: *const blas_int,
+        alpha: *const Self,
+        a: *const Self,
+        lda: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        beta: *const Self,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl GBMVNum for $type {
             unsafe fn gbmv(
                 trans: *const c_char,
@@ -41,16 +60,45 @@ macro_rules! impl_func {
                 y: *mut Self,
                 incy: *const blas_int,
             ) {
+                if blas_plan_call!($func, [trans], [m, n, kl, ku, lda, incx, incy]) {
+                    return;
+                }
                 ffi::$func(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_gbmv(
+                layout: *const c_char,
+                trans: *const c_char,
+                m: *const blas_int,
+                n: *const blas_int,
+                kl: *const blas_int,
+                ku: *const blas_int,
+                alpha: *const Self,
+                a: *const Self,
+                lda: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                beta: *const Self,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                use ffi::cblas::{self, CBLASScalar};
+                if blas_plan_call!($cfunc, [layout, trans], [m, n, kl, ku, lda, incx, incy]) {
+                    return;
+                }
+                let (layout, trans) = (cblas::layout(layout), cblas::trans(trans));
+                let (alpha, beta) = (CBLASScalar::arg(alpha), CBLASScalar::arg(beta));
+                cblas::$cfunc(layout, trans, *m, *n, *kl, *ku, alpha, a, *lda, x, *incx, beta, y, *incy);
+            }
         }
     };
 }
 
-impl_func!(f32, sgbmv_);
-impl_func!(f64, dgbmv_);
-impl_func!(c32, cgbmv_);
-impl_func!(c64, zgbmv_);
+impl_func!(f32, sgbmv_, cblas_sgbmv);
+impl_func!(f64, dgbmv_, cblas_dgbmv);
+impl_func!(c32, cgbmv_, cblas_cgbmv);
+impl_func!(c64, zgbmv_, cblas_zgbmv);
 
 /* #endregion */
 
@@ -60,6 +108,7 @@ pub struct GBMV_Driver<'a, 'x, 'y, F>
 where
     F: GBMVNum,
 {
+    layout: c_char,
     trans: c_char,
     m: blas_int,
     n: blas_int,
@@ -68,7 +117,7 @@ where
     alpha: F,
     a: ArrayView2<'a, F>,
     lda: blas_int,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
     beta: F,
     y: ArrayOut1<'y, F>,
@@ -80,10 +129,10 @@ where
     F: GBMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
-        let Self { trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, mut y, incy } = self;
+        let Self { layout, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, mut y, incy } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.get_data_mut_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = y.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -92,8 +141,17 @@ where
         }
 
         unsafe {
-            F::gbmv(&trans, &m, &n, &kl, &ku, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_gbmv(
+                    &layout, &trans, &m, &n, &kl, &ku, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy,
+                );
+            } else {
+                F::gbmv(&trans, &m, &n, &kl, &ku, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            }
         }
+        check_blas_call()?;
         return Ok(y);
     }
 }
@@ -123,6 +181,8 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for GBMV_<'a, 'x, 'y, F>
@@ -130,22 +190,35 @@ where
     F: GBMVNum,
 {
     fn driver(self) -> Result<GBMV_Driver<'a, 'x, 'y, F>, BLASError> {
-        let Self { a, x, m, kl, y, alpha, beta, trans, layout } = self;
+        let Self { a, x, m, kl, y, alpha, beta, trans, layout, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or row-major with CBLAS
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
-        assert!(layout == Some(BLASLayout::ColMajor));
+        let layout = layout.unwrap();
+        match layout {
+            BLASColMajor => assert!(layout_a.is_fpref()),
+            _ => assert!(ffi::USE_CBLAS && layout == BLASRowMajor && layout_a.is_cpref()),
+        }
 
         // initialize intent(hide)
-        let (k, n) = a.dim();
-        let lda = a.stride_of(Axis(1));
-        let incx = x.stride_of(Axis(0));
+        // band storage is k x n in col-major; in row-major, it is m x k, and `m` of builder is number of columns
+        let k = match layout {
+            BLASColMajor => a.len_of(Axis(0)),
+            _ => a.len_of(Axis(1)),
+        };
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert!(k > kl, InvalidDim)?;
         blas_assert!(m >= k, InvalidDim)?;
         let ku = k - 1 - kl;
+        let (m, n, lda) = match layout {
+            BLASColMajor => (m, a.len_of(Axis(1)), a.stride_of(Axis(1))),
+            _ => (a.len_of(Axis(0)), m, a.stride_of(Axis(0))),
+        };
         match trans {
             BLASNoTrans => blas_assert_eq!(x.len_of(Axis(0)), n, InvalidDim)?,
             BLASTrans | BLASConjTrans => blas_assert_eq!(x.len_of(Axis(0)), m, InvalidDim)?,
@@ -168,10 +241,11 @@ where
                 _ => blas_invalid!(trans)?,
             })),
         };
-        let incy = y.view().stride_of(Axis(0));
+        let incy = get_vec_inc(&y.view());
 
         // finalize
         let driver = GBMV_Driver {
+            layout: layout.try_into()?,
             trans: trans.try_into()?,
             m: m.try_into()?,
             n: n.try_into()?,
@@ -206,7 +280,8 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
-        let GBMV_ { a, x, m, kl, y, alpha, beta, trans, layout } = self.build()?;
+        let _ctx = CopyContext::enter("GBMV", self.copy_policy.flatten());
+        let GBMV_ { a, x, m, kl, y, alpha, beta, trans, layout, .. } = self.build()?;
 
         let layout_a = get_layout_array2(&a);
         let layout = match layout {
@@ -221,15 +296,44 @@ where
 
         if layout == BLASColMajor {
             // F-contiguous
+            plan_layout(BLASColMajor);
             let a_cow = a.to_col_layout()?;
-            let obj = GBMV_ { a: a_cow.view(), x, m, kl, y, alpha, beta, trans, layout: Some(BLASColMajor) };
+            let obj = GBMV_ {
+                a: a_cow.view(),
+                x,
+                m,
+                kl,
+                y,
+                alpha,
+                beta,
+                trans,
+                layout: Some(BLASColMajor),
+                copy_policy: None,
+            };
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let a_cow = a.to_row_layout()?;
             let k = a_cow.len_of(Axis(1));
             blas_assert!(k > kl, InvalidDim)?;
             let ku = k - kl - 1;
+            if ffi::USE_CBLAS {
+                // row-major band storage is passed to CBLAS directly, without conjugate of x and y
+                let obj = GBMV_ {
+                    a: a_cow.view(),
+                    x,
+                    m,
+                    kl,
+                    y,
+                    alpha,
+                    beta,
+                    trans,
+                    layout: Some(BLASRowMajor),
+                    copy_policy: None,
+                };
+                return obj.driver()?.run_blas();
+            }
             match trans {
                 BLASNoTrans => {
                     // N -> T
@@ -243,6 +347,7 @@ where
                         beta,
                         trans: BLASTrans,
                         layout: Some(BLASColMajor),
+                        copy_policy: None,
                     };
                     return obj.driver()?.run_blas();
                 },
@@ -258,6 +363,7 @@ where
                         beta,
                         trans: BLASNoTrans,
                         layout: Some(BLASColMajor),
+                        copy_policy: None,
                     };
                     return obj.driver()?.run_blas();
                 },
@@ -265,7 +371,7 @@ where
                     // C -> N
                     let x = x.mapv(F::conj);
                     let y = y.map(|mut y| {
-                        y.mapv_inplace(F::conj);
+                        conj_view_inplace(&mut y);
                         y
                     });
                     let obj = GBMV_ {
@@ -278,9 +384,10 @@ where
                         beta: F::conj(beta),
                         trans: BLASNoTrans,
                         layout: Some(BLASColMajor),
+                        copy_policy: None,
                     };
                     let mut y = obj.driver()?.run_blas()?;
-                    y.view_mut().mapv_inplace(F::conj);
+                    y.conj_inplace();
                     return Ok(y);
                 },
                 _ => return blas_invalid!(trans)?,
@@ -290,3 +397,26 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GBMV; `run` is only available when all required arguments are given.
+        GBMV_Typed for GBMV_Builder ['a, 'x, 'y] [F] where [F: GBMVNum] => ArrayOut1<'y, F>;
+        required { a: ArrayView2<'a, F> as A, x: ArrayView1<'x, F> as X, m: usize as M, kl: usize as KL }
+        optional {
+            y: ArrayViewMut1<'y, F>,
+            alpha: F,
+            beta: F,
+            trans: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { GBMV, SGBMV = f32, DGBMV = f64, CGBMV = c32, ZGBMV = c64 }
+    }
+}
+
+/* #endregion */
@@ -19,10 +19,27 @@ pub trait GEMVNum: BLASFloat {
         y: *mut Self,
         incy: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`GEMVNum::gemv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_gemv(
+        layout: *const c_char,
+        trans: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        alpha: *const Self,
+        a: *const Self,
+        lda: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        beta: *const Self,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl GEMVNum for $type {
             unsafe fn gemv(
                 trans: *const c_char,
@@ -37,16 +54,43 @@ macro_rules! impl_func {
                 y: *mut Self,
                 incy: *const blas_int,
             ) {
+                if blas_plan_call!($func, [trans], [m, n, lda, incx, incy]) {
+                    return;
+                }
                 ffi::$func(trans, m, n, alpha, a, lda, x, incx, beta, y, incy);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_gemv(
+                layout: *const c_char,
+                trans: *const c_char,
+                m: *const blas_int,
+                n: *const blas_int,
+                alpha: *const Self,
+                a: *const Self,
+                lda: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                beta: *const Self,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                use ffi::cblas::{self, CBLASScalar};
+                if blas_plan_call!($cfunc, [layout, trans], [m, n, lda, incx, incy]) {
+                    return;
+                }
+                let (layout, trans) = (cblas::layout(layout), cblas::trans(trans));
+                let (alpha, beta) = (CBLASScalar::arg(alpha), CBLASScalar::arg(beta));
+                cblas::$cfunc(layout, trans, *m, *n, alpha, a, *lda, x, *incx, beta, y, *incy);
+            }
         }
     };
 }
 
-impl_func!(f32, sgemv_);
-impl_func!(f64, dgemv_);
-impl_func!(c32, cgemv_);
-impl_func!(c64, zgemv_);
+impl_func!(f32, sgemv_, cblas_sgemv);
+impl_func!(f64, dgemv_, cblas_dgemv);
+impl_func!(c32, cgemv_, cblas_cgemv);
+impl_func!(c64, zgemv_, cblas_zgemv);
 
 /* #endregion */
 
@@ -56,13 +100,14 @@ pub struct GEMV_Driver<'a, 'x, 'y, F>
 where
     F: BLASFloat,
 {
+    layout: c_char,
     trans: c_char,
     m: blas_int,
     n: blas_int,
     alpha: F,
     a: ArrayView2<'a, F>,
     lda: blas_int,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
     beta: F,
     y: ArrayOut1<'y, F>,
@@ -74,10 +119,10 @@ where
     F: GEMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
-        let Self { trans, m, n, alpha, a, lda, x, incx, beta, mut y, incy } = self;
+        let Self { layout, trans, m, n, alpha, a, lda, x, incx, beta, mut y, incy } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.get_data_mut_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = y.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -86,8 +131,17 @@ where
         }
 
         unsafe {
-            F::gemv(&trans, &m, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_gemv(
+                    &layout, &trans, &m, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy,
+                );
+            } else {
+                F::gemv(&trans, &m, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            }
         }
+        check_blas_call()?;
         return Ok(y);
     }
 }
@@ -113,6 +167,8 @@ where
     pub beta: F,
     #[builder(setter(into), default = "BLASNoTrans")]
     pub trans: BLASTranspose,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for GEMV_<'a, 'x, 'y, F>
@@ -120,16 +176,23 @@ where
     F: GEMVNum,
 {
     fn driver(self) -> Result<GEMV_Driver<'a, 'x, 'y, F>, BLASError> {
-        let Self { a, x, y, alpha, beta, trans } = self;
+        let Self { a, x, y, alpha, beta, trans, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or C-contiguous with CBLAS
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        let layout = if layout_a.is_fpref() { BLASColMajor } else { BLASRowMajor };
+        assert!(layout_a.is_fpref() || (ffi::USE_CBLAS && layout_a.is_cpref()));
 
         // initialize intent(hide)
         let (m, n) = a.dim();
-        let lda = a.stride_of(Axis(1));
-        let incx = x.stride_of(Axis(0));
+        let lda = match layout {
+            BLASColMajor => a.stride_of(Axis(1)),
+            _ => a.stride_of(Axis(0)),
+        };
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         match trans {
@@ -154,10 +217,11 @@ where
                 _ => blas_invalid!(trans)?,
             })),
         };
-        let incy = y.view().stride_of(Axis(0));
+        let incy = get_vec_inc(&y.view());
 
         // finalize
         let driver = GEMV_Driver {
+            layout: layout.try_into()?,
             trans: trans.try_into()?,
             m: m.try_into()?,
             n: n.try_into()?,
@@ -190,16 +254,24 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("GEMV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout_a = get_layout_array2(&obj.a);
 
         if layout_a.is_fpref() {
             // F-contiguous: y = alpha op(A) x + beta y
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let a_cow = obj.a.to_row_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major matrix is passed to CBLAS directly, without conjugate of x and y
+                let obj = GEMV_ { a: a_cow.view(), ..obj };
+                return obj.driver()?.run_blas();
+            }
             match obj.trans {
                 BLASNoTrans => {
                     // N -> T: y = alpha (A')' x + beta y
@@ -215,7 +287,7 @@ where
                     // C -> N: y* = alpha* (A') x* + beta* y*; y = y*
                     let x = obj.x.mapv(F::conj);
                     let y = obj.y.map(|mut y| {
-                        y.mapv_inplace(F::conj);
+                        conj_view_inplace(&mut y);
                         y
                     });
                     let obj = GEMV_ {
@@ -225,9 +297,10 @@ where
                         y,
                         alpha: F::conj(obj.alpha),
                         beta: F::conj(obj.beta),
+                        copy_policy: None,
                     };
                     let mut y = obj.driver()?.run_blas()?;
-                    y.view_mut().mapv_inplace(F::conj);
+                    y.conj_inplace();
                     return Ok(y);
                 },
                 _ => return blas_invalid!(&obj.trans)?,
@@ -237,3 +310,139 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of GEMV, created by [`GEMV_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct GEMVPlan<F>
+where
+    F: GEMVNum,
+{
+    alpha: F,
+    beta: F,
+    trans: BLASTranspose,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_x: ArraySignature,
+    sig_y: Option<ArraySignature>,
+    len_y: usize,
+    call: Option<GEMVPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct GEMVPlanCall {
+    layout: c_char,
+    trans: c_char,
+    m: blas_int,
+    n: blas_int,
+    lda: blas_int,
+    incx: blas_int,
+    incy: blas_int,
+}
+
+impl<'a, 'x, 'y, F> GEMV_Builder<'a, 'x, 'y, F>
+where
+    F: GEMVNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<GEMVPlan<F>, BLASError> {
+        let GEMV_ { a, x, y, alpha, beta, trans, copy_policy } = self.build()?;
+        let mut plan = GEMVPlan {
+            alpha,
+            beta,
+            trans,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_x: ArraySignature::of(&x),
+            sig_y: y.as_ref().map(ArraySignature::of),
+            len_y: if trans == BLASNoTrans { a.nrows() } else { a.ncols() },
+            call: None,
+        };
+        if let Some((_, call)) = plan_direct_call(|| plan.builder(a, x, y).run().map(|_| ()))? {
+            plan.call = Some(GEMVPlanCall {
+                // layout is only recorded in call of CBLAS
+                layout: call.c_flag("layout").or(BLASColMajor.try_into())?,
+                trans: call.c_flag("trans")?,
+                m: call.c_dim("m")?,
+                n: call.c_dim("n")?,
+                lda: call.c_dim("lda")?,
+                incx: call.c_dim("incx")?,
+                incy: call.c_dim("incy")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> GEMVPlan<F>
+where
+    F: GEMVNum,
+{
+    fn builder<'a, 'x, 'y>(
+        &self,
+        a: ArrayView2<'a, F>,
+        x: ArrayView1<'x, F>,
+        y: Option<ArrayViewMut1<'y, F>>,
+    ) -> GEMV_Builder<'a, 'x, 'y, F> {
+        GEMV_Builder {
+            a: Some(a),
+            x: Some(x),
+            y: Some(y),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            trans: Some(self.trans),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'x, 'y>(
+        &self,
+        a: ArrayView2<'a, F>,
+        x: ArrayView1<'x, F>,
+        y: Option<ArrayViewMut1<'y, F>>,
+    ) -> Result<ArrayOut1<'y, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_x.check(&x, "x")?;
+        ArraySignature::check_opt(&self.sig_y, y.as_ref(), "y")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, x, y).run();
+        };
+        let GEMVPlanCall { layout, trans, m, n, lda, incx, incy } = *call;
+        let y = match y {
+            Some(y) => ArrayOut1::ViewMut(y),
+            None => ArrayOut1::Owned(Array1::zeros(self.len_y)),
+        };
+        let (alpha, beta, x) = (self.alpha, self.beta, CowArray::from(x));
+        // row-major A is passed as is (instead of transposed), since driver only uses its pointer
+        let driver = GEMV_Driver { layout, trans, m, n, alpha, a, lda, x, incx, beta, y, incy };
+        return driver.run_blas();
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GEMV; `run` is only available when all required arguments are given.
+        GEMV_Typed for GEMV_Builder ['a, 'x, 'y] [F] where [F: GEMVNum] => ArrayOut1<'y, F>;
+        required { a: ArrayView2<'a, F> as A, x: ArrayView1<'x, F> as X }
+        optional { y: ArrayViewMut1<'y, F>, alpha: F, beta: F, trans: BLASTranspose, copy_policy: CopyPolicy }
+        aliases { GEMV, SGEMV = f32, DGEMV = f64, CGEMV = c32, ZGEMV = c64 }
+    }
+}
+
+/* #endregion */
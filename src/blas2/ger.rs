@@ -33,6 +33,9 @@ macro_rules! impl_func {
                 a: *mut Self,
                 lda: *const blas_int,
             ) {
+                if blas_plan_call!($func, [], [m, n, incx, incy, lda]) {
+                    return;
+                }
                 ffi::$func(m, n, alpha, x, incx, y, incy, a, lda);
             }
         }
@@ -55,9 +58,9 @@ where
     m: blas_int,
     n: blas_int,
     alpha: F,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
-    y: ArrayView1<'y, F>,
+    y: CowArray<'y, F, Ix1>,
     incy: blas_int,
     a: ArrayOut2<'a, F>,
     lda: blas_int,
@@ -69,8 +72,8 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         let Self { m, n, alpha, x, incx, y, incy, mut a, lda } = self;
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
         let a_ptr = a.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -82,6 +85,7 @@ where
         unsafe {
             F::ger(&m, &n, &alpha, x_ptr, &incx, y_ptr, &incy, a_ptr, &lda);
         }
+        check_blas_call()?;
         return Ok(a.clone_to_view_mut());
     }
 }
@@ -103,6 +107,8 @@ where
     pub a: Option<ArrayViewMut2<'a, F>>,
     #[builder(setter(into), default = "F::one()")]
     pub alpha: F,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix2> for GER_<'x, 'y, 'a, F>
@@ -110,11 +116,15 @@ where
     F: GERNum,
 {
     fn driver(self) -> Result<GER_Driver<'x, 'y, 'a, F>, BLASError> {
-        let Self { x, y, a, alpha } = self;
+        let Self { x, y, a, alpha, .. } = self;
+
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+        let y = to_nonzero_stride(y)?;
 
         // initialize intent(hide)
-        let incx = x.stride_of(Axis(0));
-        let incy = y.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
         let m = x.len_of(Axis(0));
         let n = y.len_of(Axis(0));
 
@@ -165,13 +175,16 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("GER", self.copy_policy.flatten());
         let obj = self.build()?;
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let a = obj.a.map(|a| a.reversed_axes());
             let obj = GER_ { a, x: obj.y, y: obj.x, ..obj };
             let a = obj.driver()?.run_blas()?;
@@ -181,3 +194,19 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GER; `run` is only available when all required arguments are given.
+        GER_Typed for GER_Builder ['x, 'y, 'a] [F] where [F: GERNum] => ArrayOut2<'a, F>;
+        required { x: ArrayView1<'x, F> as X, y: ArrayView1<'y, F> as Y }
+        optional { a: ArrayViewMut2<'a, F>, alpha: F, copy_policy: CopyPolicy }
+        aliases { GER, SGER = f32, DGER = f64, CGERU = c32, ZGERU = c64 }
+    }
+}
+
+/* #endregion */
@@ -34,6 +34,9 @@ macro_rules! impl_func {
                 a: *mut Self,
                 lda: *const blas_int,
             ) {
+                if blas_plan_call!($func, [], [m, n, incx, incy, lda]) {
+                    return;
+                }
                 ffi::$func(m, n, alpha, x, incx, y, incy, a, lda);
             }
         }
@@ -54,9 +57,9 @@ where
     m: blas_int,
     n: blas_int,
     alpha: F,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
-    y: ArrayView1<'y, F>,
+    y: CowArray<'y, F, Ix1>,
     incy: blas_int,
     a: ArrayOut2<'a, F>,
     lda: blas_int,
@@ -68,8 +71,8 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         let Self { m, n, alpha, x, incx, y, incy, mut a, lda } = self;
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
         let a_ptr = a.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -81,6 +84,7 @@ where
         unsafe {
             F::gerc(&m, &n, &alpha, x_ptr, &incx, y_ptr, &incy, a_ptr, &lda);
         }
+        check_blas_call()?;
         return Ok(a.clone_to_view_mut());
     }
 }
@@ -102,6 +106,8 @@ where
     pub a: Option<ArrayViewMut2<'a, F>>,
     #[builder(setter(into), default = "F::one()")]
     pub alpha: F,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix2> for GERC_<'x, 'y, 'a, F>
@@ -109,11 +115,15 @@ where
     F: GERCNum,
 {
     fn driver(self) -> Result<GERC_Driver<'x, 'y, 'a, F>, BLASError> {
-        let Self { x, y, a, alpha } = self;
+        let Self { x, y, a, alpha, .. } = self;
+
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+        let y = to_nonzero_stride(y)?;
 
         // initialize intent(hide)
-        let incx = x.stride_of(Axis(0));
-        let incy = y.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
         let m = x.len_of(Axis(0));
         let n = y.len_of(Axis(0));
 
@@ -162,16 +172,19 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("GERC", self.copy_policy.flatten());
         let obj = self.build()?;
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let a = obj.a.map(|a| a.reversed_axes());
             let y = obj.y.mapv(F::conj);
-            let obj = GER_ { a, x: y.view(), y: obj.x, alpha: obj.alpha };
+            let obj = GER_ { a, x: y.view(), y: obj.x, alpha: obj.alpha, copy_policy: None };
             let a = obj.driver()?.run_blas()?;
             return Ok(a.reversed_axes());
         }
@@ -179,3 +192,19 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GERC; `run` is only available when all required arguments are given.
+        GERC_Typed for GERC_Builder ['x, 'y, 'a] [F] where [F: GERCNum] => ArrayOut2<'a, F>;
+        required { x: ArrayView1<'x, F> as X, y: ArrayView1<'y, F> as Y }
+        optional { a: ArrayViewMut2<'a, F>, alpha: F, copy_policy: CopyPolicy }
+        aliases { GERC, CGERC = c32, ZGERC = c64 }
+    }
+}
+
+/* #endregion */
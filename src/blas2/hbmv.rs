@@ -19,10 +19,27 @@ pub trait HBMVNum: BLASFloat {
         y: *mut Self,
         incy: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`HBMVNum::hbmv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_hbmv(
+        layout: *const c_char,
+        uplo: *const c_char,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const Self,
+        a: *const Self,
+        lda: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        beta: *const Self,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl HBMVNum for $type {
             unsafe fn hbmv(
                 uplo: *const c_char,
@@ -37,16 +54,43 @@ macro_rules! impl_func {
                 y: *mut Self,
                 incy: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo], [n, k, lda, incx, incy]) {
+                    return;
+                }
                 ffi::$func(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_hbmv(
+                layout: *const c_char,
+                uplo: *const c_char,
+                n: *const blas_int,
+                k: *const blas_int,
+                alpha: *const Self,
+                a: *const Self,
+                lda: *const blas_int,
+                x: *const Self,
+                incx: *const blas_int,
+                beta: *const Self,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                use ffi::cblas::{self, CBLASScalar};
+                if blas_plan_call!($cfunc, [layout, uplo], [n, k, lda, incx, incy]) {
+                    return;
+                }
+                let (layout, uplo) = (cblas::layout(layout), cblas::uplo(uplo));
+                let (alpha, beta) = (CBLASScalar::arg(alpha), CBLASScalar::arg(beta));
+                cblas::$cfunc(layout, uplo, *n, *k, alpha, a, *lda, x, *incx, beta, y, *incy);
+            }
         }
     };
 }
 
-impl_func!(f32, ssbmv_);
-impl_func!(f64, dsbmv_);
-impl_func!(c32, chbmv_);
-impl_func!(c64, zhbmv_);
+impl_func!(f32, ssbmv_, cblas_ssbmv);
+impl_func!(f64, dsbmv_, cblas_dsbmv);
+impl_func!(c32, chbmv_, cblas_chbmv);
+impl_func!(c64, zhbmv_, cblas_zhbmv);
 
 /* #endregion */
 
@@ -56,13 +100,14 @@ pub struct HBMV_Driver<'a, 'x, 'y, F>
 where
     F: HBMVNum,
 {
+    layout: c_char,
     uplo: c_char,
     n: blas_int,
     k: blas_int,
     alpha: F,
     a: ArrayView2<'a, F>,
     lda: blas_int,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
     beta: F,
     y: ArrayOut1<'y, F>,
@@ -74,10 +119,10 @@ where
     F: HBMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
-        let Self { uplo, n, k, alpha, a, lda, x, incx, beta, mut y, incy, .. } = self;
+        let Self { layout, uplo, n, k, alpha, a, lda, x, incx, beta, mut y, incy } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.get_data_mut_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = y.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -86,8 +131,15 @@ where
         }
 
         unsafe {
-            F::hbmv(&uplo, &n, &k, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_hbmv(&layout, &uplo, &n, &k, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            } else {
+                F::hbmv(&uplo, &n, &k, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            }
         }
+        check_blas_call()?;
         return Ok(y);
     }
 }
@@ -115,6 +167,8 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for HBMV_<'a, 'x, 'y, F>
@@ -124,17 +178,26 @@ where
     fn driver(self) -> Result<HBMV_Driver<'a, 'x, 'y, F>, BLASError> {
         let Self { a, x, y, alpha, beta, uplo, layout, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or row-major with CBLAS
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
-        assert!(layout == Some(BLASLayout::ColMajor));
+        let layout = layout.unwrap();
+        match layout {
+            BLASColMajor => assert!(layout_a.is_fpref()),
+            _ => assert!(ffi::USE_CBLAS && layout == BLASRowMajor && layout_a.is_cpref()),
+        }
 
         // initialize intent(hide)
-        let (k_, n) = a.dim();
+        // band storage is (k + 1) x n in col-major, and n x (k + 1) in row-major
+        let ((k_, n), lda) = match layout {
+            BLASColMajor => (a.dim(), a.stride_of(Axis(1))),
+            _ => ((a.len_of(Axis(1)), a.len_of(Axis(0))), a.stride_of(Axis(0))),
+        };
         blas_assert!(k_ > 0, InvalidDim, "Rows of input `a` must larger than zero.")?;
         let k = k_ - 1;
-        let lda = a.stride_of(Axis(1));
-        let incx = x.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert_eq!(x.len_of(Axis(0)), n, InvalidDim)?;
@@ -147,10 +210,11 @@ where
             },
             None => ArrayOut1::Owned(Array1::zeros(n)),
         };
-        let incy = y.view().stride_of(Axis(0));
+        let incy = get_vec_inc(&y.view());
 
         // finalize
         let driver = HBMV_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             n: n.try_into()?,
             k: k.try_into()?,
@@ -183,6 +247,7 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HBMV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout_a = get_layout_array2(&obj.a);
@@ -196,10 +261,15 @@ where
         } else {
             // C-contiguous
             let a_cow = obj.a.to_row_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major band storage is passed to CBLAS directly, without conjugate of x and y
+                let obj = HBMV_ { a: a_cow.view(), layout: Some(BLASRowMajor), ..obj };
+                return obj.driver()?.run_blas();
+            }
             if F::is_complex() {
                 let x = obj.x.mapv(F::conj);
                 let y = obj.y.map(|mut y| {
-                    y.mapv_inplace(F::conj);
+                    conj_view_inplace(&mut y);
                     y
                 });
                 let obj = HBMV_ {
@@ -213,7 +283,7 @@ where
                     ..obj
                 };
                 let mut y = obj.driver()?.run_blas()?;
-                y.view_mut().mapv_inplace(F::conj);
+                y.conj_inplace();
                 return Ok(y);
             } else {
                 let obj = HBMV_ { a: a_cow.t(), uplo: obj.uplo.flip()?, layout: Some(BLASColMajor), ..obj };
@@ -224,3 +294,26 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HBMV; `run` is only available when all required arguments are given.
+        HBMV_Typed for HBMV_Builder ['a, 'x, 'y] [F] where [F: HBMVNum] => ArrayOut1<'y, F>;
+        required { a: ArrayView2<'a, F> as A, x: ArrayView1<'x, F> as X }
+        optional {
+            y: ArrayViewMut1<'y, F>,
+            alpha: F,
+            beta: F,
+            uplo: BLASUpLo,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { HBMV, SSBMV = f32, DSBMV = f64, CHBMV = c32, ZHBMV = c64 }
+    }
+}
+
+/* #endregion */
@@ -18,10 +18,26 @@ pub trait HEMVNum: BLASFloat {
         y: *mut Self,
         incy: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`HEMVNum::hemv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_hemv(
+        layout: *const c_char,
+        uplo: *const c_char,
+        n: *const blas_int,
+        alpha: *const Self,
+        a: *const Self,
+        lda: *const blas_int,
+        x: *const Self,
+        incx: *const blas_int,
+        beta: *const Self,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl HEMVNum for $type {
             unsafe fn hemv(
                 uplo: *const c_char,
@@ -35,16 +51,42 @@ macro_rules! impl_func {
                 y: *mut $type,
                 incy: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo], [n, lda, incx, incy]) {
+                    return;
+                }
                 ffi::$func(uplo, n, alpha, a, lda, x, incx, beta, y, incy);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_hemv(
+                layout: *const c_char,
+                uplo: *const c_char,
+                n: *const blas_int,
+                alpha: *const $type,
+                a: *const $type,
+                lda: *const blas_int,
+                x: *const $type,
+                incx: *const blas_int,
+                beta: *const $type,
+                y: *mut $type,
+                incy: *const blas_int,
+            ) {
+                use ffi::cblas::{self, CBLASScalar};
+                if blas_plan_call!($cfunc, [layout, uplo], [n, lda, incx, incy]) {
+                    return;
+                }
+                let (layout, uplo) = (cblas::layout(layout), cblas::uplo(uplo));
+                let (alpha, beta) = (CBLASScalar::arg(alpha), CBLASScalar::arg(beta));
+                cblas::$cfunc(layout, uplo, *n, alpha, a, *lda, x, *incx, beta, y, *incy);
+            }
         }
     };
 }
 
-impl_func!(f32, ssymv_);
-impl_func!(f64, dsymv_);
-impl_func!(c32, chemv_);
-impl_func!(c64, zhemv_);
+impl_func!(f32, ssymv_, cblas_ssymv);
+impl_func!(f64, dsymv_, cblas_dsymv);
+impl_func!(c32, chemv_, cblas_chemv);
+impl_func!(c64, zhemv_, cblas_zhemv);
 
 /* #endregion */
 
@@ -54,12 +96,13 @@ pub struct HEMV_Driver<'a, 'x, 'y, F>
 where
     F: HEMVNum,
 {
+    layout: c_char,
     uplo: c_char,
     n: blas_int,
     alpha: F,
     a: ArrayView2<'a, F>,
     lda: blas_int,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
     beta: F,
     y: ArrayOut1<'y, F>,
@@ -71,10 +114,10 @@ where
     F: HEMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
-        let Self { uplo, n, alpha, a, lda, x, incx, beta, mut y, incy, .. } = self;
+        let Self { layout, uplo, n, alpha, a, lda, x, incx, beta, mut y, incy } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.get_data_mut_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = y.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -83,8 +126,15 @@ where
         }
 
         unsafe {
-            F::hemv(&uplo, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_hemv(&layout, &uplo, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            } else {
+                F::hemv(&uplo, &n, &alpha, a_ptr, &lda, x_ptr, &incx, &beta, y_ptr, &incy);
+            }
         }
+        check_blas_call()?;
         return Ok(y);
     }
 }
@@ -110,6 +160,8 @@ where
     pub beta: F,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for HEMV_<'a, 'x, 'y, F>
@@ -119,14 +171,21 @@ where
     fn driver(self) -> Result<HEMV_Driver<'a, 'x, 'y, F>, BLASError> {
         let Self { a, x, y, alpha, beta, uplo, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or C-contiguous with CBLAS
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        let layout = if layout_a.is_fpref() { BLASColMajor } else { BLASRowMajor };
+        assert!(layout_a.is_fpref() || (ffi::USE_CBLAS && layout_a.is_cpref()));
 
         // initialize intent(hide)
         let (n_, n) = a.dim();
-        let lda = a.stride_of(Axis(1));
-        let incx = x.stride_of(Axis(0));
+        let lda = match layout {
+            BLASColMajor => a.stride_of(Axis(1)),
+            _ => a.stride_of(Axis(0)),
+        };
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert_eq!(n, n_, InvalidDim)?;
@@ -140,10 +199,11 @@ where
             },
             None => ArrayOut1::Owned(Array1::zeros(n)),
         };
-        let incy = y.view().stride_of(Axis(0));
+        let incy = get_vec_inc(&y.view());
 
         // finalize
         let driver = HEMV_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             n: n.try_into()?,
             alpha,
@@ -175,20 +235,28 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HEMV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout_a = get_layout_array2(&obj.a);
 
         if layout_a.is_fpref() {
             // F-contiguous
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let a_cow = obj.a.to_row_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major matrix is passed to CBLAS directly, without conjugate of x and y
+                let obj = HEMV_ { a: a_cow.view(), ..obj };
+                return obj.driver()?.run_blas();
+            }
             if F::is_complex() {
                 let x = obj.x.mapv(F::conj);
                 let y = obj.y.map(|mut y| {
-                    y.mapv_inplace(F::conj);
+                    conj_view_inplace(&mut y);
                     y
                 });
                 let obj = HEMV_ {
@@ -201,7 +269,7 @@ where
                     ..obj
                 };
                 let mut y = obj.driver()?.run_blas()?;
-                y.view_mut().mapv_inplace(F::conj);
+                y.conj_inplace();
                 return Ok(y);
             } else {
                 let obj = HEMV_ { a: a_cow.t(), uplo: obj.uplo.flip()?, ..obj };
@@ -212,3 +280,19 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HEMV; `run` is only available when all required arguments are given.
+        HEMV_Typed for HEMV_Builder ['a, 'x, 'y] [F] where [F: HEMVNum] => ArrayOut1<'y, F>;
+        required { a: ArrayView2<'a, F> as A, x: ArrayView1<'x, F> as X }
+        optional { y: ArrayViewMut1<'y, F>, alpha: F, beta: F, uplo: BLASUpLo, copy_policy: CopyPolicy }
+        aliases { HEMV, SSYMV = f32, DSYMV = f64, CHEMV = c32, ZHEMV = c64 }
+    }
+}
+
+/* #endregion */
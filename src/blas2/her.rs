@@ -30,6 +30,9 @@ macro_rules! impl_func {
                 a: *mut Self,
                 lda: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo], [n, incx, lda]) {
+                    return;
+                }
                 ffi::$func(uplo, n, alpha, x, incx, a, lda);
             }
         }
@@ -52,7 +55,7 @@ where
     uplo: c_char,
     n: blas_int,
     alpha: F::RealFloat,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
     a: ArrayOut2<'a, F>,
     lda: blas_int,
@@ -64,7 +67,7 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         let Self { uplo, n, alpha, x, incx, mut a, lda, .. } = self;
-        let x_ptr = x.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
         let a_ptr = a.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -76,6 +79,7 @@ where
         unsafe {
             F::her(&uplo, &n, &alpha, x_ptr, &incx, a_ptr, &lda);
         }
+        check_blas_call()?;
         return Ok(a.clone_to_view_mut());
     }
 }
@@ -98,6 +102,8 @@ where
     pub alpha: F::RealFloat,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, 'a, F> BLASBuilder_<'a, F, Ix2> for HER_<'x, 'a, F>
@@ -107,8 +113,11 @@ where
     fn driver(self) -> Result<HER_Driver<'x, 'a, F>, BLASError> {
         let Self { x, a, alpha, uplo, .. } = self;
 
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+
         // initialize intent(hide)
-        let incx = x.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
         let n = x.len_of(Axis(0));
 
         // prepare output
@@ -156,13 +165,16 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HER", self.copy_policy.flatten());
         let obj = self.build()?;
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let uplo = obj.uplo.flip()?;
             let a = obj.a.map(|a| a.reversed_axes());
             if F::is_complex() {
@@ -180,3 +192,19 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HER; `run` is only available when all required arguments are given.
+        HER_Typed for HER_Builder ['x, 'a] [F] where [F: HERNum] => ArrayOut2<'a, F>;
+        required { x: ArrayView1<'x, F> as X }
+        optional { a: ArrayViewMut2<'a, F>, alpha: F::RealFloat, uplo: BLASUpLo, copy_policy: CopyPolicy }
+        aliases { HER, SSYR = f32, DSYR = f64, CHER = c32, ZHER = c64 }
+    }
+}
+
+/* #endregion */
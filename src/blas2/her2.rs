@@ -33,6 +33,9 @@ macro_rules! impl_func {
                 a: *mut Self,
                 lda: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo], [n, incx, incy, lda]) {
+                    return;
+                }
                 ffi::$func(uplo, n, alpha, x, incx, y, incy, a, lda);
             }
         }
@@ -55,9 +58,9 @@ where
     uplo: c_char,
     n: blas_int,
     alpha: F,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
-    y: ArrayView1<'y, F>,
+    y: CowArray<'y, F, Ix1>,
     incy: blas_int,
     a: ArrayOut2<'a, F>,
     lda: blas_int,
@@ -69,8 +72,8 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         let Self { uplo, n, alpha, x, incx, y, incy, mut a, lda } = self;
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
         let a_ptr = a.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -82,6 +85,7 @@ where
         unsafe {
             F::syr2(&uplo, &n, &alpha, x_ptr, &incx, y_ptr, &incy, a_ptr, &lda);
         }
+        check_blas_call()?;
         return Ok(a.clone_to_view_mut());
     }
 }
@@ -105,6 +109,8 @@ where
     pub alpha: F,
     #[builder(setter(into), default = "BLASUpper")]
     pub uplo: BLASUpLo,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix2> for HER2_<'x, 'y, 'a, F>
@@ -114,9 +120,13 @@ where
     fn driver(self) -> Result<HER2_Driver<'x, 'y, 'a, F>, BLASError> {
         let Self { x, y, a, alpha, uplo, .. } = self;
 
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+        let y = to_nonzero_stride(y)?;
+
         // initialize intent(hide)
-        let incx = x.stride_of(Axis(0));
-        let incy = y.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
         let n = x.len_of(Axis(0));
 
         // check optional
@@ -171,13 +181,16 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HER2", self.copy_policy.flatten());
         let obj = self.build()?;
 
         if obj.a.as_ref().map(|a| a.view().is_fpref()) == Some(true) {
             // F-contiguous
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let uplo = obj.uplo.flip()?;
             let a = obj.a.map(|a| a.reversed_axes());
             if F::is_complex() {
@@ -196,3 +209,19 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HER2; `run` is only available when all required arguments are given.
+        HER2_Typed for HER2_Builder ['x, 'y, 'a] [F] where [F: HER2Num] => ArrayOut2<'a, F>;
+        required { x: ArrayView1<'x, F> as X, y: ArrayView1<'y, F> as Y }
+        optional { a: ArrayViewMut2<'a, F>, alpha: F, uplo: BLASUpLo, copy_policy: CopyPolicy }
+        aliases { SYR2, SSYR2 = f32, DSYR2 = f64, HER2, CHER2 = c32, ZHER2 = c64 }
+    }
+}
+
+/* #endregion */
@@ -17,10 +17,25 @@ pub trait HPMVNum: BLASFloat {
         y: *mut Self,
         incy: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`HPMVNum::hpmv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_hpmv(
+        layout: *const c_char,
+        uplo: *const c_char,
+        n: *const blas_int,
+        alpha: *const Self,
+        ap: *const Self,
+        x: *const Self,
+        incx: *const blas_int,
+        beta: *const Self,
+        y: *mut Self,
+        incy: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl HPMVNum for $type {
             unsafe fn hpmv(
                 uplo: *const c_char,
@@ -33,16 +48,41 @@ macro_rules! impl_func {
                 y: *mut Self,
                 incy: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo], [n, incx, incy]) {
+                    return;
+                }
                 ffi::$func(uplo, n, alpha, ap, x, incx, beta, y, incy);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_hpmv(
+                layout: *const c_char,
+                uplo: *const c_char,
+                n: *const blas_int,
+                alpha: *const Self,
+                ap: *const Self,
+                x: *const Self,
+                incx: *const blas_int,
+                beta: *const Self,
+                y: *mut Self,
+                incy: *const blas_int,
+            ) {
+                use ffi::cblas::{self, CBLASScalar};
+                if blas_plan_call!($cfunc, [layout, uplo], [n, incx, incy]) {
+                    return;
+                }
+                let (layout, uplo) = (cblas::layout(layout), cblas::uplo(uplo));
+                let (alpha, beta) = (CBLASScalar::arg(alpha), CBLASScalar::arg(beta));
+                cblas::$cfunc(layout, uplo, *n, alpha, ap, x, *incx, beta, y, *incy);
+            }
         }
     };
 }
 
-impl_func!(f32, sspmv_);
-impl_func!(f64, dspmv_);
-impl_func!(c32, chpmv_);
-impl_func!(c64, zhpmv_);
+impl_func!(f32, sspmv_, cblas_sspmv);
+impl_func!(f64, dspmv_, cblas_dspmv);
+impl_func!(c32, chpmv_, cblas_chpmv);
+impl_func!(c64, zhpmv_, cblas_zhpmv);
 
 /* #endregion */
 
@@ -52,11 +92,12 @@ pub struct HPMV_Driver<'a, 'x, 'y, F>
 where
     F: HPMVNum,
 {
+    layout: c_char,
     uplo: c_char,
     n: blas_int,
     alpha: F,
     ap: ArrayView1<'a, F>,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
     beta: F,
     y: ArrayOut1<'y, F>,
@@ -68,10 +109,10 @@ where
     F: HPMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'y, F>, BLASError> {
-        let Self { uplo, n, alpha, ap, x, incx, beta, mut y, incy, .. } = self;
+        let Self { layout, uplo, n, alpha, ap, x, incx, beta, mut y, incy } = self;
         let ap_ptr = ap.as_ptr();
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.get_data_mut_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = y.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -80,8 +121,15 @@ where
         }
 
         unsafe {
-            F::hpmv(&uplo, &n, &alpha, ap_ptr, x_ptr, &incx, &beta, y_ptr, &incy);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_hpmv(&layout, &uplo, &n, &alpha, ap_ptr, x_ptr, &incx, &beta, y_ptr, &incy);
+            } else {
+                F::hpmv(&uplo, &n, &alpha, ap_ptr, x_ptr, &incx, &beta, y_ptr, &incy);
+            }
         }
+        check_blas_call()?;
         return Ok(y);
     }
 }
@@ -109,6 +157,8 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, 'y, F> BLASBuilder_<'y, F, Ix1> for HPMV_<'a, 'x, 'y, F>
@@ -118,15 +168,19 @@ where
     fn driver(self) -> Result<HPMV_Driver<'a, 'x, 'y, F>, BLASError> {
         let Self { ap, x, y, alpha, beta, uplo, layout, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or row-major with CBLAS
         let incap = ap.stride_of(Axis(0));
         assert!(incap <= 1);
-        assert_eq!(layout, Some(BLASColMajor));
+        let layout = layout.unwrap();
+        assert!(layout == BLASColMajor || (ffi::USE_CBLAS && layout == BLASRowMajor));
 
         // initialize intent(hide)
         let np = ap.len_of(Axis(0));
         let n = x.len_of(Axis(0));
-        let incx = x.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert_eq!(np, n * (n + 1) / 2, InvalidDim)?;
@@ -139,10 +193,11 @@ where
             },
             None => ArrayOut1::Owned(Array1::zeros(n)),
         };
-        let incy = y.view().stride_of(Axis(0));
+        let incy = get_vec_inc(&y.view());
 
         // finalize
         let driver = HPMV_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             n: n.try_into()?,
             alpha,
@@ -173,22 +228,30 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'y, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HPMV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout = obj.layout.unwrap_or(BLASRowMajor);
 
         if layout == BLASColMajor {
             // F-contiguous
+            plan_layout(BLASColMajor);
             let ap_cow = obj.ap.to_seq_layout()?;
             let obj = HPMV_ { ap: ap_cow.view(), layout: Some(BLASColMajor), ..obj };
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let ap_cow = obj.ap.to_seq_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major packed storage is passed to CBLAS directly, without conjugate of x and y
+                let obj = HPMV_ { ap: ap_cow.view(), layout: Some(BLASRowMajor), ..obj };
+                return obj.driver()?.run_blas();
+            }
             if F::is_complex() {
                 let x = obj.x.mapv(F::conj);
                 let y = obj.y.map(|mut y| {
-                    y.mapv_inplace(F::conj);
+                    conj_view_inplace(&mut y);
                     y
                 });
                 let obj = HPMV_ {
@@ -202,7 +265,7 @@ where
                     ..obj
                 };
                 let mut y = obj.driver()?.run_blas()?;
-                y.view_mut().mapv_inplace(F::conj);
+                y.conj_inplace();
                 return Ok(y);
             } else {
                 let obj =
@@ -214,3 +277,26 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HPMV; `run` is only available when all required arguments are given.
+        HPMV_Typed for HPMV_Builder ['a, 'x, 'y] [F] where [F: HPMVNum] => ArrayOut1<'y, F>;
+        required { ap: ArrayView1<'a, F> as AP, x: ArrayView1<'x, F> as X }
+        optional {
+            y: ArrayViewMut1<'y, F>,
+            alpha: F,
+            beta: F,
+            uplo: BLASUpLo,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { HPMV, SSPMV = f32, DSPMV = f64, CHPMV = c32, ZHPMV = c64 }
+    }
+}
+
+/* #endregion */
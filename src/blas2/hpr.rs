@@ -28,6 +28,9 @@ macro_rules! impl_func {
                 incx: *const blas_int,
                 ap: *mut Self,
             ) {
+                if blas_plan_call!($func, [uplo], [n, incx]) {
+                    return;
+                }
                 ffi::$func(uplo, n, alpha, x, incx, ap);
             }
         }
@@ -50,7 +53,7 @@ where
     uplo: c_char,
     n: blas_int,
     alpha: F::RealFloat,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
     ap: ArrayOut1<'a, F>,
 }
@@ -61,7 +64,7 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut1<'a, F>, BLASError> {
         let Self { uplo, n, alpha, x, incx, mut ap, .. } = self;
-        let x_ptr = x.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
         let ap_ptr = ap.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -73,6 +76,7 @@ where
         unsafe {
             F::hpr(&uplo, &n, &alpha, x_ptr, &incx, ap_ptr);
         }
+        check_blas_call()?;
         return Ok(ap.clone_to_view_mut());
     }
 }
@@ -97,6 +101,8 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, 'a, F> BLASBuilder_<'a, F, Ix1> for HPR_<'x, 'a, F>
@@ -106,8 +112,11 @@ where
     fn driver(self) -> Result<HPR_Driver<'x, 'a, F>, BLASError> {
         let Self { x, ap, alpha, uplo, layout, .. } = self;
 
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+
         // initialize intent(hide)
-        let incx = x.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
         let n = x.len_of(Axis(0));
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
@@ -150,13 +159,16 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'a, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HPR", self.copy_policy.flatten());
         let obj = self.build()?;
 
         if obj.layout == Some(BLASColMajor) {
             // F-contiguous
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let uplo = obj.uplo.flip()?;
             if F::is_complex() {
                 let x = obj.x.mapv(F::conj);
@@ -171,3 +183,25 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HPR; `run` is only available when all required arguments are given.
+        HPR_Typed for HPR_Builder ['x, 'a] [F] where [F: HPRNum] => ArrayOut1<'a, F>;
+        required { x: ArrayView1<'x, F> as X }
+        optional {
+            ap: ArrayViewMut1<'a, F>,
+            alpha: F::RealFloat,
+            uplo: BLASUpLo,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { HPR, SSPR = f32, DSPR = f64, CHPR = c32, ZHPR = c64 }
+    }
+}
+
+/* #endregion */
@@ -31,6 +31,9 @@ macro_rules! impl_func {
                 incy: *const blas_int,
                 ap: *mut Self,
             ) {
+                if blas_plan_call!($func, [uplo], [n, incx, incy]) {
+                    return;
+                }
                 ffi::$func(uplo, n, alpha, x, incx, y, incy, ap);
             }
         }
@@ -53,9 +56,9 @@ where
     uplo: c_char,
     n: blas_int,
     alpha: F,
-    x: ArrayView1<'x, F>,
+    x: CowArray<'x, F, Ix1>,
     incx: blas_int,
-    y: ArrayView1<'y, F>,
+    y: CowArray<'y, F, Ix1>,
     incy: blas_int,
     ap: ArrayOut1<'a, F>,
 }
@@ -66,8 +69,8 @@ where
 {
     fn run_blas(self) -> Result<ArrayOut1<'a, F>, BLASError> {
         let Self { uplo, n, alpha, x, incx, y, incy, mut ap } = self;
-        let x_ptr = x.as_ptr();
-        let y_ptr = y.as_ptr();
+        let x_ptr = get_vec_base_ptr(&x.view());
+        let y_ptr = get_vec_base_ptr(&y.view());
         let ap_ptr = ap.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
@@ -79,6 +82,7 @@ where
         unsafe {
             F::hpr2(&uplo, &n, &alpha, x_ptr, &incx, y_ptr, &incy, ap_ptr);
         }
+        check_blas_call()?;
         return Ok(ap.clone_to_view_mut());
     }
 }
@@ -104,6 +108,8 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'x, 'y, 'a, F> BLASBuilder_<'a, F, Ix1> for HPR2_<'x, 'y, 'a, F>
@@ -113,9 +119,13 @@ where
     fn driver(self) -> Result<HPR2_Driver<'x, 'y, 'a, F>, BLASError> {
         let Self { x, y, ap, alpha, uplo, layout, .. } = self;
 
+        // zero-stride (broadcasted) vector is not accepted by BLAS
+        let x = to_nonzero_stride(x)?;
+        let y = to_nonzero_stride(y)?;
+
         // initialize intent(hide)
-        let incx = x.stride_of(Axis(0));
-        let incy = y.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
+        let incy = get_vec_inc(&y.view());
         let n = x.len_of(Axis(0));
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
@@ -169,13 +179,16 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'a, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HPR2", self.copy_policy.flatten());
         let obj = self.build()?;
 
         if obj.layout == Some(BLASColMajor) {
             // F-contiguous
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let uplo = obj.uplo.flip()?;
             if F::is_complex() {
                 let x = obj.x.mapv(F::conj);
@@ -191,3 +204,25 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HPR2; `run` is only available when all required arguments are given.
+        HPR2_Typed for HPR2_Builder ['x, 'y, 'a] [F] where [F: HPR2Num] => ArrayOut1<'a, F>;
+        required { x: ArrayView1<'x, F> as X, y: ArrayView1<'y, F> as Y }
+        optional {
+            ap: ArrayViewMut1<'a, F>,
+            alpha: F,
+            uplo: BLASUpLo,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { HPR2, SSPR2 = f32, DSPR2 = f64, CHPR2 = c32, ZHPR2 = c64 }
+    }
+}
+
+/* #endregion */
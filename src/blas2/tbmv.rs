@@ -17,10 +17,25 @@ pub trait TBMVNum: BLASFloat {
         x: *mut Self,
         incx: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`TBMVNum::tbmv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_tbmv(
+        layout: *const c_char,
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const blas_int,
+        k: *const blas_int,
+        a: *const Self,
+        lda: *const blas_int,
+        x: *mut Self,
+        incx: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl TBMVNum for $type {
             unsafe fn tbmv(
                 uplo: *const c_char,
@@ -33,16 +48,41 @@ macro_rules! impl_func {
                 x: *mut Self,
                 incx: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo, trans, diag], [n, k, lda, incx]) {
+                    return;
+                }
                 ffi::$func(uplo, trans, diag, n, k, a, lda, x, incx);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_tbmv(
+                layout: *const c_char,
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blas_int,
+                k: *const blas_int,
+                a: *const Self,
+                lda: *const blas_int,
+                x: *mut Self,
+                incx: *const blas_int,
+            ) {
+                use ffi::cblas;
+                if blas_plan_call!($cfunc, [layout, uplo, trans, diag], [n, k, lda, incx]) {
+                    return;
+                }
+                let (layout, uplo, trans, diag) =
+                    (cblas::layout(layout), cblas::uplo(uplo), cblas::trans(trans), cblas::diag(diag));
+                cblas::$cfunc(layout, uplo, trans, diag, *n, *k, a, *lda, x, *incx);
+            }
         }
     };
 }
 
-impl_func!(f32, stbmv_);
-impl_func!(f64, dtbmv_);
-impl_func!(c32, ctbmv_);
-impl_func!(c64, ztbmv_);
+impl_func!(f32, stbmv_, cblas_stbmv);
+impl_func!(f64, dtbmv_, cblas_dtbmv);
+impl_func!(c32, ctbmv_, cblas_ctbmv);
+impl_func!(c64, ztbmv_, cblas_ztbmv);
 
 /* #endregion */
 
@@ -52,6 +92,7 @@ pub struct TBMV_Driver<'a, 'x, F>
 where
     F: TBMVNum,
 {
+    layout: c_char,
     uplo: c_char,
     trans: c_char,
     diag: c_char,
@@ -68,9 +109,9 @@ where
     F: TBMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'x, F>, BLASError> {
-        let Self { uplo, trans, diag, n, k, a, lda, mut x, incx } = self;
+        let Self { layout, uplo, trans, diag, n, k, a, lda, mut x, incx } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.get_data_mut_ptr();
+        let x_ptr = x.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -79,8 +120,15 @@ where
         }
 
         unsafe {
-            F::tbmv(&uplo, &trans, &diag, &n, &k, a_ptr, &lda, x_ptr, &incx);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_tbmv(&layout, &uplo, &trans, &diag, &n, &k, a_ptr, &lda, x_ptr, &incx);
+            } else {
+                F::tbmv(&uplo, &trans, &diag, &n, &k, a_ptr, &lda, x_ptr, &incx);
+            }
         }
+        check_blas_call()?;
         return Ok(x);
     }
 }
@@ -106,6 +154,8 @@ where
     pub diag: BLASDiag,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TBMV_<'a, 'x, F>
@@ -113,19 +163,25 @@ where
     F: TBMVNum,
 {
     fn driver(self) -> Result<TBMV_Driver<'a, 'x, F>, BLASError> {
-        let Self { a, x, uplo, trans, diag, layout } = self;
+        let Self { a, x, uplo, trans, diag, layout, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or row-major with CBLAS
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
-        assert!(layout == Some(BLASLayout::ColMajor));
+        let layout = layout.unwrap();
+        match layout {
+            BLASColMajor => assert!(layout_a.is_fpref()),
+            _ => assert!(ffi::USE_CBLAS && layout == BLASRowMajor && layout_a.is_cpref()),
+        }
 
         // initialize intent(hide)
-        let (k_, n) = a.dim();
+        // band storage is (k + 1) x n in col-major, and n x (k + 1) in row-major
+        let ((k_, n), lda) = match layout {
+            BLASColMajor => (a.dim(), a.stride_of(Axis(1))),
+            _ => ((a.len_of(Axis(1)), a.len_of(Axis(0))), a.stride_of(Axis(0))),
+        };
         blas_assert!(k_ > 0, InvalidDim, "Rows of input `a` must larger than zero.")?;
         let k = k_ - 1;
-        let lda = a.stride_of(Axis(1));
-        let incx = x.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert_eq!(x.len_of(Axis(0)), n, InvalidDim)?;
@@ -135,6 +191,7 @@ where
 
         // finalize
         let driver = TBMV_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             trans: trans.try_into()?,
             diag: diag.try_into()?,
@@ -165,6 +222,7 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("TBMV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout_a = get_layout_array2(&obj.a);
@@ -178,6 +236,11 @@ where
         } else {
             // C-contiguous
             let a_cow = obj.a.to_row_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major band storage is passed to CBLAS directly, without conjugate of x
+                let obj = TBMV_ { a: a_cow.view(), layout: Some(BLASRowMajor), ..obj };
+                return obj.driver()?.run_blas();
+            }
             match obj.trans {
                 BLASNoTrans => {
                     // N -> T
@@ -204,7 +267,7 @@ where
                 BLASConjTrans => {
                     // C -> N
                     let mut x = obj.x;
-                    x.mapv_inplace(F::conj);
+                    conj_view_inplace(&mut x);
                     let obj = TBMV_ {
                         a: a_cow.t(),
                         x,
@@ -214,7 +277,7 @@ where
                         ..obj
                     };
                     let mut x = obj.driver()?.run_blas()?;
-                    x.view_mut().mapv_inplace(F::conj);
+                    x.conj_inplace();
                     return Ok(x);
                 },
                 _ => return blas_invalid!(obj.trans)?,
@@ -224,3 +287,25 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of TBMV; `run` is only available when all required arguments are given.
+        TBMV_Typed for TBMV_Builder ['a, 'x] [F] where [F: TBMVNum] => ArrayOut1<'x, F>;
+        required { a: ArrayView2<'a, F> as A, x: ArrayViewMut1<'x, F> as X }
+        optional {
+            uplo: BLASUpLo,
+            trans: BLASTranspose,
+            diag: BLASDiag,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { TBMV, STBMV = f32, DTBMV = f64, CTBMV = c32, ZTBMV = c64 }
+    }
+}
+
+/* #endregion */
@@ -15,10 +15,23 @@ pub trait TPMVNum: BLASFloat {
         x: *mut Self,
         incx: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`TPMVNum::tpmv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_tpmv(
+        layout: *const c_char,
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const blas_int,
+        ap: *const Self,
+        x: *mut Self,
+        incx: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl TPMVNum for $type {
             unsafe fn tpmv(
                 uplo: *const c_char,
@@ -29,16 +42,39 @@ macro_rules! impl_func {
                 x: *mut Self,
                 incx: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo, trans, diag], [n, incx]) {
+                    return;
+                }
                 ffi::$func(uplo, trans, diag, n, ap, x, incx);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_tpmv(
+                layout: *const c_char,
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blas_int,
+                ap: *const Self,
+                x: *mut Self,
+                incx: *const blas_int,
+            ) {
+                use ffi::cblas;
+                if blas_plan_call!($cfunc, [layout, uplo, trans, diag], [n, incx]) {
+                    return;
+                }
+                let (layout, uplo, trans, diag) =
+                    (cblas::layout(layout), cblas::uplo(uplo), cblas::trans(trans), cblas::diag(diag));
+                cblas::$cfunc(layout, uplo, trans, diag, *n, ap, x, *incx);
+            }
         }
     };
 }
 
-impl_func!(f32, stpmv_);
-impl_func!(f64, dtpmv_);
-impl_func!(c32, ctpmv_);
-impl_func!(c64, ztpmv_);
+impl_func!(f32, stpmv_, cblas_stpmv);
+impl_func!(f64, dtpmv_, cblas_dtpmv);
+impl_func!(c32, ctpmv_, cblas_ctpmv);
+impl_func!(c64, ztpmv_, cblas_ztpmv);
 
 /* #endregion */
 
@@ -48,6 +84,7 @@ pub struct TPMV_Driver<'a, 'x, F>
 where
     F: TPMVNum,
 {
+    layout: c_char,
     uplo: c_char,
     trans: c_char,
     diag: c_char,
@@ -62,9 +99,9 @@ where
     F: TPMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'x, F>, BLASError> {
-        let Self { uplo, trans, diag, n, ap, mut x, incx } = self;
+        let Self { layout, uplo, trans, diag, n, ap, mut x, incx } = self;
         let ap_ptr = ap.as_ptr();
-        let x_ptr = x.get_data_mut_ptr();
+        let x_ptr = x.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -73,8 +110,15 @@ where
         }
 
         unsafe {
-            F::tpmv(&uplo, &trans, &diag, &n, ap_ptr, x_ptr, &incx);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_tpmv(&layout, &uplo, &trans, &diag, &n, ap_ptr, x_ptr, &incx);
+            } else {
+                F::tpmv(&uplo, &trans, &diag, &n, ap_ptr, x_ptr, &incx);
+            }
         }
+        check_blas_call()?;
         return Ok(x);
     }
 }
@@ -100,6 +144,8 @@ where
     pub diag: BLASDiag,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TPMV_<'a, 'x, F>
@@ -107,17 +153,18 @@ where
     F: TPMVNum,
 {
     fn driver(self) -> Result<TPMV_Driver<'a, 'x, F>, BLASError> {
-        let Self { ap, x, uplo, trans, diag, layout } = self;
+        let Self { ap, x, uplo, trans, diag, layout, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or row-major with CBLAS
         let incap = ap.stride_of(Axis(0));
         assert!(incap <= 1);
-        assert_eq!(layout, Some(BLASColMajor));
+        let layout = layout.unwrap();
+        assert!(layout == BLASColMajor || (ffi::USE_CBLAS && layout == BLASRowMajor));
 
         // initialize intent(hide)
         let np = ap.len_of(Axis(0));
         let n = x.len_of(Axis(0));
-        let incx = x.stride_of(Axis(0));
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert_eq!(np, n * (n + 1) / 2, InvalidDim)?;
@@ -127,6 +174,7 @@ where
 
         // finalize
         let driver = TPMV_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             trans: trans.try_into()?,
             diag: diag.try_into()?,
@@ -155,18 +203,26 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("TPMV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout = obj.layout.unwrap_or(BLASRowMajor);
 
         if layout == BLASColMajor {
             // F-contiguous
+            plan_layout(BLASColMajor);
             let ap_cow = obj.ap.to_seq_layout()?;
             let obj = TPMV_ { ap: ap_cow.view(), layout: Some(BLASColMajor), ..obj };
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous
+            plan_layout(BLASRowMajor);
             let ap_cow = obj.ap.to_seq_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major packed storage is passed to CBLAS directly, without conjugate of x
+                let obj = TPMV_ { ap: ap_cow.view(), layout: Some(BLASRowMajor), ..obj };
+                return obj.driver()?.run_blas();
+            }
             match obj.trans {
                 BLASNoTrans => {
                     // N -> T
@@ -193,7 +249,7 @@ where
                 BLASConjTrans => {
                     // C -> N
                     let mut x = obj.x;
-                    x.mapv_inplace(F::conj);
+                    conj_view_inplace(&mut x);
                     let obj = TPMV_ {
                         ap: ap_cow.view(),
                         x,
@@ -203,7 +259,7 @@ where
                         ..obj
                     };
                     let mut x = obj.driver()?.run_blas()?;
-                    x.view_mut().mapv_inplace(F::conj);
+                    x.conj_inplace();
                     return Ok(x);
                 },
                 _ => return blas_invalid!(obj.trans)?,
@@ -213,3 +269,25 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of TPMV; `run` is only available when all required arguments are given.
+        TPMV_Typed for TPMV_Builder ['a, 'x] [F] where [F: TPMVNum] => ArrayOut1<'x, F>;
+        required { ap: ArrayView1<'a, F> as AP, x: ArrayViewMut1<'x, F> as X }
+        optional {
+            uplo: BLASUpLo,
+            trans: BLASTranspose,
+            diag: BLASDiag,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { TPMV, STPMV = f32, DTPMV = f64, CTPMV = c32, ZTPMV = c64 }
+    }
+}
+
+/* #endregion */
@@ -16,10 +16,24 @@ pub trait TRMVNum: BLASFloat {
         x: *mut Self,
         incx: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`TRMVNum::trmv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_trmv(
+        layout: *const c_char,
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const blas_int,
+        a: *const Self,
+        lda: *const blas_int,
+        x: *mut Self,
+        incx: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl TRMVNum for $type {
             unsafe fn trmv(
                 uplo: *const c_char,
@@ -31,16 +45,40 @@ macro_rules! impl_func {
                 x: *mut Self,
                 incx: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo, trans, diag], [n, lda, incx]) {
+                    return;
+                }
                 ffi::$func(uplo, trans, diag, n, a, lda, x, incx);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_trmv(
+                layout: *const c_char,
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blas_int,
+                a: *const Self,
+                lda: *const blas_int,
+                x: *mut Self,
+                incx: *const blas_int,
+            ) {
+                use ffi::cblas;
+                if blas_plan_call!($cfunc, [layout, uplo, trans, diag], [n, lda, incx]) {
+                    return;
+                }
+                let (layout, uplo, trans, diag) =
+                    (cblas::layout(layout), cblas::uplo(uplo), cblas::trans(trans), cblas::diag(diag));
+                cblas::$cfunc(layout, uplo, trans, diag, *n, a, *lda, x, *incx);
+            }
         }
     };
 }
 
-impl_func!(f32, strmv_);
-impl_func!(f64, dtrmv_);
-impl_func!(c32, ctrmv_);
-impl_func!(c64, ztrmv_);
+impl_func!(f32, strmv_, cblas_strmv);
+impl_func!(f64, dtrmv_, cblas_dtrmv);
+impl_func!(c32, ctrmv_, cblas_ctrmv);
+impl_func!(c64, ztrmv_, cblas_ztrmv);
 
 /* #endregion */
 
@@ -50,6 +88,7 @@ pub struct TRMV_Driver<'a, 'x, F>
 where
     F: TRMVNum,
 {
+    layout: c_char,
     uplo: c_char,
     trans: c_char,
     diag: c_char,
@@ -65,9 +104,9 @@ where
     F: TRMVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'x, F>, BLASError> {
-        let Self { uplo, trans, diag, n, a, lda, mut x, incx } = self;
+        let Self { layout, uplo, trans, diag, n, a, lda, mut x, incx } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.get_data_mut_ptr();
+        let x_ptr = x.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -76,8 +115,15 @@ where
         }
 
         unsafe {
-            F::trmv(&uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_trmv(&layout, &uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
+            } else {
+                F::trmv(&uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
+            }
         }
+        check_blas_call()?;
         return Ok(x);
     }
 }
@@ -101,6 +147,8 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into), default = "BLASNonUnit")]
     pub diag: BLASDiag,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TRMV_<'a, 'x, F>
@@ -108,16 +156,20 @@ where
     F: TRMVNum,
 {
     fn driver(self) -> Result<TRMV_Driver<'a, 'x, F>, BLASError> {
-        let Self { a, x, uplo, trans, diag } = self;
+        let Self { a, x, uplo, trans, diag, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or C-contiguous with CBLAS
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        let layout = if layout_a.is_fpref() { BLASColMajor } else { BLASRowMajor };
+        assert!(layout_a.is_fpref() || (ffi::USE_CBLAS && layout_a.is_cpref()));
 
         // initialize intent(hide)
         let (n, n_) = a.dim();
-        let lda = a.stride_of(Axis(1));
-        let incx = x.stride_of(Axis(0));
+        let lda = match layout {
+            BLASColMajor => a.stride_of(Axis(1)),
+            _ => a.stride_of(Axis(0)),
+        };
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert_eq!(n, n_, InvalidDim)?;
@@ -128,6 +180,7 @@ where
 
         // finalize
         let driver = TRMV_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             trans: trans.try_into()?,
             diag: diag.try_into()?,
@@ -157,16 +210,24 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("TRMV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout_a = get_layout_array2(&obj.a);
 
         if layout_a.is_fpref() {
             // F-contiguous: x = op(A) x
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous:
+            plan_layout(BLASRowMajor);
             let a_cow = obj.a.to_row_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major matrix is passed to CBLAS directly, without conjugate of x
+                let obj = TRMV_ { a: a_cow.view(), ..obj };
+                return obj.driver()?.run_blas();
+            }
             match obj.trans {
                 BLASNoTrans => {
                     // N -> T: x = op(A')' x
@@ -181,10 +242,10 @@ where
                 BLASConjTrans => {
                     // C -> T: x* = op(A') x*; x = x*
                     let mut x = obj.x;
-                    x.mapv_inplace(F::conj);
+                    conj_view_inplace(&mut x);
                     let obj = TRMV_ { a: a_cow.t(), x, trans: BLASNoTrans, uplo: obj.uplo.flip()?, ..obj };
                     let mut x = obj.driver()?.run_blas()?;
-                    x.view_mut().mapv_inplace(F::conj);
+                    x.conj_inplace();
                     return Ok(x);
                 },
                 _ => return blas_invalid!(&obj.trans)?,
@@ -194,3 +255,19 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of TRMV; `run` is only available when all required arguments are given.
+        TRMV_Typed for TRMV_Builder ['a, 'x] [F] where [F: TRMVNum] => ArrayOut1<'x, F>;
+        required { a: ArrayView2<'a, F> as A, x: ArrayViewMut1<'x, F> as X }
+        optional { uplo: BLASUpLo, trans: BLASTranspose, diag: BLASDiag, copy_policy: CopyPolicy }
+        aliases { TRMV, STRMV = f32, DTRMV = f64, CTRMV = c32, ZTRMV = c64 }
+    }
+}
+
+/* #endregion */
@@ -16,10 +16,24 @@ pub trait TRSVNum: BLASFloat {
         x: *mut Self,
         incx: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`TRSVNum::trsv`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_trsv(
+        layout: *const c_char,
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const blas_int,
+        a: *const Self,
+        lda: *const blas_int,
+        x: *mut Self,
+        incx: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl TRSVNum for $type {
             unsafe fn trsv(
                 uplo: *const c_char,
@@ -31,16 +45,40 @@ macro_rules! impl_func {
                 x: *mut Self,
                 incx: *const blas_int,
             ) {
+                if blas_plan_call!($func, [uplo, trans, diag], [n, lda, incx]) {
+                    return;
+                }
                 ffi::$func(uplo, trans, diag, n, a, lda, x, incx);
             }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_trsv(
+                layout: *const c_char,
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blas_int,
+                a: *const Self,
+                lda: *const blas_int,
+                x: *mut Self,
+                incx: *const blas_int,
+            ) {
+                use ffi::cblas;
+                if blas_plan_call!($cfunc, [layout, uplo, trans, diag], [n, lda, incx]) {
+                    return;
+                }
+                let (layout, uplo, trans, diag) =
+                    (cblas::layout(layout), cblas::uplo(uplo), cblas::trans(trans), cblas::diag(diag));
+                cblas::$cfunc(layout, uplo, trans, diag, *n, a, *lda, x, *incx);
+            }
         }
     };
 }
 
-impl_func!(f32, strsv_);
-impl_func!(f64, dtrsv_);
-impl_func!(c32, ctrsv_);
-impl_func!(c64, ztrsv_);
+impl_func!(f32, strsv_, cblas_strsv);
+impl_func!(f64, dtrsv_, cblas_dtrsv);
+impl_func!(c32, ctrsv_, cblas_ctrsv);
+impl_func!(c64, ztrsv_, cblas_ztrsv);
 
 /* #endregion */
 
@@ -50,6 +88,7 @@ pub struct TRSV_Driver<'a, 'x, F>
 where
     F: TRSVNum,
 {
+    layout: c_char,
     uplo: c_char,
     trans: c_char,
     diag: c_char,
@@ -65,9 +104,9 @@ where
     F: TRSVNum,
 {
     fn run_blas(self) -> Result<ArrayOut1<'x, F>, BLASError> {
-        let Self { uplo, trans, diag, n, a, lda, mut x, incx } = self;
+        let Self { layout, uplo, trans, diag, n, a, lda, mut x, incx } = self;
         let a_ptr = a.as_ptr();
-        let x_ptr = x.get_data_mut_ptr();
+        let x_ptr = x.get_base_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
@@ -76,8 +115,15 @@ where
         }
 
         unsafe {
-            F::trsv(&uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_trsv(&layout, &uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
+            } else {
+                F::trsv(&uplo, &trans, &diag, &n, a_ptr, &lda, x_ptr, &incx);
+            }
         }
+        check_blas_call()?;
         return Ok(x);
     }
 }
@@ -101,6 +147,8 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into), default = "BLASNonUnit")]
     pub diag: BLASDiag,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'x, F> BLASBuilder_<'x, F, Ix1> for TRSV_<'a, 'x, F>
@@ -108,16 +156,20 @@ where
     F: TRSVNum,
 {
     fn driver(self) -> Result<TRSV_Driver<'a, 'x, F>, BLASError> {
-        let Self { a, x, uplo, trans, diag } = self;
+        let Self { a, x, uplo, trans, diag, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or C-contiguous with CBLAS
         let layout_a = get_layout_array2(&a);
-        assert!(layout_a.is_fpref());
+        let layout = if layout_a.is_fpref() { BLASColMajor } else { BLASRowMajor };
+        assert!(layout_a.is_fpref() || (ffi::USE_CBLAS && layout_a.is_cpref()));
 
         // initialize intent(hide)
         let (n, n_) = a.dim();
-        let lda = a.stride_of(Axis(1));
-        let incx = x.stride_of(Axis(0));
+        let lda = match layout {
+            BLASColMajor => a.stride_of(Axis(1)),
+            _ => a.stride_of(Axis(0)),
+        };
+        let incx = get_vec_inc(&x.view());
 
         // perform check
         blas_assert_eq!(n, n_, InvalidDim)?;
@@ -128,6 +180,7 @@ where
 
         // finalize
         let driver = TRSV_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             trans: trans.try_into()?,
             diag: diag.try_into()?,
@@ -157,16 +210,24 @@ where
 {
     fn run(self) -> Result<ArrayOut1<'x, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("TRSV", self.copy_policy.flatten());
         let obj = self.build()?;
 
         let layout_a = get_layout_array2(&obj.a);
 
         if layout_a.is_fpref() {
             // F-contiguous: x = op(A) x
+            plan_layout(BLASColMajor);
             return obj.driver()?.run_blas();
         } else {
             // C-contiguous:
+            plan_layout(BLASRowMajor);
             let a_cow = obj.a.to_row_layout()?;
+            if ffi::USE_CBLAS {
+                // row-major matrix is passed to CBLAS directly, without conjugate of x
+                let obj = TRSV_ { a: a_cow.view(), ..obj };
+                return obj.driver()?.run_blas();
+            }
             match obj.trans {
                 BLASNoTrans => {
                     // N -> T: x = op(A')' x
@@ -181,10 +242,10 @@ where
                 BLASConjTrans => {
                     // C -> T: x* = op(A') x*; x = x*
                     let mut x = obj.x;
-                    x.mapv_inplace(F::conj);
+                    conj_view_inplace(&mut x);
                     let obj = TRSV_ { a: a_cow.t(), x, trans: BLASNoTrans, uplo: obj.uplo.flip()?, ..obj };
                     let mut x = obj.driver()?.run_blas()?;
-                    x.view_mut().mapv_inplace(F::conj);
+                    x.conj_inplace();
                     return Ok(x);
                 },
                 _ => return blas_invalid!(&obj.trans)?,
@@ -194,3 +255,19 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of TRSV; `run` is only available when all required arguments are given.
+        TRSV_Typed for TRSV_Builder ['a, 'x] [F] where [F: TRSVNum] => ArrayOut1<'x, F>;
+        required { a: ArrayView2<'a, F> as A, x: ArrayViewMut1<'x, F> as X }
+        optional { uplo: BLASUpLo, trans: BLASTranspose, diag: BLASDiag, copy_policy: CopyPolicy }
+        aliases { TRSV, STRSV = f32, DTRSV = f64, CTRSV = c32, ZTRSV = c64 }
+    }
+}
+
+/* #endregion */
@@ -1,3 +1,4 @@
+use crate::blas3::gemm_strided::{prefer_gemm_strided, run_gemm_strided, GEMMStridedNum};
 use crate::ffi::{self, blas_int, c_char};
 use crate::util::*;
 use derive_builder::Builder;
@@ -5,7 +6,7 @@ use ndarray::prelude::*;
 
 /* #region BLAS func */
 
-pub trait GEMMNum: BLASFloat {
+pub trait GEMMNum: GEMMStridedNum {
     unsafe fn gemm(
         transa: *const c_char,
         transb: *const c_char,
@@ -21,10 +22,29 @@ pub trait GEMMNum: BLASFloat {
         c: *mut Self,
         ldc: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`GEMMNum::gemm`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_gemm(
+        layout: *const c_char,
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const Self,
+        a: *const Self,
+        lda: *const blas_int,
+        b: *const Self,
+        ldb: *const blas_int,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: *const blas_int,
+    );
 }
 
 macro_rules! impl_func {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl GEMMNum for $type {
             unsafe fn gemm(
                 transa: *const c_char,
@@ -41,16 +61,49 @@ macro_rules! impl_func {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [transa, transb], [m, n, k, lda, ldb, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+            }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_gemm(
+                layout: *const c_char,
+                transa: *const c_char,
+                transb: *const c_char,
+                m: *const blas_int,
+                n: *const blas_int,
+                k: *const blas_int,
+                alpha: *const Self,
+                a: *const Self,
+                lda: *const blas_int,
+                b: *const Self,
+                ldb: *const blas_int,
+                beta: *const Self,
+                c: *mut Self,
+                ldc: *const blas_int,
+            ) {
+                use ffi::cblas::{self, CBLASScalar};
+                if blas_plan_call!($cfunc, [layout, transa, transb], [m, n, k, lda, ldb, ldc]) {
+                    return;
+                }
+                let (layout, transa, transb) =
+                    (cblas::layout(layout), cblas::trans(transa), cblas::trans(transb));
+                let (alpha, beta) = (CBLASScalar::arg(alpha), CBLASScalar::arg(beta));
+                cblas::$cfunc(layout, transa, transb, *m, *n, *k, alpha, a, *lda, b, *ldb, beta, c, *ldc);
             }
         }
     };
 }
 
-impl_func!(f32, sgemm_);
-impl_func!(f64, dgemm_);
-impl_func!(c32, cgemm_);
-impl_func!(c64, zgemm_);
+impl_func!(f32, sgemm_, cblas_sgemm);
+impl_func!(f64, dgemm_, cblas_dgemm);
+impl_func!(c32, cgemm_, cblas_cgemm);
+impl_func!(c64, zgemm_, cblas_zgemm);
 
 /* #endregion */
 
@@ -60,19 +113,36 @@ pub struct GEMM_Driver<'a, 'b, 'c, F>
 where
     F: GEMMNum,
 {
-    transa: c_char,
-    transb: c_char,
-    m: blas_int,
-    n: blas_int,
-    k: blas_int,
-    alpha: F,
-    a: ArrayView2<'a, F>,
-    lda: blas_int,
-    b: ArrayView2<'b, F>,
-    ldb: blas_int,
-    beta: F,
-    c: ArrayOut2<'c, F>,
-    ldc: blas_int,
+    pub(crate) layout: c_char,
+    pub(crate) transa: c_char,
+    pub(crate) transb: c_char,
+    pub(crate) m: blas_int,
+    pub(crate) n: blas_int,
+    pub(crate) k: blas_int,
+    pub(crate) alpha: F,
+    pub(crate) a: ArrayView2<'a, F>,
+    pub(crate) lda: blas_int,
+    pub(crate) b: ArrayView2<'b, F>,
+    pub(crate) ldb: blas_int,
+    pub(crate) beta: F,
+    pub(crate) c: ArrayOut2<'c, F>,
+    pub(crate) ldc: blas_int,
+    /// Whether C is conjugated in-place by [`conj_c`](Self::conj_c); conjugated back if BLAS call fails.
+    pub(crate) conj_c: bool,
+}
+
+impl<'a, 'b, 'c, F> GEMM_Driver<'a, 'b, 'c, F>
+where
+    F: GEMMNum,
+{
+    /// Conjugate C in-place before BLAS call, if conjugate of output is computed.
+    fn conj_c(mut self, conj: bool) -> Self {
+        if conj {
+            conj_view_inplace(&mut self.c.view_mut());
+        }
+        self.conj_c = conj;
+        return self;
+    }
 }
 
 impl<'a, 'b, 'c, F> BLASDriver<'c, F, Ix2> for GEMM_Driver<'a, 'b, 'c, F>
@@ -80,14 +150,15 @@ where
     F: GEMMNum,
 {
     fn run_blas(self) -> Result<ArrayOut2<'c, F>, BLASError> {
-        let Self { transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, mut c, ldc } = self;
+        let Self { layout, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, mut c, ldc, conj_c } = self;
         let a_ptr = a.as_ptr();
         let b_ptr = b.as_ptr();
         let c_ptr = c.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if m == 0 || n == 0 {
+        // output is left untouched in `explain`
+        if m == 0 || n == 0 || (k == 0 && is_planning()) {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
             if beta == F::zero() {
@@ -99,7 +170,22 @@ where
         }
 
         unsafe {
-            F::gemm(&transa, &transb, &m, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_gemm(
+                    &layout, &transa, &transb, &m, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr,
+                    &ldc,
+                );
+            } else {
+                F::gemm(&transa, &transb, &m, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
+            }
+        }
+        if let Err(err) = check_blas_call() {
+            if conj_c {
+                conj_view_inplace(&mut c.view_mut());
+            }
+            return Err(err);
         }
         return Ok(c.clone_to_view_mut());
     }
@@ -130,6 +216,8 @@ where
     pub transb: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for GEMM_<'a, 'b, 'c, F>
@@ -137,11 +225,18 @@ where
     F: GEMMNum,
 {
     fn driver(self) -> Result<GEMM_Driver<'a, 'b, 'c, F>, BLASError> {
-        let Self { a, b, c, alpha, beta, transa, transb, layout } = self;
+        let Self { a, b, c, alpha, beta, transa, transb, layout, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref() && b.is_fpref());
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or C-contiguous with CBLAS
+        let layout = layout.unwrap();
+        match layout {
+            BLASColMajor => assert!(a.is_fpref() && b.is_fpref()),
+            _ => assert!(ffi::USE_CBLAS && layout == BLASRowMajor && a.is_cpref() && b.is_cpref()),
+        }
+        let ld_axis = match layout {
+            BLASColMajor => Axis(1),
+            _ => Axis(0),
+        };
 
         // initialize intent(hide)
         let (m, k) = match transa {
@@ -154,8 +249,8 @@ where
             BLASTrans | BLASConjTrans => b.len_of(Axis(0)),
             _ => blas_invalid!(transb)?,
         };
-        let lda = a.stride_of(Axis(1));
-        let ldb = b.stride_of(Axis(1));
+        let lda = a.stride_of(ld_axis);
+        let ldb = b.stride_of(ld_axis);
 
         // perform check
         match transb {
@@ -168,19 +263,29 @@ where
         let c = match c {
             Some(c) => {
                 blas_assert_eq!(c.dim(), (m, n), InvalidDim)?;
-                if c.view().is_fpref() {
-                    ArrayOut2::ViewMut(c)
-                } else {
-                    let c_buffer = c.view().to_col_layout()?.into_owned();
-                    ArrayOut2::ToBeCloned(c, c_buffer)
+                match layout {
+                    BLASColMajor if c.view().is_fpref() => ArrayOut2::ViewMut(c),
+                    BLASColMajor => {
+                        let c_buffer = c.view().to_col_layout()?.into_owned();
+                        ArrayOut2::ToBeCloned(c, c_buffer)
+                    },
+                    _ if c.view().is_cpref() => ArrayOut2::ViewMut(c),
+                    _ => {
+                        let c_buffer = c.view().to_row_layout()?.into_owned();
+                        ArrayOut2::ToBeCloned(c, c_buffer)
+                    },
                 }
             },
-            None => ArrayOut2::Owned(Array2::zeros((m, n).f())),
+            None => match layout {
+                BLASColMajor => ArrayOut2::Owned(Array2::zeros((m, n).f())),
+                _ => ArrayOut2::Owned(Array2::zeros((m, n))),
+            },
         };
-        let ldc = c.view().stride_of(Axis(1));
+        let ldc = c.view().stride_of(ld_axis);
 
         // finalize
         let driver = GEMM_Driver {
+            layout: layout.try_into()?,
             transa: transa.try_into()?,
             transb: transb.try_into()?,
             m: m.try_into()?,
@@ -194,6 +299,7 @@ where
             beta,
             c,
             ldc: ldc.try_into()?,
+            conj_c: false,
         };
         return Ok(driver);
     }
@@ -215,16 +321,59 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let GEMM_ { a, b, c, alpha, beta, transa, transb, layout } = self.build()?;
+        let _ctx = CopyContext::enter("GEMM", self.copy_policy.flatten());
+        let GEMM_ { a, b, c, alpha, beta, transa, transb, layout, .. } = self.build()?;
         let at = a.t();
         let bt = b.t();
 
+        // Note that since we will use `ConjNoTrans` in outer wrapper to avoid conjugate copy
+        // additional check to this parameter is required
+        for trans in [transa, transb] {
+            match trans {
+                BLASNoTrans | BLASTrans | BLASConjTrans => (),
+                _ => blas_invalid!(trans)?,
+            };
+        }
+
+        // non-contiguous arrays are not copied, if copy would dominate or be rejected
+        let c_view = c.as_ref().map(|c| c.view());
+        if prefer_gemm_strided(&a, &b, c_view.as_ref(), beta, transa, transb) {
+            return run_gemm_strided(&a, &b, c, alpha, beta, transa, transb);
+        }
+
         let layout_a = get_layout_array2(&a);
         let layout_b = get_layout_array2(&b);
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
         let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
-        if layout == BLASColMajor {
+
+        // conjugate copy of inputs may be avoided by computing conjugate of output
+        // conj(C) = conj(alpha) conj(op(A)) conj(op(B)) + conj(beta) conj(C)
+        let m = match transa {
+            BLASNoTrans | BLASConjNoTrans => a.len_of(Axis(0)),
+            _ => a.len_of(Axis(1)),
+        };
+        let n = match transb {
+            BLASNoTrans | BLASConjNoTrans => b.len_of(Axis(1)),
+            _ => b.len_of(Axis(0)),
+        };
+        let cost = |transa, transb| {
+            flip_trans_conj_cost(transa, &a, layout, false) + flip_trans_conj_cost(transb, &b, layout, false)
+        };
+        let conj_out = prefer_conj_output::<F>(
+            cost(transa, transb),
+            cost(transa.conj()?, transb.conj()?),
+            m * n,
+            c.is_some() && !beta.is_zero(),
+        );
+        // C is conjugated only after checks of driver, so it is left untouched on error
+        let conj_c = conj_out && !beta.is_zero();
+        let (alpha, beta, transa, transb, c) = match conj_out {
+            false => (alpha, beta, transa, transb, c),
+            true => (F::conj(alpha), F::conj(beta), transa.conj()?, transb.conj()?, c),
+        };
+
+        let mut c_out = if layout == BLASColMajor {
             // F-contiguous: C = op(A) op(B)
             let (transa, a_cow) = flip_trans_fpref(transa, &a, &at, false)?;
             let (transb, b_cow) = flip_trans_fpref(transb, &b, &bt, false)?;
@@ -237,26 +386,230 @@ where
                 transa,
                 transb,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            return obj.driver()?.run_blas();
+            obj.driver()?.conj_c(conj_c).run_blas()?
         } else if layout == BLASRowMajor {
-            // C-contiguous: C' = op(B') op(A')
+            // C-contiguous: C' = op(B') op(A'), or row-major matrices are passed to CBLAS directly
             let (transa, a_cow) = flip_trans_cpref(transa, &a, &at, false)?;
             let (transb, b_cow) = flip_trans_cpref(transb, &b, &bt, false)?;
-            let obj = GEMM_ {
-                a: b_cow.t(),
-                b: a_cow.t(),
-                c: c.map(|c| c.reversed_axes()),
-                alpha,
-                beta,
-                transa: transb,
-                transb: transa,
-                layout: Some(BLASColMajor),
-            };
-            return Ok(obj.driver()?.run_blas()?.reversed_axes());
+            if ffi::USE_CBLAS {
+                let obj = GEMM_ {
+                    a: a_cow.view(),
+                    b: b_cow.view(),
+                    c,
+                    alpha,
+                    beta,
+                    transa,
+                    transb,
+                    layout: Some(BLASRowMajor),
+                    copy_policy: None,
+                };
+                obj.driver()?.conj_c(conj_c).run_blas()?
+            } else {
+                let obj = GEMM_ {
+                    a: b_cow.t(),
+                    b: a_cow.t(),
+                    c: c.map(|c| c.reversed_axes()),
+                    alpha,
+                    beta,
+                    transa: transb,
+                    transb: transa,
+                    layout: Some(BLASColMajor),
+                    copy_policy: None,
+                };
+                obj.driver()?.conj_c(conj_c).run_blas()?.reversed_axes()
+            }
         } else {
             return blas_raise!(RuntimeError, "This is designed not to execuate this line.");
+        };
+        if conj_out {
+            c_out.conj_inplace();
+        }
+        return Ok(c_out);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of GEMM, created by [`GEMM_Builder::plan`].
+///
+/// Arrays passed to [`run`](GEMMPlan::run) should have the same shapes and strides as those used in creating
+/// the plan; this is the only check performed on each call. If the problem is a single BLAS call without copy or
+/// conjugate of arrays, BLAS is called directly; otherwise (or without feature `std`), the full wrapper is run.
+#[derive(Debug, Clone)]
+pub struct GEMMPlan<F>
+where
+    F: GEMMNum,
+{
+    alpha: F,
+    beta: F,
+    transa: BLASTranspose,
+    transb: BLASTranspose,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_b: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<GEMMPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct GEMMPlanCall {
+    layout: BLASLayout,
+    driver_layout: c_char,
+    transa: c_char,
+    transb: c_char,
+    m: blas_int,
+    n: blas_int,
+    k: blas_int,
+    lda: blas_int,
+    ldb: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'b, 'c, F> GEMM_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<GEMMPlan<F>, BLASError> {
+        let GEMM_ { a, b, c, alpha, beta, transa, transb, layout, copy_policy } = self.build()?;
+        let mut plan = GEMMPlan {
+            alpha,
+            beta,
+            transa,
+            transb,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_b: ArraySignature::of(&b),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, b, c).run().map(|_| ()))? {
+            plan.call = Some(GEMMPlanCall {
+                layout,
+                // layout is only recorded in call of CBLAS
+                driver_layout: call.c_flag("layout").or(BLASColMajor.try_into())?,
+                transa: call.c_flag("transa")?,
+                transb: call.c_flag("transb")?,
+                m: call.c_dim("m")?,
+                n: call.c_dim("n")?,
+                k: call.c_dim("k")?,
+                lda: call.c_dim("lda")?,
+                ldb: call.c_dim("ldb")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> GEMMPlan<F>
+where
+    F: GEMMNum,
+{
+    fn builder<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> GEMM_Builder<'a, 'b, 'c, F> {
+        GEMM_Builder {
+            a: Some(a),
+            b: Some(b),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            transa: Some(self.transa),
+            transb: Some(self.transb),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_b.check(&b, "b")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, b, c).run();
+        };
+        let GEMMPlanCall { layout, driver_layout, transa, transb, m, n, k, lda, ldb, ldc } = *call;
+        // inputs are reborrowed by common lifetime, so that they can be swapped
+        let (a, b) = (a.reborrow(), b.reborrow());
+        // row-major: C' = op(B') op(A'), unless row-major arrays are passed to CBLAS directly
+        let cblas_row_major = driver_layout == b'R' as c_char;
+        let flip = layout == BLASRowMajor && !cblas_row_major;
+        let (a, b, c) = match flip {
+            true => (b, a, c.map(|c| c.reversed_axes())),
+            false => (a, b, c),
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None if cblas_row_major => ArrayOut2::Owned(Array2::zeros((m as usize, n as usize))),
+            None => ArrayOut2::Owned(Array2::zeros((m as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = GEMM_Driver {
+            layout: driver_layout,
+            transa,
+            transb,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            conj_c: false,
+        };
+        let c = driver.run_blas()?;
+        return Ok(if flip { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GEMM; `run` is only available when all required arguments are given.
+        GEMM_Typed for GEMM_Builder ['a, 'b, 'c] [F] where [F: GEMMNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A, b: ArrayView2<'b, F> as B }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F,
+            beta: F,
+            transa: BLASTranspose,
+            transb: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
         }
+        aliases { GEMM, SGEMM = f32, DGEMM = f64, CGEMM = c32, ZGEMM = c64 }
     }
 }
 
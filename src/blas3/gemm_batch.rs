@@ -0,0 +1,351 @@
+extern crate alloc;
+
+use crate::blas3::gemm::{GEMMNum, GEMM_Builder, GEMM_Driver, GEMM_};
+use crate::util::*;
+use alloc::vec::Vec;
+use derive_builder::Builder;
+
+#[cfg(feature = "gemm_batch")]
+use crate::ffi::{self, blas_int, c_char};
+
+/* #region BLAS func */
+
+pub trait GEMMBatchNum: GEMMNum {
+    /// Grouped batched matrix-matrix multiplication (vendor extension).
+    #[cfg(feature = "gemm_batch")]
+    unsafe fn gemm_batch(
+        transa_array: *const c_char,
+        transb_array: *const c_char,
+        m_array: *const blas_int,
+        n_array: *const blas_int,
+        k_array: *const blas_int,
+        alpha_array: *const Self,
+        a_array: *mut *const Self,
+        lda_array: *const blas_int,
+        b_array: *mut *const Self,
+        ldb_array: *const blas_int,
+        beta_array: *const Self,
+        c_array: *mut *mut Self,
+        ldc_array: *const blas_int,
+        group_count: *const blas_int,
+        group_size: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl GEMMBatchNum for $type {
+            #[cfg(feature = "gemm_batch")]
+            unsafe fn gemm_batch(
+                transa_array: *const c_char,
+                transb_array: *const c_char,
+                m_array: *const blas_int,
+                n_array: *const blas_int,
+                k_array: *const blas_int,
+                alpha_array: *const Self,
+                a_array: *mut *const Self,
+                lda_array: *const blas_int,
+                b_array: *mut *const Self,
+                ldb_array: *const blas_int,
+                beta_array: *const Self,
+                c_array: *mut *mut Self,
+                ldc_array: *const blas_int,
+                group_count: *const blas_int,
+                group_size: *const blas_int,
+            ) {
+                // record each group as one call
+                let mut planned = false;
+                for g in 0..*group_count as usize {
+                    let (transa, transb) = (transa_array.add(g), transb_array.add(g));
+                    let (m, n, k) = (m_array.add(g), n_array.add(g), k_array.add(g));
+                    let (lda, ldb, ldc) = (lda_array.add(g), ldb_array.add(g), ldc_array.add(g));
+                    let group_size = group_size.add(g);
+                    planned = blas_plan_call!($func, [transa, transb], [m, n, k, lda, ldb, ldc, group_size]);
+                }
+                if planned {
+                    return;
+                }
+                ffi::$func(
+                    transa_array,
+                    transb_array,
+                    m_array,
+                    n_array,
+                    k_array,
+                    alpha_array,
+                    a_array,
+                    lda_array,
+                    b_array,
+                    ldb_array,
+                    beta_array,
+                    c_array,
+                    ldc_array,
+                    group_count,
+                    group_size,
+                );
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgemm_batch_);
+impl_func!(f64, dgemm_batch_);
+impl_func!(c32, cgemm_batch_);
+impl_func!(c64, zgemm_batch_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct GEMM_BATCH_Driver<'a, 'b, 'c, F>
+where
+    F: GEMMBatchNum,
+{
+    drivers: Vec<GEMM_Driver<'a, 'b, 'c, F>>,
+}
+
+impl<'a, 'b, 'c, F> GEMM_BATCH_Driver<'a, 'b, 'c, F>
+where
+    F: GEMMBatchNum,
+{
+    #[cfg(not(feature = "gemm_batch"))]
+    pub fn run_blas(self) -> Result<Vec<ArrayOut2<'c, F>>, BLASError> {
+        return self.drivers.into_iter().map(|driver| driver.run_blas()).collect();
+    }
+
+    #[cfg(feature = "gemm_batch")]
+    pub fn run_blas(self) -> Result<Vec<ArrayOut2<'c, F>>, BLASError> {
+        let Self { mut drivers } = self;
+
+        // problems with empty dimension are left to quick return of GEMM driver;
+        // consecutive problems sharing the same parameters are merged into one group
+        let nbatch = drivers.len();
+        let mut transa_array: Vec<c_char> = Vec::with_capacity(nbatch);
+        let mut transb_array: Vec<c_char> = Vec::with_capacity(nbatch);
+        let mut m_array: Vec<blas_int> = Vec::with_capacity(nbatch);
+        let mut n_array: Vec<blas_int> = Vec::with_capacity(nbatch);
+        let mut k_array: Vec<blas_int> = Vec::with_capacity(nbatch);
+        let mut alpha_array: Vec<F> = Vec::with_capacity(nbatch);
+        let mut lda_array: Vec<blas_int> = Vec::with_capacity(nbatch);
+        let mut ldb_array: Vec<blas_int> = Vec::with_capacity(nbatch);
+        let mut beta_array: Vec<F> = Vec::with_capacity(nbatch);
+        let mut ldc_array: Vec<blas_int> = Vec::with_capacity(nbatch);
+        let mut group_size: Vec<blas_int> = Vec::with_capacity(nbatch);
+        let mut a_array: Vec<*const F> = Vec::with_capacity(nbatch);
+        let mut b_array: Vec<*const F> = Vec::with_capacity(nbatch);
+        let mut c_array: Vec<*mut F> = Vec::with_capacity(nbatch);
+        let mut param_last = None;
+        for driver in drivers.iter_mut().filter(|d| d.m != 0 && d.n != 0 && d.k != 0) {
+            let param = (
+                driver.transa,
+                driver.transb,
+                driver.m,
+                driver.n,
+                driver.k,
+                driver.alpha,
+                driver.lda,
+                driver.ldb,
+                driver.beta,
+                driver.ldc,
+            );
+            if param_last == Some(param) {
+                *group_size.last_mut().unwrap() += 1;
+            } else {
+                let (transa, transb, m, n, k, alpha, lda, ldb, beta, ldc) = param;
+                transa_array.push(transa);
+                transb_array.push(transb);
+                m_array.push(m);
+                n_array.push(n);
+                k_array.push(k);
+                alpha_array.push(alpha);
+                lda_array.push(lda);
+                ldb_array.push(ldb);
+                beta_array.push(beta);
+                ldc_array.push(ldc);
+                group_size.push(1);
+                param_last = Some(param);
+            }
+            a_array.push(driver.a.as_ptr());
+            b_array.push(driver.b.as_ptr());
+            c_array.push(driver.c.get_data_mut_ptr());
+        }
+
+        if !group_size.is_empty() {
+            let group_count: blas_int = group_size.len().try_into()?;
+            unsafe {
+                F::gemm_batch(
+                    transa_array.as_ptr(),
+                    transb_array.as_ptr(),
+                    m_array.as_ptr(),
+                    n_array.as_ptr(),
+                    k_array.as_ptr(),
+                    alpha_array.as_ptr(),
+                    a_array.as_mut_ptr(),
+                    lda_array.as_ptr(),
+                    b_array.as_mut_ptr(),
+                    ldb_array.as_ptr(),
+                    beta_array.as_ptr(),
+                    c_array.as_mut_ptr(),
+                    ldc_array.as_ptr(),
+                    &group_count,
+                    group_size.as_ptr(),
+                );
+            }
+            check_blas_call()?;
+        }
+
+        return drivers
+            .into_iter()
+            .map(|driver| match driver.m != 0 && driver.n != 0 && driver.k != 0 {
+                true => Ok(driver.c.clone_to_view_mut()),
+                false => driver.run_blas(),
+            })
+            .collect();
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+/// Batch of independent matrix-matrix multiplications, each described by its own [`GEMM`](crate::blas3::gemm::GEMM)
+/// builder (shape, transposition, alpha and beta may differ between problems).
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct GEMM_BATCH_<'a, 'b, 'c, F>
+where
+    F: GEMMBatchNum,
+{
+    #[builder(setter(each(name = "gemm")), default = "Vec::new()")]
+    pub gemms: Vec<GEMM_Builder<'a, 'b, 'c, F>>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type GEMM_BATCH<'a, 'b, 'c, F> = GEMM_BATCH_Builder<'a, 'b, 'c, F>;
+pub type SGEMM_BATCH<'a, 'b, 'c> = GEMM_BATCH<'a, 'b, 'c, f32>;
+pub type DGEMM_BATCH<'a, 'b, 'c> = GEMM_BATCH<'a, 'b, 'c, f64>;
+pub type CGEMM_BATCH<'a, 'b, 'c> = GEMM_BATCH<'a, 'b, 'c, c32>;
+pub type ZGEMM_BATCH<'a, 'b, 'c> = GEMM_BATCH<'a, 'b, 'c, c64>;
+
+impl<'a, 'b, 'c, F> GEMM_BATCH<'a, 'b, 'c, F>
+where
+    F: GEMMBatchNum,
+{
+    pub fn run(self) -> Result<Vec<ArrayOut2<'c, F>>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("GEMM_BATCH", self.copy_policy.flatten());
+        let GEMM_BATCH_ { gemms, .. } = self.build()?;
+        let nbatch = gemms.len();
+
+        // layout is decided for each problem, so that all problems can be issued in one call
+        let mut a_list = Vec::with_capacity(nbatch);
+        let mut b_list = Vec::with_capacity(nbatch);
+        let mut c_list = Vec::with_capacity(nbatch);
+        let mut param_list = Vec::with_capacity(nbatch);
+        let mut layout_list = Vec::with_capacity(nbatch);
+        let mut policy_list = Vec::with_capacity(nbatch);
+        for gemm in gemms {
+            let GEMM_ { a, b, c, alpha, beta, transa, transb, layout, copy_policy } = gemm.build()?;
+
+            let layout_a = get_layout_array2(&a);
+            let layout_b = get_layout_array2(&b);
+            let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
+
+            let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
+            a_list.push((a, a.reversed_axes()));
+            b_list.push((b, b.reversed_axes()));
+            c_list.push(c);
+            param_list.push((alpha, beta, transa, transb));
+            layout_list.push(layout);
+            policy_list.push(copy_policy);
+        }
+
+        let mut a_cow_list = Vec::with_capacity(nbatch);
+        let mut b_cow_list = Vec::with_capacity(nbatch);
+        for (i, layout) in layout_list.iter().enumerate() {
+            // per-problem copy policy overrides that of batch
+            let _ctx = CopyContext::enter("GEMM_BATCH", policy_list[i]);
+            let (_, _, transa, transb) = param_list[i];
+            let ((a, at), (b, bt)) = (&a_list[i], &b_list[i]);
+            if *layout == BLASColMajor {
+                a_cow_list.push(flip_trans_fpref(transa, a, at, false)?);
+                b_cow_list.push(flip_trans_fpref(transb, b, bt, false)?);
+            } else if *layout == BLASRowMajor {
+                a_cow_list.push(flip_trans_cpref(transa, a, at, false)?);
+                b_cow_list.push(flip_trans_cpref(transb, b, bt, false)?);
+            } else {
+                return blas_raise!(RuntimeError, "This is designed not to execuate this line.");
+            }
+        }
+
+        let mut drivers = Vec::with_capacity(nbatch);
+        for (i, c) in c_list.into_iter().enumerate() {
+            let _ctx = CopyContext::enter("GEMM_BATCH", policy_list[i]);
+            let (alpha, beta, _, _) = param_list[i];
+            let (transa, a_cow) = &a_cow_list[i];
+            let (transb, b_cow) = &b_cow_list[i];
+            let obj = match layout_list[i] {
+                // F-contiguous: C = op(A) op(B)
+                BLASColMajor => GEMM_ {
+                    a: a_cow.view(),
+                    b: b_cow.view(),
+                    c,
+                    alpha,
+                    beta,
+                    transa: *transa,
+                    transb: *transb,
+                    layout: Some(BLASColMajor),
+                    copy_policy: None,
+                },
+                // C-contiguous: C' = op(B') op(A')
+                _ => GEMM_ {
+                    a: b_cow.t(),
+                    b: a_cow.t(),
+                    c: c.map(|c| c.reversed_axes()),
+                    alpha,
+                    beta,
+                    transa: *transb,
+                    transb: *transa,
+                    layout: Some(BLASColMajor),
+                    copy_policy: None,
+                },
+            };
+            drivers.push(obj.driver()?);
+        }
+
+        let c_list = GEMM_BATCH_Driver { drivers }.run_blas()?;
+        return Ok(c_list
+            .into_iter()
+            .zip(layout_list)
+            .map(|(c, layout)| if layout == BLASRowMajor { c.reversed_axes() } else { c })
+            .collect());
+    }
+
+    /// Validate and plan as [`run`](Self::run) does, but without calling BLAS; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    pub fn explain(self) -> Result<BLASPlan, BLASError> {
+        explain_with(|| self.run().map(|_| ()))
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GEMM_BATCH; `run` is only available when all required arguments are given.
+        GEMM_BATCH_Typed for GEMM_BATCH_Builder ['a, 'b, 'c] [F] where [F: GEMMBatchNum] => Vec<ArrayOut2<'c, F>>;
+        required {  }
+        optional { gemm: GEMM_Builder<'a, 'b, 'c, F>, copy_policy: CopyPolicy }
+        aliases { GEMM_BATCH, SGEMM_BATCH = f32, DGEMM_BATCH = f64, CGEMM_BATCH = c32, ZGEMM_BATCH = c64 }
+    }
+}
+
+/* #endregion */
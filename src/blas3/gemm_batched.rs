@@ -0,0 +1,409 @@
+use crate::blas3::gemm::GEMMNum;
+use crate::ffi::{blas_int, c_char};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+#[cfg(feature = "gemm_batch")]
+use crate::ffi;
+
+/* #region BLAS func */
+
+pub trait GEMMBatchedNum: GEMMNum {
+    /// Strided batched matrix-matrix multiplication (vendor extension).
+    #[cfg(feature = "gemm_batch")]
+    unsafe fn gemm_batch_strided(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const Self,
+        a: *const Self,
+        lda: *const blas_int,
+        stridea: *const blas_int,
+        b: *const Self,
+        ldb: *const blas_int,
+        strideb: *const blas_int,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: *const blas_int,
+        stridec: *const blas_int,
+        batch_size: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl GEMMBatchedNum for $type {
+            #[cfg(feature = "gemm_batch")]
+            unsafe fn gemm_batch_strided(
+                transa: *const c_char,
+                transb: *const c_char,
+                m: *const blas_int,
+                n: *const blas_int,
+                k: *const blas_int,
+                alpha: *const Self,
+                a: *const Self,
+                lda: *const blas_int,
+                stridea: *const blas_int,
+                b: *const Self,
+                ldb: *const blas_int,
+                strideb: *const blas_int,
+                beta: *const Self,
+                c: *mut Self,
+                ldc: *const blas_int,
+                stridec: *const blas_int,
+                batch_size: *const blas_int,
+            ) {
+                if blas_plan_call!(
+                    $func,
+                    [transa, transb],
+                    [m, n, k, lda, stridea, ldb, strideb, ldc, stridec, batch_size]
+                ) {
+                    return;
+                }
+                ffi::$func(
+                    transa, transb, m, n, k, alpha, a, lda, stridea, b, ldb, strideb, beta, c, ldc, stridec,
+                    batch_size,
+                );
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgemm_batch_strided_);
+impl_func!(f64, dgemm_batch_strided_);
+impl_func!(c32, cgemm_batch_strided_);
+impl_func!(c64, zgemm_batch_strided_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct GEMMBatched_Driver<'a, 'b, 'c, F>
+where
+    F: GEMMBatchedNum,
+{
+    transa: c_char,
+    transb: c_char,
+    m: blas_int,
+    n: blas_int,
+    k: blas_int,
+    alpha: F,
+    a: ArrayView3<'a, F>,
+    lda: blas_int,
+    stridea: blas_int,
+    b: ArrayView3<'b, F>,
+    ldb: blas_int,
+    strideb: blas_int,
+    beta: F,
+    c: ArrayOut3<'c, F>,
+    ldc: blas_int,
+    stridec: blas_int,
+    batch_size: blas_int,
+}
+
+impl<'a, 'b, 'c, F> BLASDriver<'c, F, Ix3> for GEMMBatched_Driver<'a, 'b, 'c, F>
+where
+    F: GEMMBatchedNum,
+{
+    fn run_blas(self) -> Result<ArrayOut3<'c, F>, BLASError> {
+        let Self {
+            transa,
+            transb,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            stridea,
+            b,
+            ldb,
+            strideb,
+            beta,
+            mut c,
+            ldc,
+            stridec,
+            batch_size,
+        } = self;
+        let a_ptr = a.as_ptr();
+        let b_ptr = b.as_ptr();
+        let c_ptr = c.get_data_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        // output is left untouched in `explain`
+        if batch_size == 0 || m == 0 || n == 0 || (k == 0 && is_planning()) {
+            return Ok(c.clone_to_view_mut());
+        } else if k == 0 {
+            if beta == F::zero() {
+                c.view_mut().fill(F::zero());
+            } else if beta != F::one() {
+                c.view_mut().mapv_inplace(|v| v * beta);
+            }
+            return Ok(c.clone_to_view_mut());
+        }
+
+        #[cfg(feature = "gemm_batch")]
+        unsafe {
+            F::gemm_batch_strided(
+                &transa,
+                &transb,
+                &m,
+                &n,
+                &k,
+                &alpha,
+                a_ptr,
+                &lda,
+                &stridea,
+                b_ptr,
+                &ldb,
+                &strideb,
+                &beta,
+                c_ptr,
+                &ldc,
+                &stridec,
+                &batch_size,
+            );
+        }
+        check_blas_call()?;
+
+        #[cfg(not(feature = "gemm_batch"))]
+        for i in 0..batch_size as isize {
+            unsafe {
+                let a_ptr = a_ptr.offset(i * stridea as isize);
+                let b_ptr = b_ptr.offset(i * strideb as isize);
+                let c_ptr = c_ptr.offset(i * stridec as isize);
+                F::gemm(&transa, &transb, &m, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
+            }
+            check_blas_call()?;
+        }
+        return Ok(c.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+/// Batched matrix-matrix multiplication (axis 0 of arrays is batch).
+///
+/// Batch of A or B that contains only one matrix is broadcasted to the whole batch.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct GEMMBatched_<'a, 'b, 'c, F>
+where
+    F: GEMMBatchedNum,
+{
+    pub a: ArrayView3<'a, F>,
+    pub b: ArrayView3<'b, F>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub c: Option<ArrayViewMut3<'c, F>>,
+    #[builder(setter(into), default = "F::one()")]
+    pub alpha: F,
+    #[builder(setter(into), default = "F::zero()")]
+    pub beta: F,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub transa: BLASTranspose,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub transb: BLASTranspose,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix3> for GEMMBatched_<'a, 'b, 'c, F>
+where
+    F: GEMMBatchedNum,
+{
+    fn driver(self) -> Result<GEMMBatched_Driver<'a, 'b, 'c, F>, BLASError> {
+        let Self { a, b, c, alpha, beta, transa, transb, layout, .. } = self;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper
+        // batch of A and B should have been broadcasted, and batch stride should be non-negative
+        assert_eq!(layout, Some(BLASColMajor));
+        assert!(get_layout_batch(&a).is_fpref() && get_layout_batch(&b).is_fpref());
+        assert!(a.stride_of(Axis(0)) >= 0 && b.stride_of(Axis(0)) >= 0);
+
+        // initialize intent(hide)
+        let batch_size = a.len_of(Axis(0));
+        let (m, k) = match transa {
+            BLASNoTrans => (a.len_of(Axis(1)), a.len_of(Axis(2))),
+            BLASTrans | BLASConjTrans => (a.len_of(Axis(2)), a.len_of(Axis(1))),
+            _ => blas_invalid!(transa)?,
+        };
+        let n = match transb {
+            BLASNoTrans => b.len_of(Axis(2)),
+            BLASTrans | BLASConjTrans => b.len_of(Axis(1)),
+            _ => blas_invalid!(transb)?,
+        };
+        let lda = a.stride_of(Axis(2));
+        let ldb = b.stride_of(Axis(2));
+        let stridea = a.stride_of(Axis(0));
+        let strideb = b.stride_of(Axis(0));
+
+        // perform check
+        blas_assert_eq!(b.len_of(Axis(0)), batch_size, InvalidDim)?;
+        match transb {
+            BLASNoTrans => blas_assert_eq!(b.len_of(Axis(1)), k, InvalidDim)?,
+            BLASTrans | BLASConjTrans => blas_assert_eq!(b.len_of(Axis(2)), k, InvalidDim)?,
+            _ => blas_invalid!(transb)?,
+        }
+
+        // optional intent(out)
+        let c = match c {
+            Some(c) => {
+                blas_assert_eq!(c.dim(), (batch_size, m, n), InvalidDim)?;
+                if get_layout_batch(&c.view()).is_fpref() && c.stride_of(Axis(0)) >= 0 {
+                    ArrayOut3::ViewMut(c)
+                } else {
+                    let c_buffer = to_col_layout_batch(&c.view())?;
+                    ArrayOut3::ToBeCloned(c, c_buffer)
+                }
+            },
+            None => ArrayOut3::Owned(Array3::zeros((batch_size, n, m)).permuted_axes([0, 2, 1])),
+        };
+        let ldc = c.view().stride_of(Axis(2));
+        let stridec = c.view().stride_of(Axis(0));
+
+        // finalize
+        let driver = GEMMBatched_Driver {
+            transa: transa.try_into()?,
+            transb: transb.try_into()?,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            alpha,
+            a,
+            lda: lda.try_into()?,
+            stridea: stridea.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            strideb: strideb.try_into()?,
+            beta,
+            c,
+            ldc: ldc.try_into()?,
+            stridec: stridec.try_into()?,
+            batch_size: batch_size.try_into()?,
+        };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type GEMMBatched<'a, 'b, 'c, F> = GEMMBatched_Builder<'a, 'b, 'c, F>;
+pub type SGEMMBatched<'a, 'b, 'c> = GEMMBatched<'a, 'b, 'c, f32>;
+pub type DGEMMBatched<'a, 'b, 'c> = GEMMBatched<'a, 'b, 'c, f64>;
+pub type CGEMMBatched<'a, 'b, 'c> = GEMMBatched<'a, 'b, 'c, c32>;
+pub type ZGEMMBatched<'a, 'b, 'c> = GEMMBatched<'a, 'b, 'c, c64>;
+
+impl<'a, 'b, 'c, F> BLASBuilder<'c, F, Ix3> for GEMMBatched_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMBatchedNum,
+{
+    fn run(self) -> Result<ArrayOut3<'c, F>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("GEMMBatched", self.copy_policy.flatten());
+        let GEMMBatched_ { a, b, c, alpha, beta, transa, transb, layout, .. } = self.build()?;
+
+        // batch size; batch of only one matrix will be broadcasted
+        let batch_size = match &c {
+            Some(c) => c.len_of(Axis(0)),
+            None => match a.len_of(Axis(0)) {
+                1 => b.len_of(Axis(0)),
+                nbatch_a => nbatch_a,
+            },
+        };
+        blas_assert!(a.len_of(Axis(0)) == batch_size || a.len_of(Axis(0)) == 1, InvalidDim)?;
+        blas_assert!(b.len_of(Axis(0)) == batch_size || b.len_of(Axis(0)) == 1, InvalidDim)?;
+
+        // layout is decided once for the whole batch
+        let layout_a = get_layout_batch(&a);
+        let layout_b = get_layout_batch(&b);
+        let layout_c = c.as_ref().map(|c| get_layout_batch(&c.view()));
+
+        let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
+        if layout == BLASColMajor {
+            // F-contiguous: C = op(A) op(B)
+            let (transa, a_cow) = flip_trans_fpref_batch(transa, &a)?;
+            let (transb, b_cow) = flip_trans_fpref_batch(transb, &b)?;
+            let obj = GEMMBatched_ {
+                a: broadcast_batch(&a_cow, batch_size)?,
+                b: broadcast_batch(&b_cow, batch_size)?,
+                c,
+                alpha,
+                beta,
+                transa,
+                transb,
+                layout: Some(BLASColMajor),
+                copy_policy: None,
+            };
+            return obj.driver()?.run_blas();
+        } else if layout == BLASRowMajor {
+            // C-contiguous: C' = op(B') op(A')
+            let at = a.permuted_axes([0, 2, 1]);
+            let bt = b.permuted_axes([0, 2, 1]);
+            let (transa, a_cow) = flip_trans_fpref_batch(transa, &at)?;
+            let (transb, b_cow) = flip_trans_fpref_batch(transb, &bt)?;
+            let obj = GEMMBatched_ {
+                a: broadcast_batch(&b_cow, batch_size)?,
+                b: broadcast_batch(&a_cow, batch_size)?,
+                c: c.map(|c| c.permuted_axes([0, 2, 1])),
+                alpha,
+                beta,
+                transa: transb,
+                transb: transa,
+                layout: Some(BLASColMajor),
+                copy_policy: None,
+            };
+            return Ok(obj.driver()?.run_blas()?.permuted_axes([0, 2, 1]));
+        } else {
+            return blas_raise!(RuntimeError, "This is designed not to execuate this line.");
+        }
+    }
+}
+
+/// Broadcast batch of only one matrix to `batch_size` (by zero batch stride, without copy).
+fn broadcast_batch<'a, F>(
+    arr: &'a CowArray<'_, F, Ix3>,
+    batch_size: usize,
+) -> Result<ArrayView3<'a, F>, BLASError> {
+    let (_, nrow, ncol) = arr.dim();
+    match arr.broadcast((batch_size, nrow, ncol)) {
+        Some(view) => Ok(view),
+        None => blas_raise!(InvalidDim),
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GEMMBatched; `run` is only available when all required arguments are given.
+        GEMMBatched_Typed for GEMMBatched_Builder ['a, 'b, 'c] [F] where [F: GEMMBatchedNum] => ArrayOut3<'c, F>;
+        required { a: ArrayView3<'a, F> as A, b: ArrayView3<'b, F> as B }
+        optional {
+            c: ArrayViewMut3<'c, F>,
+            alpha: F,
+            beta: F,
+            transa: BLASTranspose,
+            transb: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { GEMMBatched, SGEMMBatched = f32, DGEMMBatched = f64, CGEMMBatched = c32, ZGEMMBatched = c64 }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,255 @@
+//! Stride-native GEMM by packing kernel of `matrixmultiply`, for arrays of arbitrary row and column strides.
+//!
+//! BLAS requires one dimension of each matrix to be contiguous, so non-contiguous arrays (such as
+//! `s![..;2, ..;3]` of a larger matrix) are copied before calling BLAS. The packing kernel reads arrays by their
+//! strides when packing blocks, so such copies are not performed. [`GEMM`](crate::blas3::gemm::GEMM) chooses
+//! this kernel automatically when some array is non-contiguous, and
+//!
+//! - the copy is rejected by current [`CopyPolicy`] (`Error`), or
+//! - the copy would dominate the computation, i.e., `m n k` is not larger than
+//!   [`STRIDED_FLOPS_PER_COPY`] times number of elements copied.
+//!
+//! The kernel does not conjugate inputs; if only one of `op(A)` and `op(B)` is conjugated, BLAS is always called.
+
+use crate::util::*;
+use matrixmultiply::CGemmOption;
+use ndarray::prelude::*;
+
+/* #region kernel */
+
+pub trait GEMMStridedNum: BLASFloat {
+    /// Name of kernel, as recorded by `explain`.
+    const KERNEL: &'static str;
+
+    /// `C := alpha A B + beta C`, where `A` is `m x k`, `B` is `k x n`, and `C` is `m x n`, given by pointers
+    /// to first elements and row/column strides (in elements). `C` is not read if `beta` is zero.
+    unsafe fn gemm_strided(
+        m: usize,
+        k: usize,
+        n: usize,
+        alpha: Self,
+        a: *const Self,
+        rsa: isize,
+        csa: isize,
+        b: *const Self,
+        rsb: isize,
+        csb: isize,
+        beta: Self,
+        c: *mut Self,
+        rsc: isize,
+        csc: isize,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl GEMMStridedNum for $type {
+            const KERNEL: &'static str = concat!("matrixmultiply::", stringify!($func));
+
+            unsafe fn gemm_strided(
+                m: usize,
+                k: usize,
+                n: usize,
+                alpha: Self,
+                a: *const Self,
+                rsa: isize,
+                csa: isize,
+                b: *const Self,
+                rsb: isize,
+                csb: isize,
+                beta: Self,
+                c: *mut Self,
+                rsc: isize,
+                csc: isize,
+            ) {
+                matrixmultiply::$func(m, k, n, alpha, a, rsa, csa, b, rsb, csb, beta, c, rsc, csc);
+            }
+        }
+    };
+    ($type: ty, $func: ident, $real: ty) => {
+        impl GEMMStridedNum for $type {
+            const KERNEL: &'static str = concat!("matrixmultiply::", stringify!($func));
+
+            unsafe fn gemm_strided(
+                m: usize,
+                k: usize,
+                n: usize,
+                alpha: Self,
+                a: *const Self,
+                rsa: isize,
+                csa: isize,
+                b: *const Self,
+                rsb: isize,
+                csb: isize,
+                beta: Self,
+                c: *mut Self,
+                rsc: isize,
+                csc: isize,
+            ) {
+                // layout of `Complex<T>` is the same to `[T; 2]` (`#[repr(C)]`)
+                let std = CGemmOption::Standard;
+                let (alpha, beta) = ([alpha.re, alpha.im], [beta.re, beta.im]);
+                let a = a as *const [$real; 2];
+                let b = b as *const [$real; 2];
+                let c = c as *mut [$real; 2];
+                matrixmultiply::$func(std, std, m, k, n, alpha, a, rsa, csa, b, rsb, csb, beta, c, rsc, csc);
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgemm);
+impl_func!(f64, dgemm);
+impl_func!(c32, cgemm, f32);
+impl_func!(c64, zgemm, f64);
+
+/* #endregion */
+
+/* #region strided path */
+
+/// Stride-native kernel is used if `m n k` is not larger than this times number of elements to be copied for
+/// BLAS.
+pub const STRIDED_FLOPS_PER_COPY: usize = 64;
+
+/// Whether GEMM should be computed by stride-native kernel instead of BLAS.
+///
+/// `transa` and `transb` should have been validated.
+pub(crate) fn prefer_gemm_strided<F>(
+    a: &ArrayView2<F>,
+    b: &ArrayView2<F>,
+    c: Option<&ArrayView2<F>>,
+    beta: F,
+    transa: BLASTranspose,
+    transb: BLASTranspose,
+) -> bool
+where
+    F: BLASFloat,
+{
+    // only one of inputs is conjugated, which is not supported by kernel
+    if F::is_complex() && (transa == BLASConjTrans) != (transb == BLASConjTrans) {
+        return false;
+    }
+
+    // number of elements to be copied by BLAS path; output of non-standard layout is copied, and written back
+    let copied = |view: &ArrayView2<F>| match get_layout_array2(view) {
+        BLASLayout::NonContiguous => view.len(),
+        _ => 0,
+    };
+    let cost_c = c.map_or(0, |c| copied(c) * (1 + !beta.is_zero() as usize));
+    let cost = copied(a) + copied(b) + cost_c;
+    if cost == 0 {
+        return false;
+    }
+
+    let (m, k) = match transa {
+        BLASNoTrans => a.dim(),
+        _ => a.t().dim(),
+    };
+    let n = match transb {
+        BLASNoTrans => b.len_of(Axis(1)),
+        _ => b.len_of(Axis(0)),
+    };
+    let flops = m.saturating_mul(n).saturating_mul(k);
+    return is_copy_rejected() || flops <= cost.saturating_mul(STRIDED_FLOPS_PER_COPY);
+}
+
+/// Compute GEMM by stride-native kernel; no array is copied.
+///
+/// Inputs are conjugated by computing conjugate of output, if both `transa` and `transb` are `C`.
+pub(crate) fn run_gemm_strided<'c, F>(
+    a: &ArrayView2<F>,
+    b: &ArrayView2<F>,
+    c: Option<ArrayViewMut2<'c, F>>,
+    alpha: F,
+    beta: F,
+    transa: BLASTranspose,
+    transb: BLASTranspose,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: GEMMStridedNum,
+{
+    plan_layout(BLASLayout::NonContiguous);
+
+    // op(A), op(B) are given by strides
+    let a = match transa {
+        BLASNoTrans => a.view(),
+        _ => a.t(),
+    };
+    let b = match transb {
+        BLASNoTrans => b.view(),
+        _ => b.t(),
+    };
+    let (m, k) = a.dim();
+    let n = b.len_of(Axis(1));
+    blas_assert_eq!(b.len_of(Axis(0)), k, InvalidDim)?;
+
+    // both inputs are ConjTrans: conj(C) = conj(alpha) A^T B^T + conj(beta) conj(C),
+    // where A^T and B^T are the transposed views `a`, `b` above (kernel performs no conjugate)
+    let conj_out = F::is_complex() && transa == BLASConjTrans;
+    let (alpha, beta) = match conj_out {
+        false => (alpha, beta),
+        true => (F::conj(alpha), F::conj(beta)),
+    };
+    let mut c = match c {
+        Some(mut c) => {
+            blas_assert_eq!(c.dim(), (m, n), InvalidDim)?;
+            if conj_out && !beta.is_zero() {
+                conj_view_inplace(&mut c);
+            }
+            ArrayOut2::ViewMut(c)
+        },
+        None => ArrayOut2::Owned(Array2::zeros((m, n).f())),
+    };
+
+    let [rsa, csa]: [isize; 2] = a.strides().try_into().unwrap();
+    let [rsb, csb]: [isize; 2] = b.strides().try_into().unwrap();
+    let [rsc, csc]: [isize; 2] = c.view().strides().try_into().unwrap();
+    let flag = |trans| match trans {
+        BLASNoTrans => 'N',
+        _ => 'T',
+    };
+    let planned = plan_kernel_call(
+        F::KERNEL,
+        &[("transa", flag(transa)), ("transb", flag(transb))],
+        &[
+            ("m", m as isize),
+            ("n", n as isize),
+            ("k", k as isize),
+            ("rsa", rsa),
+            ("csa", csa),
+            ("rsb", rsb),
+            ("csb", csb),
+            ("rsc", rsc),
+            ("csc", csc),
+        ],
+    );
+    // output is left untouched in `explain`; quick return if output does not contain anything
+    if !planned && m != 0 && n != 0 {
+        let c_ptr = c.get_data_mut_ptr();
+        unsafe {
+            F::gemm_strided(
+                m,
+                k,
+                n,
+                alpha,
+                a.as_ptr(),
+                rsa,
+                csa,
+                b.as_ptr(),
+                rsb,
+                csb,
+                beta,
+                c_ptr,
+                rsc,
+                csc,
+            );
+        }
+    }
+
+    if conj_out {
+        c.conj_inplace();
+    }
+    return Ok(c);
+}
+
+/* #endregion */
@@ -46,7 +46,13 @@ macro_rules! impl_func {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [uplo, transa, transb], [n, k, lda, ldb, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::gemmt(uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
             }
         }
     };
@@ -92,7 +98,8 @@ where
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if n == 0 {
+        // output is left untouched in `explain`
+        if n == 0 || (k == 0 && is_planning()) {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
             if uplo == BLASLower.try_into()? {
@@ -112,6 +119,7 @@ where
         unsafe {
             F::gemmt(&uplo, &transa, &transb, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
+        check_blas_call()?;
         return Ok(c.clone_to_view_mut());
     }
 }
@@ -143,6 +151,8 @@ where
     pub transb: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for GEMMT_<'a, 'b, 'c, F>
@@ -150,7 +160,7 @@ where
     F: GEMMTNum,
 {
     fn driver(self) -> Result<GEMMT_Driver<'a, 'b, 'c, F>, BLASError> {
-        let Self { a, b, c, alpha, beta, uplo, transa, transb, layout } = self;
+        let Self { a, b, c, alpha, beta, uplo, transa, transb, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         assert_eq!(layout, Some(BLASColMajor));
@@ -223,7 +233,8 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let GEMMT_ { a, b, c, alpha, beta, uplo, transa, transb, layout } = self.build()?;
+        let _ctx = CopyContext::enter("GEMMT", self.copy_policy.flatten());
+        let GEMMT_ { a, b, c, alpha, beta, uplo, transa, transb, layout, .. } = self.build()?;
         let at = a.t();
         let bt = b.t();
 
@@ -245,6 +256,7 @@ where
                 transa,
                 transb,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
@@ -260,6 +272,7 @@ where
                 transa: transb,
                 transb: transa,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
@@ -269,3 +282,166 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of GEMMT, created by [`GEMMT_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct GEMMTPlan<F>
+where
+    F: GEMMTNum,
+{
+    alpha: F,
+    beta: F,
+    uplo: BLASUpLo,
+    transa: BLASTranspose,
+    transb: BLASTranspose,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_b: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<GEMMTPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct GEMMTPlanCall {
+    layout: BLASLayout,
+    uplo: c_char,
+    transa: c_char,
+    transb: c_char,
+    n: blas_int,
+    k: blas_int,
+    lda: blas_int,
+    ldb: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'b, 'c, F> GEMMT_Builder<'a, 'b, 'c, F>
+where
+    F: GEMMTNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<GEMMTPlan<F>, BLASError> {
+        let GEMMT_ { a, b, c, alpha, beta, uplo, transa, transb, layout, copy_policy } = self.build()?;
+        let mut plan = GEMMTPlan {
+            alpha,
+            beta,
+            uplo,
+            transa,
+            transb,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_b: ArraySignature::of(&b),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, b, c).run().map(|_| ()))? {
+            plan.call = Some(GEMMTPlanCall {
+                layout,
+                uplo: call.c_flag("uplo")?,
+                transa: call.c_flag("transa")?,
+                transb: call.c_flag("transb")?,
+                n: call.c_dim("n")?,
+                k: call.c_dim("k")?,
+                lda: call.c_dim("lda")?,
+                ldb: call.c_dim("ldb")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> GEMMTPlan<F>
+where
+    F: GEMMTNum,
+{
+    fn builder<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> GEMMT_Builder<'a, 'b, 'c, F> {
+        GEMMT_Builder {
+            a: Some(a),
+            b: Some(b),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            uplo: Some(self.uplo),
+            transa: Some(self.transa),
+            transb: Some(self.transb),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_b.check(&b, "b")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, b, c).run();
+        };
+        let GEMMTPlanCall { layout, uplo, transa, transb, n, k, lda, ldb, ldc } = *call;
+        // inputs are reborrowed by common lifetime, so that they can be swapped
+        let (a, b) = (a.reborrow(), b.reborrow());
+        // row-major: C' = op(B') op(A')
+        let (a, b, c) = match layout {
+            BLASRowMajor => (b, a, c.map(|c| c.reversed_axes())),
+            _ => (a, b, c),
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None => ArrayOut2::Owned(Array2::zeros((n as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = GEMMT_Driver { uplo, transa, transb, n, k, lda, ldb, ldc, a, b, c, alpha, beta };
+        let c = driver.run_blas()?;
+        return Ok(if layout == BLASRowMajor { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of GEMMT; `run` is only available when all required arguments are given.
+        GEMMT_Typed for GEMMT_Builder ['a, 'b, 'c] [F] where [F: GEMMTNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A, b: ArrayView2<'b, F> as B }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F,
+            beta: F,
+            uplo: BLASUpLo,
+            transa: BLASTranspose,
+            transb: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { GEMMT, SGEMMT = f32, DGEMMT = f64, CGEMMT = c32, ZGEMMT = c64 }
+    }
+}
+
+/* #endregion */
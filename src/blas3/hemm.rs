@@ -39,7 +39,13 @@ macro_rules! impl_func {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [side, uplo], [m, n, lda, ldb, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::hemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
             }
         }
     };
@@ -68,6 +74,22 @@ where
     beta: F,
     c: ArrayOut2<'c, F>,
     ldc: blas_int,
+    /// Whether C is conjugated in-place by [`conj_c`](Self::conj_c); conjugated back if BLAS call fails.
+    conj_c: bool,
+}
+
+impl<'a, 'b, 'c, F> HEMM_Driver<'a, 'b, 'c, F>
+where
+    F: HEMMNum,
+{
+    /// Conjugate C in-place before BLAS call, if conjugate of output is computed.
+    fn conj_c(mut self, conj: bool) -> Self {
+        if conj {
+            conj_view_inplace(&mut self.c.view_mut());
+        }
+        self.conj_c = conj;
+        return self;
+    }
 }
 
 impl<'a, 'b, 'c, F> BLASDriver<'c, F, Ix2> for HEMM_Driver<'a, 'b, 'c, F>
@@ -75,7 +97,7 @@ where
     F: HEMMNum,
 {
     fn run_blas(self) -> Result<ArrayOut2<'c, F>, BLASError> {
-        let Self { side, uplo, m, n, alpha, a, lda, b, ldb, beta, mut c, ldc, .. } = self;
+        let Self { side, uplo, m, n, alpha, a, lda, b, ldb, beta, mut c, ldc, conj_c, .. } = self;
         let a_ptr = a.as_ptr();
         let b_ptr = b.as_ptr();
         let c_ptr = c.get_data_mut_ptr();
@@ -89,6 +111,12 @@ where
         unsafe {
             F::hemm(&side, &uplo, &m, &n, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
+        if let Err(err) = check_blas_call() {
+            if conj_c {
+                conj_view_inplace(&mut c.view_mut());
+            }
+            return Err(err);
+        }
         return Ok(c.clone_to_view_mut());
     }
 }
@@ -118,6 +146,8 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for HEMM_<'a, 'b, 'c, F>
@@ -173,6 +203,7 @@ where
             beta,
             c,
             ldc: ldc.try_into()?,
+            conj_c: false,
         };
         return Ok(driver);
     }
@@ -192,6 +223,7 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("HEMM", self.copy_policy.flatten());
         let HEMM_ { a, b, c, alpha, beta, side, uplo, layout, .. } = self.build()?;
 
         let layout_a = get_layout_array2(&a);
@@ -199,7 +231,32 @@ where
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
         let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
-        if layout == BLASColMajor {
+
+        // copy of A may be avoided by computing conjugate of C, since conj(A) is transpose of hermitian A
+        // conj(C) = conj(alpha) A' conj(B) + conj(beta) conj(C) (if side = L)
+        // however, conjugate copy of B is always required
+        let conj_out = prefer_conj_output::<F>(
+            to_layout_cost(&a, layout) + to_layout_cost(&b, layout),
+            to_layout_cost(&a.t(), layout) + b.len(),
+            b.len(),
+            c.is_some() && !beta.is_zero(),
+        );
+        // C is conjugated only after checks of driver, so it is left untouched on error
+        let conj_c = conj_out && !beta.is_zero();
+        let b_conj;
+        let (a, b, c, alpha, beta, uplo) = match conj_out {
+            false => (a, b.reborrow(), c, alpha, beta, uplo),
+            true => {
+                blas_warn_layout_clone!(b, "Perform element-wise conjugate to matrix")?;
+                b_conj = match layout {
+                    BLASColMajor => copy_to_col_layout(&b, true),
+                    _ => copy_to_col_layout(&b.t(), true).reversed_axes(),
+                };
+                (a.reversed_axes(), b_conj.view(), c, F::conj(alpha), F::conj(beta), uplo.flip()?)
+            },
+        };
+
+        let mut c_out = if layout == BLASColMajor {
             // F-contiguous: C = op(A) op(B)
             let a_cow = a.to_col_layout()?;
             let b_cow = b.to_col_layout()?;
@@ -212,8 +269,9 @@ where
                 side,
                 uplo,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            return obj.driver()?.run_blas();
+            obj.driver()?.conj_c(conj_c).run_blas()?
         } else {
             // C-contiguous: C' = op(B') op(A')
             let a_cow = a.to_row_layout()?;
@@ -227,10 +285,169 @@ where
                 side: side.flip()?,
                 uplo: uplo.flip()?,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            let c = obj.driver()?.run_blas()?.reversed_axes();
-            return Ok(c);
+            obj.driver()?.conj_c(conj_c).run_blas()?.reversed_axes()
+        };
+        if conj_out {
+            c_out.conj_inplace();
+        }
+        return Ok(c_out);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of HEMM, created by [`HEMM_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct HEMMPlan<F>
+where
+    F: HEMMNum,
+{
+    alpha: F,
+    beta: F,
+    side: BLASSide,
+    uplo: BLASUpLo,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_b: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<HEMMPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct HEMMPlanCall {
+    layout: BLASLayout,
+    side: c_char,
+    uplo: c_char,
+    m: blas_int,
+    n: blas_int,
+    lda: blas_int,
+    ldb: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'b, 'c, F> HEMM_Builder<'a, 'b, 'c, F>
+where
+    F: HEMMNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<HEMMPlan<F>, BLASError> {
+        let HEMM_ { a, b, c, alpha, beta, side, uplo, layout, copy_policy } = self.build()?;
+        let mut plan = HEMMPlan {
+            alpha,
+            beta,
+            side,
+            uplo,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_b: ArraySignature::of(&b),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, b, c).run().map(|_| ()))? {
+            plan.call = Some(HEMMPlanCall {
+                layout,
+                side: call.c_flag("side")?,
+                uplo: call.c_flag("uplo")?,
+                m: call.c_dim("m")?,
+                n: call.c_dim("n")?,
+                lda: call.c_dim("lda")?,
+                ldb: call.c_dim("ldb")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> HEMMPlan<F>
+where
+    F: HEMMNum,
+{
+    fn builder<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> HEMM_Builder<'a, 'b, 'c, F> {
+        HEMM_Builder {
+            a: Some(a),
+            b: Some(b),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            side: Some(self.side),
+            uplo: Some(self.uplo),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_b.check(&b, "b")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, b, c).run();
+        };
+        let HEMMPlanCall { layout, side, uplo, m, n, lda, ldb, ldc } = *call;
+        // row-major: C' = op(B') op(A')
+        let c = match layout {
+            BLASRowMajor => c.map(|c| c.reversed_axes()),
+            _ => c,
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None => ArrayOut2::Owned(Array2::zeros((m as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = HEMM_Driver { side, uplo, m, n, lda, ldb, ldc, a, b, c, alpha, beta, conj_c: false };
+        let c = driver.run_blas()?;
+        return Ok(if layout == BLASRowMajor { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HEMM; `run` is only available when all required arguments are given.
+        HEMM_Typed for HEMM_Builder ['a, 'b, 'c] [F] where [F: HEMMNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A, b: ArrayView2<'b, F> as B }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F,
+            beta: F,
+            side: BLASSide,
+            uplo: BLASUpLo,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
         }
+        aliases { HEMM, CHEMM = c32, ZHEMM = c64 }
     }
 }
 
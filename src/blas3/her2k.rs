@@ -40,7 +40,13 @@ macro_rules! impl_her2k {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [uplo, trans], [n, k, lda, ldb, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::her2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
             }
         }
     };
@@ -69,6 +75,22 @@ where
     beta: F::RealFloat,
     c: ArrayOut2<'c, F>,
     ldc: blas_int,
+    /// Whether C is conjugated in-place by [`conj_c`](Self::conj_c); conjugated back if BLAS call fails.
+    conj_c: bool,
+}
+
+impl<'a, 'b, 'c, F> HER2K_Driver<'a, 'b, 'c, F>
+where
+    F: HER2KNum,
+{
+    /// Conjugate C in-place before BLAS call, if conjugate of output is computed.
+    fn conj_c(mut self, conj: bool) -> Self {
+        if conj {
+            conj_view_inplace(&mut self.c.view_mut());
+        }
+        self.conj_c = conj;
+        return self;
+    }
 }
 
 impl<'a, 'b, 'c, F> BLASDriver<'c, F, Ix2> for HER2K_Driver<'a, 'b, 'c, F>
@@ -76,23 +98,24 @@ where
     F: HER2KNum,
 {
     fn run_blas(self) -> Result<ArrayOut2<'c, F>, BLASError> {
-        let Self { uplo, trans, n, k, alpha, a, lda, b, ldb, beta, mut c, ldc } = self;
+        let Self { uplo, trans, n, k, alpha, a, lda, b, ldb, beta, mut c, ldc, conj_c } = self;
         let a_ptr = a.as_ptr();
         let b_ptr = b.as_ptr();
         let c_ptr = c.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if n == 0 {
+        // output is left untouched in `explain`
+        if n == 0 || (k == 0 && is_planning()) {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
             let beta_f = F::RealFloat::from(beta);
             if uplo == BLASLower.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * F::from_real(beta_f));
                 }
             } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * F::from_real(beta_f));
                 }
             } else {
@@ -104,6 +127,12 @@ where
         unsafe {
             F::her2k(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
+        if let Err(err) = check_blas_call() {
+            if conj_c {
+                conj_view_inplace(&mut c.view_mut());
+            }
+            return Err(err);
+        }
         return Ok(c.clone_to_view_mut());
     }
 }
@@ -133,6 +162,8 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for HER2K_<'a, 'b, 'c, F>
@@ -140,7 +171,7 @@ where
     F: HER2KNum,
 {
     fn driver(self) -> Result<HER2K_Driver<'a, 'b, 'c, F>, BLASError> {
-        let Self { a, b, c, alpha, beta, uplo, trans, layout } = self;
+        let Self { a, b, c, alpha, beta, uplo, trans, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         assert_eq!(layout, Some(BLASColMajor));
@@ -192,6 +223,7 @@ where
             beta,
             c,
             ldc: ldc.try_into()?,
+            conj_c: false,
         };
         return Ok(driver);
     }
@@ -211,7 +243,8 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let HER2K_ { a, b, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let _ctx = CopyContext::enter("HER2K", self.copy_policy.flatten());
+        let HER2K_ { a, b, c, alpha, beta, uplo, trans, layout, .. } = self.build()?;
 
         // Note that since we will change `trans` in outer wrapper to utilize mix-contiguous
         // additional check to this parameter is required
@@ -227,7 +260,28 @@ where
 
         // her2k is difficult to gain any improvement when input matrices layouts are mixed
         let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a, layout_b]);
-        if layout == BLASColMajor {
+
+        // copy of A and B may be avoided by computing hermitian adjoint of C in the other layout
+        // C^H = alpha A B^H + conj(alpha) B A^H + beta C^H (if trans = N)
+        let n = match trans {
+            BLASNoTrans => a.len_of(Axis(0)),
+            _ => a.len_of(Axis(1)),
+        };
+        let cost = |layout| to_layout_cost(&a, layout) + to_layout_cost(&b, layout);
+        let conj_out = prefer_conj_output::<F>(
+            cost(layout),
+            cost(layout.flip()?),
+            n * n,
+            c.is_some() && !beta.is_zero(),
+        );
+        // C is conjugated only after checks of driver, so it is left untouched on error
+        let conj_c = conj_out && !beta.is_zero();
+        let (c, uplo, layout) = match conj_out {
+            false => (c, uplo, layout),
+            true => (c.map(|c| c.reversed_axes()), uplo.flip()?, layout.flip()?),
+        };
+
+        let c_out = if layout == BLASColMajor {
             // F-contiguous: C = A op(B) + B op(A)
             let a_cow = a.to_col_layout()?;
             let b_cow = b.to_col_layout()?;
@@ -240,8 +294,9 @@ where
                 uplo,
                 trans,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            return obj.driver()?.run_blas();
+            obj.driver()?.conj_c(conj_c).run_blas()?
         } else if layout == BLASRowMajor {
             // C-contiguous: C' = op(B') A' + op(A') B'
             let a_cow = a.to_row_layout()?;
@@ -255,11 +310,177 @@ where
                 uplo: uplo.flip()?,
                 trans: trans.flip(true)?,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            return Ok(obj.driver()?.run_blas()?.reversed_axes());
+            obj.driver()?.conj_c(conj_c).run_blas()?.reversed_axes()
         } else {
             return blas_raise!(RuntimeError, "This is designed not to execuate this line.");
+        };
+        match conj_out {
+            false => return Ok(c_out),
+            true => {
+                let mut c_out = c_out.reversed_axes();
+                c_out.conj_inplace();
+                return Ok(c_out);
+            },
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of HER2K, created by [`HER2K_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct HER2KPlan<F>
+where
+    F: HER2KNum,
+{
+    alpha: F,
+    beta: F::RealFloat,
+    uplo: BLASUpLo,
+    trans: BLASTranspose,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_b: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<HER2KPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct HER2KPlanCall {
+    layout: BLASLayout,
+    uplo: c_char,
+    trans: c_char,
+    n: blas_int,
+    k: blas_int,
+    lda: blas_int,
+    ldb: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'b, 'c, F> HER2K_Builder<'a, 'b, 'c, F>
+where
+    F: HER2KNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<HER2KPlan<F>, BLASError> {
+        let HER2K_ { a, b, c, alpha, beta, uplo, trans, layout, copy_policy } = self.build()?;
+        let mut plan = HER2KPlan {
+            alpha,
+            beta,
+            uplo,
+            trans,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_b: ArraySignature::of(&b),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, b, c).run().map(|_| ()))? {
+            plan.call = Some(HER2KPlanCall {
+                layout,
+                uplo: call.c_flag("uplo")?,
+                trans: call.c_flag("trans")?,
+                n: call.c_dim("n")?,
+                k: call.c_dim("k")?,
+                lda: call.c_dim("lda")?,
+                ldb: call.c_dim("ldb")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> HER2KPlan<F>
+where
+    F: HER2KNum,
+{
+    fn builder<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> HER2K_Builder<'a, 'b, 'c, F> {
+        HER2K_Builder {
+            a: Some(a),
+            b: Some(b),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            uplo: Some(self.uplo),
+            trans: Some(self.trans),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_b.check(&b, "b")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, b, c).run();
+        };
+        let HER2KPlanCall { layout, uplo, trans, n, k, lda, ldb, ldc } = *call;
+        // inputs are reborrowed by common lifetime, so that they can be swapped
+        let (a, b) = (a.reborrow(), b.reborrow());
+        // row-major: C' = op(B') A' + op(A') B'
+        let (a, b, c) = match layout {
+            BLASRowMajor => (b, a, c.map(|c| c.reversed_axes())),
+            _ => (a, b, c),
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None => ArrayOut2::Owned(Array2::zeros((n as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = HER2K_Driver { uplo, trans, n, k, lda, ldb, ldc, a, b, c, alpha, beta, conj_c: false };
+        let c = driver.run_blas()?;
+        return Ok(if layout == BLASRowMajor { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HER2K; `run` is only available when all required arguments are given.
+        HER2K_Typed for HER2K_Builder ['a, 'b, 'c] [F] where [F: HER2KNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A, b: ArrayView2<'b, F> as B }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F,
+            beta: F::RealFloat,
+            uplo: BLASUpLo,
+            trans: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
         }
+        aliases { HER2K, CHER2K = c32, ZHER2K = c64 }
     }
 }
 
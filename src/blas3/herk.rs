@@ -19,10 +19,26 @@ pub trait HERKNum: BLASFloat {
         c: *mut Self,
         ldc: *const blas_int,
     );
+
+    /// CBLAS counterpart of [`HERKNum::herk`], with additional `layout` (crate feature `cblas`).
+    #[cfg(feature = "cblas")]
+    unsafe fn cblas_herk(
+        layout: *const c_char,
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const Self::RealFloat,
+        a: *const Self,
+        lda: *const blas_int,
+        beta: *const Self::RealFloat,
+        c: *mut Self,
+        ldc: *const blas_int,
+    );
 }
 
 macro_rules! impl_herk {
-    ($type: ty, $func: ident) => {
+    ($type: ty, $func: ident, $cfunc: ident) => {
         impl HERKNum for $type {
             unsafe fn herk(
                 uplo: *const c_char,
@@ -36,14 +52,42 @@ macro_rules! impl_herk {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [uplo, trans], [n, k, lda, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(uplo, trans, n, k, alpha, a, lda, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::herk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc);
+            }
+
+            #[cfg(feature = "cblas")]
+            unsafe fn cblas_herk(
+                layout: *const c_char,
+                uplo: *const c_char,
+                trans: *const c_char,
+                n: *const blas_int,
+                k: *const blas_int,
+                alpha: *const Self::RealFloat,
+                a: *const Self,
+                lda: *const blas_int,
+                beta: *const Self::RealFloat,
+                c: *mut Self,
+                ldc: *const blas_int,
+            ) {
+                use ffi::cblas;
+                if blas_plan_call!($cfunc, [layout, uplo, trans], [n, k, lda, ldc]) {
+                    return;
+                }
+                let (layout, uplo, trans) = (cblas::layout(layout), cblas::uplo(uplo), cblas::trans(trans));
+                cblas::$cfunc(layout, uplo, trans, *n, *k, *alpha, a, *lda, *beta, c, *ldc);
             }
         }
     };
 }
 
-impl_herk!(c32, cherk_);
-impl_herk!(c64, zherk_);
+impl_herk!(c32, cherk_, cblas_cherk);
+impl_herk!(c64, zherk_, cblas_zherk);
 
 /* #endregion */
 
@@ -53,6 +97,7 @@ pub struct HERK_Driver<'a, 'c, F>
 where
     F: BLASFloat,
 {
+    layout: c_char,
     uplo: c_char,
     trans: c_char,
     n: blas_int,
@@ -63,6 +108,22 @@ where
     beta: F::RealFloat,
     c: ArrayOut2<'c, F>,
     ldc: blas_int,
+    /// Whether C is conjugated in-place by [`conj_c`](Self::conj_c); conjugated back if BLAS call fails.
+    conj_c: bool,
+}
+
+impl<'a, 'c, F> HERK_Driver<'a, 'c, F>
+where
+    F: BLASFloat,
+{
+    /// Conjugate C in-place before BLAS call, if conjugate of output is computed.
+    fn conj_c(mut self, conj: bool) -> Self {
+        if conj {
+            conj_view_inplace(&mut self.c.view_mut());
+        }
+        self.conj_c = conj;
+        return self;
+    }
 }
 
 impl<'a, 'c, F> BLASDriver<'c, F, Ix2> for HERK_Driver<'a, 'c, F>
@@ -70,22 +131,23 @@ where
     F: HERKNum,
 {
     fn run_blas(self) -> Result<ArrayOut2<'c, F>, BLASError> {
-        let Self { uplo, trans, n, k, alpha, a, lda, beta, mut c, ldc } = self;
+        let Self { layout, uplo, trans, n, k, alpha, a, lda, beta, mut c, ldc, conj_c } = self;
         let a_ptr = a.as_ptr();
         let c_ptr = c.get_data_mut_ptr();
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if n == 0 {
+        // output is left untouched in `explain`
+        if n == 0 || (k == 0 && is_planning()) {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
             let beta_f = F::from_real(beta);
             if uplo == BLASLower.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * beta_f);
                 }
             } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * beta_f);
                 }
             } else {
@@ -95,7 +157,19 @@ where
         }
 
         unsafe {
-            F::herk(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, &beta, c_ptr, &ldc);
+            if layout == b'R' as c_char {
+                // row-major is only passed to driver with crate feature `cblas`
+                #[cfg(feature = "cblas")]
+                F::cblas_herk(&layout, &uplo, &trans, &n, &k, &alpha, a_ptr, &lda, &beta, c_ptr, &ldc);
+            } else {
+                F::herk(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, &beta, c_ptr, &ldc);
+            }
+        }
+        if let Err(err) = check_blas_call() {
+            if conj_c {
+                conj_view_inplace(&mut c.view_mut());
+            }
+            return Err(err);
         }
         return Ok(c.clone_to_view_mut());
     }
@@ -125,6 +199,8 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'c, F> BLASBuilder_<'c, F, Ix2> for HERK_<'a, 'c, F>
@@ -132,11 +208,18 @@ where
     F: HERKNum,
 {
     fn driver(self) -> Result<HERK_Driver<'a, 'c, F>, BLASError> {
-        let Self { a, c, alpha, beta, uplo, trans, layout } = self;
+        let Self { a, c, alpha, beta, uplo, trans, layout, .. } = self;
 
-        // only fortran-preferred (col-major) is accepted in inner wrapper
-        assert_eq!(layout, Some(BLASColMajor));
-        assert!(a.is_fpref());
+        // only fortran-preferred (col-major) is accepted in inner wrapper, or C-contiguous with CBLAS
+        let layout = layout.unwrap();
+        match layout {
+            BLASColMajor => assert!(a.is_fpref()),
+            _ => assert!(ffi::USE_CBLAS && layout == BLASRowMajor && a.is_cpref()),
+        }
+        let ld_axis = match layout {
+            BLASColMajor => Axis(1),
+            _ => Axis(0),
+        };
 
         // initialize intent(hide) (cherk, zherk: NC accepted)
         let (n, k) = match trans {
@@ -144,25 +227,35 @@ where
             BLASConjTrans => (a.len_of(Axis(1)), a.len_of(Axis(0))),
             _ => blas_invalid!(trans)?,
         };
-        let lda = a.stride_of(Axis(1));
+        let lda = a.stride_of(ld_axis);
 
         // optional intent(out)
         let c = match c {
             Some(c) => {
                 blas_assert_eq!(c.dim(), (n, n), InvalidDim)?;
-                if c.view().is_fpref() {
-                    ArrayOut2::ViewMut(c)
-                } else {
-                    let c_buffer = c.view().to_col_layout()?.into_owned();
-                    ArrayOut2::ToBeCloned(c, c_buffer)
+                match layout {
+                    BLASColMajor if c.view().is_fpref() => ArrayOut2::ViewMut(c),
+                    BLASColMajor => {
+                        let c_buffer = c.view().to_col_layout()?.into_owned();
+                        ArrayOut2::ToBeCloned(c, c_buffer)
+                    },
+                    _ if c.view().is_cpref() => ArrayOut2::ViewMut(c),
+                    _ => {
+                        let c_buffer = c.view().to_row_layout()?.into_owned();
+                        ArrayOut2::ToBeCloned(c, c_buffer)
+                    },
                 }
             },
-            None => ArrayOut2::Owned(Array2::zeros((n, n).f())),
+            None => match layout {
+                BLASColMajor => ArrayOut2::Owned(Array2::zeros((n, n).f())),
+                _ => ArrayOut2::Owned(Array2::zeros((n, n))),
+            },
         };
-        let ldc = c.view().stride_of(Axis(1));
+        let ldc = c.view().stride_of(ld_axis);
 
         // finalize
         let driver = HERK_Driver {
+            layout: layout.try_into()?,
             uplo: uplo.try_into()?,
             trans: trans.try_into()?,
             n: n.try_into()?,
@@ -173,6 +266,7 @@ where
             beta,
             c,
             ldc: ldc.try_into()?,
+            conj_c: false,
         };
         return Ok(driver);
     }
@@ -192,7 +286,8 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let HERK_ { a, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let _ctx = CopyContext::enter("HERK", self.copy_policy.flatten());
+        let HERK_ { a, c, alpha, beta, uplo, trans, layout, .. } = self.build()?;
         let at = a.t();
 
         // Note that since we will change `trans` in outer wrapper to utilize mix-contiguous
@@ -207,26 +302,245 @@ where
         let layout_c = c.as_ref().map(|c| get_layout_array2(&c.view()));
 
         let layout = get_layout_row_preferred(&[layout, layout_c], &[layout_a]);
-        if layout == BLASColMajor {
+
+        // conjugate copy of A may be avoided by computing hermitian adjoint of C in the other layout
+        // C^H = alpha A A^H + beta C^H (if trans = N)
+        let n = match trans {
+            BLASNoTrans => a.len_of(Axis(0)),
+            _ => a.len_of(Axis(1)),
+        };
+        let conj_out = prefer_conj_output::<F>(
+            flip_trans_conj_cost(trans, &a, layout, true),
+            flip_trans_conj_cost(trans, &a, layout.flip()?, true),
+            n * n,
+            c.is_some() && !beta.is_zero(),
+        );
+        // C is conjugated only after checks of driver, so it is left untouched on error
+        let conj_c = conj_out && !beta.is_zero();
+        let (c, uplo, layout) = match conj_out {
+            false => (c, uplo, layout),
+            true => (c.map(|c| c.reversed_axes()), uplo.flip()?, layout.flip()?),
+        };
+
+        let c_out = if layout == BLASColMajor {
             // F-contiguous: C = A op(A) or C = op(A) A
             let (trans, a_cow) = flip_trans_fpref(trans, &a, &at, true)?;
-            let obj = HERK_ { a: a_cow.view(), c, alpha, beta, uplo, trans, layout: Some(BLASColMajor) };
-            return obj.driver()?.run_blas();
-        } else if layout == BLASRowMajor {
-            let (trans, a_cow) = flip_trans_cpref(trans, &a, &at, true)?;
             let obj = HERK_ {
-                a: a_cow.t(),
-                c: c.map(|c| c.reversed_axes()),
+                a: a_cow.view(),
+                c,
                 alpha,
                 beta,
-                uplo: uplo.flip()?,
-                trans: trans.flip(true)?,
+                uplo,
+                trans,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            return Ok(obj.driver()?.run_blas()?.reversed_axes());
+            obj.driver()?.conj_c(conj_c).run_blas()?
+        } else if layout == BLASRowMajor {
+            let (trans, a_cow) = flip_trans_cpref(trans, &a, &at, true)?;
+            if ffi::USE_CBLAS {
+                // row-major matrices are passed to CBLAS directly
+                let obj = HERK_ {
+                    a: a_cow.view(),
+                    c,
+                    alpha,
+                    beta,
+                    uplo,
+                    trans,
+                    layout: Some(BLASRowMajor),
+                    copy_policy: None,
+                };
+                obj.driver()?.conj_c(conj_c).run_blas()?
+            } else {
+                let obj = HERK_ {
+                    a: a_cow.t(),
+                    c: c.map(|c| c.reversed_axes()),
+                    alpha,
+                    beta,
+                    uplo: uplo.flip()?,
+                    trans: trans.flip(true)?,
+                    layout: Some(BLASColMajor),
+                    copy_policy: None,
+                };
+                obj.driver()?.conj_c(conj_c).run_blas()?.reversed_axes()
+            }
         } else {
             return blas_raise!(RuntimeError, "This is designed not to execuate this line.");
+        };
+        match conj_out {
+            false => return Ok(c_out),
+            true => {
+                let mut c_out = c_out.reversed_axes();
+                c_out.conj_inplace();
+                return Ok(c_out);
+            },
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of HERK, created by [`HERK_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct HERKPlan<F>
+where
+    F: HERKNum,
+{
+    alpha: F::RealFloat,
+    beta: F::RealFloat,
+    uplo: BLASUpLo,
+    trans: BLASTranspose,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<HERKPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct HERKPlanCall {
+    layout: BLASLayout,
+    driver_layout: c_char,
+    uplo: c_char,
+    trans: c_char,
+    n: blas_int,
+    k: blas_int,
+    lda: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'c, F> HERK_Builder<'a, 'c, F>
+where
+    F: HERKNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<HERKPlan<F>, BLASError> {
+        let HERK_ { a, c, alpha, beta, uplo, trans, layout, copy_policy } = self.build()?;
+        let mut plan = HERKPlan {
+            alpha,
+            beta,
+            uplo,
+            trans,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, c).run().map(|_| ()))? {
+            plan.call = Some(HERKPlanCall {
+                layout,
+                // layout is only recorded in call of CBLAS
+                driver_layout: call.c_flag("layout").or(BLASColMajor.try_into())?,
+                uplo: call.c_flag("uplo")?,
+                trans: call.c_flag("trans")?,
+                n: call.c_dim("n")?,
+                k: call.c_dim("k")?,
+                lda: call.c_dim("lda")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> HERKPlan<F>
+where
+    F: HERKNum,
+{
+    fn builder<'a, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> HERK_Builder<'a, 'c, F> {
+        HERK_Builder {
+            a: Some(a),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            uplo: Some(self.uplo),
+            trans: Some(self.trans),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, c).run();
+        };
+        let HERKPlanCall { layout, driver_layout, uplo, trans, n, k, lda, ldc } = *call;
+        // row-major: C' is computed, with uplo and trans flipped, unless row-major arrays are passed to CBLAS
+        let cblas_row_major = driver_layout == b'R' as c_char;
+        let flip = layout == BLASRowMajor && !cblas_row_major;
+        let c = match flip {
+            true => c.map(|c| c.reversed_axes()),
+            false => c,
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None if cblas_row_major => ArrayOut2::Owned(Array2::zeros((n as usize, n as usize))),
+            None => ArrayOut2::Owned(Array2::zeros((n as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = HERK_Driver {
+            layout: driver_layout,
+            uplo,
+            trans,
+            n,
+            k,
+            lda,
+            ldc,
+            a,
+            c,
+            alpha,
+            beta,
+            conj_c: false,
+        };
+        let c = driver.run_blas()?;
+        return Ok(if flip { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of HERK; `run` is only available when all required arguments are given.
+        HERK_Typed for HERK_Builder ['a, 'c] [F] where [F: HERKNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F::RealFloat,
+            beta: F::RealFloat,
+            uplo: BLASUpLo,
+            trans: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
         }
+        aliases { HERK, CHERK = c32, ZHERK = c64 }
     }
 }
 
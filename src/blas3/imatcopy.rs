@@ -0,0 +1,258 @@
+#![cfg(feature = "matcopy")]
+
+use crate::ffi::{self, blas_int, c_char};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait IMATCOPYNum: BLASFloat {
+    unsafe fn imatcopy(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const Self,
+        ab: *mut Self,
+        lda: *const blas_int,
+        ldb: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl IMATCOPYNum for $type {
+            unsafe fn imatcopy(
+                ordering: *const c_char,
+                trans: *const c_char,
+                rows: *const blas_int,
+                cols: *const blas_int,
+                alpha: *const Self,
+                ab: *mut Self,
+                lda: *const blas_int,
+                ldb: *const blas_int,
+            ) {
+                if blas_plan_call!($func, [ordering, trans], [rows, cols, lda, ldb]) {
+                    return;
+                }
+                ffi::$func(ordering, trans, rows, cols, alpha, ab, lda, ldb);
+            }
+        }
+    };
+}
+
+impl_func!(f32, simatcopy_);
+impl_func!(f64, dimatcopy_);
+impl_func!(c32, cimatcopy_);
+impl_func!(c64, zimatcopy_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct IMATCOPY_Driver<'a, F>
+where
+    F: IMATCOPYNum,
+{
+    ordering: c_char,
+    trans: c_char,
+    rows: blas_int,
+    cols: blas_int,
+    alpha: F,
+    a: ArrayOut2<'a, F>,
+    lda: blas_int,
+    ldb: blas_int,
+}
+
+impl<'a, F> BLASDriver<'a, F, Ix2> for IMATCOPY_Driver<'a, F>
+where
+    F: IMATCOPYNum,
+{
+    fn run_blas(self) -> Result<ArrayOut2<'a, F>, BLASError> {
+        let Self { ordering, trans, rows, cols, alpha, mut a, lda, ldb } = self;
+        let a_ptr = a.get_data_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // BLAS is not called if matrix does not contain anything
+        if rows != 0 && cols != 0 {
+            unsafe {
+                F::imatcopy(&ordering, &trans, &rows, &cols, &alpha, a_ptr, &lda, &ldb);
+            }
+            check_blas_call()?;
+        }
+
+        // transpose of non-square matrix changes shape of output, which shares memory of input
+        let transposed = trans == BLASTrans.try_into()? || trans == BLASConjTrans.try_into()?;
+        if transposed && rows != cols {
+            let mut a = match a {
+                ArrayOut2::ViewMut(a) => a,
+                _ => blas_raise!(
+                    RuntimeError,
+                    "In-place transpose of non-square matrix requires mutable view."
+                )?,
+            };
+            let (rows, cols, ldb) = (rows as usize, cols as usize, ldb as usize);
+            let b = unsafe { ArrayViewMut2::from_shape_ptr((cols, rows).strides((1, ldb)), a.as_mut_ptr()) };
+            return Ok(ArrayOut2::ViewMut(b));
+        }
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+/// Scaled in-place copy with optional (conjugate) transpose: $\mathbf{A} \leftarrow \alpha \operatorname{op}(\mathbf{A})$.
+///
+/// Transpose flag accepts `N`, `T`, `C` (conjugate transpose) and `R` (conjugate without transpose).
+/// For transpose of non-square matrix, the returned matrix (of transposed shape) shares memory with input, and
+/// input matrix should be contiguous (C-contiguous or F-contiguous).
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct IMATCOPY_<'a, F>
+where
+    F: IMATCOPYNum,
+{
+    pub a: ArrayViewMut2<'a, F>,
+
+    #[builder(setter(into), default = "F::one()")]
+    pub alpha: F,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub trans: BLASTranspose,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'a, F> BLASBuilder_<'a, F, Ix2> for IMATCOPY_<'a, F>
+where
+    F: IMATCOPYNum,
+{
+    fn driver(self) -> Result<IMATCOPY_Driver<'a, F>, BLASError> {
+        let Self { a, alpha, trans, layout, .. } = self;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper
+        assert_eq!(layout, Some(BLASColMajor));
+
+        // conjugate is trivial for real types
+        let trans = match (F::is_complex(), trans) {
+            (false, BLASConjNoTrans) => BLASNoTrans,
+            (false, BLASConjTrans) => BLASTrans,
+            _ => trans,
+        };
+
+        // initialize intent(hide)
+        let (rows, cols) = a.dim();
+        let transposed = match trans {
+            BLASNoTrans | BLASConjNoTrans => false,
+            BLASTrans | BLASConjTrans => true,
+            _ => blas_invalid!(trans)?,
+        };
+
+        // intent(in, out)
+        let a = if a.view().is_fpref() {
+            ArrayOut2::ViewMut(a)
+        } else {
+            // buffer can only be cloned back if shape is not changed
+            blas_assert!(
+                !transposed || rows == cols,
+                InvalidDim,
+                "Transpose of non-square matrix requires contiguous memory"
+            )?;
+            let a_buffer = a.view().to_col_layout()?.into_owned();
+            ArrayOut2::ToBeCloned(a, a_buffer)
+        };
+        let (lda, ldb) = if transposed && rows != cols {
+            // output (cols x rows) should occupy the same memory of input (rows x cols)
+            blas_assert!(
+                cols <= 1 || a.view().stride_of(Axis(1)) == rows as isize,
+                InvalidDim,
+                "Transpose of non-square matrix requires contiguous memory"
+            )?;
+            (rows.max(1) as isize, cols.max(1) as isize)
+        } else {
+            // leading dimension is not referenced for matrix of only one column
+            let lda = match cols {
+                0 | 1 => rows.max(1) as isize,
+                _ => a.view().stride_of(Axis(1)),
+            };
+            (lda, lda)
+        };
+
+        // finalize
+        let driver = IMATCOPY_Driver {
+            ordering: BLASColMajor.try_into()?,
+            trans: trans.try_into()?,
+            rows: rows.try_into()?,
+            cols: cols.try_into()?,
+            alpha,
+            a,
+            lda: lda.try_into()?,
+            ldb: ldb.try_into()?,
+        };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type IMATCOPY<'a, F> = IMATCOPY_Builder<'a, F>;
+pub type SIMATCOPY<'a> = IMATCOPY<'a, f32>;
+pub type DIMATCOPY<'a> = IMATCOPY<'a, f64>;
+pub type CIMATCOPY<'a> = IMATCOPY<'a, c32>;
+pub type ZIMATCOPY<'a> = IMATCOPY<'a, c64>;
+
+impl<'a, F> BLASBuilder<'a, F, Ix2> for IMATCOPY_Builder<'a, F>
+where
+    F: IMATCOPYNum,
+{
+    fn run(self) -> Result<ArrayOut2<'a, F>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("IMATCOPY", self.copy_policy.flatten());
+        let IMATCOPY_ { a, alpha, trans, layout, .. } = self.build()?;
+
+        let layout_a = get_layout_array2(&a.view());
+
+        let layout = get_layout_row_preferred(&[layout, Some(layout_a)], &[layout_a]);
+        if layout == BLASColMajor {
+            // F-contiguous: A = alpha op(A)
+            let obj = IMATCOPY_ { a, alpha, trans, layout: Some(BLASColMajor), copy_policy: None };
+            return obj.driver()?.run_blas();
+        } else if layout == BLASRowMajor {
+            // C-contiguous: A' = alpha op(A')
+            let obj = IMATCOPY_ {
+                a: a.reversed_axes(),
+                alpha,
+                trans,
+                layout: Some(BLASColMajor),
+                copy_policy: None,
+            };
+            return Ok(obj.driver()?.run_blas()?.reversed_axes());
+        } else {
+            return blas_raise!(RuntimeError, "This is designed not to execuate this line.");
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of IMATCOPY; `run` is only available when all required arguments are given.
+        IMATCOPY_Typed for IMATCOPY_Builder ['a] [F] where [F: IMATCOPYNum] => ArrayOut2<'a, F>;
+        required { a: ArrayViewMut2<'a, F> as A }
+        optional { alpha: F, trans: BLASTranspose, layout: BLASLayout, copy_policy: CopyPolicy }
+        aliases { IMATCOPY, SIMATCOPY = f32, DIMATCOPY = f64, CIMATCOPY = c32, ZIMATCOPY = c64 }
+    }
+}
+
+/* #endregion */
@@ -1,4 +1,7 @@
 pub mod gemm;
+pub mod gemm_batch;
+pub mod gemm_batched;
+pub mod gemm_strided;
 pub mod hemm;
 pub mod her2k;
 pub mod herk;
@@ -11,3 +14,10 @@ pub mod trsm;
 #[cfg(feature = "gemmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "gemmt")))]
 pub mod gemmt;
+
+#[cfg(feature = "matcopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+pub mod imatcopy;
+#[cfg(feature = "matcopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+pub mod omatcopy;
@@ -0,0 +1,272 @@
+#![cfg(feature = "matcopy")]
+
+use crate::ffi::{self, blas_int, c_char};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region BLAS func */
+
+pub trait OMATCOPYNum: BLASFloat {
+    unsafe fn omatcopy(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const Self,
+        a: *const Self,
+        lda: *const blas_int,
+        b: *mut Self,
+        ldb: *const blas_int,
+    );
+}
+
+macro_rules! impl_func {
+    ($type: ty, $func: ident) => {
+        impl OMATCOPYNum for $type {
+            unsafe fn omatcopy(
+                ordering: *const c_char,
+                trans: *const c_char,
+                rows: *const blas_int,
+                cols: *const blas_int,
+                alpha: *const Self,
+                a: *const Self,
+                lda: *const blas_int,
+                b: *mut Self,
+                ldb: *const blas_int,
+            ) {
+                if blas_plan_call!($func, [ordering, trans], [rows, cols, lda, ldb]) {
+                    return;
+                }
+                ffi::$func(ordering, trans, rows, cols, alpha, a, lda, b, ldb);
+            }
+        }
+    };
+}
+
+impl_func!(f32, somatcopy_);
+impl_func!(f64, domatcopy_);
+impl_func!(c32, comatcopy_);
+impl_func!(c64, zomatcopy_);
+
+/* #endregion */
+
+/* #region BLAS driver */
+
+pub struct OMATCOPY_Driver<'a, 'b, F>
+where
+    F: OMATCOPYNum,
+{
+    ordering: c_char,
+    trans: c_char,
+    rows: blas_int,
+    cols: blas_int,
+    alpha: F,
+    a: ArrayView2<'a, F>,
+    lda: blas_int,
+    b: ArrayOut2<'b, F>,
+    ldb: blas_int,
+}
+
+impl<'a, 'b, F> BLASDriver<'b, F, Ix2> for OMATCOPY_Driver<'a, 'b, F>
+where
+    F: OMATCOPYNum,
+{
+    fn run_blas(self) -> Result<ArrayOut2<'b, F>, BLASError> {
+        let Self { ordering, trans, rows, cols, alpha, a, lda, mut b, ldb } = self;
+        let a_ptr = a.as_ptr();
+        let b_ptr = b.get_data_mut_ptr();
+
+        // assuming dimension checks has been performed
+        // unconditionally return Ok if output does not contain anything
+        if rows == 0 || cols == 0 {
+            return Ok(b.clone_to_view_mut());
+        }
+
+        unsafe {
+            F::omatcopy(&ordering, &trans, &rows, &cols, &alpha, a_ptr, &lda, b_ptr, &ldb);
+        }
+        check_blas_call()?;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS builder */
+
+/// Scaled out-of-place copy with optional (conjugate) transpose: $\mathbf{B} = \alpha \operatorname{op}(\mathbf{A})$.
+///
+/// Transpose flag accepts `N`, `T`, `C` (conjugate transpose) and `R` (conjugate without transpose).
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "BLASError"), no_std)]
+pub struct OMATCOPY_<'a, 'b, F>
+where
+    F: OMATCOPYNum,
+{
+    pub a: ArrayView2<'a, F>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub b: Option<ArrayViewMut2<'b, F>>,
+    #[builder(setter(into), default = "F::one()")]
+    pub alpha: F,
+    #[builder(setter(into), default = "BLASNoTrans")]
+    pub trans: BLASTranspose,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
+}
+
+impl<'a, 'b, F> BLASBuilder_<'b, F, Ix2> for OMATCOPY_<'a, 'b, F>
+where
+    F: OMATCOPYNum,
+{
+    fn driver(self) -> Result<OMATCOPY_Driver<'a, 'b, F>, BLASError> {
+        let Self { a, b, alpha, trans, layout, .. } = self;
+
+        // only fortran-preferred (col-major) is accepted in inner wrapper
+        assert_eq!(layout, Some(BLASColMajor));
+        assert!(a.is_fpref());
+
+        // conjugate is trivial for real types
+        let trans = match (F::is_complex(), trans) {
+            (false, BLASConjNoTrans) => BLASNoTrans,
+            (false, BLASConjTrans) => BLASTrans,
+            _ => trans,
+        };
+
+        // initialize intent(hide)
+        let (rows, cols) = a.dim();
+        let lda = match cols {
+            0 | 1 => rows.max(1) as isize,
+            _ => a.stride_of(Axis(1)),
+        };
+
+        // optional intent(out)
+        let b_dim = match trans {
+            BLASNoTrans | BLASConjNoTrans => (rows, cols),
+            BLASTrans | BLASConjTrans => (cols, rows),
+            _ => blas_invalid!(trans)?,
+        };
+        let b = match b {
+            Some(b) => {
+                blas_assert_eq!(b.dim(), b_dim, InvalidDim)?;
+                if b.view().is_fpref() {
+                    ArrayOut2::ViewMut(b)
+                } else {
+                    let b_buffer = b.view().to_col_layout()?.into_owned();
+                    ArrayOut2::ToBeCloned(b, b_buffer)
+                }
+            },
+            None => ArrayOut2::Owned(Array2::zeros(b_dim.f())),
+        };
+        let ldb = match b_dim.1 {
+            0 | 1 => b_dim.0.max(1) as isize,
+            _ => b.view().stride_of(Axis(1)),
+        };
+
+        // finalize
+        let driver = OMATCOPY_Driver {
+            ordering: BLASColMajor.try_into()?,
+            trans: trans.try_into()?,
+            rows: rows.try_into()?,
+            cols: cols.try_into()?,
+            alpha,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+        };
+        return Ok(driver);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS wrapper */
+
+pub type OMATCOPY<'a, 'b, F> = OMATCOPY_Builder<'a, 'b, F>;
+pub type SOMATCOPY<'a, 'b> = OMATCOPY<'a, 'b, f32>;
+pub type DOMATCOPY<'a, 'b> = OMATCOPY<'a, 'b, f64>;
+pub type COMATCOPY<'a, 'b> = OMATCOPY<'a, 'b, c32>;
+pub type ZOMATCOPY<'a, 'b> = OMATCOPY<'a, 'b, c64>;
+
+impl<'a, 'b, F> BLASBuilder<'b, F, Ix2> for OMATCOPY_Builder<'a, 'b, F>
+where
+    F: OMATCOPYNum,
+{
+    fn run(self) -> Result<ArrayOut2<'b, F>, BLASError> {
+        // initialize
+        let _ctx = CopyContext::enter("OMATCOPY", self.copy_policy.flatten());
+        let OMATCOPY_ { a, b, alpha, trans, layout, .. } = self.build()?;
+        let at = a.t();
+
+        let layout_a = get_layout_array2(&a);
+        let layout_b = b.as_ref().map(|b| get_layout_array2(&b.view()));
+
+        let layout = get_layout_row_preferred(&[layout, layout_b], &[layout_a]);
+        if layout == BLASColMajor {
+            // F-contiguous: B = alpha op(A)
+            let (trans, a_cow) = flip_trans_fpref_exact(trans, &a)?;
+            let obj =
+                OMATCOPY_ { a: a_cow.view(), b, alpha, trans, layout: Some(BLASColMajor), copy_policy: None };
+            return obj.driver()?.run_blas();
+        } else if layout == BLASRowMajor {
+            // C-contiguous: B' = alpha op(A')
+            let (trans, a_cow) = flip_trans_fpref_exact(trans, &at)?;
+            let obj = OMATCOPY_ {
+                a: a_cow.view(),
+                b: b.map(|b| b.reversed_axes()),
+                alpha,
+                trans,
+                layout: Some(BLASColMajor),
+                copy_policy: None,
+            };
+            return Ok(obj.driver()?.run_blas()?.reversed_axes());
+        } else {
+            return blas_raise!(RuntimeError, "This is designed not to execuate this line.");
+        }
+    }
+}
+
+/// Obtain F-contiguous matrix without conjugate copy (conjugate is kept in transpose flag).
+fn flip_trans_fpref_exact<'a, F>(
+    trans: BLASTranspose,
+    view: &'a ArrayView2<F>,
+) -> Result<(BLASTranspose, CowArray<'a, F, Ix2>), BLASError>
+where
+    F: BLASFloat,
+{
+    if view.is_fpref() {
+        return Ok((trans, CowArray::from(view)));
+    } else if view.is_cpref() {
+        return Ok((trans.flip_exact()?, CowArray::from(view.t())));
+    } else {
+        return Ok((trans, view.to_col_layout()?));
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of OMATCOPY; `run` is only available when all required arguments are given.
+        OMATCOPY_Typed for OMATCOPY_Builder ['a, 'b] [F] where [F: OMATCOPYNum] => ArrayOut2<'b, F>;
+        required { a: ArrayView2<'a, F> as A }
+        optional {
+            b: ArrayViewMut2<'b, F>,
+            alpha: F,
+            trans: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { OMATCOPY, SOMATCOPY = f32, DOMATCOPY = f64, COMATCOPY = c32, ZOMATCOPY = c64 }
+    }
+}
+
+/* #endregion */
@@ -39,7 +39,13 @@ macro_rules! impl_func {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [side, uplo], [m, n, lda, ldb, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
             }
         }
     };
@@ -91,6 +97,7 @@ where
         unsafe {
             F::symm(&side, &uplo, &m, &n, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
+        check_blas_call()?;
         return Ok(c.clone_to_view_mut());
     }
 }
@@ -120,6 +127,8 @@ where
     pub uplo: BLASUpLo,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for SYMM_<'a, 'b, 'c, F>
@@ -196,6 +205,7 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
+        let _ctx = CopyContext::enter("SYMM", self.copy_policy.flatten());
         let SYMM_ { a, b, c, alpha, beta, side, uplo, layout, .. } = self.build()?;
         let at = a.t();
 
@@ -220,6 +230,7 @@ where
                 side,
                 uplo,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
             return obj.driver()?.run_blas();
         } else {
@@ -238,6 +249,7 @@ where
                 side: side.flip()?,
                 uplo: uplo.flip()?,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
             let c = obj.driver()?.run_blas()?.reversed_axes();
             return Ok(c);
@@ -246,3 +258,158 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of SYMM, created by [`SYMM_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct SYMMPlan<F>
+where
+    F: SYMMNum,
+{
+    alpha: F,
+    beta: F,
+    side: BLASSide,
+    uplo: BLASUpLo,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_b: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<SYMMPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SYMMPlanCall {
+    layout: BLASLayout,
+    side: c_char,
+    uplo: c_char,
+    m: blas_int,
+    n: blas_int,
+    lda: blas_int,
+    ldb: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'b, 'c, F> SYMM_Builder<'a, 'b, 'c, F>
+where
+    F: SYMMNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<SYMMPlan<F>, BLASError> {
+        let SYMM_ { a, b, c, alpha, beta, side, uplo, layout, copy_policy } = self.build()?;
+        let mut plan = SYMMPlan {
+            alpha,
+            beta,
+            side,
+            uplo,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_b: ArraySignature::of(&b),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, b, c).run().map(|_| ()))? {
+            plan.call = Some(SYMMPlanCall {
+                layout,
+                side: call.c_flag("side")?,
+                uplo: call.c_flag("uplo")?,
+                m: call.c_dim("m")?,
+                n: call.c_dim("n")?,
+                lda: call.c_dim("lda")?,
+                ldb: call.c_dim("ldb")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> SYMMPlan<F>
+where
+    F: SYMMNum,
+{
+    fn builder<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> SYMM_Builder<'a, 'b, 'c, F> {
+        SYMM_Builder {
+            a: Some(a),
+            b: Some(b),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            side: Some(self.side),
+            uplo: Some(self.uplo),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_b.check(&b, "b")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, b, c).run();
+        };
+        let SYMMPlanCall { layout, side, uplo, m, n, lda, ldb, ldc } = *call;
+        // row-major: C' = op(B') op(A')
+        let c = match layout {
+            BLASRowMajor => c.map(|c| c.reversed_axes()),
+            _ => c,
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None => ArrayOut2::Owned(Array2::zeros((m as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = SYMM_Driver { side, uplo, m, n, lda, ldb, ldc, a, b, c, alpha, beta };
+        let c = driver.run_blas()?;
+        return Ok(if layout == BLASRowMajor { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of SYMM; `run` is only available when all required arguments are given.
+        SYMM_Typed for SYMM_Builder ['a, 'b, 'c] [F] where [F: SYMMNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A, b: ArrayView2<'b, F> as B }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F,
+            beta: F,
+            side: BLASSide,
+            uplo: BLASUpLo,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { SYMM, SSYMM = f32, DSYMM = f64, CSYMM = c32, ZSYMM = c64 }
+    }
+}
+
+/* #endregion */
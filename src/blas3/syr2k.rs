@@ -39,7 +39,13 @@ macro_rules! impl_syr2k {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [uplo, trans], [n, k, lda, ldb, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
             }
         }
     };
@@ -84,16 +90,17 @@ where
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if n == 0 {
+        // output is left untouched in `explain`
+        if n == 0 || (k == 0 && is_planning()) {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
             let beta_f = F::from(beta);
             if uplo == BLASLower.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * beta_f);
                 }
             } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * beta_f);
                 }
             } else {
@@ -105,6 +112,7 @@ where
         unsafe {
             F::syr2k(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, b_ptr, &ldb, &beta, c_ptr, &ldc);
         }
+        check_blas_call()?;
         return Ok(c.clone_to_view_mut());
     }
 }
@@ -134,6 +142,8 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'b, 'c, F> BLASBuilder_<'c, F, Ix2> for SYR2K_<'a, 'b, 'c, F>
@@ -141,7 +151,7 @@ where
     F: SYR2KNum,
 {
     fn driver(self) -> Result<SYR2K_Driver<'a, 'b, 'c, F>, BLASError> {
-        let Self { a, b, c, alpha, beta, uplo, trans, layout } = self;
+        let Self { a, b, c, alpha, beta, uplo, trans, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         assert_eq!(layout, Some(BLASColMajor));
@@ -227,7 +237,8 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let SYR2K_ { a, b, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let _ctx = CopyContext::enter("SYR2K", self.copy_policy.flatten());
+        let SYR2K_ { a, b, c, alpha, beta, uplo, trans, layout, .. } = self.build()?;
 
         // Note that since we will change `trans` in outer wrapper to utilize mix-contiguous
         // additional check to this parameter is required
@@ -263,6 +274,7 @@ where
                 uplo,
                 trans,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
@@ -278,6 +290,7 @@ where
                 uplo: uplo.flip()?,
                 trans: trans.flip(false)?,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
@@ -287,3 +300,160 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of SYR2K, created by [`SYR2K_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct SYR2KPlan<F>
+where
+    F: SYR2KNum,
+{
+    alpha: F,
+    beta: F,
+    uplo: BLASUpLo,
+    trans: BLASTranspose,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_b: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<SYR2KPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SYR2KPlanCall {
+    layout: BLASLayout,
+    uplo: c_char,
+    trans: c_char,
+    n: blas_int,
+    k: blas_int,
+    lda: blas_int,
+    ldb: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'b, 'c, F> SYR2K_Builder<'a, 'b, 'c, F>
+where
+    F: SYR2KNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<SYR2KPlan<F>, BLASError> {
+        let SYR2K_ { a, b, c, alpha, beta, uplo, trans, layout, copy_policy } = self.build()?;
+        let mut plan = SYR2KPlan {
+            alpha,
+            beta,
+            uplo,
+            trans,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_b: ArraySignature::of(&b),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, b, c).run().map(|_| ()))? {
+            plan.call = Some(SYR2KPlanCall {
+                layout,
+                uplo: call.c_flag("uplo")?,
+                trans: call.c_flag("trans")?,
+                n: call.c_dim("n")?,
+                k: call.c_dim("k")?,
+                lda: call.c_dim("lda")?,
+                ldb: call.c_dim("ldb")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> SYR2KPlan<F>
+where
+    F: SYR2KNum,
+{
+    fn builder<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> SYR2K_Builder<'a, 'b, 'c, F> {
+        SYR2K_Builder {
+            a: Some(a),
+            b: Some(b),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            uplo: Some(self.uplo),
+            trans: Some(self.trans),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'b, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayView2<'b, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_b.check(&b, "b")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, b, c).run();
+        };
+        let SYR2KPlanCall { layout, uplo, trans, n, k, lda, ldb, ldc } = *call;
+        // inputs are reborrowed by common lifetime, so that they can be swapped
+        let (a, b) = (a.reborrow(), b.reborrow());
+        // row-major: C' = op(B') A' + op(A') B'
+        let (a, b, c) = match layout {
+            BLASRowMajor => (b, a, c.map(|c| c.reversed_axes())),
+            _ => (a, b, c),
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None => ArrayOut2::Owned(Array2::zeros((n as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = SYR2K_Driver { uplo, trans, n, k, lda, ldb, ldc, a, b, c, alpha, beta };
+        let c = driver.run_blas()?;
+        return Ok(if layout == BLASRowMajor { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of SYR2K; `run` is only available when all required arguments are given.
+        SYR2K_Typed for SYR2K_Builder ['a, 'b, 'c] [F] where [F: SYR2KNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A, b: ArrayView2<'b, F> as B }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F,
+            beta: F,
+            uplo: BLASUpLo,
+            trans: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { SYR2K, SSYR2K = f32, DSYR2K = f64, CSYR2K = c32, ZSYR2K = c64 }
+    }
+}
+
+/* #endregion */
@@ -35,7 +35,13 @@ macro_rules! impl_syrk {
                 c: *mut Self,
                 ldc: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [uplo, trans], [n, k, lda, ldc]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(uplo, trans, n, k, alpha, a, lda, beta, c, ldc);
+                #[cfg(feature = "faer")]
+                ffi::faer::syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc);
             }
         }
     };
@@ -77,16 +83,17 @@ where
 
         // assuming dimension checks has been performed
         // unconditionally return Ok if output does not contain anything
-        if n == 0 {
+        // output is left untouched in `explain`
+        if n == 0 || (k == 0 && is_planning()) {
             return Ok(c.clone_to_view_mut());
         } else if k == 0 {
             let beta_f = F::from(beta);
             if uplo == BLASLower.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![i.., i]).mapv_inplace(|v| v * beta_f);
                 }
             } else if uplo == BLASUpper.try_into()? {
-                for i in 0..n {
+                for i in 0..n as usize {
                     c.view_mut().slice_mut(s![..=i, i]).mapv_inplace(|v| v * beta_f);
                 }
             } else {
@@ -98,6 +105,7 @@ where
         unsafe {
             F::syrk(&uplo, &trans, &n, &k, &alpha, a_ptr, &lda, &beta, c_ptr, &ldc);
         }
+        check_blas_call()?;
         return Ok(c.clone_to_view_mut());
     }
 }
@@ -126,6 +134,8 @@ where
     pub trans: BLASTranspose,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'c, F> BLASBuilder_<'c, F, Ix2> for SYRK_<'a, 'c, F>
@@ -133,7 +143,7 @@ where
     F: SYRKNum,
 {
     fn driver(self) -> Result<SYRK_Driver<'a, 'c, F>, BLASError> {
-        let Self { a, c, alpha, beta, uplo, trans, layout } = self;
+        let Self { a, c, alpha, beta, uplo, trans, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         assert_eq!(layout, Some(BLASColMajor));
@@ -209,7 +219,8 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'c, F>, BLASError> {
         // initialize
-        let SYRK_ { a, c, alpha, beta, uplo, trans, layout } = self.build()?;
+        let _ctx = CopyContext::enter("SYRK", self.copy_policy.flatten());
+        let SYRK_ { a, c, alpha, beta, uplo, trans, layout, .. } = self.build()?;
         let at = a.t();
 
         // Note that since we will change `trans` in outer wrapper to utilize mix-contiguous
@@ -234,7 +245,16 @@ where
         if layout == BLASColMajor {
             // F-contiguous: C = A op(A) or C = op(A) A
             let (trans, a_cow) = flip_trans_fpref(trans, &a, &at, false)?;
-            let obj = SYRK_ { a: a_cow.view(), c, alpha, beta, uplo, trans, layout: Some(BLASColMajor) };
+            let obj = SYRK_ {
+                a: a_cow.view(),
+                c,
+                alpha,
+                beta,
+                uplo,
+                trans,
+                layout: Some(BLASColMajor),
+                copy_policy: None,
+            };
             return obj.driver()?.run_blas();
         } else if layout == BLASRowMajor {
             let (trans, a_cow) = flip_trans_cpref(trans, &a, &at, false)?;
@@ -246,6 +266,7 @@ where
                 uplo: uplo.flip()?,
                 trans: trans.flip(false)?,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
             return Ok(obj.driver()?.run_blas()?.reversed_axes());
         } else {
@@ -255,3 +276,150 @@ where
 }
 
 /* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of SYRK, created by [`SYRK_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct SYRKPlan<F>
+where
+    F: SYRKNum,
+{
+    alpha: F,
+    beta: F,
+    uplo: BLASUpLo,
+    trans: BLASTranspose,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_c: Option<ArraySignature>,
+    call: Option<SYRKPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SYRKPlanCall {
+    layout: BLASLayout,
+    uplo: c_char,
+    trans: c_char,
+    n: blas_int,
+    k: blas_int,
+    lda: blas_int,
+    ldc: blas_int,
+}
+
+impl<'a, 'c, F> SYRK_Builder<'a, 'c, F>
+where
+    F: SYRKNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<SYRKPlan<F>, BLASError> {
+        let SYRK_ { a, c, alpha, beta, uplo, trans, layout, copy_policy } = self.build()?;
+        let mut plan = SYRKPlan {
+            alpha,
+            beta,
+            uplo,
+            trans,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_c: c.as_ref().map(ArraySignature::of),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, c).run().map(|_| ()))? {
+            plan.call = Some(SYRKPlanCall {
+                layout,
+                uplo: call.c_flag("uplo")?,
+                trans: call.c_flag("trans")?,
+                n: call.c_dim("n")?,
+                k: call.c_dim("k")?,
+                lda: call.c_dim("lda")?,
+                ldc: call.c_dim("ldc")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> SYRKPlan<F>
+where
+    F: SYRKNum,
+{
+    fn builder<'a, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> SYRK_Builder<'a, 'c, F> {
+        SYRK_Builder {
+            a: Some(a),
+            c: Some(c),
+            alpha: Some(self.alpha),
+            beta: Some(self.beta),
+            uplo: Some(self.uplo),
+            trans: Some(self.trans),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'c>(
+        &self,
+        a: ArrayView2<'a, F>,
+        c: Option<ArrayViewMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        ArraySignature::check_opt(&self.sig_c, c.as_ref(), "c")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, c).run();
+        };
+        let SYRKPlanCall { layout, uplo, trans, n, k, lda, ldc } = *call;
+        // row-major: C' is computed, with uplo and trans flipped
+        let c = match layout {
+            BLASRowMajor => c.map(|c| c.reversed_axes()),
+            _ => c,
+        };
+        let c = match c {
+            Some(c) => ArrayOut2::ViewMut(c),
+            None => ArrayOut2::Owned(Array2::zeros((n as usize, n as usize).f())),
+        };
+        let (alpha, beta) = (self.alpha, self.beta);
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = SYRK_Driver { uplo, trans, n, k, lda, ldc, a, c, alpha, beta };
+        let c = driver.run_blas()?;
+        return Ok(if layout == BLASRowMajor { c.reversed_axes() } else { c });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of SYRK; `run` is only available when all required arguments are given.
+        SYRK_Typed for SYRK_Builder ['a, 'c] [F] where [F: SYRKNum] => ArrayOut2<'c, F>;
+        required { a: ArrayView2<'a, F> as A }
+        optional {
+            c: ArrayViewMut2<'c, F>,
+            alpha: F,
+            beta: F,
+            uplo: BLASUpLo,
+            trans: BLASTranspose,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
+        }
+        aliases { SYRK, SSYRK = f32, DSYRK = f64, CSYRK = c32, ZSYRK = c64 }
+    }
+}
+
+/* #endregion */
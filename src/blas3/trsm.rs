@@ -37,7 +37,13 @@ macro_rules! impl_func {
                 b: *mut Self,
                 ldb: *const blas_int,
             ) {
+                if blas_plan_call!(@level3 $func, [side, uplo, transa, diag], [m, n, lda, ldb]) {
+                    return;
+                }
+                #[cfg(not(feature = "faer"))]
                 ffi::$func(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb);
+                #[cfg(feature = "faer")]
+                ffi::faer::trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb);
             }
         }
     };
@@ -67,6 +73,22 @@ where
     lda: blas_int,
     b: ArrayOut2<'b, F>,
     ldb: blas_int,
+    /// Whether B is conjugated in-place by [`conj_b`](Self::conj_b); conjugated back if BLAS call fails.
+    conj_b: bool,
+}
+
+impl<'a, 'b, F> TRSM_Driver<'a, 'b, F>
+where
+    F: BLASFloat,
+{
+    /// Conjugate B in-place before BLAS call, if conjugate of output is computed.
+    fn conj_b(mut self, conj: bool) -> Self {
+        if conj {
+            conj_view_inplace(&mut self.b.view_mut());
+        }
+        self.conj_b = conj;
+        return self;
+    }
 }
 
 impl<'a, 'b, F> BLASDriver<'b, F, Ix2> for TRSM_Driver<'a, 'b, F>
@@ -74,7 +96,7 @@ where
     F: TRSMNum,
 {
     fn run_blas(self) -> Result<ArrayOut2<'b, F>, BLASError> {
-        let Self { side, uplo, transa, diag, m, n, alpha, a, lda, mut b, ldb } = self;
+        let Self { side, uplo, transa, diag, m, n, alpha, a, lda, mut b, ldb, conj_b } = self;
         let a_ptr = a.as_ptr();
         let b_ptr = b.get_data_mut_ptr();
 
@@ -87,6 +109,12 @@ where
         unsafe {
             F::trsm(&side, &uplo, &transa, &diag, &m, &n, &alpha, a_ptr, &lda, b_ptr, &ldb);
         }
+        if let Err(err) = check_blas_call() {
+            if conj_b {
+                conj_view_inplace(&mut b.view_mut());
+            }
+            return Err(err);
+        }
         return Ok(b.clone_to_view_mut());
     }
 }
@@ -116,6 +144,8 @@ where
     pub diag: BLASDiag,
     #[builder(setter(into, strip_option), default = "None")]
     pub layout: Option<BLASLayout>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub copy_policy: Option<CopyPolicy>,
 }
 
 impl<'a, 'b, F> BLASBuilder_<'b, F, Ix2> for TRSM_<'a, 'b, F>
@@ -123,7 +153,7 @@ where
     F: TRSMNum,
 {
     fn driver(self) -> Result<TRSM_Driver<'a, 'b, F>, BLASError> {
-        let Self { a, b, alpha, side, uplo, transa, diag, layout } = self;
+        let Self { a, b, alpha, side, uplo, transa, diag, layout, .. } = self;
 
         // only fortran-preferred (col-major) is accepted in inner wrapper
         assert_eq!(layout, Some(BLASColMajor));
@@ -162,6 +192,7 @@ where
             lda: lda.try_into()?,
             b,
             ldb: ldb.try_into()?,
+            conj_b: false,
         };
         return Ok(driver);
     }
@@ -183,17 +214,38 @@ where
 {
     fn run(self) -> Result<ArrayOut2<'b, F>, BLASError> {
         // initialize
-        let TRSM_ { a, b, alpha, side, uplo, transa, diag, layout } = self.build()?;
+        let _ctx = CopyContext::enter("TRSM", self.copy_policy.flatten());
+        let TRSM_ { a, b, alpha, side, uplo, transa, diag, layout, .. } = self.build()?;
         let at = a.t();
 
+        // Note that since we will use `ConjNoTrans` in outer wrapper to avoid conjugate copy
+        // additional check to this parameter is required
+        match transa {
+            BLASNoTrans | BLASTrans | BLASConjTrans => (),
+            _ => blas_invalid!(transa)?,
+        };
+
         let layout_a = get_layout_array2(&a);
         let layout_b = get_layout_array2(&b.view());
 
         let layout = get_layout_row_preferred(&[layout, Some(layout_b)], &[layout_a]);
-        if layout == BLASColMajor {
+
+        // conjugate copy of A may be avoided by computing conjugate of B
+        // conj(op(A)) conj(X) = conj(alpha) conj(B) (if side = L)
+        let cost = |transa| flip_trans_conj_cost(transa, &a, layout, false);
+        let conj_out = prefer_conj_output::<F>(cost(transa), cost(transa.conj()?), b.len(), true);
+        // B is conjugated only after checks of driver, so it is left untouched on error
+        let (alpha, transa, b) = match conj_out {
+            false => (alpha, transa, b),
+            true => (F::conj(alpha), transa.conj()?, b),
+        };
+        // uplo is flipped if transposed A is used
+        let is_trans = |transa| matches!(transa, BLASTrans | BLASConjTrans);
+
+        let mut b_out = if layout == BLASColMajor {
             // F-contiguous: B = op(A) B (if side = L)
             let (transa_new, a_cow) = flip_trans_fpref(transa, &a, &at, false)?;
-            let uplo = if transa_new != transa { uplo.flip()? } else { uplo };
+            let uplo = if is_trans(transa_new) != is_trans(transa) { uplo.flip()? } else { uplo };
             let obj = TRSM_ {
                 a: a_cow.view(),
                 b,
@@ -203,12 +255,13 @@ where
                 transa: transa_new,
                 diag,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            return obj.driver()?.run_blas();
+            obj.driver()?.conj_b(conj_out).run_blas()?
         } else {
             // C-contiguous: B' = B' op(A') (if side = L)
             let (transa_new, a_cow) = flip_trans_cpref(transa, &a, &at, false)?;
-            let uplo = if transa_new != transa { uplo.flip()? } else { uplo };
+            let uplo = if is_trans(transa_new) != is_trans(transa) { uplo.flip()? } else { uplo };
             let obj = TRSM_ {
                 a: a_cow.t(),
                 b: b.reversed_axes(),
@@ -218,9 +271,161 @@ where
                 transa: transa_new,
                 diag,
                 layout: Some(BLASColMajor),
+                copy_policy: None,
             };
-            return Ok(obj.driver()?.run_blas()?.reversed_axes());
+            obj.driver()?.conj_b(conj_out).run_blas()?.reversed_axes()
+        };
+        if conj_out {
+            b_out.conj_inplace();
+        }
+        return Ok(b_out);
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS plan */
+
+/// Reusable plan of TRSM, created by [`TRSM_Builder::plan`].
+///
+/// See [`GEMMPlan`](crate::blas3::gemm::GEMMPlan) for details.
+#[derive(Debug, Clone)]
+pub struct TRSMPlan<F>
+where
+    F: TRSMNum,
+{
+    alpha: F,
+    side: BLASSide,
+    uplo: BLASUpLo,
+    transa: BLASTranspose,
+    diag: BLASDiag,
+    layout: Option<BLASLayout>,
+    copy_policy: Option<CopyPolicy>,
+    sig_a: ArraySignature,
+    sig_b: ArraySignature,
+    call: Option<TRSMPlanCall>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TRSMPlanCall {
+    layout: BLASLayout,
+    side: c_char,
+    uplo: c_char,
+    transa: c_char,
+    diag: c_char,
+    m: blas_int,
+    n: blas_int,
+    lda: blas_int,
+    ldb: blas_int,
+}
+
+impl<'a, 'b, F> TRSM_Builder<'a, 'b, F>
+where
+    F: TRSMNum,
+{
+    /// Validate once, and create plan for repeated calls on arrays of the same shapes and strides.
+    pub fn plan(self) -> Result<TRSMPlan<F>, BLASError> {
+        let TRSM_ { a, b, alpha, side, uplo, transa, diag, layout, copy_policy } = self.build()?;
+        let mut plan = TRSMPlan {
+            alpha,
+            side,
+            uplo,
+            transa,
+            diag,
+            layout,
+            copy_policy,
+            sig_a: ArraySignature::of(&a),
+            sig_b: ArraySignature::of(&b),
+            call: None,
+        };
+        if let Some((layout, call)) = plan_direct_call(|| plan.builder(a, b).run().map(|_| ()))? {
+            plan.call = Some(TRSMPlanCall {
+                layout,
+                side: call.c_flag("side")?,
+                uplo: call.c_flag("uplo")?,
+                transa: call.c_flag("transa")?,
+                diag: call.c_flag("diag")?,
+                m: call.c_dim("m")?,
+                n: call.c_dim("n")?,
+                lda: call.c_dim("lda")?,
+                ldb: call.c_dim("ldb")?,
+            });
+        }
+        return Ok(plan);
+    }
+}
+
+impl<F> TRSMPlan<F>
+where
+    F: TRSMNum,
+{
+    fn builder<'a, 'b>(&self, a: ArrayView2<'a, F>, b: ArrayViewMut2<'b, F>) -> TRSM_Builder<'a, 'b, F> {
+        TRSM_Builder {
+            a: Some(a),
+            b: Some(b),
+            alpha: Some(self.alpha),
+            side: Some(self.side),
+            uplo: Some(self.uplo),
+            transa: Some(self.transa),
+            diag: Some(self.diag),
+            layout: Some(self.layout),
+            copy_policy: Some(self.copy_policy),
+        }
+    }
+
+    /// Whether BLAS is called directly, without the full wrapper.
+    pub fn is_direct(&self) -> bool {
+        self.call.is_some()
+    }
+
+    /// Run plan on arrays of the same shapes and strides as those used in creating the plan.
+    pub fn run<'a, 'b>(
+        &self,
+        a: ArrayView2<'a, F>,
+        b: ArrayViewMut2<'b, F>,
+    ) -> Result<ArrayOut2<'b, F>, BLASError> {
+        self.sig_a.check(&a, "a")?;
+        self.sig_b.check(&b, "b")?;
+
+        let Some(call) = &self.call else {
+            return self.builder(a, b).run();
+        };
+        let TRSMPlanCall { layout, side, uplo, transa, diag, m, n, lda, ldb } = *call;
+        // row-major: B' = B' op(A')^-1 (if side = L)
+        let b = match layout {
+            BLASRowMajor => b.reversed_axes(),
+            _ => b,
+        };
+        let b = ArrayOut2::ViewMut(b);
+        let alpha = self.alpha;
+        // input views are passed as is (instead of transposed), since driver only uses their pointers
+        let driver = TRSM_Driver { side, uplo, transa, diag, m, n, lda, ldb, a, b, alpha, conj_b: false };
+        let b = driver.run_blas()?;
+        return Ok(if layout == BLASRowMajor { b.reversed_axes() } else { b });
+    }
+}
+
+/* #endregion */
+
+/* #region BLAS typestate */
+
+pub mod typed {
+    use super::*;
+
+    blas_typestate! {
+        /// Typestate builder of TRSM; `run` is only available when all required arguments are given.
+        TRSM_Typed for TRSM_Builder ['a, 'b] [F] where [F: TRSMNum] => ArrayOut2<'b, F>;
+        required { a: ArrayView2<'a, F> as A, b: ArrayViewMut2<'b, F> as B }
+        optional {
+            alpha: F,
+            side: BLASSide,
+            uplo: BLASUpLo,
+            transa: BLASTranspose,
+            diag: BLASDiag,
+            layout: BLASLayout,
+            copy_policy: CopyPolicy,
         }
+        aliases { TRSM, STRSM = f32, DTRSM = f64, CTRSM = c32, ZTRSM = c64 }
     }
 }
 
@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types)]
 
+use super::blas_symbol;
 use core::ffi::c_char;
 use num_complex::*;
 
@@ -14,24 +15,31 @@ pub type c64 = Complex<f64>;
 /* automatically generated by rust-bindgen 0.69.4 */
 
 extern "C" {
+    #[link_name = blas_symbol!("dcabs1")]
     pub fn dcabs1_(z: *const c64) -> f64;
 }
 extern "C" {
+    #[link_name = blas_symbol!("scabs1")]
     pub fn scabs1_(c: *const c32) -> f32;
 }
 extern "C" {
+    #[link_name = blas_symbol!("dasum")]
     pub fn dasum_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> f64;
 }
 extern "C" {
+    #[link_name = blas_symbol!("dzasum")]
     pub fn dzasum_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> f64;
 }
 extern "C" {
+    #[link_name = blas_symbol!("sasum")]
     pub fn sasum_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> f32;
 }
 extern "C" {
+    #[link_name = blas_symbol!("scasum")]
     pub fn scasum_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> f32;
 }
 extern "C" {
+    #[link_name = blas_symbol!("caxpy")]
     pub fn caxpy_(
         n: *const blas_int,
         alpha: *const c32,
@@ -42,6 +50,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("daxpy")]
     pub fn daxpy_(
         n: *const blas_int,
         alpha: *const f64,
@@ -52,6 +61,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("saxpy")]
     pub fn saxpy_(
         n: *const blas_int,
         alpha: *const f32,
@@ -62,6 +72,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zaxpy")]
     pub fn zaxpy_(
         n: *const blas_int,
         alpha: *const c64,
@@ -72,6 +83,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ccopy")]
     pub fn ccopy_(
         n: *const blas_int,
         x: *const c32,
@@ -81,6 +93,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dcopy")]
     pub fn dcopy_(
         n: *const blas_int,
         x: *const f64,
@@ -90,6 +103,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("scopy")]
     pub fn scopy_(
         n: *const blas_int,
         x: *const f32,
@@ -99,6 +113,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zcopy")]
     pub fn zcopy_(
         n: *const blas_int,
         x: *const c64,
@@ -108,6 +123,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cdotc")]
     pub fn cdotc_(
         pres: *mut c32,
         n: *const blas_int,
@@ -118,6 +134,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cdotu")]
     pub fn cdotu_(
         pres: *mut c32,
         n: *const blas_int,
@@ -128,6 +145,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ddot")]
     pub fn ddot_(
         n: *const blas_int,
         x: *const f64,
@@ -137,6 +155,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsdot")]
     pub fn dsdot_(
         n: *const blas_int,
         x: *const f32,
@@ -146,6 +165,7 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
+    #[link_name = blas_symbol!("sdot")]
     pub fn sdot_(
         n: *const blas_int,
         x: *const f32,
@@ -155,6 +175,7 @@ extern "C" {
     ) -> f32;
 }
 extern "C" {
+    #[link_name = blas_symbol!("sdsdot")]
     pub fn sdsdot_(
         n: *const blas_int,
         sb: *const f32,
@@ -165,6 +186,7 @@ extern "C" {
     ) -> f32;
 }
 extern "C" {
+    #[link_name = blas_symbol!("zdotc")]
     pub fn zdotc_(
         pres: *mut c64,
         n: *const blas_int,
@@ -175,6 +197,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zdotu")]
     pub fn zdotu_(
         pres: *mut c64,
         n: *const blas_int,
@@ -185,60 +208,79 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("icamax")]
     pub fn icamax_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> blas_int;
 }
 extern "C" {
+    #[link_name = blas_symbol!("idamax")]
     pub fn idamax_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> blas_int;
 }
 extern "C" {
+    #[link_name = blas_symbol!("isamax")]
     pub fn isamax_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> blas_int;
 }
 extern "C" {
+    #[link_name = blas_symbol!("izamax")]
     pub fn izamax_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> blas_int;
 }
 extern "C" {
+    #[link_name = blas_symbol!("dnrm2")]
     pub fn dnrm2_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> f64;
 }
 extern "C" {
+    #[link_name = blas_symbol!("dznrm2")]
     pub fn dznrm2_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> f64;
 }
 extern "C" {
+    #[link_name = blas_symbol!("snrm2")]
     pub fn snrm2_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> f32;
 }
 extern "C" {
+    #[link_name = blas_symbol!("scnrm2")]
     pub fn scnrm2_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> f32;
 }
 extern "C" {
+    #[link_name = blas_symbol!("cscal")]
     pub fn cscal_(n: *const blas_int, a: *const c32, x: *mut c32, incx: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("csscal")]
     pub fn csscal_(n: *const blas_int, a: *const f32, x: *mut c32, incx: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("dscal")]
     pub fn dscal_(n: *const blas_int, a: *const f64, x: *mut f64, incx: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("sscal")]
     pub fn sscal_(n: *const blas_int, a: *const f32, x: *mut f32, incx: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("zdscal")]
     pub fn zdscal_(n: *const blas_int, a: *const f64, x: *mut c64, incx: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("zscal")]
     pub fn zscal_(n: *const blas_int, a: *const c64, x: *mut c64, incx: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("cswap")]
     pub fn cswap_(n: *const blas_int, x: *mut c32, incx: *const blas_int, y: *mut c32, incy: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("dswap")]
     pub fn dswap_(n: *const blas_int, x: *mut f64, incx: *const blas_int, y: *mut f64, incy: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("sswap")]
     pub fn sswap_(n: *const blas_int, x: *mut f32, incx: *const blas_int, y: *mut f32, incy: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("zswap")]
     pub fn zswap_(n: *const blas_int, x: *mut c64, incx: *const blas_int, y: *mut c64, incy: *const blas_int);
 }
 extern "C" {
+    #[link_name = blas_symbol!("csrot")]
     pub fn csrot_(
         n: *const blas_int,
         x: *mut c32,
@@ -250,6 +292,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("drot")]
     pub fn drot_(
         n: *const blas_int,
         x: *mut f64,
@@ -261,6 +304,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("srot")]
     pub fn srot_(
         n: *const blas_int,
         x: *mut f32,
@@ -272,6 +316,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zdrot")]
     pub fn zdrot_(
         n: *const blas_int,
         x: *mut c64,
@@ -283,18 +328,23 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("crotg")]
     pub fn crotg_(a: *mut c32, b: *const c32, c: *mut f32, s: *mut c32);
 }
 extern "C" {
+    #[link_name = blas_symbol!("drotg")]
     pub fn drotg_(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64);
 }
 extern "C" {
+    #[link_name = blas_symbol!("srotg")]
     pub fn srotg_(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32);
 }
 extern "C" {
+    #[link_name = blas_symbol!("zrotg")]
     pub fn zrotg_(a: *mut c64, b: *const c64, c: *mut f64, s: *mut c64);
 }
 extern "C" {
+    #[link_name = blas_symbol!("drotm")]
     pub fn drotm_(
         n: *const blas_int,
         x: *mut f64,
@@ -305,6 +355,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("srotm")]
     pub fn srotm_(
         n: *const blas_int,
         x: *mut f32,
@@ -315,12 +366,15 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("drotmg")]
     pub fn drotmg_(d1: *mut f64, d2: *mut f64, x1: *mut f64, y1: *const f64, param: *mut f64);
 }
 extern "C" {
+    #[link_name = blas_symbol!("srotmg")]
     pub fn srotmg_(d1: *mut f32, d2: *mut f32, x1: *mut f32, y1: *const f32, param: *mut f32);
 }
 extern "C" {
+    #[link_name = blas_symbol!("cgemv")]
     pub fn cgemv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -336,6 +390,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dgemv")]
     pub fn dgemv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -351,6 +406,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sgemv")]
     pub fn sgemv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -366,6 +422,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zgemv")]
     pub fn zgemv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -381,6 +438,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cgerc")]
     pub fn cgerc_(
         m: *const blas_int,
         n: *const blas_int,
@@ -394,6 +452,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cgeru")]
     pub fn cgeru_(
         m: *const blas_int,
         n: *const blas_int,
@@ -407,6 +466,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dger")]
     pub fn dger_(
         m: *const blas_int,
         n: *const blas_int,
@@ -420,6 +480,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sger")]
     pub fn sger_(
         m: *const blas_int,
         n: *const blas_int,
@@ -433,6 +494,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zgerc")]
     pub fn zgerc_(
         m: *const blas_int,
         n: *const blas_int,
@@ -446,6 +508,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zgeru")]
     pub fn zgeru_(
         m: *const blas_int,
         n: *const blas_int,
@@ -459,6 +522,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("chemv")]
     pub fn chemv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -473,6 +537,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsymv")]
     pub fn dsymv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -487,6 +552,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ssymv")]
     pub fn ssymv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -501,6 +567,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zhemv")]
     pub fn zhemv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -515,6 +582,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cher")]
     pub fn cher_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -526,6 +594,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsyr")]
     pub fn dsyr_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -537,6 +606,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ssyr")]
     pub fn ssyr_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -548,6 +618,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zher")]
     pub fn zher_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -559,6 +630,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cher2")]
     pub fn cher2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -572,6 +644,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsyr2")]
     pub fn dsyr2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -585,6 +658,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ssyr2")]
     pub fn ssyr2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -598,6 +672,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zher2")]
     pub fn zher2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -611,6 +686,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctrmv")]
     pub fn ctrmv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -623,6 +699,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtrmv")]
     pub fn dtrmv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -635,6 +712,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("strmv")]
     pub fn strmv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -647,6 +725,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztrmv")]
     pub fn ztrmv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -659,6 +738,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctrsv")]
     pub fn ctrsv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -671,6 +751,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtrsv")]
     pub fn dtrsv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -683,6 +764,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("strsv")]
     pub fn strsv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -695,6 +777,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztrsv")]
     pub fn ztrsv_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -707,6 +790,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("chpmv")]
     pub fn chpmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -720,6 +804,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dspmv")]
     pub fn dspmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -733,6 +818,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sspmv")]
     pub fn sspmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -746,6 +832,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zhpmv")]
     pub fn zhpmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -759,6 +846,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("chpr")]
     pub fn chpr_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -769,6 +857,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dspr")]
     pub fn dspr_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -779,6 +868,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sspr")]
     pub fn sspr_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -789,6 +879,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zhpr")]
     pub fn zhpr_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -799,6 +890,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("chpr2")]
     pub fn chpr2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -811,6 +903,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dspr2")]
     pub fn dspr2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -823,6 +916,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sspr2")]
     pub fn sspr2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -835,6 +929,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zhpr2")]
     pub fn zhpr2_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -847,6 +942,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctpmv")]
     pub fn ctpmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -858,6 +954,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtpmv")]
     pub fn dtpmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -869,6 +966,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("stpmv")]
     pub fn stpmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -880,6 +978,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztpmv")]
     pub fn ztpmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -891,6 +990,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctpsv")]
     pub fn ctpsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -902,6 +1002,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtpsv")]
     pub fn dtpsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -913,6 +1014,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("stpsv")]
     pub fn stpsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -924,6 +1026,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztpsv")]
     pub fn ztpsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -935,6 +1038,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cgbmv")]
     pub fn cgbmv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -952,6 +1056,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dgbmv")]
     pub fn dgbmv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -969,6 +1074,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sgbmv")]
     pub fn sgbmv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -986,6 +1092,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zgbmv")]
     pub fn zgbmv_(
         trans: *const c_char,
         m: *const blas_int,
@@ -1003,6 +1110,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("chbmv")]
     pub fn chbmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -1018,6 +1126,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsbmv")]
     pub fn dsbmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -1033,6 +1142,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ssbmv")]
     pub fn ssbmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -1048,6 +1158,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zhbmv")]
     pub fn zhbmv_(
         uplo: *const c_char,
         n: *const blas_int,
@@ -1063,6 +1174,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctbmv")]
     pub fn ctbmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1076,6 +1188,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtbmv")]
     pub fn dtbmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1089,6 +1202,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("stbmv")]
     pub fn stbmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1102,6 +1216,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztbmv")]
     pub fn ztbmv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1115,6 +1230,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctbsv")]
     pub fn ctbsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1128,6 +1244,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtbsv")]
     pub fn dtbsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1141,6 +1258,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("stbsv")]
     pub fn stbsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1154,6 +1272,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztbsv")]
     pub fn ztbsv_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1167,6 +1286,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cgemm")]
     pub fn cgemm_(
         transa: *const c_char,
         transb: *const c_char,
@@ -1184,6 +1304,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dgemm")]
     pub fn dgemm_(
         transa: *const c_char,
         transb: *const c_char,
@@ -1201,6 +1322,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sgemm")]
     pub fn sgemm_(
         transa: *const c_char,
         transb: *const c_char,
@@ -1218,6 +1340,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zgemm")]
     pub fn zgemm_(
         transa: *const c_char,
         transb: *const c_char,
@@ -1235,6 +1358,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("chemm")]
     pub fn chemm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1251,6 +1375,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("csymm")]
     pub fn csymm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1267,6 +1392,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsymm")]
     pub fn dsymm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1283,6 +1409,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ssymm")]
     pub fn ssymm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1299,6 +1426,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zhemm")]
     pub fn zhemm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1315,6 +1443,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zsymm")]
     pub fn zsymm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1331,6 +1460,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cherk")]
     pub fn cherk_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1345,6 +1475,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("csyrk")]
     pub fn csyrk_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1359,6 +1490,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsyrk")]
     pub fn dsyrk_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1373,6 +1505,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ssyrk")]
     pub fn ssyrk_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1387,6 +1520,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zherk")]
     pub fn zherk_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1401,6 +1535,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zsyrk")]
     pub fn zsyrk_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1415,6 +1550,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cher2k")]
     pub fn cher2k_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1431,6 +1567,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("csyr2k")]
     pub fn csyr2k_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1447,6 +1584,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dsyr2k")]
     pub fn dsyr2k_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1463,6 +1601,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ssyr2k")]
     pub fn ssyr2k_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1479,6 +1618,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zher2k")]
     pub fn zher2k_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1495,6 +1635,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zsyr2k")]
     pub fn zsyr2k_(
         uplo: *const c_char,
         trans: *const c_char,
@@ -1511,6 +1652,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctrmm")]
     pub fn ctrmm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1526,6 +1668,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtrmm")]
     pub fn dtrmm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1541,6 +1684,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("strmm")]
     pub fn strmm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1556,6 +1700,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztrmm")]
     pub fn ztrmm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1571,6 +1716,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ctrsm")]
     pub fn ctrsm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1586,6 +1732,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dtrsm")]
     pub fn dtrsm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1601,6 +1748,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("strsm")]
     pub fn strsm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1616,6 +1764,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("ztrsm")]
     pub fn ztrsm_(
         side: *const c_char,
         uplo: *const c_char,
@@ -1631,6 +1780,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("sgemmt")]
     pub fn sgemmt_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -1648,6 +1798,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("cgemmt")]
     pub fn cgemmt_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -1665,6 +1816,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("dgemmt")]
     pub fn dgemmt_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -1682,6 +1834,7 @@ extern "C" {
     );
 }
 extern "C" {
+    #[link_name = blas_symbol!("zgemmt")]
     pub fn zgemmt_(
         uplo: *const c_char,
         transa: *const c_char,
@@ -1698,3 +1851,279 @@ extern "C" {
         ldc: *const blas_int,
     );
 }
+extern "C" {
+    #[link_name = blas_symbol!("sgemm_batch_strided")]
+    pub fn sgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const f32,
+        a: *const f32,
+        lda: *const blas_int,
+        stridea: *const blas_int,
+        b: *const f32,
+        ldb: *const blas_int,
+        strideb: *const blas_int,
+        beta: *const f32,
+        c: *mut f32,
+        ldc: *const blas_int,
+        stridec: *const blas_int,
+        batch_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("cgemm_batch_strided")]
+    pub fn cgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const blas_int,
+        stridea: *const blas_int,
+        b: *const c32,
+        ldb: *const blas_int,
+        strideb: *const blas_int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: *const blas_int,
+        stridec: *const blas_int,
+        batch_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("dgemm_batch_strided")]
+    pub fn dgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blas_int,
+        stridea: *const blas_int,
+        b: *const f64,
+        ldb: *const blas_int,
+        strideb: *const blas_int,
+        beta: *const f64,
+        c: *mut f64,
+        ldc: *const blas_int,
+        stridec: *const blas_int,
+        batch_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("zgemm_batch_strided")]
+    pub fn zgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const blas_int,
+        n: *const blas_int,
+        k: *const blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const blas_int,
+        stridea: *const blas_int,
+        b: *const c64,
+        ldb: *const blas_int,
+        strideb: *const blas_int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: *const blas_int,
+        stridec: *const blas_int,
+        batch_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("sgemm_batch")]
+    pub fn sgemm_batch_(
+        transa_array: *const c_char,
+        transb_array: *const c_char,
+        m_array: *const blas_int,
+        n_array: *const blas_int,
+        k_array: *const blas_int,
+        alpha_array: *const f32,
+        a_array: *mut *const f32,
+        lda_array: *const blas_int,
+        b_array: *mut *const f32,
+        ldb_array: *const blas_int,
+        beta_array: *const f32,
+        c_array: *mut *mut f32,
+        ldc_array: *const blas_int,
+        group_count: *const blas_int,
+        group_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("cgemm_batch")]
+    pub fn cgemm_batch_(
+        transa_array: *const c_char,
+        transb_array: *const c_char,
+        m_array: *const blas_int,
+        n_array: *const blas_int,
+        k_array: *const blas_int,
+        alpha_array: *const c32,
+        a_array: *mut *const c32,
+        lda_array: *const blas_int,
+        b_array: *mut *const c32,
+        ldb_array: *const blas_int,
+        beta_array: *const c32,
+        c_array: *mut *mut c32,
+        ldc_array: *const blas_int,
+        group_count: *const blas_int,
+        group_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("dgemm_batch")]
+    pub fn dgemm_batch_(
+        transa_array: *const c_char,
+        transb_array: *const c_char,
+        m_array: *const blas_int,
+        n_array: *const blas_int,
+        k_array: *const blas_int,
+        alpha_array: *const f64,
+        a_array: *mut *const f64,
+        lda_array: *const blas_int,
+        b_array: *mut *const f64,
+        ldb_array: *const blas_int,
+        beta_array: *const f64,
+        c_array: *mut *mut f64,
+        ldc_array: *const blas_int,
+        group_count: *const blas_int,
+        group_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("zgemm_batch")]
+    pub fn zgemm_batch_(
+        transa_array: *const c_char,
+        transb_array: *const c_char,
+        m_array: *const blas_int,
+        n_array: *const blas_int,
+        k_array: *const blas_int,
+        alpha_array: *const c64,
+        a_array: *mut *const c64,
+        lda_array: *const blas_int,
+        b_array: *mut *const c64,
+        ldb_array: *const blas_int,
+        beta_array: *const c64,
+        c_array: *mut *mut c64,
+        ldc_array: *const blas_int,
+        group_count: *const blas_int,
+        group_size: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("somatcopy")]
+    pub fn somatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const f32,
+        a: *const f32,
+        lda: *const blas_int,
+        b: *mut f32,
+        ldb: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("comatcopy")]
+    pub fn comatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: *const blas_int,
+        b: *mut c32,
+        ldb: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("domatcopy")]
+    pub fn domatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const blas_int,
+        b: *mut f64,
+        ldb: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("zomatcopy")]
+    pub fn zomatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: *const blas_int,
+        b: *mut c64,
+        ldb: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("simatcopy")]
+    pub fn simatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const f32,
+        ab: *mut f32,
+        lda: *const blas_int,
+        ldb: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("cimatcopy")]
+    pub fn cimatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const c32,
+        ab: *mut c32,
+        lda: *const blas_int,
+        ldb: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("dimatcopy")]
+    pub fn dimatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const f64,
+        ab: *mut f64,
+        lda: *const blas_int,
+        ldb: *const blas_int,
+    );
+}
+extern "C" {
+    #[link_name = blas_symbol!("zimatcopy")]
+    pub fn zimatcopy_(
+        ordering: *const c_char,
+        trans: *const c_char,
+        rows: *const blas_int,
+        cols: *const blas_int,
+        alpha: *const c64,
+        ab: *mut c64,
+        lda: *const blas_int,
+        ldb: *const blas_int,
+    );
+}
@@ -0,0 +1,189 @@
+//! BLAS functions by dynamic loading (crate feature `dynamic`).
+//!
+//! Automatically generated from `blas.rs` by `scripts/dynamic_gen.py`.
+
+#![allow(non_camel_case_types)]
+
+use super::blas_symbol;
+use super::dynamic::blas_dynamic;
+use core::ffi::c_char;
+use num_complex::*;
+
+#[cfg(not(feature = "ilp64"))]
+pub type blas_int = i32;
+#[cfg(feature = "ilp64")]
+pub type blas_int = i64;
+
+pub type c32 = Complex<f32>;
+pub type c64 = Complex<f64>;
+
+blas_dynamic! {
+    #[link_name = blas_symbol!("dcabs1")] pub fn dcabs1_(z: *const c64) -> f64;
+    #[link_name = blas_symbol!("scabs1")] pub fn scabs1_(c: *const c32) -> f32;
+    #[link_name = blas_symbol!("dasum")] pub fn dasum_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> f64;
+    #[link_name = blas_symbol!("dzasum")] pub fn dzasum_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> f64;
+    #[link_name = blas_symbol!("sasum")] pub fn sasum_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> f32;
+    #[link_name = blas_symbol!("scasum")] pub fn scasum_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> f32;
+    #[link_name = blas_symbol!("caxpy")] pub fn caxpy_(n: *const blas_int, alpha: *const c32, x: *const c32, incx: *const blas_int, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("daxpy")] pub fn daxpy_(n: *const blas_int, alpha: *const f64, x: *const f64, incx: *const blas_int, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("saxpy")] pub fn saxpy_(n: *const blas_int, alpha: *const f32, x: *const f32, incx: *const blas_int, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zaxpy")] pub fn zaxpy_(n: *const blas_int, alpha: *const c64, x: *const c64, incx: *const blas_int, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("ccopy")] pub fn ccopy_(n: *const blas_int, x: *const c32, incx: *const blas_int, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("dcopy")] pub fn dcopy_(n: *const blas_int, x: *const f64, incx: *const blas_int, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("scopy")] pub fn scopy_(n: *const blas_int, x: *const f32, incx: *const blas_int, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zcopy")] pub fn zcopy_(n: *const blas_int, x: *const c64, incx: *const blas_int, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("cdotc")] pub fn cdotc_(pres: *mut c32, n: *const blas_int, x: *const c32, incx: *const blas_int, y: *const c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("cdotu")] pub fn cdotu_(pres: *mut c32, n: *const blas_int, x: *const c32, incx: *const blas_int, y: *const c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("ddot")] pub fn ddot_(n: *const blas_int, x: *const f64, incx: *const blas_int, y: *const f64, incy: *const blas_int) -> f64;
+    #[link_name = blas_symbol!("dsdot")] pub fn dsdot_(n: *const blas_int, x: *const f32, incx: *const blas_int, y: *const f32, incy: *const blas_int) -> f64;
+    #[link_name = blas_symbol!("sdot")] pub fn sdot_(n: *const blas_int, x: *const f32, incx: *const blas_int, y: *const f32, incy: *const blas_int) -> f32;
+    #[link_name = blas_symbol!("sdsdot")] pub fn sdsdot_(n: *const blas_int, sb: *const f32, x: *const f32, incx: *const blas_int, y: *const f32, incy: *const blas_int) -> f32;
+    #[link_name = blas_symbol!("zdotc")] pub fn zdotc_(pres: *mut c64, n: *const blas_int, x: *const c64, incx: *const blas_int, y: *const c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("zdotu")] pub fn zdotu_(pres: *mut c64, n: *const blas_int, x: *const c64, incx: *const blas_int, y: *const c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("icamax")] pub fn icamax_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> blas_int;
+    #[link_name = blas_symbol!("idamax")] pub fn idamax_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> blas_int;
+    #[link_name = blas_symbol!("isamax")] pub fn isamax_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> blas_int;
+    #[link_name = blas_symbol!("izamax")] pub fn izamax_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> blas_int;
+    #[link_name = blas_symbol!("dnrm2")] pub fn dnrm2_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> f64;
+    #[link_name = blas_symbol!("dznrm2")] pub fn dznrm2_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> f64;
+    #[link_name = blas_symbol!("snrm2")] pub fn snrm2_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> f32;
+    #[link_name = blas_symbol!("scnrm2")] pub fn scnrm2_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> f32;
+    #[link_name = blas_symbol!("cscal")] pub fn cscal_(n: *const blas_int, a: *const c32, x: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("csscal")] pub fn csscal_(n: *const blas_int, a: *const f32, x: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("dscal")] pub fn dscal_(n: *const blas_int, a: *const f64, x: *mut f64, incx: *const blas_int);
+    #[link_name = blas_symbol!("sscal")] pub fn sscal_(n: *const blas_int, a: *const f32, x: *mut f32, incx: *const blas_int);
+    #[link_name = blas_symbol!("zdscal")] pub fn zdscal_(n: *const blas_int, a: *const f64, x: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("zscal")] pub fn zscal_(n: *const blas_int, a: *const c64, x: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("cswap")] pub fn cswap_(n: *const blas_int, x: *mut c32, incx: *const blas_int, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("dswap")] pub fn dswap_(n: *const blas_int, x: *mut f64, incx: *const blas_int, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("sswap")] pub fn sswap_(n: *const blas_int, x: *mut f32, incx: *const blas_int, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zswap")] pub fn zswap_(n: *const blas_int, x: *mut c64, incx: *const blas_int, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("csrot")] pub fn csrot_(n: *const blas_int, x: *mut c32, incx: *const blas_int, y: *mut c32, incy: *const blas_int, c: *const f32, s: *const f32);
+    #[link_name = blas_symbol!("drot")] pub fn drot_(n: *const blas_int, x: *mut f64, incx: *const blas_int, y: *mut f64, incy: *const blas_int, c: *const f64, s: *const f64);
+    #[link_name = blas_symbol!("srot")] pub fn srot_(n: *const blas_int, x: *mut f32, incx: *const blas_int, y: *mut f32, incy: *const blas_int, c: *const f32, s: *const f32);
+    #[link_name = blas_symbol!("zdrot")] pub fn zdrot_(n: *const blas_int, x: *mut c64, incx: *const blas_int, y: *mut c64, incy: *const blas_int, c: *const f64, s: *const f64);
+    #[link_name = blas_symbol!("crotg")] pub fn crotg_(a: *mut c32, b: *const c32, c: *mut f32, s: *mut c32);
+    #[link_name = blas_symbol!("drotg")] pub fn drotg_(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64);
+    #[link_name = blas_symbol!("srotg")] pub fn srotg_(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32);
+    #[link_name = blas_symbol!("zrotg")] pub fn zrotg_(a: *mut c64, b: *const c64, c: *mut f64, s: *mut c64);
+    #[link_name = blas_symbol!("drotm")] pub fn drotm_(n: *const blas_int, x: *mut f64, incx: *const blas_int, y: *mut f64, incy: *const blas_int, param: *const f64);
+    #[link_name = blas_symbol!("srotm")] pub fn srotm_(n: *const blas_int, x: *mut f32, incx: *const blas_int, y: *mut f32, incy: *const blas_int, param: *const f32);
+    #[link_name = blas_symbol!("drotmg")] pub fn drotmg_(d1: *mut f64, d2: *mut f64, x1: *mut f64, y1: *const f64, param: *mut f64);
+    #[link_name = blas_symbol!("srotmg")] pub fn srotmg_(d1: *mut f32, d2: *mut f32, x1: *mut f32, y1: *const f32, param: *mut f32);
+    #[link_name = blas_symbol!("cgemv")] pub fn cgemv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, x: *const c32, incx: *const blas_int, beta: *const c32, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("dgemv")] pub fn dgemv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, x: *const f64, incx: *const blas_int, beta: *const f64, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("sgemv")] pub fn sgemv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, x: *const f32, incx: *const blas_int, beta: *const f32, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zgemv")] pub fn zgemv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, x: *const c64, incx: *const blas_int, beta: *const c64, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("cgerc")] pub fn cgerc_(m: *const blas_int, n: *const blas_int, alpha: *const c32, x: *const c32, incx: *const blas_int, y: *const c32, incy: *const blas_int, a: *mut c32, lda: *const blas_int);
+    #[link_name = blas_symbol!("cgeru")] pub fn cgeru_(m: *const blas_int, n: *const blas_int, alpha: *const c32, x: *const c32, incx: *const blas_int, y: *const c32, incy: *const blas_int, a: *mut c32, lda: *const blas_int);
+    #[link_name = blas_symbol!("dger")] pub fn dger_(m: *const blas_int, n: *const blas_int, alpha: *const f64, x: *const f64, incx: *const blas_int, y: *const f64, incy: *const blas_int, a: *mut f64, lda: *const blas_int);
+    #[link_name = blas_symbol!("sger")] pub fn sger_(m: *const blas_int, n: *const blas_int, alpha: *const f32, x: *const f32, incx: *const blas_int, y: *const f32, incy: *const blas_int, a: *mut f32, lda: *const blas_int);
+    #[link_name = blas_symbol!("zgerc")] pub fn zgerc_(m: *const blas_int, n: *const blas_int, alpha: *const c64, x: *const c64, incx: *const blas_int, y: *const c64, incy: *const blas_int, a: *mut c64, lda: *const blas_int);
+    #[link_name = blas_symbol!("zgeru")] pub fn zgeru_(m: *const blas_int, n: *const blas_int, alpha: *const c64, x: *const c64, incx: *const blas_int, y: *const c64, incy: *const blas_int, a: *mut c64, lda: *const blas_int);
+    #[link_name = blas_symbol!("chemv")] pub fn chemv_(uplo: *const c_char, n: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, x: *const c32, incx: *const blas_int, beta: *const c32, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("dsymv")] pub fn dsymv_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, x: *const f64, incx: *const blas_int, beta: *const f64, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("ssymv")] pub fn ssymv_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, x: *const f32, incx: *const blas_int, beta: *const f32, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zhemv")] pub fn zhemv_(uplo: *const c_char, n: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, x: *const c64, incx: *const blas_int, beta: *const c64, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("cher")] pub fn cher_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, x: *const c32, incx: *const blas_int, a: *mut c32, lda: *const blas_int);
+    #[link_name = blas_symbol!("dsyr")] pub fn dsyr_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, x: *const f64, incx: *const blas_int, a: *mut f64, lda: *const blas_int);
+    #[link_name = blas_symbol!("ssyr")] pub fn ssyr_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, x: *const f32, incx: *const blas_int, a: *mut f32, lda: *const blas_int);
+    #[link_name = blas_symbol!("zher")] pub fn zher_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, x: *const c64, incx: *const blas_int, a: *mut c64, lda: *const blas_int);
+    #[link_name = blas_symbol!("cher2")] pub fn cher2_(uplo: *const c_char, n: *const blas_int, alpha: *const c32, x: *const c32, incx: *const blas_int, y: *const c32, incy: *const blas_int, a: *mut c32, lda: *const blas_int);
+    #[link_name = blas_symbol!("dsyr2")] pub fn dsyr2_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, x: *const f64, incx: *const blas_int, y: *const f64, incy: *const blas_int, a: *mut f64, lda: *const blas_int);
+    #[link_name = blas_symbol!("ssyr2")] pub fn ssyr2_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, x: *const f32, incx: *const blas_int, y: *const f32, incy: *const blas_int, a: *mut f32, lda: *const blas_int);
+    #[link_name = blas_symbol!("zher2")] pub fn zher2_(uplo: *const c_char, n: *const blas_int, alpha: *const c64, x: *const c64, incx: *const blas_int, y: *const c64, incy: *const blas_int, a: *mut c64, lda: *const blas_int);
+    #[link_name = blas_symbol!("ctrmv")] pub fn ctrmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const c32, lda: *const blas_int, b: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("dtrmv")] pub fn dtrmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const f64, lda: *const blas_int, b: *mut f64, incx: *const blas_int);
+    #[link_name = blas_symbol!("strmv")] pub fn strmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const f32, lda: *const blas_int, b: *mut f32, incx: *const blas_int);
+    #[link_name = blas_symbol!("ztrmv")] pub fn ztrmv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const c64, lda: *const blas_int, b: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("ctrsv")] pub fn ctrsv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const c32, lda: *const blas_int, b: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("dtrsv")] pub fn dtrsv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const f64, lda: *const blas_int, b: *mut f64, incx: *const blas_int);
+    #[link_name = blas_symbol!("strsv")] pub fn strsv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const f32, lda: *const blas_int, b: *mut f32, incx: *const blas_int);
+    #[link_name = blas_symbol!("ztrsv")] pub fn ztrsv_(uplo: *const c_char, transa: *const c_char, diag: *const c_char, n: *const blas_int, a: *const c64, lda: *const blas_int, b: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("chpmv")] pub fn chpmv_(uplo: *const c_char, n: *const blas_int, alpha: *const c32, ap: *const c32, x: *const c32, incx: *const blas_int, beta: *const c32, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("dspmv")] pub fn dspmv_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, ap: *const f64, x: *const f64, incx: *const blas_int, beta: *const f64, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("sspmv")] pub fn sspmv_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, ap: *const f32, x: *const f32, incx: *const blas_int, beta: *const f32, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zhpmv")] pub fn zhpmv_(uplo: *const c_char, n: *const blas_int, alpha: *const c64, ap: *const c64, x: *const c64, incx: *const blas_int, beta: *const c64, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("chpr")] pub fn chpr_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, x: *const c32, incx: *const blas_int, ap: *mut c32);
+    #[link_name = blas_symbol!("dspr")] pub fn dspr_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, x: *const f64, incx: *const blas_int, ap: *mut f64);
+    #[link_name = blas_symbol!("sspr")] pub fn sspr_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, x: *const f32, incx: *const blas_int, ap: *mut f32);
+    #[link_name = blas_symbol!("zhpr")] pub fn zhpr_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, x: *const c64, incx: *const blas_int, ap: *mut c64);
+    #[link_name = blas_symbol!("chpr2")] pub fn chpr2_(uplo: *const c_char, n: *const blas_int, alpha: *const c32, x: *const c32, incx: *const blas_int, y: *const c32, incy: *const blas_int, ap: *mut c32);
+    #[link_name = blas_symbol!("dspr2")] pub fn dspr2_(uplo: *const c_char, n: *const blas_int, alpha: *const f64, x: *const f64, incx: *const blas_int, y: *const f64, incy: *const blas_int, ap: *mut f64);
+    #[link_name = blas_symbol!("sspr2")] pub fn sspr2_(uplo: *const c_char, n: *const blas_int, alpha: *const f32, x: *const f32, incx: *const blas_int, y: *const f32, incy: *const blas_int, ap: *mut f32);
+    #[link_name = blas_symbol!("zhpr2")] pub fn zhpr2_(uplo: *const c_char, n: *const blas_int, alpha: *const c64, x: *const c64, incx: *const blas_int, y: *const c64, incy: *const blas_int, ap: *mut c64);
+    #[link_name = blas_symbol!("ctpmv")] pub fn ctpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const c32, x: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("dtpmv")] pub fn dtpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const f64, x: *mut f64, incx: *const blas_int);
+    #[link_name = blas_symbol!("stpmv")] pub fn stpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const f32, x: *mut f32, incx: *const blas_int);
+    #[link_name = blas_symbol!("ztpmv")] pub fn ztpmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const c64, x: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("ctpsv")] pub fn ctpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const c32, x: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("dtpsv")] pub fn dtpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const f64, x: *mut f64, incx: *const blas_int);
+    #[link_name = blas_symbol!("stpsv")] pub fn stpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const f32, x: *mut f32, incx: *const blas_int);
+    #[link_name = blas_symbol!("ztpsv")] pub fn ztpsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, ap: *const c64, x: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("cgbmv")] pub fn cgbmv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, kl: *const blas_int, ku: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, x: *const c32, incx: *const blas_int, beta: *const c32, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("dgbmv")] pub fn dgbmv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, kl: *const blas_int, ku: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, x: *const f64, incx: *const blas_int, beta: *const f64, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("sgbmv")] pub fn sgbmv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, kl: *const blas_int, ku: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, x: *const f32, incx: *const blas_int, beta: *const f32, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zgbmv")] pub fn zgbmv_(trans: *const c_char, m: *const blas_int, n: *const blas_int, kl: *const blas_int, ku: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, x: *const c64, incx: *const blas_int, beta: *const c64, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("chbmv")] pub fn chbmv_(uplo: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, x: *const c32, incx: *const blas_int, beta: *const c32, y: *mut c32, incy: *const blas_int);
+    #[link_name = blas_symbol!("dsbmv")] pub fn dsbmv_(uplo: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, x: *const f64, incx: *const blas_int, beta: *const f64, y: *mut f64, incy: *const blas_int);
+    #[link_name = blas_symbol!("ssbmv")] pub fn ssbmv_(uplo: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, x: *const f32, incx: *const blas_int, beta: *const f32, y: *mut f32, incy: *const blas_int);
+    #[link_name = blas_symbol!("zhbmv")] pub fn zhbmv_(uplo: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, x: *const c64, incx: *const blas_int, beta: *const c64, y: *mut c64, incy: *const blas_int);
+    #[link_name = blas_symbol!("ctbmv")] pub fn ctbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const c32, lda: *const blas_int, x: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("dtbmv")] pub fn dtbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const f64, lda: *const blas_int, x: *mut f64, incx: *const blas_int);
+    #[link_name = blas_symbol!("stbmv")] pub fn stbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const f32, lda: *const blas_int, x: *mut f32, incx: *const blas_int);
+    #[link_name = blas_symbol!("ztbmv")] pub fn ztbmv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const c64, lda: *const blas_int, x: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("ctbsv")] pub fn ctbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const c32, lda: *const blas_int, x: *mut c32, incx: *const blas_int);
+    #[link_name = blas_symbol!("dtbsv")] pub fn dtbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const f64, lda: *const blas_int, x: *mut f64, incx: *const blas_int);
+    #[link_name = blas_symbol!("stbsv")] pub fn stbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const f32, lda: *const blas_int, x: *mut f32, incx: *const blas_int);
+    #[link_name = blas_symbol!("ztbsv")] pub fn ztbsv_(uplo: *const c_char, trans: *const c_char, diag: *const c_char, n: *const blas_int, k: *const blas_int, a: *const c64, lda: *const blas_int, x: *mut c64, incx: *const blas_int);
+    #[link_name = blas_symbol!("cgemm")] pub fn cgemm_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *const c32, ldb: *const blas_int, beta: *const c32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("dgemm")] pub fn dgemm_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, b: *const f64, ldb: *const blas_int, beta: *const f64, c: *mut f64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("sgemm")] pub fn sgemm_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, b: *const f32, ldb: *const blas_int, beta: *const f32, c: *mut f32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zgemm")] pub fn zgemm_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *const c64, ldb: *const blas_int, beta: *const c64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("chemm")] pub fn chemm_(side: *const c_char, uplo: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *const c32, ldb: *const blas_int, beta: *const c32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("csymm")] pub fn csymm_(side: *const c_char, uplo: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *const c32, ldb: *const blas_int, beta: *const c32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("dsymm")] pub fn dsymm_(side: *const c_char, uplo: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, b: *const f64, ldb: *const blas_int, beta: *const f64, c: *mut f64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("ssymm")] pub fn ssymm_(side: *const c_char, uplo: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, b: *const f32, ldb: *const blas_int, beta: *const f32, c: *mut f32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zhemm")] pub fn zhemm_(side: *const c_char, uplo: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *const c64, ldb: *const blas_int, beta: *const c64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zsymm")] pub fn zsymm_(side: *const c_char, uplo: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *const c64, ldb: *const blas_int, beta: *const c64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("cherk")] pub fn cherk_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f32, a: *const c32, lda: *const blas_int, beta: *const f32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("csyrk")] pub fn csyrk_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, beta: *const c32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("dsyrk")] pub fn dsyrk_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, beta: *const f64, c: *mut f64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("ssyrk")] pub fn ssyrk_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, beta: *const f32, c: *mut f32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zherk")] pub fn zherk_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f64, a: *const c64, lda: *const blas_int, beta: *const f64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zsyrk")] pub fn zsyrk_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, beta: *const c64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("cher2k")] pub fn cher2k_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *const c32, ldb: *const blas_int, beta: *const f32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("csyr2k")] pub fn csyr2k_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *const c32, ldb: *const blas_int, beta: *const c32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("dsyr2k")] pub fn dsyr2k_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, b: *const f64, ldb: *const blas_int, beta: *const f64, c: *mut f64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("ssyr2k")] pub fn ssyr2k_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, b: *const f32, ldb: *const blas_int, beta: *const f32, c: *mut f32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zher2k")] pub fn zher2k_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *const c64, ldb: *const blas_int, beta: *const f64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zsyr2k")] pub fn zsyr2k_(uplo: *const c_char, trans: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *const c64, ldb: *const blas_int, beta: *const c64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("ctrmm")] pub fn ctrmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *mut c32, ldb: *const blas_int);
+    #[link_name = blas_symbol!("dtrmm")] pub fn dtrmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, b: *mut f64, ldb: *const blas_int);
+    #[link_name = blas_symbol!("strmm")] pub fn strmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, b: *mut f32, ldb: *const blas_int);
+    #[link_name = blas_symbol!("ztrmm")] pub fn ztrmm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *mut c64, ldb: *const blas_int);
+    #[link_name = blas_symbol!("ctrsm")] pub fn ctrsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *mut c32, ldb: *const blas_int);
+    #[link_name = blas_symbol!("dtrsm")] pub fn dtrsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, b: *mut f64, ldb: *const blas_int);
+    #[link_name = blas_symbol!("strsm")] pub fn strsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, b: *mut f32, ldb: *const blas_int);
+    #[link_name = blas_symbol!("ztrsm")] pub fn ztrsm_(side: *const c_char, uplo: *const c_char, transa: *const c_char, diag: *const c_char, m: *const blas_int, n: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *mut c64, ldb: *const blas_int);
+    #[link_name = blas_symbol!("sgemmt")] pub fn sgemmt_(uplo: *const c_char, transa: *const c_char, transb: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, b: *const f32, ldb: *const blas_int, beta: *const f32, c: *mut f32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("cgemmt")] pub fn cgemmt_(uplo: *const c_char, transa: *const c_char, transb: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *const c32, ldb: *const blas_int, beta: *const c32, c: *mut c32, ldc: *const blas_int);
+    #[link_name = blas_symbol!("dgemmt")] pub fn dgemmt_(uplo: *const c_char, transa: *const c_char, transb: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, b: *const f64, ldb: *const blas_int, beta: *const f64, c: *mut f64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("zgemmt")] pub fn zgemmt_(uplo: *const c_char, transa: *const c_char, transb: *const c_char, n: *const blas_int, k: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *const c64, ldb: *const blas_int, beta: *const c64, c: *mut c64, ldc: *const blas_int);
+    #[link_name = blas_symbol!("sgemm_batch_strided")] pub fn sgemm_batch_strided_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, stridea: *const blas_int, b: *const f32, ldb: *const blas_int, strideb: *const blas_int, beta: *const f32, c: *mut f32, ldc: *const blas_int, stridec: *const blas_int, batch_size: *const blas_int);
+    #[link_name = blas_symbol!("cgemm_batch_strided")] pub fn cgemm_batch_strided_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, stridea: *const blas_int, b: *const c32, ldb: *const blas_int, strideb: *const blas_int, beta: *const c32, c: *mut c32, ldc: *const blas_int, stridec: *const blas_int, batch_size: *const blas_int);
+    #[link_name = blas_symbol!("dgemm_batch_strided")] pub fn dgemm_batch_strided_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, stridea: *const blas_int, b: *const f64, ldb: *const blas_int, strideb: *const blas_int, beta: *const f64, c: *mut f64, ldc: *const blas_int, stridec: *const blas_int, batch_size: *const blas_int);
+    #[link_name = blas_symbol!("zgemm_batch_strided")] pub fn zgemm_batch_strided_(transa: *const c_char, transb: *const c_char, m: *const blas_int, n: *const blas_int, k: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, stridea: *const blas_int, b: *const c64, ldb: *const blas_int, strideb: *const blas_int, beta: *const c64, c: *mut c64, ldc: *const blas_int, stridec: *const blas_int, batch_size: *const blas_int);
+    #[link_name = blas_symbol!("sgemm_batch")] pub fn sgemm_batch_(transa_array: *const c_char, transb_array: *const c_char, m_array: *const blas_int, n_array: *const blas_int, k_array: *const blas_int, alpha_array: *const f32, a_array: *mut *const f32, lda_array: *const blas_int, b_array: *mut *const f32, ldb_array: *const blas_int, beta_array: *const f32, c_array: *mut *mut f32, ldc_array: *const blas_int, group_count: *const blas_int, group_size: *const blas_int);
+    #[link_name = blas_symbol!("cgemm_batch")] pub fn cgemm_batch_(transa_array: *const c_char, transb_array: *const c_char, m_array: *const blas_int, n_array: *const blas_int, k_array: *const blas_int, alpha_array: *const c32, a_array: *mut *const c32, lda_array: *const blas_int, b_array: *mut *const c32, ldb_array: *const blas_int, beta_array: *const c32, c_array: *mut *mut c32, ldc_array: *const blas_int, group_count: *const blas_int, group_size: *const blas_int);
+    #[link_name = blas_symbol!("dgemm_batch")] pub fn dgemm_batch_(transa_array: *const c_char, transb_array: *const c_char, m_array: *const blas_int, n_array: *const blas_int, k_array: *const blas_int, alpha_array: *const f64, a_array: *mut *const f64, lda_array: *const blas_int, b_array: *mut *const f64, ldb_array: *const blas_int, beta_array: *const f64, c_array: *mut *mut f64, ldc_array: *const blas_int, group_count: *const blas_int, group_size: *const blas_int);
+    #[link_name = blas_symbol!("zgemm_batch")] pub fn zgemm_batch_(transa_array: *const c_char, transb_array: *const c_char, m_array: *const blas_int, n_array: *const blas_int, k_array: *const blas_int, alpha_array: *const c64, a_array: *mut *const c64, lda_array: *const blas_int, b_array: *mut *const c64, ldb_array: *const blas_int, beta_array: *const c64, c_array: *mut *mut c64, ldc_array: *const blas_int, group_count: *const blas_int, group_size: *const blas_int);
+    #[link_name = blas_symbol!("somatcopy")] pub fn somatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const f32, a: *const f32, lda: *const blas_int, b: *mut f32, ldb: *const blas_int);
+    #[link_name = blas_symbol!("comatcopy")] pub fn comatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const c32, a: *const c32, lda: *const blas_int, b: *mut c32, ldb: *const blas_int);
+    #[link_name = blas_symbol!("domatcopy")] pub fn domatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const f64, a: *const f64, lda: *const blas_int, b: *mut f64, ldb: *const blas_int);
+    #[link_name = blas_symbol!("zomatcopy")] pub fn zomatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const c64, a: *const c64, lda: *const blas_int, b: *mut c64, ldb: *const blas_int);
+    #[link_name = blas_symbol!("simatcopy")] pub fn simatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const f32, ab: *mut f32, lda: *const blas_int, ldb: *const blas_int);
+    #[link_name = blas_symbol!("cimatcopy")] pub fn cimatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const c32, ab: *mut c32, lda: *const blas_int, ldb: *const blas_int);
+    #[link_name = blas_symbol!("dimatcopy")] pub fn dimatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const f64, ab: *mut f64, lda: *const blas_int, ldb: *const blas_int);
+    #[link_name = blas_symbol!("zimatcopy")] pub fn zimatcopy_(ordering: *const c_char, trans: *const c_char, rows: *const blas_int, cols: *const blas_int, alpha: *const c64, ab: *mut c64, lda: *const blas_int, ldb: *const blas_int);
+}
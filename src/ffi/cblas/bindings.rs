@@ -0,0 +1,1903 @@
+//! Extern functions of CBLAS.
+//!
+//! Automatically generated by `scripts/cblas_gen.py`.
+
+use super::*;
+use crate::ffi::{blas_int, c32, c64, cblas_symbol};
+
+extern "C" {
+    #[link_name = cblas_symbol!("sdsdot")]
+    pub fn cblas_sdsdot(
+        n: blas_int,
+        alpha: f32,
+        x: *const f32,
+        incx: blas_int,
+        y: *const f32,
+        incy: blas_int,
+    ) -> f32;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsdot")]
+    pub fn cblas_dsdot(n: blas_int, x: *const f32, incx: blas_int, y: *const f32, incy: blas_int) -> f64;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sdot")]
+    pub fn cblas_sdot(n: blas_int, x: *const f32, incx: blas_int, y: *const f32, incy: blas_int) -> f32;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ddot")]
+    pub fn cblas_ddot(n: blas_int, x: *const f64, incx: blas_int, y: *const f64, incy: blas_int) -> f64;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cdotu_sub")]
+    pub fn cblas_cdotu_sub(
+        n: blas_int,
+        x: *const c32,
+        incx: blas_int,
+        y: *const c32,
+        incy: blas_int,
+        dotu: *mut c32,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zdotu_sub")]
+    pub fn cblas_zdotu_sub(
+        n: blas_int,
+        x: *const c64,
+        incx: blas_int,
+        y: *const c64,
+        incy: blas_int,
+        dotu: *mut c64,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cdotc_sub")]
+    pub fn cblas_cdotc_sub(
+        n: blas_int,
+        x: *const c32,
+        incx: blas_int,
+        y: *const c32,
+        incy: blas_int,
+        dotc: *mut c32,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zdotc_sub")]
+    pub fn cblas_zdotc_sub(
+        n: blas_int,
+        x: *const c64,
+        incx: blas_int,
+        y: *const c64,
+        incy: blas_int,
+        dotc: *mut c64,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("snrm2")]
+    pub fn cblas_snrm2(n: blas_int, x: *const f32, incx: blas_int) -> f32;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dnrm2")]
+    pub fn cblas_dnrm2(n: blas_int, x: *const f64, incx: blas_int) -> f64;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("scnrm2")]
+    pub fn cblas_scnrm2(n: blas_int, x: *const c32, incx: blas_int) -> f32;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dznrm2")]
+    pub fn cblas_dznrm2(n: blas_int, x: *const c64, incx: blas_int) -> f64;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sasum")]
+    pub fn cblas_sasum(n: blas_int, x: *const f32, incx: blas_int) -> f32;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dasum")]
+    pub fn cblas_dasum(n: blas_int, x: *const f64, incx: blas_int) -> f64;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("scasum")]
+    pub fn cblas_scasum(n: blas_int, x: *const c32, incx: blas_int) -> f32;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dzasum")]
+    pub fn cblas_dzasum(n: blas_int, x: *const c64, incx: blas_int) -> f64;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("isamax")]
+    pub fn cblas_isamax(n: blas_int, x: *const f32, incx: blas_int) -> CBLAS_INDEX;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("idamax")]
+    pub fn cblas_idamax(n: blas_int, x: *const f64, incx: blas_int) -> CBLAS_INDEX;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("icamax")]
+    pub fn cblas_icamax(n: blas_int, x: *const c32, incx: blas_int) -> CBLAS_INDEX;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("izamax")]
+    pub fn cblas_izamax(n: blas_int, x: *const c64, incx: blas_int) -> CBLAS_INDEX;
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sswap")]
+    pub fn cblas_sswap(n: blas_int, x: *mut f32, incx: blas_int, y: *mut f32, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dswap")]
+    pub fn cblas_dswap(n: blas_int, x: *mut f64, incx: blas_int, y: *mut f64, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cswap")]
+    pub fn cblas_cswap(n: blas_int, x: *mut c32, incx: blas_int, y: *mut c32, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zswap")]
+    pub fn cblas_zswap(n: blas_int, x: *mut c64, incx: blas_int, y: *mut c64, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("scopy")]
+    pub fn cblas_scopy(n: blas_int, x: *const f32, incx: blas_int, y: *mut f32, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dcopy")]
+    pub fn cblas_dcopy(n: blas_int, x: *const f64, incx: blas_int, y: *mut f64, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ccopy")]
+    pub fn cblas_ccopy(n: blas_int, x: *const c32, incx: blas_int, y: *mut c32, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zcopy")]
+    pub fn cblas_zcopy(n: blas_int, x: *const c64, incx: blas_int, y: *mut c64, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("saxpy")]
+    pub fn cblas_saxpy(n: blas_int, alpha: f32, x: *const f32, incx: blas_int, y: *mut f32, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("daxpy")]
+    pub fn cblas_daxpy(n: blas_int, alpha: f64, x: *const f64, incx: blas_int, y: *mut f64, incy: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("caxpy")]
+    pub fn cblas_caxpy(
+        n: blas_int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: blas_int,
+        y: *mut c32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zaxpy")]
+    pub fn cblas_zaxpy(
+        n: blas_int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: blas_int,
+        y: *mut c64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("srotg")]
+    pub fn cblas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("drotg")]
+    pub fn cblas_drotg(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("crotg")]
+    pub fn cblas_crotg(a: *mut c32, b: *mut c32, c: *mut f32, s: *mut c32);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zrotg")]
+    pub fn cblas_zrotg(a: *mut c64, b: *mut c64, c: *mut f64, s: *mut c64);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("srotmg")]
+    pub fn cblas_srotmg(d1: *mut f32, d2: *mut f32, b1: *mut f32, b2: f32, p: *mut f32);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("drotmg")]
+    pub fn cblas_drotmg(d1: *mut f64, d2: *mut f64, b1: *mut f64, b2: f64, p: *mut f64);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("srot")]
+    pub fn cblas_srot(n: blas_int, x: *mut f32, incx: blas_int, y: *mut f32, incy: blas_int, c: f32, s: f32);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("drot")]
+    pub fn cblas_drot(n: blas_int, x: *mut f64, incx: blas_int, y: *mut f64, incy: blas_int, c: f64, s: f64);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("csrot")]
+    pub fn cblas_csrot(n: blas_int, x: *mut c32, incx: blas_int, y: *mut c32, incy: blas_int, c: f32, s: f32);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zdrot")]
+    pub fn cblas_zdrot(n: blas_int, x: *mut c64, incx: blas_int, y: *mut c64, incy: blas_int, c: f64, s: f64);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("srotm")]
+    pub fn cblas_srotm(n: blas_int, x: *mut f32, incx: blas_int, y: *mut f32, incy: blas_int, p: *const f32);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("drotm")]
+    pub fn cblas_drotm(n: blas_int, x: *mut f64, incx: blas_int, y: *mut f64, incy: blas_int, p: *const f64);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sscal")]
+    pub fn cblas_sscal(n: blas_int, alpha: f32, x: *mut f32, incx: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dscal")]
+    pub fn cblas_dscal(n: blas_int, alpha: f64, x: *mut f64, incx: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cscal")]
+    pub fn cblas_cscal(n: blas_int, alpha: *const c32, x: *mut c32, incx: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zscal")]
+    pub fn cblas_zscal(n: blas_int, alpha: *const c64, x: *mut c64, incx: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("csscal")]
+    pub fn cblas_csscal(n: blas_int, alpha: f32, x: *mut c32, incx: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zdscal")]
+    pub fn cblas_zdscal(n: blas_int, alpha: f64, x: *mut c64, incx: blas_int);
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sgemv")]
+    pub fn cblas_sgemv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        x: *const f32,
+        incx: blas_int,
+        beta: f32,
+        y: *mut f32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dgemv")]
+    pub fn cblas_dgemv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        x: *const f64,
+        incx: blas_int,
+        beta: f64,
+        y: *mut f64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cgemv")]
+    pub fn cblas_cgemv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        x: *const c32,
+        incx: blas_int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zgemv")]
+    pub fn cblas_zgemv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        x: *const c64,
+        incx: blas_int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sgbmv")]
+    pub fn cblas_sgbmv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        kl: blas_int,
+        ku: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        x: *const f32,
+        incx: blas_int,
+        beta: f32,
+        y: *mut f32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dgbmv")]
+    pub fn cblas_dgbmv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        kl: blas_int,
+        ku: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        x: *const f64,
+        incx: blas_int,
+        beta: f64,
+        y: *mut f64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cgbmv")]
+    pub fn cblas_cgbmv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        kl: blas_int,
+        ku: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        x: *const c32,
+        incx: blas_int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zgbmv")]
+    pub fn cblas_zgbmv(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        kl: blas_int,
+        ku: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        x: *const c64,
+        incx: blas_int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("strmv")]
+    pub fn cblas_strmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const f32,
+        lda: blas_int,
+        x: *mut f32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtrmv")]
+    pub fn cblas_dtrmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const f64,
+        lda: blas_int,
+        x: *mut f64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctrmv")]
+    pub fn cblas_ctrmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const c32,
+        lda: blas_int,
+        x: *mut c32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztrmv")]
+    pub fn cblas_ztrmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const c64,
+        lda: blas_int,
+        x: *mut c64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("stbmv")]
+    pub fn cblas_stbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const f32,
+        lda: blas_int,
+        x: *mut f32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtbmv")]
+    pub fn cblas_dtbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const f64,
+        lda: blas_int,
+        x: *mut f64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctbmv")]
+    pub fn cblas_ctbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const c32,
+        lda: blas_int,
+        x: *mut c32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztbmv")]
+    pub fn cblas_ztbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const c64,
+        lda: blas_int,
+        x: *mut c64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("stpmv")]
+    pub fn cblas_stpmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const f32,
+        x: *mut f32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtpmv")]
+    pub fn cblas_dtpmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const f64,
+        x: *mut f64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctpmv")]
+    pub fn cblas_ctpmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const c32,
+        x: *mut c32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztpmv")]
+    pub fn cblas_ztpmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const c64,
+        x: *mut c64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("strsv")]
+    pub fn cblas_strsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const f32,
+        lda: blas_int,
+        x: *mut f32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtrsv")]
+    pub fn cblas_dtrsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const f64,
+        lda: blas_int,
+        x: *mut f64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctrsv")]
+    pub fn cblas_ctrsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const c32,
+        lda: blas_int,
+        x: *mut c32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztrsv")]
+    pub fn cblas_ztrsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        a: *const c64,
+        lda: blas_int,
+        x: *mut c64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("stbsv")]
+    pub fn cblas_stbsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const f32,
+        lda: blas_int,
+        x: *mut f32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtbsv")]
+    pub fn cblas_dtbsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const f64,
+        lda: blas_int,
+        x: *mut f64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctbsv")]
+    pub fn cblas_ctbsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const c32,
+        lda: blas_int,
+        x: *mut c32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztbsv")]
+    pub fn cblas_ztbsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        k: blas_int,
+        a: *const c64,
+        lda: blas_int,
+        x: *mut c64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("stpsv")]
+    pub fn cblas_stpsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const f32,
+        x: *mut f32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtpsv")]
+    pub fn cblas_dtpsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const f64,
+        x: *mut f64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctpsv")]
+    pub fn cblas_ctpsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const c32,
+        x: *mut c32,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztpsv")]
+    pub fn cblas_ztpsv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blas_int,
+        ap: *const c64,
+        x: *mut c64,
+        incx: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ssymv")]
+    pub fn cblas_ssymv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        x: *const f32,
+        incx: blas_int,
+        beta: f32,
+        y: *mut f32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsymv")]
+    pub fn cblas_dsymv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        x: *const f64,
+        incx: blas_int,
+        beta: f64,
+        y: *mut f64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("chemv")]
+    pub fn cblas_chemv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        x: *const c32,
+        incx: blas_int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zhemv")]
+    pub fn cblas_zhemv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        x: *const c64,
+        incx: blas_int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ssbmv")]
+    pub fn cblas_ssbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        k: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        x: *const f32,
+        incx: blas_int,
+        beta: f32,
+        y: *mut f32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsbmv")]
+    pub fn cblas_dsbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        k: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        x: *const f64,
+        incx: blas_int,
+        beta: f64,
+        y: *mut f64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("chbmv")]
+    pub fn cblas_chbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        x: *const c32,
+        incx: blas_int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zhbmv")]
+    pub fn cblas_zhbmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        x: *const c64,
+        incx: blas_int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sspmv")]
+    pub fn cblas_sspmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        ap: *const f32,
+        x: *const f32,
+        incx: blas_int,
+        beta: f32,
+        y: *mut f32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dspmv")]
+    pub fn cblas_dspmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        ap: *const f64,
+        x: *const f64,
+        incx: blas_int,
+        beta: f64,
+        y: *mut f64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("chpmv")]
+    pub fn cblas_chpmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c32,
+        ap: *const c32,
+        x: *const c32,
+        incx: blas_int,
+        beta: *const c32,
+        y: *mut c32,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zhpmv")]
+    pub fn cblas_zhpmv(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c64,
+        ap: *const c64,
+        x: *const c64,
+        incx: blas_int,
+        beta: *const c64,
+        y: *mut c64,
+        incy: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sger")]
+    pub fn cblas_sger(
+        order: CBLAS_ORDER,
+        m: blas_int,
+        n: blas_int,
+        alpha: f32,
+        x: *const f32,
+        incx: blas_int,
+        y: *const f32,
+        incy: blas_int,
+        a: *mut f32,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dger")]
+    pub fn cblas_dger(
+        order: CBLAS_ORDER,
+        m: blas_int,
+        n: blas_int,
+        alpha: f64,
+        x: *const f64,
+        incx: blas_int,
+        y: *const f64,
+        incy: blas_int,
+        a: *mut f64,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cgeru")]
+    pub fn cblas_cgeru(
+        order: CBLAS_ORDER,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: blas_int,
+        y: *const c32,
+        incy: blas_int,
+        a: *mut c32,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zgeru")]
+    pub fn cblas_zgeru(
+        order: CBLAS_ORDER,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: blas_int,
+        y: *const c64,
+        incy: blas_int,
+        a: *mut c64,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cgerc")]
+    pub fn cblas_cgerc(
+        order: CBLAS_ORDER,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: blas_int,
+        y: *const c32,
+        incy: blas_int,
+        a: *mut c32,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zgerc")]
+    pub fn cblas_zgerc(
+        order: CBLAS_ORDER,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: blas_int,
+        y: *const c64,
+        incy: blas_int,
+        a: *mut c64,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ssyr")]
+    pub fn cblas_ssyr(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        x: *const f32,
+        incx: blas_int,
+        a: *mut f32,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsyr")]
+    pub fn cblas_dsyr(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        x: *const f64,
+        incx: blas_int,
+        a: *mut f64,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cher")]
+    pub fn cblas_cher(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        x: *const c32,
+        incx: blas_int,
+        a: *mut c32,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zher")]
+    pub fn cblas_zher(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        x: *const c64,
+        incx: blas_int,
+        a: *mut c64,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sspr")]
+    pub fn cblas_sspr(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        x: *const f32,
+        incx: blas_int,
+        ap: *mut f32,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dspr")]
+    pub fn cblas_dspr(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        x: *const f64,
+        incx: blas_int,
+        ap: *mut f64,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("chpr")]
+    pub fn cblas_chpr(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        x: *const c32,
+        incx: blas_int,
+        ap: *mut c32,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zhpr")]
+    pub fn cblas_zhpr(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        x: *const c64,
+        incx: blas_int,
+        ap: *mut c64,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ssyr2")]
+    pub fn cblas_ssyr2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        x: *const f32,
+        incx: blas_int,
+        y: *const f32,
+        incy: blas_int,
+        a: *mut f32,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsyr2")]
+    pub fn cblas_dsyr2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        x: *const f64,
+        incx: blas_int,
+        y: *const f64,
+        incy: blas_int,
+        a: *mut f64,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cher2")]
+    pub fn cblas_cher2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: blas_int,
+        y: *const c32,
+        incy: blas_int,
+        a: *mut c32,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zher2")]
+    pub fn cblas_zher2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: blas_int,
+        y: *const c64,
+        incy: blas_int,
+        a: *mut c64,
+        lda: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sspr2")]
+    pub fn cblas_sspr2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f32,
+        x: *const f32,
+        incx: blas_int,
+        y: *const f32,
+        incy: blas_int,
+        ap: *mut f32,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dspr2")]
+    pub fn cblas_dspr2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: f64,
+        x: *const f64,
+        incx: blas_int,
+        y: *const f64,
+        incy: blas_int,
+        ap: *mut f64,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("chpr2")]
+    pub fn cblas_chpr2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c32,
+        x: *const c32,
+        incx: blas_int,
+        y: *const c32,
+        incy: blas_int,
+        ap: *mut c32,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zhpr2")]
+    pub fn cblas_zhpr2(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        n: blas_int,
+        alpha: *const c64,
+        x: *const c64,
+        incx: blas_int,
+        y: *const c64,
+        incy: blas_int,
+        ap: *mut c64,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("sgemm")]
+    pub fn cblas_sgemm(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        k: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        b: *const f32,
+        ldb: blas_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dgemm")]
+    pub fn cblas_dgemm(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        k: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        b: *const f64,
+        ldb: blas_int,
+        beta: f64,
+        c: *mut f64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cgemm")]
+    pub fn cblas_cgemm(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        b: *const c32,
+        ldb: blas_int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zgemm")]
+    pub fn cblas_zgemm(
+        order: CBLAS_ORDER,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: blas_int,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        b: *const c64,
+        ldb: blas_int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ssymm")]
+    pub fn cblas_ssymm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blas_int,
+        n: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        b: *const f32,
+        ldb: blas_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsymm")]
+    pub fn cblas_dsymm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blas_int,
+        n: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        b: *const f64,
+        ldb: blas_int,
+        beta: f64,
+        c: *mut f64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("csymm")]
+    pub fn cblas_csymm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        b: *const c32,
+        ldb: blas_int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zsymm")]
+    pub fn cblas_zsymm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        b: *const c64,
+        ldb: blas_int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("chemm")]
+    pub fn cblas_chemm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        b: *const c32,
+        ldb: blas_int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zhemm")]
+    pub fn cblas_zhemm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        b: *const c64,
+        ldb: blas_int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ssyrk")]
+    pub fn cblas_ssyrk(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsyrk")]
+    pub fn cblas_dsyrk(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        beta: f64,
+        c: *mut f64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("csyrk")]
+    pub fn cblas_csyrk(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zsyrk")]
+    pub fn cblas_zsyrk(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cherk")]
+    pub fn cblas_cherk(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: f32,
+        a: *const c32,
+        lda: blas_int,
+        beta: f32,
+        c: *mut c32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zherk")]
+    pub fn cblas_zherk(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: f64,
+        a: *const c64,
+        lda: blas_int,
+        beta: f64,
+        c: *mut c64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ssyr2k")]
+    pub fn cblas_ssyr2k(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        b: *const f32,
+        ldb: blas_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dsyr2k")]
+    pub fn cblas_dsyr2k(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        b: *const f64,
+        ldb: blas_int,
+        beta: f64,
+        c: *mut f64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("csyr2k")]
+    pub fn cblas_csyr2k(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        b: *const c32,
+        ldb: blas_int,
+        beta: *const c32,
+        c: *mut c32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zsyr2k")]
+    pub fn cblas_zsyr2k(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        b: *const c64,
+        ldb: blas_int,
+        beta: *const c64,
+        c: *mut c64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("cher2k")]
+    pub fn cblas_cher2k(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        b: *const c32,
+        ldb: blas_int,
+        beta: f32,
+        c: *mut c32,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("zher2k")]
+    pub fn cblas_zher2k(
+        order: CBLAS_ORDER,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blas_int,
+        k: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        b: *const c64,
+        ldb: blas_int,
+        beta: f64,
+        c: *mut c64,
+        ldc: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("strmm")]
+    pub fn cblas_strmm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        b: *mut f32,
+        ldb: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtrmm")]
+    pub fn cblas_dtrmm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        b: *mut f64,
+        ldb: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctrmm")]
+    pub fn cblas_ctrmm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        b: *mut c32,
+        ldb: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztrmm")]
+    pub fn cblas_ztrmm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        b: *mut c64,
+        ldb: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("strsm")]
+    pub fn cblas_strsm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: f32,
+        a: *const f32,
+        lda: blas_int,
+        b: *mut f32,
+        ldb: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("dtrsm")]
+    pub fn cblas_dtrsm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: f64,
+        a: *const f64,
+        lda: blas_int,
+        b: *mut f64,
+        ldb: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ctrsm")]
+    pub fn cblas_ctrsm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c32,
+        a: *const c32,
+        lda: blas_int,
+        b: *mut c32,
+        ldb: blas_int,
+    );
+}
+
+extern "C" {
+    #[link_name = cblas_symbol!("ztrsm")]
+    pub fn cblas_ztrsm(
+        order: CBLAS_ORDER,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blas_int,
+        n: blas_int,
+        alpha: *const c64,
+        a: *const c64,
+        lda: blas_int,
+        b: *mut c64,
+        ldb: blas_int,
+    );
+}
@@ -0,0 +1,126 @@
+//! Bindings of CBLAS (crate feature `cblas`).
+//!
+//! With this feature, wrappers `GEMV`, `GBMV`, `HEMV`, `HBMV`, `HPMV`, `TRMV`, `TBMV`, `TPMV`, `TRSV`, `TBSV`,
+//! `TPSV`, `GEMM` and `HERK` pass row-major arrays to CBLAS with `CblasRowMajor` directly, instead of emulating
+//! row-major by transposed column-major Fortran BLAS call. This avoids element-wise conjugate of vectors (such
+//! as `x` of `TPMV` with `trans = C`), which can not be represented by Fortran BLAS flags. Column-major arrays
+//! are still passed to Fortran BLAS.
+//!
+//! The linked BLAS library should provide CBLAS (such as OpenBLAS, BLIS or MKL). Symbols are `cblas_dgemm` by
+//! default, `cblas_dgemm64_` with crate feature `suffix64_`, and `cblas_dgemm_64` with `suffix_64`; environment
+//! variable `BLAS_ARRAY2_CBLAS_SYMBOL_SUFFIX` at build time overrides the suffix.
+//!
+//! Extern functions are called by BLAS func traits of wrappers (such as `TPMVNum::cblas_tpmv`), which take the
+//! same arguments as Fortran BLAS with additional `layout` flag; flags are converted to CBLAS enums by functions
+//! of this module.
+//!
+//! Bindings in `bindings.rs` are generated by `scripts/cblas_gen.py`.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::missing_safety_doc)]
+#![allow(non_camel_case_types)]
+
+mod bindings;
+pub use bindings::*;
+
+use super::{c32, c64, c_char};
+use core::ffi::c_int;
+
+/* #region enums */
+
+pub type CBLAS_ORDER = c_int;
+pub type CBLAS_LAYOUT = CBLAS_ORDER;
+pub type CBLAS_TRANSPOSE = c_int;
+pub type CBLAS_UPLO = c_int;
+pub type CBLAS_DIAG = c_int;
+pub type CBLAS_SIDE = c_int;
+pub type CBLAS_INDEX = usize;
+
+/// CBLAS enum of layout flag `R` or `C`; invalid flag is 0, which is reported by CBLAS.
+#[inline]
+pub unsafe fn layout(c: *const c_char) -> CBLAS_LAYOUT {
+    match (*c as u8).to_ascii_uppercase() {
+        b'R' => 101,
+        b'C' => 102,
+        _ => 0,
+    }
+}
+
+/// CBLAS enum of transpose flag `N`, `T` or `C`.
+#[inline]
+pub unsafe fn trans(c: *const c_char) -> CBLAS_TRANSPOSE {
+    match (*c as u8).to_ascii_uppercase() {
+        b'N' => 111,
+        b'T' => 112,
+        b'C' => 113,
+        _ => 0,
+    }
+}
+
+/// CBLAS enum of uplo flag `U` or `L`.
+#[inline]
+pub unsafe fn uplo(c: *const c_char) -> CBLAS_UPLO {
+    match (*c as u8).to_ascii_uppercase() {
+        b'U' => 121,
+        b'L' => 122,
+        _ => 0,
+    }
+}
+
+/// CBLAS enum of diag flag `N` or `U`.
+#[inline]
+pub unsafe fn diag(c: *const c_char) -> CBLAS_DIAG {
+    match (*c as u8).to_ascii_uppercase() {
+        b'N' => 131,
+        b'U' => 132,
+        _ => 0,
+    }
+}
+
+/// CBLAS enum of side flag `L` or `R`.
+#[inline]
+pub unsafe fn side(c: *const c_char) -> CBLAS_SIDE {
+    match (*c as u8).to_ascii_uppercase() {
+        b'L' => 141,
+        b'R' => 142,
+        _ => 0,
+    }
+}
+
+/* #endregion */
+
+/* #region scalar */
+
+/// Scalar argument of CBLAS: real numbers are passed by value, and complex numbers by pointer.
+pub trait CBLASScalar: Sized {
+    type Arg;
+    unsafe fn arg(x: *const Self) -> Self::Arg;
+}
+
+macro_rules! impl_cblas_scalar {
+    ($type: ty, by_value) => {
+        impl CBLASScalar for $type {
+            type Arg = $type;
+            #[inline]
+            unsafe fn arg(x: *const Self) -> Self::Arg {
+                *x
+            }
+        }
+    };
+    ($type: ty, by_pointer) => {
+        impl CBLASScalar for $type {
+            type Arg = *const $type;
+            #[inline]
+            unsafe fn arg(x: *const Self) -> Self::Arg {
+                x
+            }
+        }
+    };
+}
+
+impl_cblas_scalar!(f32, by_value);
+impl_cblas_scalar!(f64, by_value);
+impl_cblas_scalar!(c32, by_pointer);
+impl_cblas_scalar!(c64, by_pointer);
+
+/* #endregion */
@@ -0,0 +1,215 @@
+//! Dynamic loading of BLAS library at runtime (crate feature `dynamic`).
+//!
+//! With this feature, functions in [`ffi::blas`](crate::ffi::blas) are not linked, but dispatched through a
+//! table of function pointers, loaded by `dlopen`/`dlsym` from a shared library:
+//! - given by [`load`] (such as `load("libopenblas.so")`);
+//! - or, if not loaded before the first BLAS call, given by environment variable `BLAS_ARRAY2_LIBRARY`.
+//!
+//! Symbols not found in the library (such as `dgemmt_` or `domatcopy_` of BLAS extensions) do not fail on
+//! linking or loading; wrappers calling them return [`BLASError::MissingSymbol`] instead. Symbol names follow
+//! the naming convention of [`symbol_name`](crate::ffi::symbol_name) (such as `dgemm_64_`).
+//!
+//! Loading another library replaces the table for later calls. Libraries loaded before are never closed, since
+//! other threads may still be calling them. Only unix targets are supported.
+//!
+//! Note that `xerbla_` of crate feature `xerbla` is usually not used by dynamically loaded library.
+
+extern crate alloc;
+extern crate std;
+
+use crate::util::*;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use core::ffi::{c_char, c_int, c_void, CStr};
+use core::sync::atomic::{AtomicPtr, Ordering};
+use std::ffi::CString;
+
+/// Environment variable of BLAS library path, loaded if [`load`] is not called before the first BLAS call.
+pub const BLAS_LIBRARY_ENV: &str = "BLAS_ARRAY2_LIBRARY";
+
+/* #region dlopen */
+
+#[cfg(unix)]
+extern "C" {
+    fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlerror() -> *const c_char;
+}
+
+#[cfg(unix)]
+const RTLD_NOW: c_int = 2;
+
+#[cfg(unix)]
+fn open_library(path: &str) -> Result<*mut c_void, String> {
+    let c_path = CString::new(path).map_err(|e| format!("{e}"))?;
+    let handle = unsafe { dlopen(c_path.as_ptr(), RTLD_NOW) };
+    if handle.is_null() {
+        let err = unsafe { dlerror() };
+        let err = if err.is_null() {
+            "unknown error".into()
+        } else {
+            unsafe { CStr::from_ptr(err) }.to_string_lossy()
+        };
+        return Err(err.into());
+    }
+    return Ok(handle);
+}
+
+#[cfg(not(unix))]
+fn open_library(_path: &str) -> Result<*mut c_void, String> {
+    Err("dynamic loading is only supported on unix targets".into())
+}
+
+pub(crate) fn symbol(handle: *mut c_void, name: &CStr) -> *mut c_void {
+    #[cfg(unix)]
+    if !handle.is_null() {
+        return unsafe { dlsym(handle, name.as_ptr()) };
+    }
+    let _ = name;
+    return core::ptr::null_mut();
+}
+
+/* #endregion */
+
+/* #region library state */
+
+/// Loaded library: handle, path and table of function pointers.
+struct Library {
+    handle: *mut c_void,
+    path: String,
+    table: BLASTable,
+}
+
+static LIBRARY: AtomicPtr<Library> = AtomicPtr::new(core::ptr::null_mut());
+static INIT_FROM_ENV: std::sync::Once = std::sync::Once::new();
+
+std::thread_local! {
+    static MISSING: core::cell::Cell<Option<&'static str>> = const { core::cell::Cell::new(None) };
+}
+
+/// Load BLAS library of `path` (passed to `dlopen`), replacing the library loaded before.
+///
+/// Library should at least provide `dgemm_` (under the symbol naming convention).
+pub fn load(path: &str) -> Result<(), BLASError> {
+    let handle = open_library(path)
+        .map_err(|e| BLASError::Miscellaneous(format!("Failed to load BLAS library {path:?}: {e}")))?;
+    let table = unsafe { BLASTable::load(handle) };
+    if table.dgemm_.is_none() {
+        let dgemm = super::blas_symbol!("dgemm");
+        return Err(BLASError::Miscellaneous(format!("Library {path:?} is not BLAS ({dgemm} not found)")));
+    }
+    let library = Box::into_raw(Box::new(Library { handle, path: path.into(), table }));
+    // previous library is leaked, since it may still be used by other threads
+    LIBRARY.store(library, Ordering::Release);
+    return Ok(());
+}
+
+/// Load BLAS library given by environment variable [`BLAS_LIBRARY_ENV`].
+pub fn load_from_env() -> Result<(), BLASError> {
+    let path = std::env::var(BLAS_LIBRARY_ENV).map_err(|_| {
+        BLASError::Miscellaneous(format!("Environment variable {BLAS_LIBRARY_ENV} is not set"))
+    })?;
+    return load(&path);
+}
+
+fn library() -> Option<&'static Library> {
+    let mut ptr = LIBRARY.load(Ordering::Acquire);
+    if ptr.is_null() {
+        INIT_FROM_ENV.call_once(|| {
+            let _ = load_from_env();
+        });
+        ptr = LIBRARY.load(Ordering::Acquire);
+    }
+    return unsafe { ptr.as_ref() };
+}
+
+/// Path of loaded BLAS library; `None` if not loaded.
+pub fn library_path() -> Option<&'static str> {
+    library().map(|lib| lib.path.as_str())
+}
+
+/// Table of function pointers of loaded library; all symbols are missing if no library is loaded.
+pub fn table() -> &'static BLASTable {
+    static EMPTY: BLASTable = BLASTable::empty();
+    library().map_or(&EMPTY, |lib| &lib.table)
+}
+
+/// Address of symbol in loaded library; `None` if not found or not loaded.
+pub fn lookup(name: &CStr) -> Option<*mut c_void> {
+    let ptr = symbol(library()?.handle, name);
+    return if ptr.is_null() { None } else { Some(ptr) };
+}
+
+/// Record of missing symbol called on current thread, and clear the record.
+pub fn take_missing_symbol() -> Option<&'static str> {
+    MISSING.with(|m| m.take())
+}
+
+#[doc(hidden)]
+pub fn on_missing_symbol(name: &'static str) {
+    MISSING.with(|m| m.set(Some(name)));
+}
+
+/* #endregion */
+
+/* #region table generation */
+
+/// Generate table of function pointers, and functions calling them with the same signatures as `extern "C"`
+/// declarations.
+macro_rules! blas_dynamic {
+    ($(#[link_name = $symbol:expr] pub fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;)*) => {
+        /// Table of function pointers of dynamically loaded BLAS library; `None` for missing symbols.
+        pub struct BLASTable {
+            $(pub $name: Option<unsafe extern "C" fn($($ty),*) $(-> $ret)?>,)*
+        }
+
+        // function pointers are shared by all threads
+        unsafe impl Sync for BLASTable {}
+
+        impl BLASTable {
+            pub(crate) const fn empty() -> BLASTable {
+                BLASTable { $($name: None,)* }
+            }
+
+            /// # Safety
+            ///
+            /// Symbols found in `handle` should have the declared signatures.
+            pub(crate) unsafe fn load(handle: *mut core::ffi::c_void) -> BLASTable {
+                BLASTable {
+                    $($name: {
+                        let symbol = concat!($symbol, "\0").as_bytes();
+                        let ptr = $crate::ffi::dynamic::symbol(handle, core::ffi::CStr::from_bytes_with_nul_unchecked(symbol));
+                        let f = core::mem::transmute::<*mut core::ffi::c_void, unsafe extern "C" fn($($ty),*) $(-> $ret)?>;
+                        if ptr.is_null() { None } else { Some(f(ptr)) }
+                    },)*
+                }
+            }
+        }
+
+        $(
+            /// Call function of dynamically loaded BLAS library; missing symbol is recorded and wrappers return
+            /// [`BLASError::MissingSymbol`](crate::util::BLASError).
+            ///
+            /// # Safety
+            ///
+            /// The same to the BLAS function.
+            #[allow(clippy::too_many_arguments)]
+            pub unsafe fn $name($($arg: $ty),*) $(-> $ret)? {
+                match $crate::ffi::dynamic::table().$name {
+                    Some(f) => f($($arg),*),
+                    None => {
+                        $crate::ffi::dynamic::on_missing_symbol($symbol);
+                        core::mem::zeroed()
+                    },
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use blas_dynamic;
+
+/* #endregion */
+
+pub use super::blas::BLASTable;
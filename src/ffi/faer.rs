@@ -0,0 +1,630 @@
+//! Level 3 BLAS by [faer](https://docs.rs/faer) (crate feature `faer`).
+//!
+//! With this feature, wrappers `GEMM`, `SYMM`, `HEMM`, `SYRK`, `HERK`, `SYR2K`, `HER2K`, `TRMM`, `TRSM` and
+//! `GEMMT` call functions of this module instead of BLAS library. These functions take the same arguments as
+//! Fortran BLAS: column-major matrices given by pointer and leading dimension (as prepared by driver of
+//! wrappers) are viewed by faer without copy. The only exception is `TRMM`, where faer requires output not to
+//! alias input, so `B` is copied before multiplication.
+//!
+//! Arguments are assumed to have been checked by wrappers; nothing is reported to `xerbla`. Parallelism is the
+//! global parallelism of faer, which is also controlled by [`threading`](crate::threading).
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::missing_safety_doc)]
+
+use super::{blas_int, c32, c64, c_char};
+use crate::util::BLASFloat;
+use faer::linalg::matmul::triangular::{self, BlockStructure};
+use faer::linalg::triangular_solve;
+use faer::{ComplexField, Conj, MatMut, MatRef, Parallelism, SimpleEntity};
+
+/* #region scalar */
+
+/// BLAS float type with faer counterpart of the same memory layout.
+pub trait FaerNum: BLASFloat {
+    type Faer: ComplexField + SimpleEntity;
+    fn to_faer(x: Self) -> Self::Faer;
+    fn real_to_faer(x: Self::RealFloat) -> <Self::Faer as ComplexField>::Real;
+}
+
+macro_rules! impl_faer_num_real {
+    ($type: ty) => {
+        impl FaerNum for $type {
+            type Faer = $type;
+            #[inline]
+            fn to_faer(x: Self) -> Self::Faer {
+                x
+            }
+            #[inline]
+            fn real_to_faer(x: Self::RealFloat) -> <Self::Faer as ComplexField>::Real {
+                x
+            }
+        }
+    };
+}
+
+macro_rules! impl_faer_num_complex {
+    ($type: ty, $faer: ty) => {
+        impl FaerNum for $type {
+            type Faer = $faer;
+            #[inline]
+            fn to_faer(x: Self) -> Self::Faer {
+                <$faer>::new(x.re, x.im)
+            }
+            #[inline]
+            fn real_to_faer(x: Self::RealFloat) -> <Self::Faer as ComplexField>::Real {
+                x
+            }
+        }
+    };
+}
+
+impl_faer_num_real!(f32);
+impl_faer_num_real!(f64);
+impl_faer_num_complex!(c32, faer::complex_native::c32);
+impl_faer_num_complex!(c64, faer::complex_native::c64);
+
+/* #endregion */
+
+/* #region helpers */
+
+#[inline]
+unsafe fn flag(c: *const c_char) -> u8 {
+    (*c as u8).to_ascii_uppercase()
+}
+
+#[inline]
+fn parallelism() -> Parallelism<'static> {
+    faer::get_global_parallelism()
+}
+
+/// View of column-major matrix `rows x cols` with leading dimension `ld`.
+#[inline]
+unsafe fn mat_ref<'a, F: FaerNum>(
+    ptr: *const F,
+    (rows, cols): (blas_int, blas_int),
+    ld: blas_int,
+) -> MatRef<'a, F::Faer> {
+    faer::mat::from_raw_parts(ptr as *const F::Faer, rows as usize, cols as usize, 1, ld as isize)
+}
+
+/// Mutable view of column-major matrix `rows x cols` with leading dimension `ld`.
+#[inline]
+unsafe fn mat_mut<'a, F: FaerNum>(
+    ptr: *mut F,
+    (rows, cols): (blas_int, blas_int),
+    ld: blas_int,
+) -> MatMut<'a, F::Faer> {
+    faer::mat::from_raw_parts_mut(ptr as *mut F::Faer, rows as usize, cols as usize, 1, ld as isize)
+}
+
+/// View of `op(A)` with its conjugate flag.
+type OpRef<'a, E> = (MatRef<'a, E>, Conj);
+
+/// View of `op(A)` of shape `rows x cols`, where `trans` is `N`, `T` or `C`; conjugate is returned separately.
+#[inline]
+unsafe fn op_ref<'a, F: FaerNum>(
+    trans: u8,
+    ptr: *const F,
+    (rows, cols): (blas_int, blas_int),
+    ld: blas_int,
+) -> OpRef<'a, F::Faer> {
+    match trans {
+        b'N' => (mat_ref(ptr, (rows, cols), ld), Conj::No),
+        b'T' => (mat_ref(ptr, (cols, rows), ld).transpose(), Conj::No),
+        _ => (mat_ref(ptr, (cols, rows), ld).transpose(), Conj::Yes),
+    }
+}
+
+/// Scaling of output for faer: `None` (overwrite without reading) if `beta` is zero.
+#[inline]
+fn accumulate<F: FaerNum>(beta: F) -> Option<F::Faer> {
+    if beta == F::zero() {
+        None
+    } else {
+        Some(F::to_faer(beta))
+    }
+}
+
+#[inline]
+fn conj_if(conj: bool) -> Conj {
+    if conj {
+        Conj::Yes
+    } else {
+        Conj::No
+    }
+}
+
+#[inline]
+fn triangle(upper: bool) -> BlockStructure {
+    if upper {
+        BlockStructure::TriangularUpper
+    } else {
+        BlockStructure::TriangularLower
+    }
+}
+
+/// `C := alpha op(A) op(B) + beta C`, where only triangular part of `C` (square) is computed; `beta` is given
+/// by [`accumulate`].
+///
+/// faer 0.19 also scales the product by `beta` when triangular output is accumulated, so triangular part of `C`
+/// is scaled before multiplication instead.
+fn triangular_update<E: ComplexField>(
+    mut c: MatMut<E>,
+    upper: bool,
+    (a, conj_a): OpRef<E>,
+    (b, conj_b): OpRef<E>,
+    alpha: E,
+    beta: Option<E>,
+) {
+    let beta = beta.map(|beta| {
+        let n = c.ncols();
+        for j in 0..n {
+            let rows = if upper { 0..(j + 1) } else { j..n };
+            for i in rows {
+                c.write(i, j, beta.faer_mul(c.read(i, j)));
+            }
+        }
+        E::faer_one()
+    });
+    let rect = BlockStructure::Rectangular;
+    triangular::matmul_with_conj(
+        c,
+        triangle(upper),
+        a,
+        rect,
+        conj_a,
+        b,
+        rect,
+        conj_b,
+        beta,
+        alpha,
+        parallelism(),
+    );
+}
+
+/// Set imaginary part of diagonal to zero, as Hermitian routines of BLAS.
+fn real_diagonal<E: ComplexField>(mut c: MatMut<E>) {
+    for i in 0..c.nrows().min(c.ncols()) {
+        let val = c.read(i, i);
+        c.write(i, i, E::faer_from_real(val.faer_real()));
+    }
+}
+
+/* #endregion */
+
+/* #region gemm */
+
+/// `C := alpha op(A) op(B) + beta C`.
+pub unsafe fn gemm<F: FaerNum>(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    let (m, n, k) = (*m, *n, *k);
+    let (a, conj_a) = op_ref(flag(transa), a, (m, k), *lda);
+    let (b, conj_b) = op_ref(flag(transb), b, (k, n), *ldb);
+    let c = mat_mut(c, (m, n), *ldc);
+    // note that faer scales output by its `alpha`, and product by its `beta`
+    faer::linalg::matmul::matmul_with_conj(
+        c,
+        a,
+        conj_a,
+        b,
+        conj_b,
+        accumulate(*beta),
+        F::to_faer(*alpha),
+        parallelism(),
+    );
+}
+
+/// `C := alpha op(A) op(B) + beta C`, where only triangular part `uplo` of `C` is computed.
+pub unsafe fn gemmt<F: FaerNum>(
+    uplo: *const c_char,
+    transa: *const c_char,
+    transb: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    let (n, k) = (*n, *k);
+    let (a, conj_a) = op_ref(flag(transa), a, (n, k), *lda);
+    let (b, conj_b) = op_ref(flag(transb), b, (k, n), *ldb);
+    let c = mat_mut(c, (n, n), *ldc);
+    triangular_update(c, flag(uplo) == b'U', (a, conj_a), (b, conj_b), F::to_faer(*alpha), accumulate(*beta));
+}
+
+/* #endregion */
+
+/* #region symm, hemm */
+
+/// `C := alpha A B + beta C` (side `L`) or `C := alpha B A + beta C` (side `R`), where `A` is symmetric
+/// (Hermitian if `herm`) with triangular part `uplo` stored.
+///
+/// `A` is split into strict triangular part, its (conjugate) transpose, and diagonal (real part if `herm`).
+unsafe fn symm_impl<F: FaerNum>(
+    herm: bool,
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    let (m, n) = (*m, *n);
+    let left = flag(side) == b'L';
+    let na = if left { m } else { n };
+    let a = mat_ref(a, (na, na), *lda);
+    let b = mat_ref(b, (m, n), *ldb);
+    let mut c = mat_mut(c, (m, n), *ldc);
+    let (alpha, one) = (F::to_faer(*alpha), F::Faer::faer_one());
+    let (strict, strict_t) = match flag(uplo) {
+        b'U' => (BlockStructure::StrictTriangularUpper, BlockStructure::StrictTriangularLower),
+        _ => (BlockStructure::StrictTriangularLower, BlockStructure::StrictTriangularUpper),
+    };
+    let rect = BlockStructure::Rectangular;
+    let parts =
+        [(a, strict, Conj::No, accumulate(*beta)), (a.transpose(), strict_t, conj_if(herm), Some(one))];
+    for (a, structure, conj, acc) in parts {
+        if left {
+            triangular::matmul_with_conj(
+                c.as_mut(),
+                rect,
+                a,
+                structure,
+                conj,
+                b,
+                rect,
+                Conj::No,
+                acc,
+                alpha,
+                parallelism(),
+            );
+        } else {
+            triangular::matmul_with_conj(
+                c.as_mut(),
+                rect,
+                b,
+                rect,
+                Conj::No,
+                a,
+                structure,
+                conj,
+                acc,
+                alpha,
+                parallelism(),
+            );
+        }
+    }
+    for j in 0..n as usize {
+        for i in 0..m as usize {
+            let d = if left { a.read(i, i) } else { a.read(j, j) };
+            let d = if herm { F::Faer::faer_from_real(d.faer_real()) } else { d };
+            c.write(i, j, c.read(i, j).faer_add(alpha.faer_mul(d).faer_mul(b.read(i, j))));
+        }
+    }
+}
+
+/// `C := alpha A B + beta C` or `C := alpha B A + beta C`, where `A` is symmetric.
+pub unsafe fn symm<F: FaerNum>(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    symm_impl(false, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+/// `C := alpha A B + beta C` or `C := alpha B A + beta C`, where `A` is Hermitian.
+pub unsafe fn hemm<F: FaerNum>(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    symm_impl(true, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+/* #endregion */
+
+/* #region syrk, herk, syr2k, her2k */
+
+/// Views of `op(A)` and `op(A)^T` (`op(A)^H` if `herm`) of rank-k update, where `op(A)` is `n x k`.
+unsafe fn rank_k_ops<'a, F: FaerNum>(
+    herm: bool,
+    trans: *const c_char,
+    a: *const F,
+    (n, k): (blas_int, blas_int),
+    lda: blas_int,
+) -> (OpRef<'a, F::Faer>, OpRef<'a, F::Faer>) {
+    let trans = match flag(trans) {
+        b'N' => b'N',
+        _ if herm => b'C',
+        _ => b'T',
+    };
+    let (a, conj) = op_ref(trans, a, (n, k), lda);
+    let conj_t = if herm { conj.compose(Conj::Yes) } else { conj };
+    return ((a, conj), (a.transpose(), conj_t));
+}
+
+/// `C := alpha op(A) op(A)^T + beta C`, or `op(A)^H` if `herm`; only triangular part `uplo` is computed.
+unsafe fn rank_k<F: FaerNum>(
+    herm: bool,
+    uplo: *const c_char,
+    trans: *const c_char,
+    (n, k): (blas_int, blas_int),
+    alpha: F::Faer,
+    a: *const F,
+    lda: blas_int,
+    beta: Option<F::Faer>,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (a, at) = rank_k_ops(herm, trans, a, (n, k), lda);
+    let mut c = mat_mut(c, (n, n), ldc);
+    triangular_update(c.as_mut(), flag(uplo) == b'U', a, at, alpha, beta);
+    if herm {
+        real_diagonal(c);
+    }
+}
+
+/// `C := alpha op(A) op(A)^T + beta C`, where `C` is symmetric.
+pub unsafe fn syrk<F: FaerNum>(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    rank_k(false, uplo, trans, (*n, *k), F::to_faer(*alpha), a, *lda, accumulate(*beta), c, *ldc);
+}
+
+/// `C := alpha op(A) op(A)^H + beta C`, where `C` is Hermitian.
+pub unsafe fn herk<F: FaerNum>(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F::RealFloat,
+    a: *const F,
+    lda: *const blas_int,
+    beta: *const F::RealFloat,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    let alpha = F::Faer::faer_from_real(F::real_to_faer(*alpha));
+    let beta = accumulate(F::from_real(*beta));
+    rank_k(true, uplo, trans, (*n, *k), alpha, a, *lda, beta, c, *ldc);
+}
+
+/// `C := alpha op(A) op(B)^T + alpha' op(B) op(A)^T + beta C`, where `^T` is `^H` and `alpha'` is `conj(alpha)`
+/// if `herm`; only triangular part `uplo` is computed.
+unsafe fn rank_2k<F: FaerNum>(
+    herm: bool,
+    uplo: *const c_char,
+    trans: *const c_char,
+    (n, k): (blas_int, blas_int),
+    alpha: F,
+    (a, lda): (*const F, blas_int),
+    (b, ldb): (*const F, blas_int),
+    beta: Option<F::Faer>,
+    c: *mut F,
+    ldc: blas_int,
+) {
+    let (a, at) = rank_k_ops(herm, trans, a, (n, k), lda);
+    let (b, bt) = rank_k_ops(herm, trans, b, (n, k), ldb);
+    let alpha_t = if herm { F::conj(alpha) } else { alpha };
+    let upper = flag(uplo) == b'U';
+    let mut c = mat_mut(c, (n, n), ldc);
+    triangular_update(c.as_mut(), upper, a, bt, F::to_faer(alpha), beta);
+    triangular_update(c.as_mut(), upper, b, at, F::to_faer(alpha_t), Some(F::Faer::faer_one()));
+    if herm {
+        real_diagonal(c);
+    }
+}
+
+/// `C := alpha op(A) op(B)^T + alpha op(B) op(A)^T + beta C`, where `C` is symmetric.
+pub unsafe fn syr2k<F: FaerNum>(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    rank_2k(false, uplo, trans, (*n, *k), *alpha, (a, *lda), (b, *ldb), accumulate(*beta), c, *ldc);
+}
+
+/// `C := alpha op(A) op(B)^H + conj(alpha) op(B) op(A)^H + beta C`, where `C` is Hermitian.
+pub unsafe fn her2k<F: FaerNum>(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F::RealFloat,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    let beta = accumulate(F::from_real(*beta));
+    rank_2k(true, uplo, trans, (*n, *k), *alpha, (a, *lda), (b, *ldb), beta, c, *ldc);
+}
+
+/* #endregion */
+
+/* #region trmm, trsm */
+
+/// View of triangular `op(A)` (`na x na`), with conjugate, whether `op(A)` is lower, and whether unit.
+unsafe fn tr_op<'a, F: FaerNum>(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    a: *const F,
+    na: blas_int,
+    lda: blas_int,
+) -> (MatRef<'a, F::Faer>, Conj, bool, bool) {
+    let transa = flag(transa);
+    let (a, conj) = op_ref(transa, a, (na, na), lda);
+    let lower = (flag(uplo) == b'L') ^ (transa != b'N');
+    return (a, conj, lower, flag(diag) == b'U');
+}
+
+/// `B := alpha op(A) B` (side `L`) or `B := alpha B op(A)` (side `R`), where `A` is triangular.
+pub unsafe fn trmm<F: FaerNum>(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *mut F,
+    ldb: *const blas_int,
+) {
+    let (m, n) = (*m, *n);
+    let left = flag(side) == b'L';
+    let mut b = mat_mut(b, (m, n), *ldb);
+    if *alpha == F::zero() {
+        b.fill_zero();
+        return;
+    }
+    let (a, conj, lower, unit) = tr_op(uplo, transa, diag, a, if left { m } else { n }, *lda);
+    let structure = match (lower, unit) {
+        (false, false) => BlockStructure::TriangularUpper,
+        (false, true) => BlockStructure::UnitTriangularUpper,
+        (true, false) => BlockStructure::TriangularLower,
+        (true, true) => BlockStructure::UnitTriangularLower,
+    };
+    let rect = BlockStructure::Rectangular;
+    // faer requires output not to alias input
+    let b_copy = b.to_owned();
+    let (alpha, par) = (F::to_faer(*alpha), parallelism());
+    if left {
+        triangular::matmul_with_conj(
+            b,
+            rect,
+            a,
+            structure,
+            conj,
+            b_copy.as_ref(),
+            rect,
+            Conj::No,
+            None,
+            alpha,
+            par,
+        );
+    } else {
+        triangular::matmul_with_conj(
+            b,
+            rect,
+            b_copy.as_ref(),
+            rect,
+            Conj::No,
+            a,
+            structure,
+            conj,
+            None,
+            alpha,
+            par,
+        );
+    }
+}
+
+/// Solve `op(A) X = alpha B` (side `L`) or `X op(A) = alpha B` (side `R`) in place of `B`, where `A` is
+/// triangular.
+pub unsafe fn trsm<F: FaerNum>(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *mut F,
+    ldb: *const blas_int,
+) {
+    let (m, n) = (*m, *n);
+    let left = flag(side) == b'L';
+    let mut b = mat_mut(b, (m, n), *ldb);
+    if *alpha == F::zero() {
+        b.fill_zero();
+        return;
+    } else if *alpha != F::one() {
+        let alpha = F::to_faer(*alpha);
+        for j in 0..n as usize {
+            for i in 0..m as usize {
+                b.write(i, j, alpha.faer_mul(b.read(i, j)));
+            }
+        }
+    }
+    let (a, conj, lower, unit) = tr_op(uplo, transa, diag, a, if left { m } else { n }, *lda);
+    // X op(A) = B is solved as op(A)^T X^T = B^T
+    let (a, lower, b) = if left { (a, lower, b) } else { (a.transpose(), !lower, b.transpose_mut()) };
+    let par = parallelism();
+    match (lower, unit) {
+        (false, false) => triangular_solve::solve_upper_triangular_in_place_with_conj(a, conj, b, par),
+        (false, true) => triangular_solve::solve_unit_upper_triangular_in_place_with_conj(a, conj, b, par),
+        (true, false) => triangular_solve::solve_lower_triangular_in_place_with_conj(a, conj, b, par),
+        (true, true) => triangular_solve::solve_unit_lower_triangular_in_place_with_conj(a, conj, b, par),
+    }
+}
+
+/* #endregion */
@@ -1,4 +1,78 @@
+extern crate alloc;
+
+#[cfg(not(any(feature = "dynamic", feature = "reference")))]
+pub mod blas;
+#[cfg(feature = "dynamic")]
+#[path = "blas_dynamic.rs"]
+pub mod blas;
+#[cfg(all(feature = "reference", not(feature = "dynamic")))]
+#[path = "reference/mod.rs"]
 pub mod blas;
+#[cfg(feature = "cblas")]
+pub mod cblas;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+#[cfg(feature = "faer")]
+pub mod faer;
+pub mod threading;
+
+#[cfg(all(feature = "dynamic", feature = "reference"))]
+compile_error!("crate features `dynamic` and `reference` are mutually exclusive");
+#[cfg(all(feature = "cblas", any(feature = "dynamic", feature = "reference", feature = "faer")))]
+compile_error!("crate feature `cblas` conflicts with `dynamic`, `reference` and `faer`");
+
+/// Whether wrappers call CBLAS for row-major arrays (crate feature `cblas`).
+///
+/// A library exporting `cblas_*` functions implemented by wrappers of this crate (such as `blas-array2-cblas`)
+/// should check this to be `false`, otherwise the calls would be recursive.
+pub const USE_CBLAS: bool = cfg!(feature = "cblas");
 
 pub use blas::*;
 pub use core::ffi::c_char;
+
+/// Prefix of BLAS symbols, selected at build time (see [`symbol_name`]).
+pub const SYMBOL_PREFIX: &str = env!("BLAS_ARRAY2_SYMBOL_PREFIX");
+/// Suffix of BLAS symbols, selected at build time (see [`symbol_name`]).
+pub const SYMBOL_SUFFIX: &str = env!("BLAS_ARRAY2_SYMBOL_SUFFIX");
+/// Suffix of C symbols (CBLAS functions such as `cblas_dgemm`, and vendor functions of [`threading`]), selected at
+/// build time: `64_` with crate feature `suffix64_`, `_64` with `suffix_64`, and empty by default.
+pub const CBLAS_SYMBOL_SUFFIX: &str = env!("BLAS_ARRAY2_CBLAS_SYMBOL_SUFFIX");
+
+/// Symbol of BLAS routine `name` (lowercase, without trailing underscore) in the linked library.
+///
+/// Functions in this module keep the names of the default convention (such as `dgemm_`), but are linked to
+/// `{SYMBOL_PREFIX}{name}{SYMBOL_SUFFIX}`:
+/// - default: `dgemm_`;
+/// - crate feature `suffix64_`: `dgemm_64_` (OpenBLAS with `SYMBOLSUFFIX=64_`, Debian `libopenblas64`);
+/// - crate feature `suffix_64`: `dgemm_64` (Reference-LAPACK with 64-bit index API);
+/// - environment variables `BLAS_ARRAY2_SYMBOL_PREFIX` and `BLAS_ARRAY2_SYMBOL_SUFFIX` at build time, which
+///   override the above.
+///
+/// One build of this crate uses exactly one naming and one integer width (cargo features are unified), so it
+/// does not call LP64 and ILP64 libraries together; suffixed symbols only avoid clashing with another LP64
+/// BLAS linked by a different crate.
+pub fn symbol_name(name: &str) -> alloc::string::String {
+    return alloc::format!("{SYMBOL_PREFIX}{name}{SYMBOL_SUFFIX}");
+}
+
+/// Symbol of BLAS routine in the linked library, as string literal.
+#[cfg_attr(feature = "reference", allow(unused_macros))]
+macro_rules! blas_symbol {
+    ($name:literal) => {
+        concat!(env!("BLAS_ARRAY2_SYMBOL_PREFIX"), $name, env!("BLAS_ARRAY2_SYMBOL_SUFFIX"))
+    };
+}
+
+#[cfg_attr(feature = "reference", allow(unused_imports))]
+pub(crate) use blas_symbol;
+
+/// Symbol of CBLAS routine in the linked library, as string literal.
+#[cfg(feature = "cblas")]
+macro_rules! cblas_symbol {
+    ($name:literal) => {
+        concat!("cblas_", $name, env!("BLAS_ARRAY2_CBLAS_SYMBOL_SUFFIX"))
+    };
+}
+
+#[cfg(feature = "cblas")]
+pub(crate) use cblas_symbol;
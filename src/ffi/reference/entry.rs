@@ -0,0 +1,2287 @@
+//! Entry functions of reference implementation, with the same signatures as `blas.rs`.
+//!
+//! Automatically generated from `blas.rs` by `scripts/reference_gen.py`.
+
+use super::*;
+use core::ffi::c_char;
+
+pub unsafe extern "C" fn dcabs1_(z: *const c64) -> f64 {
+    level1::cabs1::<c64>(z)
+}
+
+pub unsafe extern "C" fn scabs1_(c: *const c32) -> f32 {
+    level1::cabs1::<c32>(c)
+}
+
+pub unsafe extern "C" fn dasum_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> f64 {
+    level1::asum::<f64>(n, x, incx)
+}
+
+pub unsafe extern "C" fn dzasum_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> f64 {
+    level1::asum::<c64>(n, x, incx)
+}
+
+pub unsafe extern "C" fn sasum_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> f32 {
+    level1::asum::<f32>(n, x, incx)
+}
+
+pub unsafe extern "C" fn scasum_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> f32 {
+    level1::asum::<c32>(n, x, incx)
+}
+
+pub unsafe extern "C" fn caxpy_(
+    n: *const blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level1::axpy::<c32>(n, alpha, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn daxpy_(
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const blas_int,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level1::axpy::<f64>(n, alpha, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn saxpy_(
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level1::axpy::<f32>(n, alpha, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn zaxpy_(
+    n: *const blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level1::axpy::<c64>(n, alpha, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn ccopy_(
+    n: *const blas_int,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level1::copy::<c32>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn dcopy_(
+    n: *const blas_int,
+    x: *const f64,
+    incx: *const blas_int,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level1::copy::<f64>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn scopy_(
+    n: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level1::copy::<f32>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn zcopy_(
+    n: *const blas_int,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level1::copy::<c64>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn cdotc_(
+    pres: *mut c32,
+    n: *const blas_int,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *const c32,
+    incy: *const blas_int,
+) {
+    level1::dotc::<c32>(pres, n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn cdotu_(
+    pres: *mut c32,
+    n: *const blas_int,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *const c32,
+    incy: *const blas_int,
+) {
+    level1::dotu::<c32>(pres, n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn ddot_(
+    n: *const blas_int,
+    x: *const f64,
+    incx: *const blas_int,
+    y: *const f64,
+    incy: *const blas_int,
+) -> f64 {
+    level1::dot::<f64>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn dsdot_(
+    n: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+) -> f64 {
+    level1::dsdot(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn sdot_(
+    n: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+) -> f32 {
+    level1::dot::<f32>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn sdsdot_(
+    n: *const blas_int,
+    sb: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+) -> f32 {
+    level1::sdsdot(n, sb, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn zdotc_(
+    pres: *mut c64,
+    n: *const blas_int,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *const c64,
+    incy: *const blas_int,
+) {
+    level1::dotc::<c64>(pres, n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn zdotu_(
+    pres: *mut c64,
+    n: *const blas_int,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *const c64,
+    incy: *const blas_int,
+) {
+    level1::dotu::<c64>(pres, n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn icamax_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> blas_int {
+    level1::iamax::<c32>(n, x, incx)
+}
+
+pub unsafe extern "C" fn idamax_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> blas_int {
+    level1::iamax::<f64>(n, x, incx)
+}
+
+pub unsafe extern "C" fn isamax_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> blas_int {
+    level1::iamax::<f32>(n, x, incx)
+}
+
+pub unsafe extern "C" fn izamax_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> blas_int {
+    level1::iamax::<c64>(n, x, incx)
+}
+
+pub unsafe extern "C" fn dnrm2_(n: *const blas_int, x: *const f64, incx: *const blas_int) -> f64 {
+    level1::nrm2::<f64>(n, x, incx)
+}
+
+pub unsafe extern "C" fn dznrm2_(n: *const blas_int, x: *const c64, incx: *const blas_int) -> f64 {
+    level1::nrm2::<c64>(n, x, incx)
+}
+
+pub unsafe extern "C" fn snrm2_(n: *const blas_int, x: *const f32, incx: *const blas_int) -> f32 {
+    level1::nrm2::<f32>(n, x, incx)
+}
+
+pub unsafe extern "C" fn scnrm2_(n: *const blas_int, x: *const c32, incx: *const blas_int) -> f32 {
+    level1::nrm2::<c32>(n, x, incx)
+}
+
+pub unsafe extern "C" fn cscal_(n: *const blas_int, a: *const c32, x: *mut c32, incx: *const blas_int) {
+    level1::scal::<c32>(n, a, x, incx)
+}
+
+pub unsafe extern "C" fn csscal_(n: *const blas_int, a: *const f32, x: *mut c32, incx: *const blas_int) {
+    level1::rscal::<c32>(n, a, x, incx)
+}
+
+pub unsafe extern "C" fn dscal_(n: *const blas_int, a: *const f64, x: *mut f64, incx: *const blas_int) {
+    level1::scal::<f64>(n, a, x, incx)
+}
+
+pub unsafe extern "C" fn sscal_(n: *const blas_int, a: *const f32, x: *mut f32, incx: *const blas_int) {
+    level1::scal::<f32>(n, a, x, incx)
+}
+
+pub unsafe extern "C" fn zdscal_(n: *const blas_int, a: *const f64, x: *mut c64, incx: *const blas_int) {
+    level1::rscal::<c64>(n, a, x, incx)
+}
+
+pub unsafe extern "C" fn zscal_(n: *const blas_int, a: *const c64, x: *mut c64, incx: *const blas_int) {
+    level1::scal::<c64>(n, a, x, incx)
+}
+
+pub unsafe extern "C" fn cswap_(
+    n: *const blas_int,
+    x: *mut c32,
+    incx: *const blas_int,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level1::swap::<c32>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn dswap_(
+    n: *const blas_int,
+    x: *mut f64,
+    incx: *const blas_int,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level1::swap::<f64>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn sswap_(
+    n: *const blas_int,
+    x: *mut f32,
+    incx: *const blas_int,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level1::swap::<f32>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn zswap_(
+    n: *const blas_int,
+    x: *mut c64,
+    incx: *const blas_int,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level1::swap::<c64>(n, x, incx, y, incy)
+}
+
+pub unsafe extern "C" fn csrot_(
+    n: *const blas_int,
+    x: *mut c32,
+    incx: *const blas_int,
+    y: *mut c32,
+    incy: *const blas_int,
+    c: *const f32,
+    s: *const f32,
+) {
+    level1::rot::<c32>(n, x, incx, y, incy, c, s)
+}
+
+pub unsafe extern "C" fn drot_(
+    n: *const blas_int,
+    x: *mut f64,
+    incx: *const blas_int,
+    y: *mut f64,
+    incy: *const blas_int,
+    c: *const f64,
+    s: *const f64,
+) {
+    level1::rot::<f64>(n, x, incx, y, incy, c, s)
+}
+
+pub unsafe extern "C" fn srot_(
+    n: *const blas_int,
+    x: *mut f32,
+    incx: *const blas_int,
+    y: *mut f32,
+    incy: *const blas_int,
+    c: *const f32,
+    s: *const f32,
+) {
+    level1::rot::<f32>(n, x, incx, y, incy, c, s)
+}
+
+pub unsafe extern "C" fn zdrot_(
+    n: *const blas_int,
+    x: *mut c64,
+    incx: *const blas_int,
+    y: *mut c64,
+    incy: *const blas_int,
+    c: *const f64,
+    s: *const f64,
+) {
+    level1::rot::<c64>(n, x, incx, y, incy, c, s)
+}
+
+pub unsafe extern "C" fn crotg_(a: *mut c32, b: *const c32, c: *mut f32, s: *mut c32) {
+    level1::rotg_complex::<c32>(a, b, c, s)
+}
+
+pub unsafe extern "C" fn drotg_(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64) {
+    level1::rotg::<f64>(a, b, c, s)
+}
+
+pub unsafe extern "C" fn srotg_(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    level1::rotg::<f32>(a, b, c, s)
+}
+
+pub unsafe extern "C" fn zrotg_(a: *mut c64, b: *const c64, c: *mut f64, s: *mut c64) {
+    level1::rotg_complex::<c64>(a, b, c, s)
+}
+
+pub unsafe extern "C" fn drotm_(
+    n: *const blas_int,
+    x: *mut f64,
+    incx: *const blas_int,
+    y: *mut f64,
+    incy: *const blas_int,
+    param: *const f64,
+) {
+    level1::rotm::<f64>(n, x, incx, y, incy, param)
+}
+
+pub unsafe extern "C" fn srotm_(
+    n: *const blas_int,
+    x: *mut f32,
+    incx: *const blas_int,
+    y: *mut f32,
+    incy: *const blas_int,
+    param: *const f32,
+) {
+    level1::rotm::<f32>(n, x, incx, y, incy, param)
+}
+
+pub unsafe extern "C" fn drotmg_(d1: *mut f64, d2: *mut f64, x1: *mut f64, y1: *const f64, param: *mut f64) {
+    level1::rotmg::<f64>(d1, d2, x1, y1, param)
+}
+
+pub unsafe extern "C" fn srotmg_(d1: *mut f32, d2: *mut f32, x1: *mut f32, y1: *const f32, param: *mut f32) {
+    level1::rotmg::<f32>(d1, d2, x1, y1, param)
+}
+
+pub unsafe extern "C" fn cgemv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    x: *const c32,
+    incx: *const blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level2::gemv::<c32>("CGEMV", trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn dgemv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    x: *const f64,
+    incx: *const blas_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level2::gemv::<f64>("DGEMV", trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn sgemv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level2::gemv::<f32>("SGEMV", trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn zgemv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    x: *const c64,
+    incx: *const blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level2::gemv::<c64>("ZGEMV", trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn cgerc_(
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *const c32,
+    incy: *const blas_int,
+    a: *mut c32,
+    lda: *const blas_int,
+) {
+    level2::gerc::<c32>("CGERC", m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn cgeru_(
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *const c32,
+    incy: *const blas_int,
+    a: *mut c32,
+    lda: *const blas_int,
+) {
+    level2::geru::<c32>("CGERU", m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn dger_(
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const blas_int,
+    y: *const f64,
+    incy: *const blas_int,
+    a: *mut f64,
+    lda: *const blas_int,
+) {
+    level2::geru::<f64>("DGER", m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn sger_(
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+    a: *mut f32,
+    lda: *const blas_int,
+) {
+    level2::geru::<f32>("SGER", m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn zgerc_(
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *const c64,
+    incy: *const blas_int,
+    a: *mut c64,
+    lda: *const blas_int,
+) {
+    level2::gerc::<c64>("ZGERC", m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn zgeru_(
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *const c64,
+    incy: *const blas_int,
+    a: *mut c64,
+    lda: *const blas_int,
+) {
+    level2::geru::<c64>("ZGERU", m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn chemv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    x: *const c32,
+    incx: *const blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level2::hemv::<c32>("CHEMV", uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn dsymv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    x: *const f64,
+    incx: *const blas_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level2::symv::<f64>("DSYMV", uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn ssymv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level2::symv::<f32>("SSYMV", uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn zhemv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    x: *const c64,
+    incx: *const blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level2::hemv::<c64>("ZHEMV", uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn cher_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const c32,
+    incx: *const blas_int,
+    a: *mut c32,
+    lda: *const blas_int,
+) {
+    level2::her::<c32>("CHER", uplo, n, alpha, x, incx, a, lda)
+}
+
+pub unsafe extern "C" fn dsyr_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const blas_int,
+    a: *mut f64,
+    lda: *const blas_int,
+) {
+    level2::syr::<f64>("DSYR", uplo, n, alpha, x, incx, a, lda)
+}
+
+pub unsafe extern "C" fn ssyr_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    a: *mut f32,
+    lda: *const blas_int,
+) {
+    level2::syr::<f32>("SSYR", uplo, n, alpha, x, incx, a, lda)
+}
+
+pub unsafe extern "C" fn zher_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const c64,
+    incx: *const blas_int,
+    a: *mut c64,
+    lda: *const blas_int,
+) {
+    level2::her::<c64>("ZHER", uplo, n, alpha, x, incx, a, lda)
+}
+
+pub unsafe extern "C" fn cher2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *const c32,
+    incy: *const blas_int,
+    a: *mut c32,
+    lda: *const blas_int,
+) {
+    level2::her2::<c32>("CHER2", uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn dsyr2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const blas_int,
+    y: *const f64,
+    incy: *const blas_int,
+    a: *mut f64,
+    lda: *const blas_int,
+) {
+    level2::syr2::<f64>("DSYR2", uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn ssyr2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+    a: *mut f32,
+    lda: *const blas_int,
+) {
+    level2::syr2::<f32>("SSYR2", uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn zher2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *const c64,
+    incy: *const blas_int,
+    a: *mut c64,
+    lda: *const blas_int,
+) {
+    level2::her2::<c64>("ZHER2", uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+pub unsafe extern "C" fn ctrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *mut c32,
+    incx: *const blas_int,
+) {
+    level2::trmv::<c32>("CTRMV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn dtrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *mut f64,
+    incx: *const blas_int,
+) {
+    level2::trmv::<f64>("DTRMV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn strmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *mut f32,
+    incx: *const blas_int,
+) {
+    level2::trmv::<f32>("STRMV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn ztrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *mut c64,
+    incx: *const blas_int,
+) {
+    level2::trmv::<c64>("ZTRMV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn ctrsv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *mut c32,
+    incx: *const blas_int,
+) {
+    level2::trsv::<c32>("CTRSV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn dtrsv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *mut f64,
+    incx: *const blas_int,
+) {
+    level2::trsv::<f64>("DTRSV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn strsv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *mut f32,
+    incx: *const blas_int,
+) {
+    level2::trsv::<f32>("STRSV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn ztrsv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *mut c64,
+    incx: *const blas_int,
+) {
+    level2::trsv::<c64>("ZTRSV", uplo, transa, diag, n, a, lda, b, incx)
+}
+
+pub unsafe extern "C" fn chpmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c32,
+    ap: *const c32,
+    x: *const c32,
+    incx: *const blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level2::hpmv::<c32>("CHPMV", uplo, n, alpha, ap, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn dspmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: *const blas_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level2::spmv::<f64>("DSPMV", uplo, n, alpha, ap, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn sspmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level2::spmv::<f32>("SSPMV", uplo, n, alpha, ap, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn zhpmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c64,
+    ap: *const c64,
+    x: *const c64,
+    incx: *const blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level2::hpmv::<c64>("ZHPMV", uplo, n, alpha, ap, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn chpr_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const c32,
+    incx: *const blas_int,
+    ap: *mut c32,
+) {
+    level2::hpr::<c32>("CHPR", uplo, n, alpha, x, incx, ap)
+}
+
+pub unsafe extern "C" fn dspr_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const blas_int,
+    ap: *mut f64,
+) {
+    level2::spr::<f64>("DSPR", uplo, n, alpha, x, incx, ap)
+}
+
+pub unsafe extern "C" fn sspr_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    ap: *mut f32,
+) {
+    level2::spr::<f32>("SSPR", uplo, n, alpha, x, incx, ap)
+}
+
+pub unsafe extern "C" fn zhpr_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const c64,
+    incx: *const blas_int,
+    ap: *mut c64,
+) {
+    level2::hpr::<c64>("ZHPR", uplo, n, alpha, x, incx, ap)
+}
+
+pub unsafe extern "C" fn chpr2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c32,
+    x: *const c32,
+    incx: *const blas_int,
+    y: *const c32,
+    incy: *const blas_int,
+    ap: *mut c32,
+) {
+    level2::hpr2::<c32>("CHPR2", uplo, n, alpha, x, incx, y, incy, ap)
+}
+
+pub unsafe extern "C" fn dspr2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const blas_int,
+    y: *const f64,
+    incy: *const blas_int,
+    ap: *mut f64,
+) {
+    level2::spr2::<f64>("DSPR2", uplo, n, alpha, x, incx, y, incy, ap)
+}
+
+pub unsafe extern "C" fn sspr2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+    ap: *mut f32,
+) {
+    level2::spr2::<f32>("SSPR2", uplo, n, alpha, x, incx, y, incy, ap)
+}
+
+pub unsafe extern "C" fn zhpr2_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const c64,
+    x: *const c64,
+    incx: *const blas_int,
+    y: *const c64,
+    incy: *const blas_int,
+    ap: *mut c64,
+) {
+    level2::hpr2::<c64>("ZHPR2", uplo, n, alpha, x, incx, y, incy, ap)
+}
+
+pub unsafe extern "C" fn ctpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const c32,
+    x: *mut c32,
+    incx: *const blas_int,
+) {
+    level2::tpmv::<c32>("CTPMV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn dtpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: *const blas_int,
+) {
+    level2::tpmv::<f64>("DTPMV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn stpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: *const blas_int,
+) {
+    level2::tpmv::<f32>("STPMV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn ztpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const c64,
+    x: *mut c64,
+    incx: *const blas_int,
+) {
+    level2::tpmv::<c64>("ZTPMV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn ctpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const c32,
+    x: *mut c32,
+    incx: *const blas_int,
+) {
+    level2::tpsv::<c32>("CTPSV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn dtpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: *const blas_int,
+) {
+    level2::tpsv::<f64>("DTPSV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn stpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: *const blas_int,
+) {
+    level2::tpsv::<f32>("STPSV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn ztpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    ap: *const c64,
+    x: *mut c64,
+    incx: *const blas_int,
+) {
+    level2::tpsv::<c64>("ZTPSV", uplo, trans, diag, n, ap, x, incx)
+}
+
+pub unsafe extern "C" fn cgbmv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    kl: *const blas_int,
+    ku: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    x: *const c32,
+    incx: *const blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level2::gbmv::<c32>("CGBMV", trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn dgbmv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    kl: *const blas_int,
+    ku: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    x: *const f64,
+    incx: *const blas_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level2::gbmv::<f64>("DGBMV", trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn sgbmv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    kl: *const blas_int,
+    ku: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level2::gbmv::<f32>("SGBMV", trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn zgbmv_(
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    kl: *const blas_int,
+    ku: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    x: *const c64,
+    incx: *const blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level2::gbmv::<c64>("ZGBMV", trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn chbmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    x: *const c32,
+    incx: *const blas_int,
+    beta: *const c32,
+    y: *mut c32,
+    incy: *const blas_int,
+) {
+    level2::hbmv::<c32>("CHBMV", uplo, n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn dsbmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    x: *const f64,
+    incx: *const blas_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const blas_int,
+) {
+    level2::sbmv::<f64>("DSBMV", uplo, n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn ssbmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const blas_int,
+) {
+    level2::sbmv::<f32>("SSBMV", uplo, n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn zhbmv_(
+    uplo: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    x: *const c64,
+    incx: *const blas_int,
+    beta: *const c64,
+    y: *mut c64,
+    incy: *const blas_int,
+) {
+    level2::hbmv::<c64>("ZHBMV", uplo, n, k, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+pub unsafe extern "C" fn ctbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const c32,
+    lda: *const blas_int,
+    x: *mut c32,
+    incx: *const blas_int,
+) {
+    level2::tbmv::<c32>("CTBMV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn dtbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const f64,
+    lda: *const blas_int,
+    x: *mut f64,
+    incx: *const blas_int,
+) {
+    level2::tbmv::<f64>("DTBMV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn stbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const f32,
+    lda: *const blas_int,
+    x: *mut f32,
+    incx: *const blas_int,
+) {
+    level2::tbmv::<f32>("STBMV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn ztbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const c64,
+    lda: *const blas_int,
+    x: *mut c64,
+    incx: *const blas_int,
+) {
+    level2::tbmv::<c64>("ZTBMV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn ctbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const c32,
+    lda: *const blas_int,
+    x: *mut c32,
+    incx: *const blas_int,
+) {
+    level2::tbsv::<c32>("CTBSV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn dtbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const f64,
+    lda: *const blas_int,
+    x: *mut f64,
+    incx: *const blas_int,
+) {
+    level2::tbsv::<f64>("DTBSV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn stbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const f32,
+    lda: *const blas_int,
+    x: *mut f32,
+    incx: *const blas_int,
+) {
+    level2::tbsv::<f32>("STBSV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn ztbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    a: *const c64,
+    lda: *const blas_int,
+    x: *mut c64,
+    incx: *const blas_int,
+) {
+    level2::tbsv::<c64>("ZTBSV", uplo, trans, diag, n, k, a, lda, x, incx)
+}
+
+pub unsafe extern "C" fn cgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *const c32,
+    ldb: *const blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::gemm::<c32>("CGEMM", transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn dgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *const f64,
+    ldb: *const blas_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const blas_int,
+) {
+    level3::gemm::<f64>("DGEMM", transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn sgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *const f32,
+    ldb: *const blas_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const blas_int,
+) {
+    level3::gemm::<f32>("SGEMM", transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *const c64,
+    ldb: *const blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::gemm::<c64>("ZGEMM", transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn chemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *const c32,
+    ldb: *const blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::hemm::<c32>("CHEMM", side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn csymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *const c32,
+    ldb: *const blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::symm::<c32>("CSYMM", side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn dsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *const f64,
+    ldb: *const blas_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const blas_int,
+) {
+    level3::symm::<f64>("DSYMM", side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn ssymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *const f32,
+    ldb: *const blas_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const blas_int,
+) {
+    level3::symm::<f32>("SSYMM", side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zhemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *const c64,
+    ldb: *const blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::hemm::<c64>("ZHEMM", side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *const c64,
+    ldb: *const blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::symm::<c64>("ZSYMM", side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn cherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f32,
+    a: *const c32,
+    lda: *const blas_int,
+    beta: *const f32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::herk::<c32>("CHERK", uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn csyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::syrk::<c32>("CSYRK", uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn dsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const blas_int,
+) {
+    level3::syrk::<f64>("DSYRK", uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn ssyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const blas_int,
+) {
+    level3::syrk::<f32>("SSYRK", uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f64,
+    a: *const c64,
+    lda: *const blas_int,
+    beta: *const f64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::herk::<c64>("ZHERK", uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::syrk::<c64>("ZSYRK", uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn cher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *const c32,
+    ldb: *const blas_int,
+    beta: *const f32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::her2k::<c32>("CHER2K", uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn csyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *const c32,
+    ldb: *const blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::syr2k::<c32>("CSYR2K", uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn dsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *const f64,
+    ldb: *const blas_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const blas_int,
+) {
+    level3::syr2k::<f64>("DSYR2K", uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn ssyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *const f32,
+    ldb: *const blas_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const blas_int,
+) {
+    level3::syr2k::<f32>("SSYR2K", uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *const c64,
+    ldb: *const blas_int,
+    beta: *const f64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::her2k::<c64>("ZHER2K", uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *const c64,
+    ldb: *const blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::syr2k::<c64>("ZSYR2K", uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn ctrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *mut c32,
+    ldb: *const blas_int,
+) {
+    level3::trmm::<c32>("CTRMM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn dtrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *mut f64,
+    ldb: *const blas_int,
+) {
+    level3::trmm::<f64>("DTRMM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn strmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *mut f32,
+    ldb: *const blas_int,
+) {
+    level3::trmm::<f32>("STRMM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn ztrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *mut c64,
+    ldb: *const blas_int,
+) {
+    level3::trmm::<c64>("ZTRMM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn ctrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *mut c32,
+    ldb: *const blas_int,
+) {
+    level3::trsm::<c32>("CTRSM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn dtrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *mut f64,
+    ldb: *const blas_int,
+) {
+    level3::trsm::<f64>("DTRSM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn strsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *mut f32,
+    ldb: *const blas_int,
+) {
+    level3::trsm::<f32>("STRSM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn ztrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *mut c64,
+    ldb: *const blas_int,
+) {
+    level3::trsm::<c64>("ZTRSM", side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn sgemmt_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    transb: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *const f32,
+    ldb: *const blas_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const blas_int,
+) {
+    level3::gemmt::<f32>("SGEMMT", uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn cgemmt_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    transb: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *const c32,
+    ldb: *const blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const blas_int,
+) {
+    level3::gemmt::<c32>("CGEMMT", uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn dgemmt_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    transb: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *const f64,
+    ldb: *const blas_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const blas_int,
+) {
+    level3::gemmt::<f64>("DGEMMT", uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn zgemmt_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    transb: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *const c64,
+    ldb: *const blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const blas_int,
+) {
+    level3::gemmt::<c64>("ZGEMMT", uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+pub unsafe extern "C" fn sgemm_batch_strided_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    stridea: *const blas_int,
+    b: *const f32,
+    ldb: *const blas_int,
+    strideb: *const blas_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const blas_int,
+    stridec: *const blas_int,
+    batch_size: *const blas_int,
+) {
+    extension::gemm_batch_strided::<f32>(
+        "SGEMM_BATCH_STRIDED",
+        transa,
+        transb,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        stridea,
+        b,
+        ldb,
+        strideb,
+        beta,
+        c,
+        ldc,
+        stridec,
+        batch_size,
+    )
+}
+
+pub unsafe extern "C" fn cgemm_batch_strided_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    stridea: *const blas_int,
+    b: *const c32,
+    ldb: *const blas_int,
+    strideb: *const blas_int,
+    beta: *const c32,
+    c: *mut c32,
+    ldc: *const blas_int,
+    stridec: *const blas_int,
+    batch_size: *const blas_int,
+) {
+    extension::gemm_batch_strided::<c32>(
+        "CGEMM_BATCH_STRIDED",
+        transa,
+        transb,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        stridea,
+        b,
+        ldb,
+        strideb,
+        beta,
+        c,
+        ldc,
+        stridec,
+        batch_size,
+    )
+}
+
+pub unsafe extern "C" fn dgemm_batch_strided_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    stridea: *const blas_int,
+    b: *const f64,
+    ldb: *const blas_int,
+    strideb: *const blas_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const blas_int,
+    stridec: *const blas_int,
+    batch_size: *const blas_int,
+) {
+    extension::gemm_batch_strided::<f64>(
+        "DGEMM_BATCH_STRIDED",
+        transa,
+        transb,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        stridea,
+        b,
+        ldb,
+        strideb,
+        beta,
+        c,
+        ldc,
+        stridec,
+        batch_size,
+    )
+}
+
+pub unsafe extern "C" fn zgemm_batch_strided_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    stridea: *const blas_int,
+    b: *const c64,
+    ldb: *const blas_int,
+    strideb: *const blas_int,
+    beta: *const c64,
+    c: *mut c64,
+    ldc: *const blas_int,
+    stridec: *const blas_int,
+    batch_size: *const blas_int,
+) {
+    extension::gemm_batch_strided::<c64>(
+        "ZGEMM_BATCH_STRIDED",
+        transa,
+        transb,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        stridea,
+        b,
+        ldb,
+        strideb,
+        beta,
+        c,
+        ldc,
+        stridec,
+        batch_size,
+    )
+}
+
+pub unsafe extern "C" fn sgemm_batch_(
+    transa_array: *const c_char,
+    transb_array: *const c_char,
+    m_array: *const blas_int,
+    n_array: *const blas_int,
+    k_array: *const blas_int,
+    alpha_array: *const f32,
+    a_array: *mut *const f32,
+    lda_array: *const blas_int,
+    b_array: *mut *const f32,
+    ldb_array: *const blas_int,
+    beta_array: *const f32,
+    c_array: *mut *mut f32,
+    ldc_array: *const blas_int,
+    group_count: *const blas_int,
+    group_size: *const blas_int,
+) {
+    extension::gemm_batch::<f32>(
+        "SGEMM_BATCH",
+        transa_array,
+        transb_array,
+        m_array,
+        n_array,
+        k_array,
+        alpha_array,
+        a_array,
+        lda_array,
+        b_array,
+        ldb_array,
+        beta_array,
+        c_array,
+        ldc_array,
+        group_count,
+        group_size,
+    )
+}
+
+pub unsafe extern "C" fn cgemm_batch_(
+    transa_array: *const c_char,
+    transb_array: *const c_char,
+    m_array: *const blas_int,
+    n_array: *const blas_int,
+    k_array: *const blas_int,
+    alpha_array: *const c32,
+    a_array: *mut *const c32,
+    lda_array: *const blas_int,
+    b_array: *mut *const c32,
+    ldb_array: *const blas_int,
+    beta_array: *const c32,
+    c_array: *mut *mut c32,
+    ldc_array: *const blas_int,
+    group_count: *const blas_int,
+    group_size: *const blas_int,
+) {
+    extension::gemm_batch::<c32>(
+        "CGEMM_BATCH",
+        transa_array,
+        transb_array,
+        m_array,
+        n_array,
+        k_array,
+        alpha_array,
+        a_array,
+        lda_array,
+        b_array,
+        ldb_array,
+        beta_array,
+        c_array,
+        ldc_array,
+        group_count,
+        group_size,
+    )
+}
+
+pub unsafe extern "C" fn dgemm_batch_(
+    transa_array: *const c_char,
+    transb_array: *const c_char,
+    m_array: *const blas_int,
+    n_array: *const blas_int,
+    k_array: *const blas_int,
+    alpha_array: *const f64,
+    a_array: *mut *const f64,
+    lda_array: *const blas_int,
+    b_array: *mut *const f64,
+    ldb_array: *const blas_int,
+    beta_array: *const f64,
+    c_array: *mut *mut f64,
+    ldc_array: *const blas_int,
+    group_count: *const blas_int,
+    group_size: *const blas_int,
+) {
+    extension::gemm_batch::<f64>(
+        "DGEMM_BATCH",
+        transa_array,
+        transb_array,
+        m_array,
+        n_array,
+        k_array,
+        alpha_array,
+        a_array,
+        lda_array,
+        b_array,
+        ldb_array,
+        beta_array,
+        c_array,
+        ldc_array,
+        group_count,
+        group_size,
+    )
+}
+
+pub unsafe extern "C" fn zgemm_batch_(
+    transa_array: *const c_char,
+    transb_array: *const c_char,
+    m_array: *const blas_int,
+    n_array: *const blas_int,
+    k_array: *const blas_int,
+    alpha_array: *const c64,
+    a_array: *mut *const c64,
+    lda_array: *const blas_int,
+    b_array: *mut *const c64,
+    ldb_array: *const blas_int,
+    beta_array: *const c64,
+    c_array: *mut *mut c64,
+    ldc_array: *const blas_int,
+    group_count: *const blas_int,
+    group_size: *const blas_int,
+) {
+    extension::gemm_batch::<c64>(
+        "ZGEMM_BATCH",
+        transa_array,
+        transb_array,
+        m_array,
+        n_array,
+        k_array,
+        alpha_array,
+        a_array,
+        lda_array,
+        b_array,
+        ldb_array,
+        beta_array,
+        c_array,
+        ldc_array,
+        group_count,
+        group_size,
+    )
+}
+
+pub unsafe extern "C" fn somatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const blas_int,
+    b: *mut f32,
+    ldb: *const blas_int,
+) {
+    extension::omatcopy::<f32>("SOMATCOPY", ordering, trans, rows, cols, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn comatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const c32,
+    a: *const c32,
+    lda: *const blas_int,
+    b: *mut c32,
+    ldb: *const blas_int,
+) {
+    extension::omatcopy::<c32>("COMATCOPY", ordering, trans, rows, cols, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn domatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const blas_int,
+    b: *mut f64,
+    ldb: *const blas_int,
+) {
+    extension::omatcopy::<f64>("DOMATCOPY", ordering, trans, rows, cols, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn zomatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const c64,
+    a: *const c64,
+    lda: *const blas_int,
+    b: *mut c64,
+    ldb: *const blas_int,
+) {
+    extension::omatcopy::<c64>("ZOMATCOPY", ordering, trans, rows, cols, alpha, a, lda, b, ldb)
+}
+
+pub unsafe extern "C" fn simatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const f32,
+    ab: *mut f32,
+    lda: *const blas_int,
+    ldb: *const blas_int,
+) {
+    extension::imatcopy::<f32>("SIMATCOPY", ordering, trans, rows, cols, alpha, ab, lda, ldb)
+}
+
+pub unsafe extern "C" fn cimatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const c32,
+    ab: *mut c32,
+    lda: *const blas_int,
+    ldb: *const blas_int,
+) {
+    extension::imatcopy::<c32>("CIMATCOPY", ordering, trans, rows, cols, alpha, ab, lda, ldb)
+}
+
+pub unsafe extern "C" fn dimatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const f64,
+    ab: *mut f64,
+    lda: *const blas_int,
+    ldb: *const blas_int,
+) {
+    extension::imatcopy::<f64>("DIMATCOPY", ordering, trans, rows, cols, alpha, ab, lda, ldb)
+}
+
+pub unsafe extern "C" fn zimatcopy_(
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const c64,
+    ab: *mut c64,
+    lda: *const blas_int,
+    ldb: *const blas_int,
+) {
+    extension::imatcopy::<c64>("ZIMATCOPY", ordering, trans, rows, cols, alpha, ab, lda, ldb)
+}
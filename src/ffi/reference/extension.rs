@@ -0,0 +1,221 @@
+//! BLAS extensions: batched GEMM and matrix copy with transposition.
+
+use super::level3::{gemm_checks, gemm_kernel};
+use super::*;
+use alloc::vec::Vec;
+
+/* #region gemm batch */
+
+/// Strided batch of `?gemm_`; `INFO` follows arguments of this routine.
+pub unsafe fn gemm_batch_strided<F: Scalar>(
+    name: &str,
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    stridea: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    strideb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+    stridec: *const blas_int,
+    batch_size: *const blas_int,
+) {
+    let (m, n, k) = (*m as isize, *n as isize, *k as isize);
+    let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+    let (stridea, strideb, stridec) = (*stridea as isize, *strideb as isize, *stridec as isize);
+    let batch_size = *batch_size as isize;
+    let ((ta, tb), mut checks) = gemm_checks::<F>(transa, transb, (m, n, k), (lda, ldb, ldc));
+    // positions of ldb and ldc are shifted by strides
+    checks[6].1 = 11;
+    checks[7].1 = 15;
+    let (alpha, beta) = (*alpha, *beta);
+    if !check_args(name, &checks) || !check_args(name, &[(batch_size < 0, 17)]) {
+        return;
+    }
+    if m == 0 || n == 0 || ((alpha == F::zero() || k == 0) && beta == F::one()) {
+        return;
+    }
+    for p in 0..batch_size {
+        let a = Matrix::new(a.offset(p * stridea), lda);
+        let b = Matrix::new(b.offset(p * strideb), ldb);
+        let c = Matrix::new(c.offset(p * stridec), ldc);
+        gemm_kernel(ta, tb, (n, k), alpha, a, b, beta, c, |_| 0..m);
+    }
+}
+
+/// Grouped batch of `?gemm_`, where group `g` has `group_size[g]` problems sharing arguments of index `g`.
+pub unsafe fn gemm_batch<F: Scalar>(
+    name: &str,
+    transa_array: *const c_char,
+    transb_array: *const c_char,
+    m_array: *const blas_int,
+    n_array: *const blas_int,
+    k_array: *const blas_int,
+    alpha_array: *const F,
+    a_array: *mut *const F,
+    lda_array: *const blas_int,
+    b_array: *mut *const F,
+    ldb_array: *const blas_int,
+    beta_array: *const F,
+    c_array: *mut *mut F,
+    ldc_array: *const blas_int,
+    group_count: *const blas_int,
+    group_size: *const blas_int,
+) {
+    let group_count = *group_count as isize;
+    if !check_args(name, &[(group_count < 0, 14)]) {
+        return;
+    }
+    // all groups are checked before computation
+    for g in 0..group_count {
+        let (m, n, k) =
+            (*m_array.offset(g) as isize, *n_array.offset(g) as isize, *k_array.offset(g) as isize);
+        let lds =
+            (*lda_array.offset(g) as isize, *ldb_array.offset(g) as isize, *ldc_array.offset(g) as isize);
+        let (_, checks) = gemm_checks::<F>(transa_array.offset(g), transb_array.offset(g), (m, n, k), lds);
+        if !check_args(name, &checks) || !check_args(name, &[(*group_size.offset(g) < 0, 15)]) {
+            return;
+        }
+    }
+    let mut offset = 0;
+    for g in 0..group_count {
+        let (m, n, k) =
+            (*m_array.offset(g) as isize, *n_array.offset(g) as isize, *k_array.offset(g) as isize);
+        let (lda, ldb, ldc) =
+            (*lda_array.offset(g) as isize, *ldb_array.offset(g) as isize, *ldc_array.offset(g) as isize);
+        let ((ta, tb), _) =
+            gemm_checks::<F>(transa_array.offset(g), transb_array.offset(g), (m, n, k), (lda, ldb, ldc));
+        let (alpha, beta) = (*alpha_array.offset(g), *beta_array.offset(g));
+        let size = *group_size.offset(g) as isize;
+        if !(m == 0 || n == 0 || ((alpha == F::zero() || k == 0) && beta == F::one())) {
+            for p in offset..(offset + size) {
+                let a = Matrix::new(*a_array.offset(p), lda);
+                let b = Matrix::new(*b_array.offset(p), ldb);
+                let c = Matrix::new(*c_array.offset(p), ldc);
+                gemm_kernel(ta, tb, (n, k), alpha, a, b, beta, c, |_| 0..m);
+            }
+        }
+        offset += size;
+    }
+}
+
+/* #endregion */
+
+/* #region matrix copy */
+
+/// Parsed arguments of `?omatcopy_` and `?imatcopy_`, in column-major convention.
+struct MatCopy {
+    rows: isize,
+    cols: isize,
+    trans: bool,
+    conj: bool,
+}
+
+/// Check arguments of matrix copy; row-major `ordering` is regarded as column-major of transposed shape.
+unsafe fn matcopy_args<F: Scalar>(
+    name: &str,
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    (lda, ldb): (isize, isize),
+    info_ldb: blas_int,
+) -> Option<MatCopy> {
+    let (rows, cols) = (*rows as isize, *cols as isize);
+    let col_major = lsame(ordering, b'C');
+    let (rows, cols) = if col_major { (rows, cols) } else { (cols, rows) };
+    let flags = if lsame(trans, b'N') {
+        Some((false, false))
+    } else if lsame(trans, b'T') {
+        Some((true, false))
+    } else if lsame(trans, b'C') {
+        Some((true, F::COMPLEX))
+    } else if lsame(trans, b'R') {
+        Some((false, F::COMPLEX))
+    } else {
+        None
+    };
+    let (tr, cj) = flags.unwrap_or_default();
+    let rows_b = if tr { cols } else { rows };
+    let legal = check_args(
+        name,
+        &[
+            (!(col_major || lsame(ordering, b'R')), 1),
+            (flags.is_none(), 2),
+            (rows < 0, 3),
+            (cols < 0, 4),
+            (lda < rows.max(1), 7),
+            (ldb < rows_b.max(1), info_ldb),
+        ],
+    );
+    return legal.then_some(MatCopy { rows, cols, trans: tr, conj: cj });
+}
+
+/// `B := alpha * op(A)`, where `op` is given by `trans` as `N`, `T`, `C` (conjugate transpose) or `R`
+/// (conjugate only).
+pub unsafe fn omatcopy<F: Scalar>(
+    name: &str,
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *mut F,
+    ldb: *const blas_int,
+) {
+    let (lda, ldb) = (*lda as isize, *ldb as isize);
+    let Some(args) = matcopy_args::<F>(name, ordering, trans, rows, cols, (lda, ldb), 9) else {
+        return;
+    };
+    omatcopy_impl(&args, *alpha, Matrix::new(a, lda), Matrix::new(b, ldb));
+}
+
+unsafe fn omatcopy_impl<F: Scalar>(args: &MatCopy, alpha: F, a: Matrix<F>, b: Matrix<F>) {
+    for j in 0..args.cols {
+        for i in 0..args.rows {
+            let val = alpha * a.get(i, j).conj_if(args.conj);
+            if args.trans {
+                b.set(j, i, val);
+            } else {
+                b.set(i, j, val);
+            }
+        }
+    }
+}
+
+/// In-place `AB := alpha * op(AB)`, with leading dimension `lda` before and `ldb` after.
+pub unsafe fn imatcopy<F: Scalar>(
+    name: &str,
+    ordering: *const c_char,
+    trans: *const c_char,
+    rows: *const blas_int,
+    cols: *const blas_int,
+    alpha: *const F,
+    ab: *mut F,
+    lda: *const blas_int,
+    ldb: *const blas_int,
+) {
+    let (lda, ldb) = (*lda as isize, *ldb as isize);
+    let Some(args) = matcopy_args::<F>(name, ordering, trans, rows, cols, (lda, ldb), 8) else {
+        return;
+    };
+    let a = Matrix::new(ab, lda);
+    let mut buf = Vec::with_capacity((args.rows * args.cols) as usize);
+    for j in 0..args.cols {
+        for i in 0..args.rows {
+            buf.push(a.get(i, j));
+        }
+    }
+    omatcopy_impl(&args, *alpha, Matrix::new(buf.as_ptr(), args.rows), Matrix::new(ab, ldb));
+}
+
+/* #endregion */
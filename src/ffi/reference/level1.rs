@@ -0,0 +1,561 @@
+//! Level 1 BLAS: vector operations.
+
+use super::*;
+
+/* #region norm and index */
+
+pub unsafe fn cabs1<F: Scalar>(z: *const F) -> F::Real {
+    (*z).abs1()
+}
+
+pub unsafe fn asum<F: Scalar>(n: *const blas_int, x: *const F, incx: *const blas_int) -> F::Real {
+    let (n, incx) = (*n as isize, *incx as isize);
+    let mut sum = F::Real::zero();
+    if n <= 0 || incx <= 0 {
+        return sum;
+    }
+    let x = Vector::new(x, n, incx);
+    for i in 0..n {
+        sum += x.get(i).abs1();
+    }
+    return sum;
+}
+
+/// Euclidean norm, by scaled sum of squares to avoid overflow.
+pub unsafe fn nrm2<F: Scalar>(n: *const blas_int, x: *const F, incx: *const blas_int) -> F::Real {
+    let (n, incx) = (*n as isize, *incx as isize);
+    let zero = F::Real::zero();
+    let one = F::Real::one();
+    if n <= 0 || incx <= 0 {
+        return zero;
+    }
+    let x = Vector::new(x, n, incx);
+    let mut scale = zero;
+    let mut ssq = one;
+    for i in 0..n {
+        let xi = x.get(i);
+        for v in [xi.re(), xi.im()] {
+            if v != zero {
+                let absxi = v.abs();
+                if scale < absxi {
+                    ssq = one + ssq * (scale / absxi) * (scale / absxi);
+                    scale = absxi;
+                } else {
+                    ssq += (absxi / scale) * (absxi / scale);
+                }
+            }
+        }
+    }
+    return scale * ssq.sqrt();
+}
+
+/// 1-based index of the first element of maximum `|re| + |im|`; 0 for empty vector.
+pub unsafe fn iamax<F: Scalar>(n: *const blas_int, x: *const F, incx: *const blas_int) -> blas_int {
+    let (n, incx) = (*n as isize, *incx as isize);
+    if n < 1 || incx <= 0 {
+        return 0;
+    }
+    let x = Vector::new(x, n, incx);
+    let mut imax = 0;
+    let mut dmax = x.get(0).abs1();
+    for i in 1..n {
+        let v = x.get(i).abs1();
+        if v > dmax {
+            imax = i;
+            dmax = v;
+        }
+    }
+    return (imax + 1) as blas_int;
+}
+
+/* #endregion */
+
+/* #region vector update */
+
+pub unsafe fn axpy<F: Scalar>(
+    n: *const blas_int,
+    alpha: *const F,
+    x: *const F,
+    incx: *const blas_int,
+    y: *mut F,
+    incy: *const blas_int,
+) {
+    let (n, incx, incy, alpha) = (*n as isize, *incx as isize, *incy as isize, *alpha);
+    if n <= 0 || alpha == F::zero() {
+        return;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n {
+        y.set(i, y.get(i) + alpha * x.get(i));
+    }
+}
+
+pub unsafe fn copy<F: Scalar>(
+    n: *const blas_int,
+    x: *const F,
+    incx: *const blas_int,
+    y: *mut F,
+    incy: *const blas_int,
+) {
+    let (n, incx, incy) = (*n as isize, *incx as isize, *incy as isize);
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n {
+        y.set(i, x.get(i));
+    }
+}
+
+pub unsafe fn swap<F: Scalar>(
+    n: *const blas_int,
+    x: *mut F,
+    incx: *const blas_int,
+    y: *mut F,
+    incy: *const blas_int,
+) {
+    let (n, incx, incy) = (*n as isize, *incx as isize, *incy as isize);
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n {
+        let tmp = x.get(i);
+        x.set(i, y.get(i));
+        y.set(i, tmp);
+    }
+}
+
+pub unsafe fn scal<F: Scalar>(n: *const blas_int, a: *const F, x: *mut F, incx: *const blas_int) {
+    let (n, incx, a) = (*n as isize, *incx as isize, *a);
+    if n <= 0 || incx <= 0 {
+        return;
+    }
+    let x = Vector::new(x, n, incx);
+    for i in 0..n {
+        x.set(i, a * x.get(i));
+    }
+}
+
+/// Scale complex vector by real scalar (`csscal_`, `zdscal_`).
+pub unsafe fn rscal<F: Scalar>(n: *const blas_int, a: *const F::Real, x: *mut F, incx: *const blas_int) {
+    let (n, incx, a) = (*n as isize, *incx as isize, *a);
+    if n <= 0 || incx <= 0 {
+        return;
+    }
+    let x = Vector::new(x, n, incx);
+    for i in 0..n {
+        x.set(i, x.get(i).mul_real(a));
+    }
+}
+
+/* #endregion */
+
+/* #region dot */
+
+/// Unconjugated dot product, returned by value (`sdot_`, `ddot_`).
+pub unsafe fn dot<F: Scalar>(
+    n: *const blas_int,
+    x: *const F,
+    incx: *const blas_int,
+    y: *const F,
+    incy: *const blas_int,
+) -> F {
+    return dot_impl(n, x, incx, y, incy, false);
+}
+
+/// Unconjugated dot product, returned by pointer (`cdotu_`, `zdotu_`).
+pub unsafe fn dotu<F: Scalar>(
+    pres: *mut F,
+    n: *const blas_int,
+    x: *const F,
+    incx: *const blas_int,
+    y: *const F,
+    incy: *const blas_int,
+) {
+    *pres = dot_impl(n, x, incx, y, incy, false);
+}
+
+/// Conjugated dot product `x^H y`, returned by pointer (`cdotc_`, `zdotc_`).
+pub unsafe fn dotc<F: Scalar>(
+    pres: *mut F,
+    n: *const blas_int,
+    x: *const F,
+    incx: *const blas_int,
+    y: *const F,
+    incy: *const blas_int,
+) {
+    *pres = dot_impl(n, x, incx, y, incy, true);
+}
+
+unsafe fn dot_impl<F: Scalar>(
+    n: *const blas_int,
+    x: *const F,
+    incx: *const blas_int,
+    y: *const F,
+    incy: *const blas_int,
+    conj: bool,
+) -> F {
+    let (n, incx, incy) = (*n as isize, *incx as isize, *incy as isize);
+    let mut sum = F::zero();
+    if n <= 0 {
+        return sum;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n {
+        sum += x.get(i).conj_if(conj) * y.get(i);
+    }
+    return sum;
+}
+
+/// Dot product of `f32` vectors, accumulated and returned in `f64`.
+pub unsafe fn dsdot(
+    n: *const blas_int,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+) -> f64 {
+    let (n, incx, incy) = (*n as isize, *incx as isize, *incy as isize);
+    let mut sum = 0.0_f64;
+    if n <= 0 {
+        return sum;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n {
+        sum += x.get(i) as f64 * y.get(i) as f64;
+    }
+    return sum;
+}
+
+/// `sb` plus dot product of `f32` vectors, accumulated in `f64`.
+pub unsafe fn sdsdot(
+    n: *const blas_int,
+    sb: *const f32,
+    x: *const f32,
+    incx: *const blas_int,
+    y: *const f32,
+    incy: *const blas_int,
+) -> f32 {
+    return (*sb as f64 + dsdot(n, x, incx, y, incy)) as f32;
+}
+
+/* #endregion */
+
+/* #region plane rotation */
+
+/// Apply plane rotation with real `c` and `s` (also `csrot_`, `zdrot_`).
+pub unsafe fn rot<F: Scalar>(
+    n: *const blas_int,
+    x: *mut F,
+    incx: *const blas_int,
+    y: *mut F,
+    incy: *const blas_int,
+    c: *const F::Real,
+    s: *const F::Real,
+) {
+    let (n, incx, incy, c, s) = (*n as isize, *incx as isize, *incy as isize, *c, *s);
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n {
+        let (xi, yi) = (x.get(i), y.get(i));
+        x.set(i, xi.mul_real(c) + yi.mul_real(s));
+        y.set(i, yi.mul_real(c) - xi.mul_real(s));
+    }
+}
+
+/// Safe minimum and its reciprocal, as `la_constants` of LAPACK.
+fn safe_min_max<T: Float>() -> (T, T) {
+    let safmin = T::min_positive_value();
+    return (safmin, T::one() / safmin);
+}
+
+/// Construct real plane rotation (algorithm of LAPACK 3.10 `drotg`).
+pub unsafe fn rotg<F: Scalar<Real = F> + Float>(a: *mut F, b: *mut F, c: *mut F, s: *mut F) {
+    let (zero, one) = (F::zero(), F::one());
+    let (safmin, safmax) = safe_min_max::<F>();
+    let (anorm, bnorm) = ((*a).abs(), (*b).abs());
+    if bnorm == zero {
+        *c = one;
+        *s = zero;
+        *b = zero;
+    } else if anorm == zero {
+        *c = zero;
+        *s = one;
+        *a = *b;
+        *b = one;
+    } else {
+        let scl = safmax.min(safmin.max(anorm).max(bnorm));
+        let sigma = if anorm > bnorm { one.copysign(*a) } else { one.copysign(*b) };
+        let r = sigma * (scl * ((*a / scl).powi(2) + (*b / scl).powi(2)).sqrt());
+        *c = *a / r;
+        *s = *b / r;
+        let z = if anorm > bnorm {
+            *s
+        } else if *c != zero {
+            one / *c
+        } else {
+            one
+        };
+        *a = r;
+        *b = z;
+    }
+}
+
+/// Construct complex plane rotation (algorithm of LAPACK 3.10 `zrotg`).
+pub unsafe fn rotg_complex<F: Scalar>(a: *mut F, b: *const F, c: *mut F::Real, s: *mut F) {
+    type R<F> = <F as Scalar>::Real;
+    let (zero, one) = (R::<F>::zero(), R::<F>::one());
+    let two = one + one;
+    let (safmin, safmax) = safe_min_max::<R<F>>();
+    let rtmin = safmin.sqrt();
+    let abssq = |z: F| z.re() * z.re() + z.im() * z.im();
+    let (f, g) = (*a, *b);
+    let r: F;
+    if g == F::zero() {
+        *c = one;
+        *s = F::zero();
+        r = f;
+    } else if f == F::zero() {
+        *c = zero;
+        if g.re() == zero {
+            let d = g.im().abs();
+            *s = g.conj().div_real(d);
+            r = F::from_real(d);
+        } else if g.im() == zero {
+            let d = g.re().abs();
+            *s = g.conj().div_real(d);
+            r = F::from_real(d);
+        } else {
+            let g1 = g.re().abs().max(g.im().abs());
+            let rtmax = (safmax / two).sqrt();
+            if g1 > rtmin && g1 < rtmax {
+                let d = abssq(g).sqrt();
+                *s = g.conj().div_real(d);
+                r = F::from_real(d);
+            } else {
+                let u = safmax.min(safmin.max(g1));
+                let gs = g.div_real(u);
+                let d = abssq(gs).sqrt();
+                *s = gs.conj().div_real(d);
+                r = F::from_real(d * u);
+            }
+        }
+    } else {
+        let f1 = f.re().abs().max(f.im().abs());
+        let g1 = g.re().abs().max(g.im().abs());
+        let rtmax = (safmax / (two + two)).sqrt();
+        if f1 > rtmin && f1 < rtmax && g1 > rtmin && g1 < rtmax {
+            let (f2, g2) = (abssq(f), abssq(g));
+            let h2 = f2 + g2;
+            if f2 >= h2 * safmin {
+                *c = (f2 / h2).sqrt();
+                r = f.div_real(*c);
+                if f2 > rtmin && h2 < rtmax * two {
+                    *s = g.conj() * f.div_real((f2 * h2).sqrt());
+                } else {
+                    *s = g.conj() * r.div_real(h2);
+                }
+            } else {
+                let d = (f2 * h2).sqrt();
+                *c = f2 / d;
+                r = if *c >= safmin { f.div_real(*c) } else { f.mul_real(h2 / d) };
+                *s = g.conj() * f.div_real(d);
+            }
+        } else {
+            let u = safmax.min(safmin.max(f1).max(g1));
+            let gs = g.div_real(u);
+            let g2 = abssq(gs);
+            let (w, fs, f2, h2);
+            if f1 / u < rtmin {
+                let v = safmax.min(safmin.max(f1));
+                w = v / u;
+                fs = f.div_real(v);
+                f2 = abssq(fs);
+                h2 = f2 * w * w + g2;
+            } else {
+                w = one;
+                fs = f.div_real(u);
+                f2 = abssq(fs);
+                h2 = f2 + g2;
+            }
+            let rs;
+            if f2 >= h2 * safmin {
+                *c = (f2 / h2).sqrt();
+                rs = fs.div_real(*c);
+                if f2 > rtmin && h2 < rtmax * two {
+                    *s = gs.conj() * fs.div_real((f2 * h2).sqrt());
+                } else {
+                    *s = gs.conj() * rs.div_real(h2);
+                }
+            } else {
+                let d = (f2 * h2).sqrt();
+                *c = f2 / d;
+                rs = if *c >= safmin { fs.div_real(*c) } else { fs.mul_real(h2 / d) };
+                *s = gs.conj() * fs.div_real(d);
+            }
+            *c *= w;
+            r = rs.mul_real(u);
+        }
+    }
+    *a = r;
+}
+
+/// Apply modified Givens rotation.
+pub unsafe fn rotm<F: Scalar<Real = F> + Float>(
+    n: *const blas_int,
+    x: *mut F,
+    incx: *const blas_int,
+    y: *mut F,
+    incy: *const blas_int,
+    param: *const F,
+) {
+    let (n, incx, incy) = (*n as isize, *incx as isize, *incy as isize);
+    let p = core::slice::from_raw_parts(param, 5);
+    let flag = p[0];
+    let two = F::one() + F::one();
+    if n <= 0 || flag == -two {
+        return;
+    }
+    let (h11, h21, h12, h22) = if flag < F::zero() {
+        (p[1], p[2], p[3], p[4])
+    } else if flag == F::zero() {
+        (F::one(), p[2], p[3], F::one())
+    } else {
+        (p[1], -F::one(), F::one(), p[4])
+    };
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n {
+        let (w, z) = (x.get(i), y.get(i));
+        x.set(i, w * h11 + z * h12);
+        y.set(i, w * h21 + z * h22);
+    }
+}
+
+/// Construct modified Givens rotation (netlib `drotmg`).
+pub unsafe fn rotmg<F: Scalar<Real = F> + Float>(
+    d1: *mut F,
+    d2: *mut F,
+    x1: *mut F,
+    y1: *const F,
+    param: *mut F,
+) {
+    let (zero, one) = (F::zero(), F::one());
+    let gam = F::from(4096.0).unwrap();
+    let gamsq = gam * gam;
+    let rgamsq = one / gamsq;
+    let param = core::slice::from_raw_parts_mut(param, 5);
+    let (mut h11, mut h12, mut h21, mut h22) = (zero, zero, zero, zero);
+    let mut flag;
+
+    if *d1 < zero {
+        flag = -one;
+        *d1 = zero;
+        *d2 = zero;
+        *x1 = zero;
+    } else {
+        let p2 = *d2 * *y1;
+        if p2 == zero {
+            param[0] = -(one + one);
+            return;
+        }
+        let p1 = *d1 * *x1;
+        let q2 = p2 * *y1;
+        let q1 = p1 * *x1;
+        if q1.abs() > q2.abs() {
+            h21 = -*y1 / *x1;
+            h12 = p2 / p1;
+            let u = one - h12 * h21;
+            if u > zero {
+                flag = zero;
+                *d1 /= u;
+                *d2 /= u;
+                *x1 *= u;
+            } else {
+                flag = -one;
+                (h11, h12, h21, h22) = (zero, zero, zero, zero);
+                *d1 = zero;
+                *d2 = zero;
+                *x1 = zero;
+            }
+        } else if q2 < zero {
+            flag = -one;
+            (h11, h12, h21, h22) = (zero, zero, zero, zero);
+            *d1 = zero;
+            *d2 = zero;
+            *x1 = zero;
+        } else {
+            flag = one;
+            h11 = p1 / p2;
+            h22 = *x1 / *y1;
+            let u = one + h11 * h22;
+            let temp = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = temp;
+            *x1 = *y1 * u;
+        }
+
+        // rescale d1 and d2 into range [1/gamsq, gamsq]; implicit elements of H are filled once, before the
+        // first rescale, since H of flag -1 is already explicit
+        let rescale = |flag: &mut F, h11: &mut F, h12: &mut F, h21: &mut F, h22: &mut F| {
+            if *flag >= zero {
+                if *flag == zero {
+                    *h11 = one;
+                    *h22 = one;
+                } else {
+                    *h21 = -one;
+                    *h12 = one;
+                }
+                *flag = -one;
+            }
+        };
+        if *d1 != zero {
+            while *d1 <= rgamsq || *d1 >= gamsq {
+                rescale(&mut flag, &mut h11, &mut h12, &mut h21, &mut h22);
+                if *d1 <= rgamsq {
+                    *d1 *= gamsq;
+                    *x1 /= gam;
+                    h11 /= gam;
+                    h12 /= gam;
+                } else {
+                    *d1 /= gamsq;
+                    *x1 *= gam;
+                    h11 *= gam;
+                    h12 *= gam;
+                }
+            }
+        }
+        if *d2 != zero {
+            while (*d2).abs() <= rgamsq || (*d2).abs() >= gamsq {
+                rescale(&mut flag, &mut h11, &mut h12, &mut h21, &mut h22);
+                if (*d2).abs() <= rgamsq {
+                    *d2 *= gamsq;
+                    h21 /= gam;
+                    h22 /= gam;
+                } else {
+                    *d2 /= gamsq;
+                    h21 *= gam;
+                    h22 *= gam;
+                }
+            }
+        }
+    }
+
+    if flag < zero {
+        param[1] = h11;
+        param[2] = h21;
+        param[3] = h12;
+        param[4] = h22;
+    } else if flag == zero {
+        param[2] = h21;
+        param[3] = h12;
+    } else {
+        param[1] = h11;
+        param[4] = h22;
+    }
+    param[0] = flag;
+}
+
+/* #endregion */
@@ -0,0 +1,730 @@
+//! Level 2 BLAS: matrix-vector operations.
+//!
+//! Routines of full, band and packed storage share kernels, where matrix elements are given by accessor
+//! closures on the stored part.
+
+use super::*;
+
+/* #region storage accessors */
+
+/// Element `(i, j)` of symmetric or triangular band matrix, for `(i, j)` in stored triangle within bandwidth `k`.
+#[inline]
+unsafe fn band_tri<F: Scalar>(a: Matrix<F>, upper: bool, k: isize, i: isize, j: isize) -> *mut F {
+    if upper {
+        a.at(k + i - j, j)
+    } else {
+        a.at(i - j, j)
+    }
+}
+
+/// Element `(i, j)` of packed matrix of dimension `n`, for `(i, j)` in stored triangle.
+#[inline]
+unsafe fn packed<F: Scalar>(ap: *mut F, upper: bool, n: isize, i: isize, j: isize) -> *mut F {
+    if upper {
+        ap.offset(i + j * (j + 1) / 2)
+    } else {
+        ap.offset(i - j + j * n - j * (j - 1) / 2)
+    }
+}
+
+/// Element `(i, j)` of symmetric (or Hermitian) matrix, given accessor `a` of stored triangle.
+#[inline]
+pub(crate) fn sym_elem<F: Scalar>(
+    upper: bool,
+    herm: bool,
+    i: isize,
+    j: isize,
+    a: impl Fn(isize, isize) -> F,
+) -> F {
+    if i == j {
+        let aii = a(i, i);
+        return if herm { F::from_real(aii.re()) } else { aii };
+    }
+    if (i < j) == upper {
+        a(i, j)
+    } else {
+        a(j, i).conj_if(herm)
+    }
+}
+
+/* #endregion */
+
+/* #region kernels */
+
+/// `y := beta * y`, with `beta == 0` giving zero and `beta == 1` not touching `y`.
+unsafe fn scale_vector<F: Scalar>(beta: F, y: Vector<F>, n: isize) {
+    if beta == F::one() {
+        return;
+    }
+    for i in 0..n {
+        y.set(i, scale_beta(beta, y.get(i)));
+    }
+}
+
+/// `y := alpha * op(A) x + beta * y` for `m x n` matrix `A`, where nonzero rows of column `j` are in
+/// `rows(j)`.
+unsafe fn gemv_kernel<F: Scalar>(
+    trans: bool,
+    conj: bool,
+    (m, n): (isize, isize),
+    a: impl Fn(isize, isize) -> F,
+    rows: impl Fn(isize) -> core::ops::Range<isize>,
+    alpha: F,
+    x: (*const F, isize),
+    beta: F,
+    y: (*mut F, isize),
+) {
+    let (lenx, leny) = if trans { (m, n) } else { (n, m) };
+    let x = Vector::new(x.0, lenx, x.1);
+    let y = Vector::new(y.0, leny, y.1);
+    scale_vector(beta, y, leny);
+    if alpha == F::zero() {
+        return;
+    }
+    for j in 0..n {
+        if !trans {
+            let temp = alpha * x.get(j);
+            for i in rows(j) {
+                y.set(i, y.get(i) + temp * a(i, j));
+            }
+        } else {
+            let mut temp = F::zero();
+            for i in rows(j) {
+                temp += a(i, j).conj_if(conj) * x.get(i);
+            }
+            y.set(j, y.get(j) + alpha * temp);
+        }
+    }
+}
+
+/// `y := alpha * A x + beta * y` for symmetric (or Hermitian) matrix `A` of bandwidth `k`.
+unsafe fn symv_kernel<F: Scalar>(
+    upper: bool,
+    herm: bool,
+    n: isize,
+    k: isize,
+    a: impl Fn(isize, isize) -> F,
+    alpha: F,
+    x: (*const F, isize),
+    beta: F,
+    y: (*mut F, isize),
+) {
+    let x = Vector::new(x.0, n, x.1);
+    let y = Vector::new(y.0, n, y.1);
+    scale_vector(beta, y, n);
+    if alpha == F::zero() {
+        return;
+    }
+    for j in 0..n {
+        let temp = alpha * x.get(j);
+        for i in (j - k).max(0)..(j + k + 1).min(n) {
+            y.set(i, y.get(i) + temp * sym_elem(upper, herm, i, j, &a));
+        }
+    }
+}
+
+/// `x := op(A) x`, or solve `op(A) x = b` in place (`solve`), for triangular `A` of bandwidth `k`, given accessor
+/// `a` of stored triangle.
+pub(crate) unsafe fn tr_kernel<F: Scalar>(
+    (upper, trans, conj, unit): (bool, bool, bool, bool),
+    solve: bool,
+    n: isize,
+    k: isize,
+    a: impl Fn(isize, isize) -> F,
+    x: Vector<F>,
+) {
+    // effective triangular matrix t = op(A)
+    let t = |i: isize, j: isize| if trans { a(j, i) } else { a(i, j) }.conj_if(conj);
+    let upper = upper != trans;
+    let cols = |i: isize| {
+        if upper {
+            (i + 1)..(i + k + 1).min(n)
+        } else {
+            (i - k).max(0)..i
+        }
+    };
+    // multiplication overwrites x(i) after x(j) of off-diagonal are used; solution uses solved x(j)
+    let reversed = upper == solve;
+    for idx in 0..n {
+        let i = if reversed { n - 1 - idx } else { idx };
+        let mut s = if solve { x.get(i) } else { F::zero() };
+        for j in cols(i) {
+            if solve {
+                s -= t(i, j) * x.get(j);
+            } else {
+                s += t(i, j) * x.get(j);
+            }
+        }
+        let val = if solve {
+            if unit {
+                s
+            } else {
+                s / t(i, i)
+            }
+        } else if unit {
+            s + x.get(i)
+        } else {
+            s + t(i, i) * x.get(i)
+        };
+        x.set(i, val);
+    }
+}
+
+/// Rank-1 or rank-2 update of stored triangle: `A(i, j) += f(i, j)`; diagonal of Hermitian `A` is kept real.
+unsafe fn rank_update<F: Scalar>(
+    upper: bool,
+    herm: bool,
+    n: isize,
+    a: impl Fn(isize, isize) -> *mut F,
+    f: impl Fn(isize, isize) -> F,
+) {
+    for j in 0..n {
+        let rows = if upper { 0..(j + 1) } else { j..n };
+        for i in rows {
+            let p = a(i, j);
+            let val = *p + f(i, j);
+            *p = if herm && i == j { F::from_real(val.re()) } else { val };
+        }
+    }
+}
+
+/// Flags of `trans` argument: (transposed, conjugated).
+pub(crate) type TransFlags = (bool, bool);
+
+/// Flags of `trans` argument; `None` if illegal.
+pub(crate) unsafe fn trans_flags<F: Scalar>(trans: *const c_char) -> Option<TransFlags> {
+    if lsame(trans, b'N') {
+        Some((false, false))
+    } else if lsame(trans, b'T') {
+        Some((true, false))
+    } else if lsame(trans, b'C') {
+        Some((true, F::COMPLEX))
+    } else {
+        None
+    }
+}
+
+/// Whether `uplo` or `diag` argument is one of `a` and `b`.
+#[inline]
+pub(crate) unsafe fn is_either(arg: *const c_char, a: u8, b: u8) -> bool {
+    lsame(arg, a) || lsame(arg, b)
+}
+
+/* #endregion */
+
+/* #region general */
+
+pub unsafe fn gemv<F: Scalar>(
+    name: &str,
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    x: *const F,
+    incx: *const blas_int,
+    beta: *const F,
+    y: *mut F,
+    incy: *const blas_int,
+) {
+    let (m, n, lda, incx, incy) = (*m as isize, *n as isize, *lda as isize, *incx as isize, *incy as isize);
+    let flags = trans_flags::<F>(trans);
+    let legal = check_args(
+        name,
+        &[(flags.is_none(), 1), (m < 0, 2), (n < 0, 3), (lda < m.max(1), 6), (incx == 0, 8), (incy == 0, 11)],
+    );
+    let (alpha, beta) = (*alpha, *beta);
+    if !legal || m == 0 || n == 0 || (alpha == F::zero() && beta == F::one()) {
+        return;
+    }
+    let (tr, cj) = flags.unwrap();
+    let a = Matrix::new(a, lda);
+    gemv_kernel(tr, cj, (m, n), |i, j| a.get(i, j), |_| 0..m, alpha, (x, incx), beta, (y, incy));
+}
+
+pub unsafe fn gbmv<F: Scalar>(
+    name: &str,
+    trans: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    kl: *const blas_int,
+    ku: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    x: *const F,
+    incx: *const blas_int,
+    beta: *const F,
+    y: *mut F,
+    incy: *const blas_int,
+) {
+    let (m, n, kl, ku) = (*m as isize, *n as isize, *kl as isize, *ku as isize);
+    let (lda, incx, incy) = (*lda as isize, *incx as isize, *incy as isize);
+    let flags = trans_flags::<F>(trans);
+    let legal = check_args(
+        name,
+        &[
+            (flags.is_none(), 1),
+            (m < 0, 2),
+            (n < 0, 3),
+            (kl < 0, 4),
+            (ku < 0, 5),
+            (lda < kl + ku + 1, 8),
+            (incx == 0, 10),
+            (incy == 0, 13),
+        ],
+    );
+    let (alpha, beta) = (*alpha, *beta);
+    if !legal || m == 0 || n == 0 || (alpha == F::zero() && beta == F::one()) {
+        return;
+    }
+    let (tr, cj) = flags.unwrap();
+    let a = Matrix::new(a, lda);
+    let elem = |i: isize, j: isize| a.get(ku + i - j, j);
+    let rows = |j: isize| (j - ku).max(0)..(j + kl + 1).min(m);
+    gemv_kernel(tr, cj, (m, n), elem, rows, alpha, (x, incx), beta, (y, incy));
+}
+
+/// `A += alpha x y^T` (`?ger_`, `?geru_`).
+pub unsafe fn geru<F: Scalar>(
+    name: &str,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    x: *const F,
+    incx: *const blas_int,
+    y: *const F,
+    incy: *const blas_int,
+    a: *mut F,
+    lda: *const blas_int,
+) {
+    ger_impl(name, false, m, n, alpha, x, incx, y, incy, a, lda);
+}
+
+/// `A += alpha x y^H`.
+pub unsafe fn gerc<F: Scalar>(
+    name: &str,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    x: *const F,
+    incx: *const blas_int,
+    y: *const F,
+    incy: *const blas_int,
+    a: *mut F,
+    lda: *const blas_int,
+) {
+    ger_impl(name, true, m, n, alpha, x, incx, y, incy, a, lda);
+}
+
+unsafe fn ger_impl<F: Scalar>(
+    name: &str,
+    conj: bool,
+    m: *const blas_int,
+    n: *const blas_int,
+    alpha: *const F,
+    x: *const F,
+    incx: *const blas_int,
+    y: *const F,
+    incy: *const blas_int,
+    a: *mut F,
+    lda: *const blas_int,
+) {
+    let (m, n, lda, incx, incy) = (*m as isize, *n as isize, *lda as isize, *incx as isize, *incy as isize);
+    let legal =
+        check_args(name, &[(m < 0, 1), (n < 0, 2), (incx == 0, 5), (incy == 0, 7), (lda < m.max(1), 9)]);
+    let alpha = *alpha;
+    if !legal || m == 0 || n == 0 || alpha == F::zero() {
+        return;
+    }
+    let (x, y, a) = (Vector::new(x, m, incx), Vector::new(y, n, incy), Matrix::new(a, lda));
+    for j in 0..n {
+        let temp = alpha * y.get(j).conj_if(conj);
+        for i in 0..m {
+            a.set(i, j, a.get(i, j) + x.get(i) * temp);
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region symmetric and Hermitian */
+
+macro_rules! impl_symv {
+    ($($name: ident, $herm: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            n: *const blas_int,
+            alpha: *const F,
+            a: *const F,
+            lda: *const blas_int,
+            x: *const F,
+            incx: *const blas_int,
+            beta: *const F,
+            y: *mut F,
+            incy: *const blas_int,
+        ) {
+            let (n, lda, incx, incy) = (*n as isize, *lda as isize, *incx as isize, *incy as isize);
+            let legal = check_args(name, &[
+                (!is_either(uplo, b'U', b'L'), 1),
+                (n < 0, 2),
+                (lda < n.max(1), 5),
+                (incx == 0, 7),
+                (incy == 0, 10),
+            ]);
+            let (alpha, beta) = (*alpha, *beta);
+            if !legal || n == 0 || (alpha == F::zero() && beta == F::one()) {
+                return;
+            }
+            let a = Matrix::new(a, lda);
+            let upper = lsame(uplo, b'U');
+            symv_kernel(upper, $herm, n, n, |i, j| a.get(i, j), alpha, (x, incx), beta, (y, incy));
+        }
+    )*};
+}
+
+impl_symv!(symv, false; hemv, true);
+
+macro_rules! impl_sbmv {
+    ($($name: ident, $herm: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            n: *const blas_int,
+            k: *const blas_int,
+            alpha: *const F,
+            a: *const F,
+            lda: *const blas_int,
+            x: *const F,
+            incx: *const blas_int,
+            beta: *const F,
+            y: *mut F,
+            incy: *const blas_int,
+        ) {
+            let (n, k, lda, incx, incy) = (*n as isize, *k as isize, *lda as isize, *incx as isize, *incy as isize);
+            let legal = check_args(name, &[
+                (!is_either(uplo, b'U', b'L'), 1),
+                (n < 0, 2),
+                (k < 0, 3),
+                (lda < k + 1, 6),
+                (incx == 0, 8),
+                (incy == 0, 11),
+            ]);
+            let (alpha, beta) = (*alpha, *beta);
+            if !legal || n == 0 || (alpha == F::zero() && beta == F::one()) {
+                return;
+            }
+            let a = Matrix::new(a, lda);
+            let upper = lsame(uplo, b'U');
+            let elem = |i, j| *band_tri(a, upper, k, i, j);
+            symv_kernel(upper, $herm, n, k, elem, alpha, (x, incx), beta, (y, incy));
+        }
+    )*};
+}
+
+impl_sbmv!(sbmv, false; hbmv, true);
+
+macro_rules! impl_spmv {
+    ($($name: ident, $herm: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            n: *const blas_int,
+            alpha: *const F,
+            ap: *const F,
+            x: *const F,
+            incx: *const blas_int,
+            beta: *const F,
+            y: *mut F,
+            incy: *const blas_int,
+        ) {
+            let (n, incx, incy) = (*n as isize, *incx as isize, *incy as isize);
+            let legal = check_args(name, &[
+                (!is_either(uplo, b'U', b'L'), 1),
+                (n < 0, 2),
+                (incx == 0, 6),
+                (incy == 0, 9),
+            ]);
+            let (alpha, beta) = (*alpha, *beta);
+            if !legal || n == 0 || (alpha == F::zero() && beta == F::one()) {
+                return;
+            }
+            let upper = lsame(uplo, b'U');
+            let elem = |i, j| *packed(ap as *mut F, upper, n, i, j);
+            symv_kernel(upper, $herm, n, n, elem, alpha, (x, incx), beta, (y, incy));
+        }
+    )*};
+}
+
+impl_spmv!(spmv, false; hpmv, true);
+
+/// `A += alpha x x^T`.
+pub unsafe fn syr<F: Scalar>(
+    name: &str,
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const F,
+    x: *const F,
+    incx: *const blas_int,
+    a: *mut F,
+    lda: *const blas_int,
+) {
+    let (n, incx, lda) = (*n as isize, *incx as isize, *lda as isize);
+    let legal = check_args(
+        name,
+        &[(!is_either(uplo, b'U', b'L'), 1), (n < 0, 2), (incx == 0, 5), (lda < n.max(1), 7)],
+    );
+    let alpha = *alpha;
+    if !legal || n == 0 || alpha == F::zero() {
+        return;
+    }
+    let (x, a) = (Vector::new(x, n, incx), Matrix::new(a, lda));
+    rank_update(lsame(uplo, b'U'), false, n, |i, j| a.at(i, j), |i, j| alpha * x.get(i) * x.get(j));
+}
+
+/// `A += alpha x x^H` with real `alpha`.
+pub unsafe fn her<F: Scalar>(
+    name: &str,
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const F::Real,
+    x: *const F,
+    incx: *const blas_int,
+    a: *mut F,
+    lda: *const blas_int,
+) {
+    let (n, incx, lda) = (*n as isize, *incx as isize, *lda as isize);
+    let legal = check_args(
+        name,
+        &[(!is_either(uplo, b'U', b'L'), 1), (n < 0, 2), (incx == 0, 5), (lda < n.max(1), 7)],
+    );
+    let alpha = F::from_real(*alpha);
+    if !legal || n == 0 || alpha == F::zero() {
+        return;
+    }
+    let (x, a) = (Vector::new(x, n, incx), Matrix::new(a, lda));
+    rank_update(lsame(uplo, b'U'), true, n, |i, j| a.at(i, j), |i, j| alpha * x.get(i) * x.get(j).conj());
+}
+
+/// `AP += alpha x x^T` of packed storage.
+pub unsafe fn spr<F: Scalar>(
+    name: &str,
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const F,
+    x: *const F,
+    incx: *const blas_int,
+    ap: *mut F,
+) {
+    let (n, incx) = (*n as isize, *incx as isize);
+    let legal = check_args(name, &[(!is_either(uplo, b'U', b'L'), 1), (n < 0, 2), (incx == 0, 5)]);
+    let alpha = *alpha;
+    if !legal || n == 0 || alpha == F::zero() {
+        return;
+    }
+    let x = Vector::new(x, n, incx);
+    let upper = lsame(uplo, b'U');
+    rank_update(upper, false, n, |i, j| packed(ap, upper, n, i, j), |i, j| alpha * x.get(i) * x.get(j));
+}
+
+/// `AP += alpha x x^H` of packed storage with real `alpha`.
+pub unsafe fn hpr<F: Scalar>(
+    name: &str,
+    uplo: *const c_char,
+    n: *const blas_int,
+    alpha: *const F::Real,
+    x: *const F,
+    incx: *const blas_int,
+    ap: *mut F,
+) {
+    let (n, incx) = (*n as isize, *incx as isize);
+    let legal = check_args(name, &[(!is_either(uplo, b'U', b'L'), 1), (n < 0, 2), (incx == 0, 5)]);
+    let alpha = F::from_real(*alpha);
+    if !legal || n == 0 || alpha == F::zero() {
+        return;
+    }
+    let x = Vector::new(x, n, incx);
+    let upper = lsame(uplo, b'U');
+    rank_update(upper, true, n, |i, j| packed(ap, upper, n, i, j), |i, j| alpha * x.get(i) * x.get(j).conj());
+}
+
+/// Update of rank 2: `alpha x y^T + alpha y x^T`, or `alpha x y^H + conj(alpha) y x^H` if `herm`.
+#[inline]
+unsafe fn rank2<F: Scalar>(herm: bool, alpha: F, x: Vector<F>, y: Vector<F>, i: isize, j: isize) -> F {
+    let t1 = alpha * y.get(j).conj_if(herm);
+    let t2 = alpha.conj_if(herm) * x.get(j).conj_if(herm);
+    return x.get(i) * t1 + y.get(i) * t2;
+}
+
+macro_rules! impl_syr2 {
+    ($($name: ident, $herm: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            n: *const blas_int,
+            alpha: *const F,
+            x: *const F,
+            incx: *const blas_int,
+            y: *const F,
+            incy: *const blas_int,
+            a: *mut F,
+            lda: *const blas_int,
+        ) {
+            let (n, incx, incy, lda) = (*n as isize, *incx as isize, *incy as isize, *lda as isize);
+            let legal = check_args(name, &[
+                (!is_either(uplo, b'U', b'L'), 1),
+                (n < 0, 2),
+                (incx == 0, 5),
+                (incy == 0, 7),
+                (lda < n.max(1), 9),
+            ]);
+            let alpha = *alpha;
+            if !legal || n == 0 || alpha == F::zero() {
+                return;
+            }
+            let (x, y, a) = (Vector::new(x, n, incx), Vector::new(y, n, incy), Matrix::new(a, lda));
+            rank_update(lsame(uplo, b'U'), $herm, n, |i, j| a.at(i, j), |i, j| rank2($herm, alpha, x, y, i, j));
+        }
+    )*};
+}
+
+impl_syr2!(syr2, false; her2, true);
+
+macro_rules! impl_spr2 {
+    ($($name: ident, $herm: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            n: *const blas_int,
+            alpha: *const F,
+            x: *const F,
+            incx: *const blas_int,
+            y: *const F,
+            incy: *const blas_int,
+            ap: *mut F,
+        ) {
+            let (n, incx, incy) = (*n as isize, *incx as isize, *incy as isize);
+            let legal = check_args(name, &[
+                (!is_either(uplo, b'U', b'L'), 1),
+                (n < 0, 2),
+                (incx == 0, 5),
+                (incy == 0, 7),
+            ]);
+            let alpha = *alpha;
+            if !legal || n == 0 || alpha == F::zero() {
+                return;
+            }
+            let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+            let upper = lsame(uplo, b'U');
+            let elem = |i, j| packed(ap, upper, n, i, j);
+            rank_update(upper, $herm, n, elem, |i, j| rank2($herm, alpha, x, y, i, j));
+        }
+    )*};
+}
+
+impl_spr2!(spr2, false; hpr2, true);
+
+/* #endregion */
+
+/* #region triangular */
+
+/// Check of `uplo`, `trans`, `diag` of triangular routines; returns flags `(upper, trans, conj, unit)` and
+/// checks of `INFO` 1 to 3.
+unsafe fn tr_flags<F: Scalar>(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+) -> ((bool, bool, bool, bool), [Check; 3]) {
+    let flags = trans_flags::<F>(trans);
+    let checks = [(!is_either(uplo, b'U', b'L'), 1), (flags.is_none(), 2), (!is_either(diag, b'U', b'N'), 3)];
+    let (tr, cj) = flags.unwrap_or_default();
+    return ((lsame(uplo, b'U'), tr, cj, lsame(diag, b'U')), checks);
+}
+
+macro_rules! impl_trmv {
+    ($($name: ident, $solve: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            trans: *const c_char,
+            diag: *const c_char,
+            n: *const blas_int,
+            a: *const F,
+            lda: *const blas_int,
+            x: *mut F,
+            incx: *const blas_int,
+        ) {
+            let (n, lda, incx) = (*n as isize, *lda as isize, *incx as isize);
+            let (flags, checks) = tr_flags::<F>(uplo, trans, diag);
+            let legal = check_args(name, &[checks[0], checks[1], checks[2], (n < 0, 4), (lda < n.max(1), 6), (incx == 0, 8)]);
+            if !legal || n == 0 {
+                return;
+            }
+            let a = Matrix::new(a, lda);
+            tr_kernel(flags, $solve, n, n, |i, j| a.get(i, j), Vector::new(x, n, incx));
+        }
+    )*};
+}
+
+impl_trmv!(trmv, false; trsv, true);
+
+macro_rules! impl_tbmv {
+    ($($name: ident, $solve: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            trans: *const c_char,
+            diag: *const c_char,
+            n: *const blas_int,
+            k: *const blas_int,
+            a: *const F,
+            lda: *const blas_int,
+            x: *mut F,
+            incx: *const blas_int,
+        ) {
+            let (n, k, lda, incx) = (*n as isize, *k as isize, *lda as isize, *incx as isize);
+            let (flags, checks) = tr_flags::<F>(uplo, trans, diag);
+            let legal = check_args(name, &[checks[0], checks[1], checks[2], (n < 0, 4), (k < 0, 5), (lda < k + 1, 7), (incx == 0, 9)]);
+            if !legal || n == 0 {
+                return;
+            }
+            let a = Matrix::new(a, lda);
+            let upper = lsame(uplo, b'U');
+            let elem = |i, j| *band_tri(a, upper, k, i, j);
+            tr_kernel(flags, $solve, n, k, elem, Vector::new(x, n, incx));
+        }
+    )*};
+}
+
+impl_tbmv!(tbmv, false; tbsv, true);
+
+macro_rules! impl_tpmv {
+    ($($name: ident, $solve: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            trans: *const c_char,
+            diag: *const c_char,
+            n: *const blas_int,
+            ap: *const F,
+            x: *mut F,
+            incx: *const blas_int,
+        ) {
+            let (n, incx) = (*n as isize, *incx as isize);
+            let (flags, checks) = tr_flags::<F>(uplo, trans, diag);
+            let legal = check_args(name, &[checks[0], checks[1], checks[2], (n < 0, 4), (incx == 0, 7)]);
+            if !legal || n == 0 {
+                return;
+            }
+            let upper = lsame(uplo, b'U');
+            let elem = |i, j| *packed(ap as *mut F, upper, n, i, j);
+            tr_kernel(flags, $solve, n, n, elem, Vector::new(x, n, incx));
+        }
+    )*};
+}
+
+impl_tpmv!(tpmv, false; tpsv, true);
+
+/* #endregion */
@@ -0,0 +1,413 @@
+//! Level 3 BLAS: matrix-matrix operations, and `?gemmt_`.
+
+use super::level2::{is_either, sym_elem, tr_kernel, trans_flags, TransFlags};
+use super::*;
+use core::convert::identity;
+
+/* #region kernels */
+
+/// Element `(i, l)` of `op(A)`.
+#[inline]
+unsafe fn op_elem<F: Scalar>((trans, conj): (bool, bool), a: Matrix<F>, i: isize, l: isize) -> F {
+    if trans { a.get(l, i) } else { a.get(i, l) }.conj_if(conj)
+}
+
+/// `C := alpha * op(A) op(B) + beta * C`, for rows `rows(j)` of column `j` of `C`.
+pub(crate) unsafe fn gemm_kernel<F: Scalar>(
+    ta: (bool, bool),
+    tb: (bool, bool),
+    (n, k): (isize, isize),
+    alpha: F,
+    a: Matrix<F>,
+    b: Matrix<F>,
+    beta: F,
+    c: Matrix<F>,
+    rows: impl Fn(isize) -> core::ops::Range<isize>,
+) {
+    for j in 0..n {
+        for i in rows(j) {
+            let mut temp = F::zero();
+            if alpha != F::zero() {
+                for l in 0..k {
+                    temp += op_elem(ta, a, i, l) * op_elem(tb, b, l, j);
+                }
+            }
+            c.set(i, j, alpha * temp + scale_beta(beta, c.get(i, j)));
+        }
+    }
+}
+
+/// Checks of `?gemm_` by `INFO` of `?gemm_`; returns flags of `transa` and `transb`.
+pub(crate) unsafe fn gemm_checks<F: Scalar>(
+    transa: *const c_char,
+    transb: *const c_char,
+    (m, n, k): (isize, isize, isize),
+    (lda, ldb, ldc): (isize, isize, isize),
+) -> ((TransFlags, TransFlags), [Check; 8]) {
+    let (fa, fb) = (trans_flags::<F>(transa), trans_flags::<F>(transb));
+    let (ta, tb) = (fa.unwrap_or_default(), fb.unwrap_or_default());
+    let nrowa = if ta.0 { k } else { m };
+    let nrowb = if tb.0 { n } else { k };
+    let checks = [
+        (fa.is_none(), 1),
+        (fb.is_none(), 2),
+        (m < 0, 3),
+        (n < 0, 4),
+        (k < 0, 5),
+        (lda < nrowa.max(1), 8),
+        (ldb < nrowb.max(1), 10),
+        (ldc < m.max(1), 13),
+    ];
+    return ((ta, tb), checks);
+}
+
+/* #endregion */
+
+/* #region general */
+
+pub unsafe fn gemm<F: Scalar>(
+    name: &str,
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const blas_int,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    let (m, n, k) = (*m as isize, *n as isize, *k as isize);
+    let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+    let ((ta, tb), checks) = gemm_checks::<F>(transa, transb, (m, n, k), (lda, ldb, ldc));
+    let (alpha, beta) = (*alpha, *beta);
+    if !check_args(name, &checks) || m == 0 || n == 0 || ((alpha == F::zero() || k == 0) && beta == F::one())
+    {
+        return;
+    }
+    let (a, b, c) = (Matrix::new(a, lda), Matrix::new(b, ldb), Matrix::new(c, ldc));
+    gemm_kernel(ta, tb, (n, k), alpha, a, b, beta, c, |_| 0..m);
+}
+
+/// Triangular part of `C := alpha * op(A) op(B) + beta * C` (BLAS extension).
+pub unsafe fn gemmt<F: Scalar>(
+    name: &str,
+    uplo: *const c_char,
+    transa: *const c_char,
+    transb: *const c_char,
+    n: *const blas_int,
+    k: *const blas_int,
+    alpha: *const F,
+    a: *const F,
+    lda: *const blas_int,
+    b: *const F,
+    ldb: *const blas_int,
+    beta: *const F,
+    c: *mut F,
+    ldc: *const blas_int,
+) {
+    let (n, k) = (*n as isize, *k as isize);
+    let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+    let ((ta, tb), checks) = gemm_checks::<F>(transa, transb, (n, n, k), (lda, ldb, ldc));
+    // arguments are shifted by `uplo`; `m` is absent
+    let legal = check_args(
+        name,
+        &[
+            (!is_either(uplo, b'U', b'L'), 1),
+            (checks[0].0, 2),
+            (checks[1].0, 3),
+            (n < 0, 4),
+            (k < 0, 5),
+            (checks[5].0, 8),
+            (checks[6].0, 10),
+            (checks[7].0, 13),
+        ],
+    );
+    let (alpha, beta) = (*alpha, *beta);
+    if !legal || n == 0 || ((alpha == F::zero() || k == 0) && beta == F::one()) {
+        return;
+    }
+    let upper = lsame(uplo, b'U');
+    let (a, b, c) = (Matrix::new(a, lda), Matrix::new(b, ldb), Matrix::new(c, ldc));
+    let rows = |j: isize| if upper { 0..(j + 1) } else { j..n };
+    gemm_kernel(ta, tb, (n, k), alpha, a, b, beta, c, rows);
+}
+
+/* #endregion */
+
+/* #region symmetric and Hermitian */
+
+macro_rules! impl_symm {
+    ($($name: ident, $herm: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            side: *const c_char,
+            uplo: *const c_char,
+            m: *const blas_int,
+            n: *const blas_int,
+            alpha: *const F,
+            a: *const F,
+            lda: *const blas_int,
+            b: *const F,
+            ldb: *const blas_int,
+            beta: *const F,
+            c: *mut F,
+            ldc: *const blas_int,
+        ) {
+            let (m, n) = (*m as isize, *n as isize);
+            let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+            let left = lsame(side, b'L');
+            let nrowa = if left { m } else { n };
+            let legal = check_args(name, &[
+                (!is_either(side, b'L', b'R'), 1),
+                (!is_either(uplo, b'U', b'L'), 2),
+                (m < 0, 3),
+                (n < 0, 4),
+                (lda < nrowa.max(1), 7),
+                (ldb < m.max(1), 9),
+                (ldc < m.max(1), 12),
+            ]);
+            let (alpha, beta) = (*alpha, *beta);
+            if !legal || m == 0 || n == 0 || (alpha == F::zero() && beta == F::one()) {
+                return;
+            }
+            let upper = lsame(uplo, b'U');
+            let (a, b, c) = (Matrix::new(a, lda), Matrix::new(b, ldb), Matrix::new(c, ldc));
+            let sym = |i, j| sym_elem(upper, $herm, i, j, |i, j| a.get(i, j));
+            for j in 0..n {
+                for i in 0..m {
+                    let mut temp = F::zero();
+                    if alpha != F::zero() {
+                        if left {
+                            for l in 0..m {
+                                temp += sym(i, l) * b.get(l, j);
+                            }
+                        } else {
+                            for l in 0..n {
+                                temp += b.get(i, l) * sym(l, j);
+                            }
+                        }
+                    }
+                    c.set(i, j, alpha * temp + scale_beta(beta, c.get(i, j)));
+                }
+            }
+        }
+    )*};
+}
+
+impl_symm!(symm, false; hemm, true);
+
+/// Check of `trans` of rank-k updates: `N` or `T` for symmetric (also `C` for real), `N` or `C` for Hermitian;
+/// returns whether `A` is transposed.
+unsafe fn rank_k_trans<F: Scalar>(trans: *const c_char, herm: bool) -> Option<bool> {
+    if lsame(trans, b'N') {
+        Some(false)
+    } else if (lsame(trans, b'T') && !herm) || (lsame(trans, b'C') && (herm || !F::COMPLEX)) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Store `val` to `C(i, j)` of rank-k update; diagonal of Hermitian `C` is kept real.
+#[inline]
+unsafe fn store_rank_k<F: Scalar>(c: Matrix<F>, herm: bool, i: isize, j: isize, val: F) {
+    c.set(i, j, if herm && i == j { F::from_real(val.re()) } else { val });
+}
+
+macro_rules! impl_syrk {
+    ($($name: ident, $herm: expr, $alpha: ty, $to_scalar: path);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            trans: *const c_char,
+            n: *const blas_int,
+            k: *const blas_int,
+            alpha: *const $alpha,
+            a: *const F,
+            lda: *const blas_int,
+            beta: *const $alpha,
+            c: *mut F,
+            ldc: *const blas_int,
+        ) {
+            let (n, k, lda, ldc) = (*n as isize, *k as isize, *lda as isize, *ldc as isize);
+            let tr = rank_k_trans::<F>(trans, $herm);
+            let nrowa = if tr == Some(true) { k } else { n };
+            let legal = check_args(name, &[
+                (!is_either(uplo, b'U', b'L'), 1),
+                (tr.is_none(), 2),
+                (n < 0, 3),
+                (k < 0, 4),
+                (lda < nrowa.max(1), 7),
+                (ldc < n.max(1), 10),
+            ]);
+            let (alpha, beta) = ($to_scalar(*alpha), $to_scalar(*beta));
+            if !legal || n == 0 || ((alpha == F::zero() || k == 0) && beta == F::one()) {
+                return;
+            }
+            let (upper, tr) = (lsame(uplo, b'U'), tr.unwrap());
+            let (a, c) = (Matrix::new(a, lda), Matrix::new(c, ldc));
+            for j in 0..n {
+                let rows = if upper { 0..(j + 1) } else { j..n };
+                for i in rows {
+                    let mut temp = F::zero();
+                    if alpha != F::zero() {
+                        for l in 0..k {
+                            temp += if tr {
+                                a.get(l, i).conj_if($herm) * a.get(l, j)
+                            } else {
+                                a.get(i, l) * a.get(j, l).conj_if($herm)
+                            };
+                        }
+                    }
+                    store_rank_k(c, $herm, i, j, alpha * temp + scale_beta(beta, c.get(i, j)));
+                }
+            }
+        }
+    )*};
+}
+
+impl_syrk!(syrk, false, F, identity; herk, true, F::Real, F::from_real);
+
+macro_rules! impl_syr2k {
+    ($($name: ident, $herm: expr, $beta: ty, $to_scalar: path);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            uplo: *const c_char,
+            trans: *const c_char,
+            n: *const blas_int,
+            k: *const blas_int,
+            alpha: *const F,
+            a: *const F,
+            lda: *const blas_int,
+            b: *const F,
+            ldb: *const blas_int,
+            beta: *const $beta,
+            c: *mut F,
+            ldc: *const blas_int,
+        ) {
+            let (n, k) = (*n as isize, *k as isize);
+            let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+            let tr = rank_k_trans::<F>(trans, $herm);
+            let nrowa = if tr == Some(true) { k } else { n };
+            let legal = check_args(name, &[
+                (!is_either(uplo, b'U', b'L'), 1),
+                (tr.is_none(), 2),
+                (n < 0, 3),
+                (k < 0, 4),
+                (lda < nrowa.max(1), 7),
+                (ldb < nrowa.max(1), 9),
+                (ldc < n.max(1), 12),
+            ]);
+            let (alpha, beta) = (*alpha, $to_scalar(*beta));
+            if !legal || n == 0 || ((alpha == F::zero() || k == 0) && beta == F::one()) {
+                return;
+            }
+            let (upper, tr) = (lsame(uplo, b'U'), tr.unwrap());
+            let (a, b, c) = (Matrix::new(a, lda), Matrix::new(b, ldb), Matrix::new(c, ldc));
+            for j in 0..n {
+                let rows = if upper { 0..(j + 1) } else { j..n };
+                for i in rows {
+                    let (mut temp1, mut temp2) = (F::zero(), F::zero());
+                    if alpha != F::zero() {
+                        for l in 0..k {
+                            if tr {
+                                temp1 += a.get(l, i).conj_if($herm) * b.get(l, j);
+                                temp2 += b.get(l, i).conj_if($herm) * a.get(l, j);
+                            } else {
+                                temp1 += a.get(i, l) * b.get(j, l).conj_if($herm);
+                                temp2 += b.get(i, l) * a.get(j, l).conj_if($herm);
+                            }
+                        }
+                    }
+                    let val = alpha * temp1 + alpha.conj_if($herm) * temp2 + scale_beta(beta, c.get(i, j));
+                    store_rank_k(c, $herm, i, j, val);
+                }
+            }
+        }
+    )*};
+}
+
+impl_syr2k!(syr2k, false, F, identity; her2k, true, F::Real, F::from_real);
+
+/* #endregion */
+
+/* #region triangular */
+
+macro_rules! impl_trmm {
+    ($($name: ident, $solve: expr);*) => {$(
+        pub unsafe fn $name<F: Scalar>(
+            name: &str,
+            side: *const c_char,
+            uplo: *const c_char,
+            transa: *const c_char,
+            diag: *const c_char,
+            m: *const blas_int,
+            n: *const blas_int,
+            alpha: *const F,
+            a: *const F,
+            lda: *const blas_int,
+            b: *mut F,
+            ldb: *const blas_int,
+        ) {
+            let (m, n, lda, ldb) = (*m as isize, *n as isize, *lda as isize, *ldb as isize);
+            let left = lsame(side, b'L');
+            let nrowa = if left { m } else { n };
+            let flags = trans_flags::<F>(transa);
+            let legal = check_args(name, &[
+                (!is_either(side, b'L', b'R'), 1),
+                (!is_either(uplo, b'U', b'L'), 2),
+                (flags.is_none(), 3),
+                (!is_either(diag, b'U', b'N'), 4),
+                (m < 0, 5),
+                (n < 0, 6),
+                (lda < nrowa.max(1), 9),
+                (ldb < m.max(1), 11),
+            ]);
+            if !legal || m == 0 || n == 0 {
+                return;
+            }
+            let alpha = *alpha;
+            let (a, b) = (Matrix::new(a, lda), Matrix::new(b, ldb));
+            let scale = |alpha: F| {
+                for j in 0..n {
+                    for i in 0..m {
+                        b.set(i, j, scale_beta(alpha, b.get(i, j)));
+                    }
+                }
+            };
+            if alpha == F::zero() {
+                scale(alpha);
+                return;
+            }
+            // trsm solves `op(A) X = alpha B`; trmm gives `alpha op(A) B`
+            if $solve && alpha != F::one() {
+                scale(alpha);
+            }
+            let (upper, unit) = (lsame(uplo, b'U'), lsame(diag, b'U'));
+            let (tr, cj) = flags.unwrap();
+            let elem = |i, j| a.get(i, j);
+            if left {
+                for j in 0..n {
+                    tr_kernel((upper, tr, cj, unit), $solve, m, m, elem, Vector::new(b.at(0, j), m, 1));
+                }
+            } else {
+                // row of `B op(A)` is `op(A)^T` applied to row of `B`
+                for i in 0..m {
+                    tr_kernel((upper, !tr, cj, unit), $solve, n, n, elem, Vector::new(b.at(i, 0), n, ldb));
+                }
+            }
+            if !$solve && alpha != F::one() {
+                scale(alpha);
+            }
+        }
+    )*};
+}
+
+impl_trmm!(trmm, false; trsm, true);
+
+/* #endregion */
@@ -0,0 +1,261 @@
+//! Pure-Rust reference implementation of BLAS (crate feature `reference`).
+//!
+//! With this feature, functions in [`ffi::blas`](crate::ffi::blas) are not linked to a BLAS library, but
+//! implemented in Rust with the same signatures, so this crate works without any system BLAS. All routines bound
+//! by this crate are provided, including BLAS extensions `?gemmt_`, `?gemm_batch_`, `?gemm_batch_strided_`,
+//! `?omatcopy_` and `?imatcopy_`.
+//!
+//! Implementations follow netlib reference BLAS semantics:
+//! - illegal arguments are reported through `xerbla` with the same `INFO` as netlib; with crate feature `xerbla`
+//!   they are recorded and returned by wrappers as [`BLASError::LibraryArgument`](crate::util::BLASError),
+//!   otherwise the process is aborted, as netlib `XERBLA` stops the program;
+//! - quick returns (such as `m == 0`, or `alpha == 0` and `beta == 1`) do not touch output;
+//! - negative increments traverse vectors backwards from the last element;
+//! - `beta == 0` overwrites output without reading it (NaN in output is not propagated);
+//! - Hermitian routines use only the real part of diagonal, and set imaginary part of output diagonal to zero.
+//!
+//! Implementations are straightforward loops, and are not intended for performance.
+//!
+//! Entry functions in `entry.rs` are generated from `blas.rs` by `scripts/reference_gen.py`.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::missing_safety_doc)]
+
+extern crate alloc;
+
+use core::ffi::c_char;
+use core::ops::{AddAssign, DivAssign, MulAssign, Neg, SubAssign};
+use num_complex::*;
+use num_traits::{Float, Num, One, Zero};
+
+#[cfg(not(feature = "ilp64"))]
+pub type blas_int = i32;
+#[cfg(feature = "ilp64")]
+pub type blas_int = i64;
+
+pub type c32 = Complex<f32>;
+pub type c64 = Complex<f64>;
+
+mod entry;
+mod extension;
+mod level1;
+mod level2;
+mod level3;
+
+pub use entry::*;
+
+/* #region scalar */
+
+/// Scalar type of reference implementation.
+pub(crate) trait Scalar:
+    Num + Copy + Neg<Output = Self> + AddAssign + SubAssign + MulAssign + DivAssign + PartialEq + 'static
+{
+    type Real: Scalar<Real = Self::Real> + Float;
+    const COMPLEX: bool;
+    fn conj(self) -> Self;
+    fn re(self) -> Self::Real;
+    fn im(self) -> Self::Real;
+    fn from_real(x: Self::Real) -> Self;
+    fn mul_real(self, x: Self::Real) -> Self;
+    fn div_real(self, x: Self::Real) -> Self;
+
+    /// `|re| + |im|`, used by `i?amax` and `?asum`.
+    #[inline]
+    fn abs1(self) -> Self::Real {
+        self.re().abs() + self.im().abs()
+    }
+
+    /// Conjugate if `conj` is true.
+    #[inline]
+    fn conj_if(self, conj: bool) -> Self {
+        if conj {
+            self.conj()
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! impl_scalar_real {
+    ($ty: ty) => {
+        impl Scalar for $ty {
+            type Real = $ty;
+            const COMPLEX: bool = false;
+            #[inline]
+            fn conj(self) -> Self {
+                self
+            }
+            #[inline]
+            fn re(self) -> Self::Real {
+                self
+            }
+            #[inline]
+            fn im(self) -> Self::Real {
+                0.0
+            }
+            #[inline]
+            fn from_real(x: Self::Real) -> Self {
+                x
+            }
+            #[inline]
+            fn mul_real(self, x: Self::Real) -> Self {
+                self * x
+            }
+            #[inline]
+            fn div_real(self, x: Self::Real) -> Self {
+                self / x
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_complex {
+    ($ty: ty, $real: ty) => {
+        impl Scalar for $ty {
+            type Real = $real;
+            const COMPLEX: bool = true;
+            #[inline]
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+            #[inline]
+            fn re(self) -> Self::Real {
+                self.re
+            }
+            #[inline]
+            fn im(self) -> Self::Real {
+                self.im
+            }
+            #[inline]
+            fn from_real(x: Self::Real) -> Self {
+                Complex::new(x, 0.0)
+            }
+            #[inline]
+            fn mul_real(self, x: Self::Real) -> Self {
+                Complex::new(self.re * x, self.im * x)
+            }
+            #[inline]
+            fn div_real(self, x: Self::Real) -> Self {
+                Complex::new(self.re / x, self.im / x)
+            }
+        }
+    };
+}
+
+impl_scalar_real!(f32);
+impl_scalar_real!(f64);
+impl_scalar_complex!(c32, f32);
+impl_scalar_complex!(c64, f64);
+
+/* #endregion */
+
+/* #region argument helpers */
+
+/// Whether character argument equals `c` (case-insensitive), as netlib `LSAME`.
+#[inline]
+pub(crate) unsafe fn lsame(arg: *const c_char, c: u8) -> bool {
+    (*arg as u8).eq_ignore_ascii_case(&c)
+}
+
+/// Report illegal argument `info` (1-based position) of routine `name`, as netlib `XERBLA`.
+///
+/// With crate feature `xerbla`, it is recorded by [`xerbla_`](crate::util::xerbla_); otherwise the process is
+/// aborted (panic in `extern "C"` function).
+pub(crate) fn xerbla(name: &str, info: blas_int) {
+    #[cfg(feature = "xerbla")]
+    unsafe {
+        crate::util::xerbla_(name.as_ptr() as *const c_char, &info, name.len());
+    }
+    #[cfg(not(feature = "xerbla"))]
+    panic!("** On entry to {name} parameter number {info} had an illegal value");
+}
+
+/// Check of argument: `(condition of illegal, INFO)`.
+pub(crate) type Check = (bool, blas_int);
+
+/// Check arguments by list of checks, reporting the first illegal one.
+///
+/// Returns whether all arguments are legal.
+#[inline]
+pub(crate) fn check_args(name: &str, checks: &[Check]) -> bool {
+    match checks.iter().find(|&&(illegal, _)| illegal) {
+        Some(&(_, info)) => {
+            xerbla(name, info);
+            false
+        },
+        None => true,
+    }
+}
+
+/// Vector of BLAS convention: `n` elements with increment `inc` from pointer `ptr`, where negative increment
+/// starts from the last element (lowest address is given by `ptr`).
+#[derive(Clone, Copy)]
+pub(crate) struct Vector<F> {
+    ptr: *mut F,
+    start: isize,
+    inc: isize,
+}
+
+impl<F: Copy> Vector<F> {
+    #[inline]
+    pub(crate) fn new(ptr: *const F, n: isize, inc: isize) -> Self {
+        let start = if inc < 0 { -(n - 1).max(0) * inc } else { 0 };
+        Vector { ptr: ptr as *mut F, start, inc }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn at(&self, i: isize) -> *mut F {
+        self.ptr.offset(self.start + i * self.inc)
+    }
+
+    #[inline]
+    pub(crate) unsafe fn get(&self, i: isize) -> F {
+        *self.at(i)
+    }
+
+    #[inline]
+    pub(crate) unsafe fn set(&self, i: isize, val: F) {
+        *self.at(i) = val;
+    }
+}
+
+/// Column-major matrix of leading dimension `ld`.
+#[derive(Clone, Copy)]
+pub(crate) struct Matrix<F> {
+    ptr: *mut F,
+    ld: isize,
+}
+
+impl<F: Copy> Matrix<F> {
+    #[inline]
+    pub(crate) fn new(ptr: *const F, ld: isize) -> Self {
+        Matrix { ptr: ptr as *mut F, ld }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn at(&self, i: isize, j: isize) -> *mut F {
+        self.ptr.offset(i + j * self.ld)
+    }
+
+    #[inline]
+    pub(crate) unsafe fn get(&self, i: isize, j: isize) -> F {
+        *self.at(i, j)
+    }
+
+    #[inline]
+    pub(crate) unsafe fn set(&self, i: isize, j: isize, val: F) {
+        *self.at(i, j) = val;
+    }
+}
+
+/// `beta * y`, where `beta == 0` gives zero even if `y` is NaN.
+#[inline]
+pub(crate) fn scale_beta<F: Scalar>(beta: F, y: F) -> F {
+    if beta == F::zero() {
+        F::zero()
+    } else {
+        beta * y
+    }
+}
+
+/* #endregion */
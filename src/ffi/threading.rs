@@ -0,0 +1,64 @@
+//! Threading control of BLAS vendors, looked up at runtime.
+//!
+//! These functions are not part of BLAS standard, so they are not linked. Instead, symbols are looked up by
+//! `dlsym` in the global scope of the process (or in the loaded library with crate feature `dynamic`), as
+//! [`backend_info`](crate::backend::backend_info) does; each function gives `None` if the symbol is not found.
+//!
+//! OpenBLAS built with `SYMBOLSUFFIX` also suffixes these functions (such as `openblas_set_num_threads64_` of
+//! Debian `libopenblas64`, with crate feature `suffix64_`), so symbol with [`CBLAS_SYMBOL_SUFFIX`] is looked up
+//! before the plain one.
+
+extern crate alloc;
+
+use super::CBLAS_SYMBOL_SUFFIX;
+use crate::backend::lookup;
+use alloc::ffi::CString;
+use core::ffi::{c_int, c_void};
+
+/// Address of vendor function `name`, with or without suffix; `None` if not found.
+fn lookup_vendor(name: &str) -> Option<*mut c_void> {
+    if !CBLAS_SYMBOL_SUFFIX.is_empty() {
+        let symbol = CString::new(alloc::format!("{name}{CBLAS_SYMBOL_SUFFIX}")).ok()?;
+        if let Some(ptr) = lookup(&symbol) {
+            return Some(ptr);
+        }
+    }
+    return lookup(&CString::new(name).ok()?);
+}
+
+macro_rules! vendor_fn {
+    ($name:ident: $ty:ty) => {
+        #[doc = concat!("Function `", stringify!($name), "` of linked library; `None` if not found.")]
+        pub fn $name() -> Option<$ty> {
+            let ptr = lookup_vendor(stringify!($name))?;
+            return Some(unsafe { core::mem::transmute::<*mut c_void, $ty>(ptr) });
+        }
+    };
+}
+
+/* #region OpenBLAS */
+
+vendor_fn!(openblas_set_num_threads: unsafe extern "C" fn(num_threads: c_int));
+vendor_fn!(openblas_get_num_threads: unsafe extern "C" fn() -> c_int);
+
+/* #endregion */
+
+/* #region MKL */
+
+vendor_fn!(mkl_set_num_threads_local: unsafe extern "C" fn(nt: c_int) -> c_int);
+vendor_fn!(mkl_get_max_threads: unsafe extern "C" fn() -> c_int);
+vendor_fn!(mkl_cbwr_set: unsafe extern "C" fn(settings: c_int) -> c_int);
+vendor_fn!(mkl_cbwr_get: unsafe extern "C" fn(option: c_int) -> c_int);
+
+pub const MKL_CBWR_ALL: c_int = !0;
+pub const MKL_CBWR_COMPATIBLE: c_int = 3;
+pub const MKL_CBWR_SUCCESS: c_int = 0;
+
+/* #endregion */
+
+/* #region BLIS */
+
+vendor_fn!(bli_thread_set_num_threads: unsafe extern "C" fn(n_threads: i64));
+vendor_fn!(bli_thread_get_num_threads: unsafe extern "C" fn() -> i64);
+
+/* #endregion */
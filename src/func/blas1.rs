@@ -0,0 +1,65 @@
+use crate::blas1::asum::{ASUMNum, ASUM};
+use crate::blas1::axpy::{AXPYNum, AXPY};
+use crate::blas1::dotc::{DOTCNum, DOTC};
+use crate::blas1::dotu::{DOTUNum, DOTU};
+use crate::blas1::iamax::{IAMAXNum, IAMAX};
+use crate::blas1::nrm2::{NRM2Num, NRM2};
+use crate::blas1::scal::{SCALNum, SCAL};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/// `z = dot(x, y)` (`dotu` for complex types)
+pub fn dot<F>(x: ArrayView1<F>, y: ArrayView1<F>) -> Result<F, BLASError>
+where
+    F: DOTUNum,
+{
+    return DOTU::default().x(x).y(y).run();
+}
+
+/// `z = dotc(x, y)`, conjugating `x`
+pub fn dotc<F>(x: ArrayView1<F>, y: ArrayView1<F>) -> Result<F, BLASError>
+where
+    F: DOTCNum,
+{
+    return DOTC::default().x(x).y(y).run();
+}
+
+/// `z = axpy(x, y, a=1)`, overwriting `y` by `a * x + y`
+pub fn axpy<'y, F>(x: ArrayView1<F>, y: ArrayViewMut1<'y, F>, a: F) -> Result<ArrayOut1<'y, F>, BLASError>
+where
+    F: AXPYNum,
+{
+    return AXPY::default().x(x).y(y).alpha(a).run();
+}
+
+/// `x = scal(a, x)`, overwriting `x` by `a * x`
+pub fn scal<'x, F>(a: F, x: ArrayViewMut1<'x, F>) -> Result<ArrayOut1<'x, F>, BLASError>
+where
+    F: SCALNum,
+{
+    return SCAL::default().x(x).alpha(a).run();
+}
+
+/// `n = nrm2(x)`
+pub fn nrm2<F>(x: ArrayView1<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: NRM2Num,
+{
+    return NRM2::default().x(x).run();
+}
+
+/// `s = asum(x)`
+pub fn asum<F>(x: ArrayView1<F>) -> Result<F::RealFloat, BLASError>
+where
+    F: ASUMNum,
+{
+    return ASUM::default().x(x).run();
+}
+
+/// `k = iamax(x)`
+pub fn iamax<F>(x: ArrayView1<F>) -> Result<usize, BLASError>
+where
+    F: IAMAXNum,
+{
+    return IAMAX::default().x(x).run();
+}
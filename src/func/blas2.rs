@@ -0,0 +1,239 @@
+use crate::blas2::gemv::{GEMVNum, GEMV};
+use crate::blas2::ger::{GERNum, GER};
+use crate::blas2::gerc::{GERCNum, GERC};
+use crate::blas2::hemv::{HEMVNum, HEMV};
+use crate::blas2::her::{HERNum, HER};
+use crate::blas2::her2::{HER2Num, HER2};
+use crate::blas2::trmv::{TRMVNum, TRMV};
+use crate::blas2::trsv::{TRSVNum, TRSV};
+use crate::func::*;
+use ndarray::prelude::*;
+
+/* #region gemv, hemv */
+
+/// Optional arguments of [`gemv`].
+#[derive(Debug)]
+pub struct GEMVKwargs<'y, F> {
+    pub beta: F,
+    pub y: Option<ArrayViewMut1<'y, F>>,
+    pub trans: i32,
+    pub overwrite_y: bool,
+}
+
+impl<F> Default for GEMVKwargs<'_, F>
+where
+    F: BLASFloat,
+{
+    fn default() -> Self {
+        Self { beta: F::zero(), y: None, trans: 0, overwrite_y: false }
+    }
+}
+
+/// `y = gemv(alpha, a, x, beta=0, y=None, trans=0, overwrite_y=0)`
+///
+/// General matrix-vector multiply: `alpha * op(A) x + beta * y`.
+pub fn gemv<'y, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    x: ArrayView1<F>,
+    kwargs: GEMVKwargs<'y, F>,
+) -> Result<ArrayOut1<'y, F>, BLASError>
+where
+    F: GEMVNum,
+{
+    let GEMVKwargs { beta, y, trans, overwrite_y } = kwargs;
+    return run_output!(
+        GEMV::default().a(a).x(x).alpha(alpha).beta(beta).trans(trans_code(trans)),
+        y = Option y,
+        overwrite_y
+    );
+}
+
+/// Optional arguments of [`hemv`].
+#[derive(Debug)]
+pub struct HEMVKwargs<'y, F> {
+    pub beta: F,
+    pub y: Option<ArrayViewMut1<'y, F>>,
+    pub lower: i32,
+    pub overwrite_y: bool,
+}
+
+impl<F> Default for HEMVKwargs<'_, F>
+where
+    F: BLASFloat,
+{
+    fn default() -> Self {
+        Self { beta: F::zero(), y: None, lower: 0, overwrite_y: false }
+    }
+}
+
+/// `y = hemv(alpha, a, x, beta=0, y=None, lower=0, overwrite_y=0)`
+///
+/// Hermitian (symmetric for real types) matrix-vector multiply: `alpha * A x + beta * y`.
+pub fn hemv<'y, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    x: ArrayView1<F>,
+    kwargs: HEMVKwargs<'y, F>,
+) -> Result<ArrayOut1<'y, F>, BLASError>
+where
+    F: HEMVNum,
+{
+    let HEMVKwargs { beta, y, lower, overwrite_y } = kwargs;
+    return run_output!(
+        HEMV::default().a(a).x(x).alpha(alpha).beta(beta).uplo(lower_code(lower)),
+        y = Option y,
+        overwrite_y
+    );
+}
+
+/* #endregion */
+
+/* #region trmv, trsv */
+
+/// Optional arguments of [`trmv`] and [`trsv`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TRMVKwargs {
+    pub lower: i32,
+    pub trans: i32,
+    pub diag: i32,
+    pub overwrite_x: bool,
+}
+
+/// `x = trmv(a, x, lower=0, trans=0, diag=0, overwrite_x=0)`
+///
+/// Triangular matrix-vector multiply: `op(A) x`.
+pub fn trmv<'x, F>(
+    a: ArrayView2<F>,
+    x: ArrayViewMut1<'x, F>,
+    kwargs: TRMVKwargs,
+) -> Result<ArrayOut1<'x, F>, BLASError>
+where
+    F: TRMVNum,
+{
+    let TRMVKwargs { lower, trans, diag, overwrite_x } = kwargs;
+    return run_output!(
+        TRMV::default().a(a).uplo(lower_code(lower)).trans(trans_code(trans)).diag(diag_code(diag)),
+        x = x,
+        overwrite_x
+    );
+}
+
+/// `x = trsv(a, x, lower=0, trans=0, diag=0, overwrite_x=0)`
+///
+/// Triangular solve: `op(A) x' = x`.
+pub fn trsv<'x, F>(
+    a: ArrayView2<F>,
+    x: ArrayViewMut1<'x, F>,
+    kwargs: TRMVKwargs,
+) -> Result<ArrayOut1<'x, F>, BLASError>
+where
+    F: TRSVNum,
+{
+    let TRMVKwargs { lower, trans, diag, overwrite_x } = kwargs;
+    return run_output!(
+        TRSV::default().a(a).uplo(lower_code(lower)).trans(trans_code(trans)).diag(diag_code(diag)),
+        x = x,
+        overwrite_x
+    );
+}
+
+/* #endregion */
+
+/* #region ger, gerc, her, her2 */
+
+/// Optional arguments of [`ger`] and [`gerc`].
+#[derive(Debug)]
+pub struct GERKwargs<'a, F> {
+    pub a: Option<ArrayViewMut2<'a, F>>,
+    pub overwrite_a: bool,
+}
+
+impl<F> Default for GERKwargs<'_, F> {
+    fn default() -> Self {
+        Self { a: None, overwrite_a: false }
+    }
+}
+
+/// `a = ger(alpha, x, y, a=None, overwrite_a=0)` (`geru` for complex types)
+///
+/// Rank-1 update: `alpha * x y^T + A`.
+pub fn ger<'a, F>(
+    alpha: F,
+    x: ArrayView1<F>,
+    y: ArrayView1<F>,
+    kwargs: GERKwargs<'a, F>,
+) -> Result<ArrayOut2<'a, F>, BLASError>
+where
+    F: GERNum,
+{
+    let GERKwargs { a, overwrite_a } = kwargs;
+    return run_output!(GER::default().x(x).y(y).alpha(alpha), a = Option a, overwrite_a);
+}
+
+/// `a = gerc(alpha, x, y, a=None, overwrite_a=0)`
+///
+/// Rank-1 update: `alpha * x y^H + A`.
+pub fn gerc<'a, F>(
+    alpha: F,
+    x: ArrayView1<F>,
+    y: ArrayView1<F>,
+    kwargs: GERKwargs<'a, F>,
+) -> Result<ArrayOut2<'a, F>, BLASError>
+where
+    F: GERCNum,
+{
+    let GERKwargs { a, overwrite_a } = kwargs;
+    return run_output!(GERC::default().x(x).y(y).alpha(alpha), a = Option a, overwrite_a);
+}
+
+/// Optional arguments of [`her`] and [`her2`].
+#[derive(Debug)]
+pub struct HERKwargs<'a, F> {
+    pub lower: i32,
+    pub a: Option<ArrayViewMut2<'a, F>>,
+    pub overwrite_a: bool,
+}
+
+impl<F> Default for HERKwargs<'_, F> {
+    fn default() -> Self {
+        Self { lower: 0, a: None, overwrite_a: false }
+    }
+}
+
+/// `a = her(alpha, x, lower=0, a=None, overwrite_a=0)`
+///
+/// Hermitian (symmetric for real types) rank-1 update: `alpha * x x^H + A`.
+pub fn her<'a, F>(
+    alpha: F::RealFloat,
+    x: ArrayView1<F>,
+    kwargs: HERKwargs<'a, F>,
+) -> Result<ArrayOut2<'a, F>, BLASError>
+where
+    F: HERNum,
+{
+    let HERKwargs { lower, a, overwrite_a } = kwargs;
+    return run_output!(HER::default().x(x).alpha(alpha).uplo(lower_code(lower)), a = Option a, overwrite_a);
+}
+
+/// `a = her2(alpha, x, y, lower=0, a=None, overwrite_a=0)`
+///
+/// Hermitian (symmetric for real types) rank-2 update: `alpha * x y^H + conj(alpha) * y x^H + A`.
+pub fn her2<'a, F>(
+    alpha: F,
+    x: ArrayView1<F>,
+    y: ArrayView1<F>,
+    kwargs: HERKwargs<'a, F>,
+) -> Result<ArrayOut2<'a, F>, BLASError>
+where
+    F: HER2Num,
+{
+    let HERKwargs { a, lower, overwrite_a } = kwargs;
+    return run_output!(
+        HER2::default().x(x).y(y).alpha(alpha).uplo(lower_code(lower)),
+        a = Option a,
+        overwrite_a
+    );
+}
+
+/* #endregion */
@@ -0,0 +1,293 @@
+use crate::blas3::gemm::{GEMMNum, GEMM};
+use crate::blas3::hemm::{HEMMNum, HEMM};
+use crate::blas3::her2k::{HER2KNum, HER2K};
+use crate::blas3::herk::{HERKNum, HERK};
+use crate::blas3::symm::{SYMMNum, SYMM};
+use crate::blas3::syr2k::{SYR2KNum, SYR2K};
+use crate::blas3::syrk::{SYRKNum, SYRK};
+use crate::blas3::trmm::{TRMMNum, TRMM};
+use crate::blas3::trsm::{TRSMNum, TRSM};
+use crate::func::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+/* #region gemm */
+
+/// Optional arguments of [`gemm`].
+#[derive(Debug)]
+pub struct GEMMKwargs<'c, F> {
+    pub beta: F,
+    pub c: Option<ArrayViewMut2<'c, F>>,
+    pub trans_a: i32,
+    pub trans_b: i32,
+    pub overwrite_c: bool,
+}
+
+impl<F> Default for GEMMKwargs<'_, F>
+where
+    F: BLASFloat,
+{
+    fn default() -> Self {
+        Self { beta: F::zero(), c: None, trans_a: 0, trans_b: 0, overwrite_c: false }
+    }
+}
+
+/// `c = gemm(alpha, a, b, beta=0, c=None, trans_a=0, trans_b=0, overwrite_c=0)`
+///
+/// General matrix-matrix multiply: `alpha * op(A) op(B) + beta * C`.
+pub fn gemm<'c, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    b: ArrayView2<F>,
+    kwargs: GEMMKwargs<'c, F>,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: GEMMNum,
+{
+    let GEMMKwargs { beta, c, trans_a, trans_b, overwrite_c } = kwargs;
+    return run_output!(
+        GEMM::default()
+            .a(a)
+            .b(b)
+            .alpha(alpha)
+            .beta(beta)
+            .transa(trans_code(trans_a))
+            .transb(trans_code(trans_b)),
+        c = Option c,
+        overwrite_c
+    );
+}
+
+/* #endregion */
+
+/* #region symm, hemm */
+
+/// Optional arguments of [`symm`] and [`hemm`].
+#[derive(Debug)]
+pub struct SYMMKwargs<'c, F> {
+    pub beta: F,
+    pub c: Option<ArrayViewMut2<'c, F>>,
+    pub side: i32,
+    pub lower: i32,
+    pub overwrite_c: bool,
+}
+
+impl<F> Default for SYMMKwargs<'_, F>
+where
+    F: BLASFloat,
+{
+    fn default() -> Self {
+        Self { beta: F::zero(), c: None, side: 0, lower: 0, overwrite_c: false }
+    }
+}
+
+/// `c = symm(alpha, a, b, beta=0, c=None, side=0, lower=0, overwrite_c=0)`
+///
+/// Symmetric matrix-matrix multiply: `alpha * A B + beta * C` (left) or `alpha * B A + beta * C` (right).
+pub fn symm<'c, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    b: ArrayView2<F>,
+    kwargs: SYMMKwargs<'c, F>,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: SYMMNum,
+{
+    let SYMMKwargs { beta, c, side, lower, overwrite_c } = kwargs;
+    return run_output!(
+        SYMM::default().a(a).b(b).alpha(alpha).beta(beta).side(side_code(side)).uplo(lower_code(lower)),
+        c = Option c,
+        overwrite_c
+    );
+}
+
+/// `c = hemm(alpha, a, b, beta=0, c=None, side=0, lower=0, overwrite_c=0)`
+///
+/// Hermitian matrix-matrix multiply: `alpha * A B + beta * C` (left) or `alpha * B A + beta * C` (right).
+pub fn hemm<'c, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    b: ArrayView2<F>,
+    kwargs: SYMMKwargs<'c, F>,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: HEMMNum,
+{
+    let SYMMKwargs { beta, c, side, lower, overwrite_c } = kwargs;
+    return run_output!(
+        HEMM::default().a(a).b(b).alpha(alpha).beta(beta).side(side_code(side)).uplo(lower_code(lower)),
+        c = Option c,
+        overwrite_c
+    );
+}
+
+/* #endregion */
+
+/* #region syrk, herk, syr2k, her2k */
+
+/// Optional arguments of [`syrk`], [`herk`], [`syr2k`] and [`her2k`].
+///
+/// Type of `beta` is `F` for `syrk` and `syr2k`, and `F::RealFloat` for `herk` and `her2k`.
+#[derive(Debug)]
+pub struct SYRKKwargs<'c, F, B> {
+    pub beta: B,
+    pub c: Option<ArrayViewMut2<'c, F>>,
+    pub trans: i32,
+    pub lower: i32,
+    pub overwrite_c: bool,
+}
+
+impl<F, B> Default for SYRKKwargs<'_, F, B>
+where
+    B: Zero,
+{
+    fn default() -> Self {
+        Self { beta: B::zero(), c: None, trans: 0, lower: 0, overwrite_c: false }
+    }
+}
+
+/// `c = syrk(alpha, a, beta=0, c=None, trans=0, lower=0, overwrite_c=0)`
+///
+/// Symmetric rank-k update: `alpha * A A^T + beta * C` (`trans=0`) or `alpha * A^T A + beta * C` (`trans=1`).
+pub fn syrk<'c, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    kwargs: SYRKKwargs<'c, F, F>,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: SYRKNum,
+{
+    let SYRKKwargs { beta, c, trans, lower, overwrite_c } = kwargs;
+    return run_output!(
+        SYRK::default().a(a).alpha(alpha).beta(beta).trans(trans_code(trans)).uplo(lower_code(lower)),
+        c = Option c,
+        overwrite_c
+    );
+}
+
+/// `c = herk(alpha, a, beta=0, c=None, trans=0, lower=0, overwrite_c=0)`
+///
+/// Hermitian rank-k update: `alpha * A A^H + beta * C` (`trans=0`) or `alpha * A^H A + beta * C` (`trans=2`).
+pub fn herk<'c, F>(
+    alpha: F::RealFloat,
+    a: ArrayView2<F>,
+    kwargs: SYRKKwargs<'c, F, F::RealFloat>,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: HERKNum,
+{
+    let SYRKKwargs { beta, c, trans, lower, overwrite_c } = kwargs;
+    return run_output!(
+        HERK::default().a(a).alpha(alpha).beta(beta).trans(trans_code(trans)).uplo(lower_code(lower)),
+        c = Option c,
+        overwrite_c
+    );
+}
+
+/// `c = syr2k(alpha, a, b, beta=0, c=None, trans=0, lower=0, overwrite_c=0)`
+///
+/// Symmetric rank-2k update: `alpha * (A B^T + B A^T) + beta * C` (`trans=0`).
+pub fn syr2k<'c, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    b: ArrayView2<F>,
+    kwargs: SYRKKwargs<'c, F, F>,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: SYR2KNum,
+{
+    let SYRKKwargs { beta, c, trans, lower, overwrite_c } = kwargs;
+    return run_output!(
+        SYR2K::default().a(a).b(b).alpha(alpha).beta(beta).trans(trans_code(trans)).uplo(lower_code(lower)),
+        c = Option c,
+        overwrite_c
+    );
+}
+
+/// `c = her2k(alpha, a, b, beta=0, c=None, trans=0, lower=0, overwrite_c=0)`
+///
+/// Hermitian rank-2k update: `alpha * A B^H + conj(alpha) * B A^H + beta * C` (`trans=0`).
+pub fn her2k<'c, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    b: ArrayView2<F>,
+    kwargs: SYRKKwargs<'c, F, F::RealFloat>,
+) -> Result<ArrayOut2<'c, F>, BLASError>
+where
+    F: HER2KNum,
+{
+    let SYRKKwargs { beta, c, trans, lower, overwrite_c } = kwargs;
+    return run_output!(
+        HER2K::default().a(a).b(b).alpha(alpha).beta(beta).trans(trans_code(trans)).uplo(lower_code(lower)),
+        c = Option c,
+        overwrite_c
+    );
+}
+
+/* #endregion */
+
+/* #region trmm, trsm */
+
+/// Optional arguments of [`trmm`] and [`trsm`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TRMMKwargs {
+    pub side: i32,
+    pub lower: i32,
+    pub trans_a: i32,
+    pub diag: i32,
+    pub overwrite_b: bool,
+}
+
+/// `b = trmm(alpha, a, b, side=0, lower=0, trans_a=0, diag=0, overwrite_b=0)`
+///
+/// Triangular matrix-matrix multiply: `alpha * op(A) B` (left) or `alpha * B op(A)` (right).
+pub fn trmm<'b, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    b: ArrayViewMut2<'b, F>,
+    kwargs: TRMMKwargs,
+) -> Result<ArrayOut2<'b, F>, BLASError>
+where
+    F: TRMMNum,
+{
+    let TRMMKwargs { side, lower, trans_a, diag, overwrite_b } = kwargs;
+    return run_output!(
+        TRMM::default()
+            .a(a)
+            .alpha(alpha)
+            .side(side_code(side))
+            .uplo(lower_code(lower))
+            .transa(trans_code(trans_a))
+            .diag(diag_code(diag)),
+        b = b,
+        overwrite_b
+    );
+}
+
+/// `x = trsm(alpha, a, b, side=0, lower=0, trans_a=0, diag=0, overwrite_b=0)`
+///
+/// Triangular solve: `op(A) X = alpha * B` (left) or `X op(A) = alpha * B` (right).
+pub fn trsm<'b, F>(
+    alpha: F,
+    a: ArrayView2<F>,
+    b: ArrayViewMut2<'b, F>,
+    kwargs: TRMMKwargs,
+) -> Result<ArrayOut2<'b, F>, BLASError>
+where
+    F: TRSMNum,
+{
+    let TRMMKwargs { side, lower, trans_a, diag, overwrite_b } = kwargs;
+    return run_output!(
+        TRSM::default()
+            .a(a)
+            .alpha(alpha)
+            .side(side_code(side))
+            .uplo(lower_code(lower))
+            .transa(trans_code(trans_a))
+            .diag(diag_code(diag)),
+        b = b,
+        overwrite_b
+    );
+}
+
+/* #endregion */
@@ -0,0 +1,102 @@
+//! Function-style API, mirroring `scipy.linalg.blas`.
+//!
+//! See also [scipy document](https://docs.scipy.org/doc/scipy/reference/linalg.blas.html). Required arguments are
+//! positional, in the same order as scipy. Optional arguments are given by keyword structs with [`Default`] (such
+//! as [`GEMMKwargs`]), using scipy's integer codes:
+//! - `trans`, `trans_a`, `trans_b`: 0 for no transpose, 1 for transpose, 2 for conjugate transpose;
+//! - `lower`: 0 for upper triangular, 1 for lower triangular;
+//! - `side`: 0 for left (`op(A) B`), 1 for right (`B op(A)`);
+//! - `diag`: 0 for non-unit, 1 for unit triangular.
+//!
+//! Invalid codes raise [`BLASError::InvalidFlag`](crate::util::BLASError).
+//!
+//! As scipy, the output array given in keyword struct (such as `c` of [`gemm`]) is not modified, unless
+//! `overwrite_c` (`overwrite_y`, etc.) is set; then the result is written to that array. Level-1 functions
+//! that update their input ([`axpy`], [`scal`]) always overwrite it.
+//!
+//! ```rust
+//! use blas_array2::func::*;
+//! use ndarray::prelude::*;
+//!
+//! // python: c = dgemm(2.0, a, b, trans_b=1)
+//! let a = Array2::<f64>::ones((3, 4));
+//! let b = Array2::<f64>::ones((5, 4));
+//! let c = gemm(2.0, a.view(), b.view(), GEMMKwargs { trans_b: 1, ..Default::default() }).unwrap();
+//! assert_eq!(c.view()[[0, 0]], 8.0);
+//! ```
+//!
+//! For real types, [`hemv`], [`her`], [`her2`] compute as `symv`, `syr`, `syr2` of scipy.
+
+pub mod blas1;
+pub mod blas2;
+pub mod blas3;
+
+pub use blas1::*;
+pub use blas2::*;
+pub use blas3::*;
+
+use crate::util::*;
+
+/* #region scipy codes */
+
+fn trans_code(trans: i32) -> BLASTranspose {
+    match trans {
+        0 => BLASNoTrans,
+        1 => BLASTrans,
+        2 => BLASConjTrans,
+        _ => BLASTranspose::Undefined,
+    }
+}
+
+fn lower_code(lower: i32) -> BLASUpLo {
+    match lower {
+        0 => BLASUpper,
+        1 => BLASLower,
+        _ => BLASUpLo::Undefined,
+    }
+}
+
+fn side_code(side: i32) -> BLASSide {
+    match side {
+        0 => BLASLeft,
+        1 => BLASRight,
+        _ => BLASSide::Undefined,
+    }
+}
+
+fn diag_code(diag: i32) -> BLASDiag {
+    match diag {
+        0 => BLASNonUnit,
+        1 => BLASUnit,
+        _ => BLASDiag::Undefined,
+    }
+}
+
+/* #endregion */
+
+/* #region macros */
+
+/// Run wrapper `$builder` with output `$c` given by setter `$set`.
+///
+/// Unless `$overwrite`, output is computed on a copy of `$c`, which is returned as owned array.
+macro_rules! run_output {
+    ($builder:expr, $set:ident = Option $c:expr, $overwrite:expr) => {
+        match $c {
+            None => $builder.run(),
+            Some(c) => run_output!($builder, $set = c, $overwrite),
+        }
+    };
+    ($builder:expr, $set:ident = $c:expr, $overwrite:expr) => {
+        if $overwrite {
+            Ok($builder.$set($c).run()?.clone_to_view_mut())
+        } else {
+            let mut c_copy = $c.to_owned();
+            $builder.$set(c_copy.view_mut()).run()?.clone_to_view_mut();
+            Ok(ArrayOut::Owned(c_copy))
+        }
+    };
+}
+
+pub(crate) use run_output;
+
+/* #endregion */
@@ -4,11 +4,14 @@
 #![allow(non_upper_case_globals)]
 #![cfg_attr(not(test), no_std)]
 
+pub mod backend;
 pub mod blas1;
 pub mod blas2;
 pub mod blas3;
 pub mod ffi;
+pub mod func;
 pub mod prelude;
+pub mod threading;
 pub mod util;
 
 pub mod document_dev {
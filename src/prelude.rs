@@ -2,11 +2,22 @@ pub use crate::ffi::blas_int;
 pub use crate::util::*;
 
 pub use crate::blas1::asum::{ASUMNum, ASUM, DASUM, DZASUM, SASUM, SCASUM};
+pub use crate::blas1::axpy::{AXPYNum, AXPY, CAXPY, DAXPY, SAXPY, ZAXPY};
+pub use crate::blas1::copy::{COPYNum, CCOPY, COPY, DCOPY, SCOPY, ZCOPY};
+pub use crate::blas1::dotc::{DOTCNum, CDOTC, DOTC, ZDOTC};
+pub use crate::blas1::dotu::{DOTUNum, CDOTU, DDOT, DOTU, SDOT, ZDOTU};
+pub use crate::blas1::dsdot::{DSDOT, SDSDOT};
 pub use crate::blas1::iamax::{IAMAXNum, IAMAX, ICAMAX, IDAMAX, ISAMAX, IZAMAX};
 pub use crate::blas1::nrm2::{NRM2Num, DNRM2, DZNRM2, NRM2, SCNRM2, SNRM2};
+pub use crate::blas1::rot::{ROTNum, CSROT, DROT, ROT, SROT, ZDROT};
+pub use crate::blas1::rotg::{ROTGNum, ROTGResult, CROTG, DROTG, ROTG, SROTG, ZROTG};
+pub use crate::blas1::rotm::{ROTMNum, RotmParam, DROTM, ROTM, SROTM};
+pub use crate::blas1::rotmg::{ROTMGNum, ROTMGResult, DROTMG, ROTMG, SROTMG};
+pub use crate::blas1::scal::{SCALNum, CSCAL, CSSCAL, DSCAL, RSCAL, SCAL, SSCAL, ZDSCAL, ZSCAL};
+pub use crate::blas1::swap::{SWAPNum, CSWAP, DSWAP, SSWAP, SWAP, ZSWAP};
 
 pub use crate::blas2::gbmv::{GBMVNum, CGBMV, DGBMV, GBMV, SGBMV, ZGBMV};
-pub use crate::blas2::gemv::{GEMVNum, CGEMV, DGEMV, GEMV, SGEMV, ZGEMV};
+pub use crate::blas2::gemv::{GEMVNum, GEMVPlan, CGEMV, DGEMV, GEMV, SGEMV, ZGEMV};
 pub use crate::blas2::ger::{GERNum, CGERU, DGER, GER, SGER, ZGERU};
 pub use crate::blas2::gerc::{GERCNum, CGERC, GERC, ZGERC};
 pub use crate::blas2::hbmv::{HBMVNum, CHBMV, DSBMV, HBMV, SSBMV, ZHBMV};
@@ -23,24 +34,47 @@ pub use crate::blas2::tpsv::{TPSVNum, CTPSV, DTPSV, STPSV, TPSV, ZTPSV};
 pub use crate::blas2::trmv::{TRMVNum, CTRMV, DTRMV, STRMV, TRMV, ZTRMV};
 pub use crate::blas2::trsv::{TRSVNum, CTRSV, DTRSV, STRSV, TRSV, ZTRSV};
 
-pub use crate::blas3::gemm::{GEMMNum, CGEMM, DGEMM, GEMM, SGEMM, ZGEMM};
-pub use crate::blas3::hemm::{HEMMNum, CHEMM, HEMM, ZHEMM};
-pub use crate::blas3::her2k::{HER2KNum, CHER2K, HER2K, ZHER2K};
-pub use crate::blas3::herk::{HERKNum, CHERK, HERK, ZHERK};
-pub use crate::blas3::symm::{SYMMNum, CSYMM, DSYMM, SSYMM, SYMM, ZSYMM};
-pub use crate::blas3::syr2k::{SYR2KNum, CSYR2K, DSYR2K, SSYR2K, SYR2K, ZSYR2K};
-pub use crate::blas3::syrk::{SYRKNum, CSYRK, DSYRK, SSYRK, SYRK, ZSYRK};
-pub use crate::blas3::trmm::{TRMMNum, CTRMM, DTRMM, STRMM, TRMM, ZTRMM};
-pub use crate::blas3::trsm::{TRSMNum, CTRSM, DTRSM, STRSM, TRSM, ZTRSM};
+pub use crate::blas3::gemm::{GEMMNum, GEMMPlan, CGEMM, DGEMM, GEMM, SGEMM, ZGEMM};
+pub use crate::blas3::gemm_batch::{
+    GEMMBatchNum, CGEMM_BATCH, DGEMM_BATCH, GEMM_BATCH, SGEMM_BATCH, ZGEMM_BATCH,
+};
+pub use crate::blas3::gemm_batched::{
+    CGEMMBatched, DGEMMBatched, GEMMBatched, GEMMBatchedNum, SGEMMBatched, ZGEMMBatched,
+};
+pub use crate::blas3::hemm::{HEMMNum, HEMMPlan, CHEMM, HEMM, ZHEMM};
+pub use crate::blas3::her2k::{HER2KNum, HER2KPlan, CHER2K, HER2K, ZHER2K};
+pub use crate::blas3::herk::{HERKNum, HERKPlan, CHERK, HERK, ZHERK};
+pub use crate::blas3::symm::{SYMMNum, SYMMPlan, CSYMM, DSYMM, SSYMM, SYMM, ZSYMM};
+pub use crate::blas3::syr2k::{SYR2KNum, SYR2KPlan, CSYR2K, DSYR2K, SSYR2K, SYR2K, ZSYR2K};
+pub use crate::blas3::syrk::{SYRKNum, SYRKPlan, CSYRK, DSYRK, SSYRK, SYRK, ZSYRK};
+pub use crate::blas3::trmm::{TRMMNum, TRMMPlan, CTRMM, DTRMM, STRMM, TRMM, ZTRMM};
+pub use crate::blas3::trsm::{TRSMNum, TRSMPlan, CTRSM, DTRSM, STRSM, TRSM, ZTRSM};
 
 #[cfg(feature = "gemmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "gemmt")))]
-pub use crate::blas3::gemmt::{GEMMTNum, CGEMMT, DGEMMT, GEMMT, SGEMMT, ZGEMMT};
+pub use crate::blas3::gemmt::{GEMMTNum, GEMMTPlan, CGEMMT, DGEMMT, GEMMT, SGEMMT, ZGEMMT};
+#[cfg(feature = "matcopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+pub use crate::blas3::imatcopy::{IMATCOPYNum, CIMATCOPY, DIMATCOPY, IMATCOPY, SIMATCOPY, ZIMATCOPY};
+#[cfg(feature = "matcopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+pub use crate::blas3::omatcopy::{OMATCOPYNum, COMATCOPY, DOMATCOPY, OMATCOPY, SOMATCOPY, ZOMATCOPY};
 
 pub mod generic {
     pub use crate::blas1::asum::ASUM_;
+    pub use crate::blas1::axpy::AXPY_;
+    pub use crate::blas1::copy::COPY_;
+    pub use crate::blas1::dotc::DOTC_;
+    pub use crate::blas1::dotu::DOTU_;
+    pub use crate::blas1::dsdot::{DSDOT_, SDSDOT_};
     pub use crate::blas1::iamax::IAMAX_;
     pub use crate::blas1::nrm2::NRM2_;
+    pub use crate::blas1::rot::ROT_;
+    pub use crate::blas1::rotg::ROTG_;
+    pub use crate::blas1::rotm::ROTM_;
+    pub use crate::blas1::rotmg::ROTMG_;
+    pub use crate::blas1::scal::{RSCAL_, SCAL_};
+    pub use crate::blas1::swap::SWAP_;
 
     pub use crate::blas2::gbmv::GBMV_;
     pub use crate::blas2::gemv::GEMV_;
@@ -61,6 +95,8 @@ pub mod generic {
     pub use crate::blas2::trsv::TRSV_;
 
     pub use crate::blas3::gemm::GEMM_;
+    pub use crate::blas3::gemm_batch::GEMM_BATCH_;
+    pub use crate::blas3::gemm_batched::GEMMBatched_;
     pub use crate::blas3::hemm::HEMM_;
     pub use crate::blas3::her2k::HER2K_;
     pub use crate::blas3::herk::HERK_;
@@ -73,4 +109,70 @@ pub mod generic {
     #[cfg(feature = "gemmt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "gemmt")))]
     pub use crate::blas3::gemmt::GEMMT_;
+    #[cfg(feature = "matcopy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+    pub use crate::blas3::imatcopy::IMATCOPY_;
+    #[cfg(feature = "matcopy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+    pub use crate::blas3::omatcopy::OMATCOPY_;
+}
+
+/// Typestate builders, where missing required arguments are compile errors; see [`crate::util::blas_typestate`].
+pub mod typed {
+    pub use crate::util::*;
+
+    pub use crate::blas1::asum::typed::*;
+    pub use crate::blas1::axpy::typed::*;
+    pub use crate::blas1::copy::typed::*;
+    pub use crate::blas1::dotc::typed::*;
+    pub use crate::blas1::dotu::typed::*;
+    pub use crate::blas1::dsdot::typed::*;
+    pub use crate::blas1::iamax::typed::*;
+    pub use crate::blas1::nrm2::typed::*;
+    pub use crate::blas1::rot::typed::*;
+    pub use crate::blas1::rotg::typed::*;
+    pub use crate::blas1::rotm::typed::*;
+    pub use crate::blas1::rotmg::typed::*;
+    pub use crate::blas1::scal::typed::*;
+    pub use crate::blas1::swap::typed::*;
+
+    pub use crate::blas2::gbmv::typed::*;
+    pub use crate::blas2::gemv::typed::*;
+    pub use crate::blas2::ger::typed::*;
+    pub use crate::blas2::gerc::typed::*;
+    pub use crate::blas2::hbmv::typed::*;
+    pub use crate::blas2::hemv::typed::*;
+    pub use crate::blas2::her::typed::*;
+    pub use crate::blas2::her2::typed::*;
+    pub use crate::blas2::hpmv::typed::*;
+    pub use crate::blas2::hpr::typed::*;
+    pub use crate::blas2::hpr2::typed::*;
+    pub use crate::blas2::tbmv::typed::*;
+    pub use crate::blas2::tbsv::typed::*;
+    pub use crate::blas2::tpmv::typed::*;
+    pub use crate::blas2::tpsv::typed::*;
+    pub use crate::blas2::trmv::typed::*;
+    pub use crate::blas2::trsv::typed::*;
+
+    pub use crate::blas3::gemm::typed::*;
+    pub use crate::blas3::gemm_batch::typed::*;
+    pub use crate::blas3::gemm_batched::typed::*;
+    pub use crate::blas3::hemm::typed::*;
+    pub use crate::blas3::her2k::typed::*;
+    pub use crate::blas3::herk::typed::*;
+    pub use crate::blas3::symm::typed::*;
+    pub use crate::blas3::syr2k::typed::*;
+    pub use crate::blas3::syrk::typed::*;
+    pub use crate::blas3::trmm::typed::*;
+    pub use crate::blas3::trsm::typed::*;
+
+    #[cfg(feature = "gemmt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gemmt")))]
+    pub use crate::blas3::gemmt::typed::*;
+    #[cfg(feature = "matcopy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+    pub use crate::blas3::imatcopy::typed::*;
+    #[cfg(feature = "matcopy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+    pub use crate::blas3::omatcopy::typed::*;
 }
@@ -0,0 +1,225 @@
+//! Thread count and determinism control of the linked BLAS library.
+//!
+//! Threading control is not part of BLAS standard. Vendor functions of OpenBLAS (`openblas_set_num_threads`),
+//! MKL (`mkl_set_num_threads_local`) and BLIS (`bli_thread_set_num_threads`) are looked up at runtime (see
+//! [`ffi::threading`](crate::ffi::threading)), so the same binary works with any of these libraries. With crate
+//! feature `faer`, global parallelism of faer (used by Level 3 wrappers) is also controlled. If none of these is
+//! found, [`set_num_threads`] gives [`BLASError::MissingSymbol`], guards do nothing, and [`get_num_threads`]
+//! gives `None`; [`threading_vendors`] tells which vendors are controlled.
+//!
+//! Vendor functions are only found if they are exported by a shared library (or by the executable itself). When
+//! OpenBLAS, MKL or BLIS is linked statically, these symbols are usually not exported (unless linked with
+//! `-rdynamic` or `--export-dynamic`), so threading control is not available; in this case, set thread count by
+//! environment variables of the vendor (such as `OPENBLAS_NUM_THREADS`, `MKL_NUM_THREADS` or `BLIS_NUM_THREADS`)
+//! instead.
+//!
+//! Calling BLAS inside parallel tasks (such as rayon) may oversubscribe threads, since BLAS libraries run their
+//! own thread pools. A guard, such as [`scoped_single_thread`], forces BLAS to run single-threaded until dropped:
+//!
+//! ```rust
+//! use blas_array2::threading::*;
+//!
+//! {
+//!     let _guard = scoped_single_thread();
+//!     // call BLAS wrappers inside parallel tasks here
+//! }
+//! // previous thread count is restored here
+//! ```
+//!
+//! Thread count of MKL is set for the current thread only. For OpenBLAS, BLIS and faer, thread count is global to
+//! the process, so the guard should be created outside of parallel tasks (before spawning them).
+
+extern crate alloc;
+
+use crate::ffi::threading as ffi;
+use crate::util::BLASError;
+use alloc::vec::Vec;
+use core::ffi::c_int;
+
+/// BLAS vendor of threading control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadingVendor {
+    OpenBLAS,
+    MKL,
+    BLIS,
+    Faer,
+}
+
+/// Vendors whose threading control is found in the linked library (or enabled by crate feature `faer`).
+pub fn threading_vendors() -> Vec<ThreadingVendor> {
+    let mut vendors = Vec::new();
+    if ffi::openblas_set_num_threads().is_some() {
+        vendors.push(ThreadingVendor::OpenBLAS);
+    }
+    if ffi::mkl_set_num_threads_local().is_some() {
+        vendors.push(ThreadingVendor::MKL);
+    }
+    if ffi::bli_thread_set_num_threads().is_some() {
+        vendors.push(ThreadingVendor::BLIS);
+    }
+    if cfg!(feature = "faer") {
+        vendors.push(ThreadingVendor::Faer);
+    }
+    return vendors;
+}
+
+/* #region vendor state */
+
+/// Threading state of vendors found, to be restored by [`ThreadingGuard`]; `None` for vendors not found.
+#[derive(Debug, Clone, Copy)]
+struct VendorState {
+    openblas: Option<c_int>,
+    mkl: Option<c_int>,
+    mkl_cbwr: Option<c_int>,
+    blis: Option<i64>,
+    #[cfg(feature = "faer")]
+    faer: faer::Parallelism<'static>,
+}
+
+/// Set thread count of vendors found, and return previous state.
+fn swap_num_threads(n: usize) -> VendorState {
+    let n = n.max(1);
+    let openblas =
+        ffi::openblas_get_num_threads().zip(ffi::openblas_set_num_threads()).map(|(get, set)| unsafe {
+            let prev = get();
+            set(n.try_into().unwrap_or(c_int::MAX));
+            prev
+        });
+    // previous thread-local setting is returned (0 for global setting)
+    let mkl = ffi::mkl_set_num_threads_local().map(|set| unsafe { set(n.try_into().unwrap_or(c_int::MAX)) });
+    let blis =
+        ffi::bli_thread_get_num_threads().zip(ffi::bli_thread_set_num_threads()).map(|(get, set)| unsafe {
+            let prev = get();
+            set(n.try_into().unwrap_or(i64::MAX));
+            prev
+        });
+    #[cfg(feature = "faer")]
+    let faer = {
+        let prev = faer::get_global_parallelism();
+        faer::set_global_parallelism(if n == 1 {
+            faer::Parallelism::None
+        } else {
+            faer::Parallelism::Rayon(n)
+        });
+        prev
+    };
+    return VendorState {
+        openblas,
+        mkl,
+        mkl_cbwr: None,
+        blis,
+        #[cfg(feature = "faer")]
+        faer,
+    };
+}
+
+fn restore(state: &VendorState) {
+    if let (Some(prev), Some(set)) = (state.openblas, ffi::openblas_set_num_threads()) {
+        unsafe { set(prev) };
+    }
+    if let (Some(prev), Some(set)) = (state.mkl, ffi::mkl_set_num_threads_local()) {
+        unsafe { set(prev) };
+    }
+    if let (Some(prev), Some(set)) = (state.mkl_cbwr, ffi::mkl_cbwr_set()) {
+        unsafe { set(prev) };
+    }
+    if let (Some(prev), Some(set)) = (state.blis, ffi::bli_thread_set_num_threads()) {
+        unsafe { set(prev) };
+    }
+    #[cfg(feature = "faer")]
+    faer::set_global_parallelism(state.faer);
+}
+
+/* #endregion */
+
+/* #region thread count */
+
+/// Set thread count of BLAS.
+///
+/// Thread count less than 1 is regarded as 1. [`BLASError::MissingSymbol`] is given if no vendor is found (such
+/// as BLAS linked statically), in which case nothing is changed.
+pub fn set_num_threads(n: usize) -> Result<(), BLASError> {
+    if threading_vendors().is_empty() {
+        return Err(BLASError::MissingSymbol(
+            "openblas_set_num_threads, mkl_set_num_threads_local or bli_thread_set_num_threads",
+        ));
+    }
+    let _ = swap_num_threads(n);
+    return Ok(());
+}
+
+/// Thread count of BLAS, given by the first vendor found; `None` if no vendor is found or unknown.
+pub fn get_num_threads() -> Option<usize> {
+    let n = threading_vendors().iter().find_map(|&vendor| vendor_num_threads(vendor))?;
+    return usize::try_from(n).ok().filter(|&n| n > 0);
+}
+
+fn vendor_num_threads(vendor: ThreadingVendor) -> Option<i64> {
+    match vendor {
+        ThreadingVendor::OpenBLAS => {
+            return ffi::openblas_get_num_threads().map(|get| unsafe { get() as i64 })
+        },
+        ThreadingVendor::MKL => return ffi::mkl_get_max_threads().map(|get| unsafe { get() as i64 }),
+        ThreadingVendor::BLIS => return ffi::bli_thread_get_num_threads().map(|get| unsafe { get() }),
+        ThreadingVendor::Faer => (),
+    }
+    // `Rayon(0)` (default of faer) uses the whole rayon pool, which is reported as unknown
+    #[cfg(feature = "faer")]
+    if vendor == ThreadingVendor::Faer {
+        return match faer::get_global_parallelism() {
+            faer::Parallelism::None => Some(1),
+            faer::Parallelism::Rayon(n) => Some(n as i64),
+            _ => None,
+        };
+    }
+    return None;
+}
+
+/* #endregion */
+
+/* #region guard */
+
+/// Guard of BLAS threading; previous thread count (and reproducibility mode) is restored when dropped.
+#[must_use = "BLAS threading is restored when guard is dropped"]
+#[derive(Debug)]
+pub struct ThreadingGuard {
+    prev: VendorState,
+    // guard should be dropped on the thread it is created
+    _marker: core::marker::PhantomData<*const ()>,
+}
+
+impl Drop for ThreadingGuard {
+    fn drop(&mut self) {
+        restore(&self.prev);
+    }
+}
+
+/// Set thread count of BLAS, until returned guard is dropped; no-op if no vendor is found (see
+/// [`threading_vendors`]).
+pub fn scoped_num_threads(n: usize) -> ThreadingGuard {
+    ThreadingGuard { prev: swap_num_threads(n), _marker: core::marker::PhantomData }
+}
+
+/// Run BLAS single-threaded, until returned guard is dropped.
+pub fn scoped_single_thread() -> ThreadingGuard {
+    scoped_num_threads(1)
+}
+
+/// Run BLAS single-threaded and numerically reproducible, until returned guard is dropped.
+///
+/// Single-threaded BLAS gives the same result for the same input on the same machine, for OpenBLAS and BLIS.
+/// For MKL, conditional numerical reproducibility (`MKL_CBWR_COMPATIBLE`) is additionally requested; note that
+/// MKL may refuse to change this mode after it has been initialized (the first MKL call), in which case only
+/// thread count is changed.
+pub fn scoped_reproducible() -> ThreadingGuard {
+    let mut guard = scoped_single_thread();
+    if let (Some(get), Some(set)) = (ffi::mkl_cbwr_get(), ffi::mkl_cbwr_set()) {
+        let prev = unsafe { get(ffi::MKL_CBWR_ALL) };
+        if unsafe { set(ffi::MKL_CBWR_COMPATIBLE) } == ffi::MKL_CBWR_SUCCESS {
+            guard.prev.mkl_cbwr = Some(prev);
+        }
+    }
+    return guard;
+}
+
+/* #endregion */
@@ -1,24 +1,352 @@
+//! Error type of this crate.
+//!
+//! Most variants of [`BLASError`] carry a [`BLASErrorInfo`], which is `Copy` and does not allocate: the wrapper
+//! name, the offending parameter and its position in the Fortran routine (the same to `INFO` reported by
+//! `xerbla`), expected and actual dimensions, and the source location. [`BLASError::code`] gives a
+//! non-allocating representation of any error.
+//!
+//! Parameters refer to the Fortran call to be made: for row-major problems, which are flipped to col-major
+//! before calling BLAS, parameter `a` of the error may be input `b` of the wrapper (such as GEMM).
+
 #[cfg(feature = "std")]
 extern crate std;
 
 extern crate alloc;
 
+use crate::ffi::blas_int;
 use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter};
 use core::num::TryFromIntError;
 use derive_builder::UninitializedFieldError;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BLASError {
-    OverflowDimension(String),
-    InvalidDim(String),
-    InvalidFlag(String),
-    FailedCheck(String),
+    OverflowDimension(BLASErrorInfo),
+    InvalidDim(BLASErrorInfo),
+    InvalidFlag(BLASErrorInfo),
+    FailedCheck(BLASErrorInfo),
     UninitializedField(&'static str),
-    ExplicitCopy(String),
+    ExplicitCopy(BLASErrorInfo),
+    /// Error of user message, such as errors returned by [`CopyPolicy::Callback`](crate::util::CopyPolicy).
     Miscellaneous(String),
-    RuntimeError(String),
+    RuntimeError(BLASErrorInfo),
+    /// Illegal argument reported by BLAS library through `xerbla_` (requires crate feature `xerbla`).
+    ///
+    /// `routine` is the Fortran routine name (such as `"DGEMM"`), and `info` is the position of argument.
+    LibraryArgument {
+        routine: String,
+        info: blas_int,
+    },
+    /// Symbol (such as `"dgemmt_"`) not found in dynamically loaded BLAS library (crate feature `dynamic`), or
+    /// vendor function of threading control not found (see [`threading`](crate::threading)).
+    MissingSymbol(&'static str),
+}
+
+/// Kind of [`BLASError`], without payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BLASErrorKind {
+    OverflowDimension,
+    InvalidDim,
+    InvalidFlag,
+    FailedCheck,
+    UninitializedField,
+    ExplicitCopy,
+    Miscellaneous,
+    RuntimeError,
+    LibraryArgument,
+    MissingSymbol,
+}
+
+/// Structured information of [`BLASError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BLASErrorInfo {
+    /// Name of wrapper (such as `"GEMM"`); empty if unknown.
+    pub wrapper: &'static str,
+    /// Name of offending parameter (such as `"lda"`, `"b"`); empty if unknown.
+    pub param: &'static str,
+    /// Position (1-based) of offending parameter in Fortran routine, the same to `INFO` reported by `xerbla`.
+    pub position: Option<u16>,
+    /// Expected dimension or shape.
+    pub expected: Option<BLASDims>,
+    /// Actual dimension or shape.
+    pub actual: Option<BLASDims>,
+    /// Message, or the failed condition.
+    pub message: &'static str,
+    /// Source location (`file:line`) where error is raised.
+    pub location: &'static str,
+}
+
+/// Non-allocating representation of [`BLASError`].
+///
+/// Message of [`BLASError::Miscellaneous`] is dropped, and field name of [`BLASError::UninitializedField`] (symbol
+/// of [`BLASError::MissingSymbol`]) is given as `info.param`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BLASErrorCode {
+    pub kind: BLASErrorKind,
+    pub info: BLASErrorInfo,
+}
+
+/// Dimension (scalar) or shape (up to 3 dimensions), without allocation.
+///
+/// Values are saturated to range of `i32`, which keeps [`BLASError`] small.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub struct BLASDims {
+    dims: [i32; 3],
+    ndim: u8,
+    scalar: bool,
+}
+
+/* #region impl BLASDims */
+
+impl BLASDims {
+    pub fn scalar<T>(value: T) -> BLASDims
+    where
+        T: TryInto<i32> + PartialOrd + Default,
+    {
+        BLASDims { dims: [saturate(value), 0, 0], ndim: 1, scalar: true }
+    }
+
+    /// Dimensions more than 3 are truncated.
+    pub fn from_slice<T>(dims: &[T]) -> BLASDims
+    where
+        T: Copy + TryInto<i32> + PartialOrd + Default,
+    {
+        let mut result = BLASDims::default();
+        for (i, &d) in dims.iter().take(3).enumerate() {
+            result.dims[i] = saturate(d);
+        }
+        result.ndim = dims.len().min(3) as u8;
+        return result;
+    }
+
+    pub fn as_slice(&self) -> &[i32] {
+        &self.dims[..self.ndim as usize]
+    }
+
+    pub fn is_scalar(&self) -> bool {
+        self.scalar
+    }
+}
+
+fn saturate<T>(value: T) -> i32
+where
+    T: TryInto<i32> + PartialOrd + Default,
+{
+    let negative = value < T::default();
+    return value.try_into().unwrap_or(if negative { i32::MIN } else { i32::MAX });
+}
+
+impl Display for BLASDims {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        if self.scalar {
+            return write!(f, "{}", self.dims[0]);
+        }
+        write!(f, "(")?;
+        for (i, d) in self.as_slice().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{d}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Debug for BLASDims {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+macro_rules! impl_from_scalar {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BLASDims {
+            fn from(value: $t) -> BLASDims {
+                BLASDims::scalar(value)
+            }
+        }
+    )*};
+}
+
+impl_from_scalar!(usize, isize, i32, i64);
+
+impl From<(usize, usize)> for BLASDims {
+    fn from(value: (usize, usize)) -> BLASDims {
+        BLASDims::from_slice(&[value.0, value.1])
+    }
+}
+
+impl From<(usize, usize, usize)> for BLASDims {
+    fn from(value: (usize, usize, usize)) -> BLASDims {
+        BLASDims::from_slice(&[value.0, value.1, value.2])
+    }
+}
+
+impl From<&[usize]> for BLASDims {
+    fn from(value: &[usize]) -> BLASDims {
+        BLASDims::from_slice(value)
+    }
+}
+
+impl From<&[isize]> for BLASDims {
+    fn from(value: &[isize]) -> BLASDims {
+        BLASDims::from_slice(value)
+    }
+}
+
+/* #endregion */
+
+/* #region impl BLASErrorInfo */
+
+impl BLASErrorInfo {
+    /// Information of error raised at `location`, about parameter of expression `expr` (such as `"b.dim()"`).
+    ///
+    /// Wrapper is the current wrapper call (requires feature `std`), or is inferred from `location`.
+    #[doc(hidden)]
+    pub fn new(location: &'static str, message: &'static str, expr: &'static str) -> BLASErrorInfo {
+        let wrapper = match current_wrapper() {
+            "" => wrapper_of_location(location),
+            wrapper => wrapper,
+        };
+        let param = param_of_expr(expr);
+        let position = fortran_position(wrapper, param);
+        BLASErrorInfo { wrapper, param, position, expected: None, actual: None, message, location }
+    }
+
+    /// Information of argument `info` (1-based) of Fortran routine `routine` (such as `"DGEMM"`).
+    ///
+    /// Routine is matched to wrapper by dropping type prefix; wrapper and parameter are empty if not found.
+    pub fn of_routine(routine: &str, info: blas_int) -> BLASErrorInfo {
+        let position = u16::try_from(info).ok().filter(|&p| p > 0);
+        let name = routine.get(1..).unwrap_or("");
+        let (wrapper, param) = match FORTRAN_ARGS.iter().find(|&&(w, _)| w.eq_ignore_ascii_case(name)) {
+            Some(&(wrapper, args)) => {
+                (wrapper, position.and_then(|p| args.get(p as usize - 1)).copied().unwrap_or(""))
+            },
+            None => ("", ""),
+        };
+        let message = "illegal argument reported by BLAS library";
+        BLASErrorInfo { wrapper, param, position, message, ..Default::default() }
+    }
+
+    #[doc(hidden)]
+    pub fn with_dims(self, actual: BLASDims, expected: BLASDims) -> BLASErrorInfo {
+        BLASErrorInfo { actual: Some(actual), expected: Some(expected), ..self }
+    }
+}
+
+impl Display for BLASErrorInfo {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.message)?;
+        match (self.actual, self.expected) {
+            (Some(actual), Some(expected)) => {
+                write!(f, ", actual {actual} not equal to expected {expected}")?
+            },
+            (Some(actual), None) => write!(f, ", actual {actual}")?,
+            _ => (),
+        }
+        if !self.wrapper.is_empty() {
+            write!(f, " (in {}", self.wrapper)?;
+            match (self.position, self.param) {
+                (Some(position), param) => write!(f, ", argument {position} `{param}`")?,
+                (None, "") => (),
+                (None, param) => write!(f, ", `{param}`")?,
+            }
+            write!(f, ")")?;
+        }
+        return Ok(());
+    }
+}
+
+fn current_wrapper() -> &'static str {
+    #[cfg(feature = "std")]
+    {
+        return crate::util::copy_policy::current_wrapper();
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        return "";
+    }
+}
+
+/// Name of parameter in expression, such as `"b"` for `"b.len_of(Axis(0))"`.
+fn param_of_expr(expr: &'static str) -> &'static str {
+    let expr = expr.trim_start_matches(['&', '*', ' ']);
+    let expr = expr.strip_prefix("obj.").unwrap_or(expr);
+    let end = expr.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(expr.len());
+    return match &expr[..end] {
+        "self" => "",
+        param => param,
+    };
 }
 
+/// Wrapper of source file, such as `"GEMM"` for `"src/blas3/gemm.rs:42"`.
+fn wrapper_of_location(location: &'static str) -> &'static str {
+    let file = location.split(':').next().unwrap_or("");
+    let stem = file.rsplit(['/', '\\']).next().unwrap_or("").trim_end_matches(".rs");
+    return FORTRAN_ARGS.iter().map(|&(w, _)| w).find(|w| w.eq_ignore_ascii_case(stem)).unwrap_or("");
+}
+
+/// Position of parameter in Fortran routine of wrapper.
+fn fortran_position(wrapper: &str, param: &str) -> Option<u16> {
+    let (_, args) = FORTRAN_ARGS.iter().find(|&&(w, _)| w == wrapper)?;
+    return args.iter().position(|&arg| arg == param).map(|i| i as u16 + 1);
+}
+
+/// Arguments of Fortran routines of wrappers, named as wrapper fields.
+#[rustfmt::skip]
+const FORTRAN_ARGS: &[(&str, &[&str])] = &[
+    ("ASUM", &["n", "x", "incx"]),
+    ("AXPY", &["n", "alpha", "x", "incx", "y", "incy"]),
+    ("COPY", &["n", "x", "incx", "y", "incy"]),
+    ("DOTC", &["n", "x", "incx", "y", "incy"]),
+    ("DOTU", &["n", "x", "incx", "y", "incy"]),
+    ("DSDOT", &["n", "x", "incx", "y", "incy"]),
+    ("SDSDOT", &["n", "sb", "x", "incx", "y", "incy"]),
+    ("IAMAX", &["n", "x", "incx"]),
+    ("NRM2", &["n", "x", "incx"]),
+    ("ROT", &["n", "x", "incx", "y", "incy", "c", "s"]),
+    ("ROTG", &["a", "b", "c", "s"]),
+    ("ROTM", &["n", "x", "incx", "y", "incy", "param"]),
+    ("ROTMG", &["d1", "d2", "x1", "y1", "param"]),
+    ("SCAL", &["n", "alpha", "x", "incx"]),
+    ("RSCAL", &["n", "alpha", "x", "incx"]),
+    ("SWAP", &["n", "x", "incx", "y", "incy"]),
+    ("GBMV", &["trans", "m", "n", "kl", "ku", "alpha", "a", "lda", "x", "incx", "beta", "y", "incy"]),
+    ("GEMV", &["trans", "m", "n", "alpha", "a", "lda", "x", "incx", "beta", "y", "incy"]),
+    ("GER", &["m", "n", "alpha", "x", "incx", "y", "incy", "a", "lda"]),
+    ("GERC", &["m", "n", "alpha", "x", "incx", "y", "incy", "a", "lda"]),
+    ("HBMV", &["uplo", "n", "k", "alpha", "a", "lda", "x", "incx", "beta", "y", "incy"]),
+    ("HEMV", &["uplo", "n", "alpha", "a", "lda", "x", "incx", "beta", "y", "incy"]),
+    ("HER", &["uplo", "n", "alpha", "x", "incx", "a", "lda"]),
+    ("HER2", &["uplo", "n", "alpha", "x", "incx", "y", "incy", "a", "lda"]),
+    ("HPMV", &["uplo", "n", "alpha", "ap", "x", "incx", "beta", "y", "incy"]),
+    ("HPR", &["uplo", "n", "alpha", "x", "incx", "ap"]),
+    ("HPR2", &["uplo", "n", "alpha", "x", "incx", "y", "incy", "ap"]),
+    ("TBMV", &["uplo", "trans", "diag", "n", "k", "a", "lda", "x", "incx"]),
+    ("TBSV", &["uplo", "trans", "diag", "n", "k", "a", "lda", "x", "incx"]),
+    ("TPMV", &["uplo", "trans", "diag", "n", "ap", "x", "incx"]),
+    ("TPSV", &["uplo", "trans", "diag", "n", "ap", "x", "incx"]),
+    ("TRMV", &["uplo", "trans", "diag", "n", "a", "lda", "x", "incx"]),
+    ("TRSV", &["uplo", "trans", "diag", "n", "a", "lda", "x", "incx"]),
+    ("GEMM", &["transa", "transb", "m", "n", "k", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("GEMM_BATCH", &["transa", "transb", "m", "n", "k", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("GEMMBatched", &["transa", "transb", "m", "n", "k", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("GEMMT", &["uplo", "transa", "transb", "n", "k", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("SYMM", &["side", "uplo", "m", "n", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("HEMM", &["side", "uplo", "m", "n", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("SYRK", &["uplo", "trans", "n", "k", "alpha", "a", "lda", "beta", "c", "ldc"]),
+    ("HERK", &["uplo", "trans", "n", "k", "alpha", "a", "lda", "beta", "c", "ldc"]),
+    ("SYR2K", &["uplo", "trans", "n", "k", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("HER2K", &["uplo", "trans", "n", "k", "alpha", "a", "lda", "b", "ldb", "beta", "c", "ldc"]),
+    ("TRMM", &["side", "uplo", "transa", "diag", "m", "n", "alpha", "a", "lda", "b", "ldb"]),
+    ("TRSM", &["side", "uplo", "transa", "diag", "m", "n", "alpha", "a", "lda", "b", "ldb"]),
+    ("OMATCOPY", &["layout", "trans", "rows", "cols", "alpha", "a", "lda", "b", "ldb"]),
+    ("IMATCOPY", &["layout", "trans", "rows", "cols", "alpha", "a", "lda", "ldb"]),
+];
+
+/* #endregion */
+
 /* #region impl BLASError */
 
 #[cfg(feature = "std")]
@@ -32,13 +360,84 @@ impl From<UninitializedFieldError> for BLASError {
 
 impl From<TryFromIntError> for BLASError {
     fn from(_: TryFromIntError) -> BLASError {
-        BLASError::OverflowDimension(String::from("TryFromIntError"))
+        BLASError::OverflowDimension(BLASErrorInfo::new("", "TryFromIntError", ""))
+    }
+}
+
+impl BLASError {
+    pub fn kind(&self) -> BLASErrorKind {
+        match self {
+            BLASError::OverflowDimension(_) => BLASErrorKind::OverflowDimension,
+            BLASError::InvalidDim(_) => BLASErrorKind::InvalidDim,
+            BLASError::InvalidFlag(_) => BLASErrorKind::InvalidFlag,
+            BLASError::FailedCheck(_) => BLASErrorKind::FailedCheck,
+            BLASError::UninitializedField(_) => BLASErrorKind::UninitializedField,
+            BLASError::ExplicitCopy(_) => BLASErrorKind::ExplicitCopy,
+            BLASError::Miscellaneous(_) => BLASErrorKind::Miscellaneous,
+            BLASError::RuntimeError(_) => BLASErrorKind::RuntimeError,
+            BLASError::LibraryArgument { .. } => BLASErrorKind::LibraryArgument,
+            BLASError::MissingSymbol(_) => BLASErrorKind::MissingSymbol,
+        }
+    }
+
+    /// Structured information; `None` for [`BLASError::UninitializedField`], [`BLASError::Miscellaneous`],
+    /// [`BLASError::LibraryArgument`] and [`BLASError::MissingSymbol`].
+    pub fn info(&self) -> Option<&BLASErrorInfo> {
+        match self {
+            BLASError::OverflowDimension(info)
+            | BLASError::InvalidDim(info)
+            | BLASError::InvalidFlag(info)
+            | BLASError::FailedCheck(info)
+            | BLASError::ExplicitCopy(info)
+            | BLASError::RuntimeError(info) => Some(info),
+            BLASError::UninitializedField(_)
+            | BLASError::Miscellaneous(_)
+            | BLASError::LibraryArgument { .. }
+            | BLASError::MissingSymbol(_) => None,
+        }
+    }
+
+    pub fn code(&self) -> BLASErrorCode {
+        let info = match self {
+            BLASError::UninitializedField(field) => BLASErrorInfo { param: field, ..Default::default() },
+            BLASError::MissingSymbol(symbol) => BLASErrorInfo { param: symbol, ..Default::default() },
+            BLASError::LibraryArgument { routine, info } => BLASErrorInfo::of_routine(routine, *info),
+            _ => self.info().copied().unwrap_or_default(),
+        };
+        BLASErrorCode { kind: self.kind(), info }
+    }
+}
+
+impl Display for BLASErrorCode {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        if !self.info.location.is_empty() {
+            write!(f, "{}: ", self.info.location)?;
+        }
+        match self.kind {
+            BLASErrorKind::UninitializedField => {
+                write!(f, "BLASError::{:?} : `{}`", self.kind, self.info.param)
+            },
+            BLASErrorKind::MissingSymbol => {
+                write!(f, "BLASError::{:?} : `{}` not found in BLAS library", self.kind, self.info.param)
+            },
+            _ => write!(f, "BLASError::{:?} : {}", self.kind, self.info),
+        }
     }
 }
 
-impl core::fmt::Display for BLASError {
-    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "{:?}", self)
+impl Display for BLASError {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        match self {
+            BLASError::Miscellaneous(msg) => write!(f, "BLASError::Miscellaneous : {msg}"),
+            BLASError::LibraryArgument { routine, info } => {
+                write!(f, "BLASError::LibraryArgument : illegal value of argument {info} of {routine}")?;
+                match BLASErrorInfo::of_routine(routine, *info).param {
+                    "" => Ok(()),
+                    param => write!(f, " `{param}`"),
+                }
+            },
+            _ => write!(f, "{}", self.code()),
+        }
     }
 }
 
@@ -52,24 +451,22 @@ macro_rules! blas_assert {
         if $cond {
             Ok(())
         } else {
-            extern crate alloc;
-            use alloc::string::String;
-            Err(BLASError::$errtype(String::from(concat!(
-                file!(), ":", line!(), ": ", "BLASError::", stringify!($errtype), " : ",
-                $($arg),*, ": ", stringify!($cond)
-            ))))
+            Err(BLASError::$errtype($crate::util::BLASErrorInfo::new(
+                concat!(file!(), ":", line!()),
+                concat!($($arg),*, ": ", stringify!($cond)),
+                stringify!($cond),
+            )))
         }
     };
     ($cond:expr, $errtype:ident) => {
         if $cond {
             Ok(())
         } else {
-            extern crate alloc;
-            use alloc::string::String;
-            Err(BLASError::$errtype(String::from(concat!(
-                file!(), ":", line!(), ": ", "BLASError::", stringify!($errtype), " : ",
-                stringify!($cond)
-            ))))
+            Err(BLASError::$errtype($crate::util::BLASErrorInfo::new(
+                concat!(file!(), ":", line!()),
+                stringify!($cond),
+                stringify!($cond),
+            )))
         }
     };
 }
@@ -80,53 +477,41 @@ macro_rules! blas_assert_eq {
         if $a == $b {
             Ok(())
         } else {
-            extern crate alloc;
-            use alloc::string::String;
-            use core::fmt::Write;
-            let mut s = String::from(concat!(
-                file!(),
-                ":",
-                line!(),
-                ": ",
-                "BLASError::",
-                stringify!($errtype),
-                " : "
-            ));
-            write!(s, "{:?} = {:?} not equal to {:?} = {:?}", stringify!($a), $a, stringify!($b), $b)
-                .unwrap();
-            Err(BLASError::$errtype(s))
+            let info = $crate::util::BLASErrorInfo::new(
+                concat!(file!(), ":", line!()),
+                concat!(stringify!($a), " == ", stringify!($b)),
+                stringify!($a),
+            );
+            Err(BLASError::$errtype(
+                info.with_dims($crate::util::BLASDims::from($a), $crate::util::BLASDims::from($b)),
+            ))
         }
     };
 }
 
 #[macro_export]
 macro_rules! blas_raise {
-    ($errtype:ident) => {{
-        extern crate alloc;
-        use alloc::string::String;
-        Err(BLASError::$errtype(String::from(concat!(
-            file!(), ":", line!(), ": ", "BLASError::", stringify!($errtype)
-        ))))
-    }};
-    ($errtype:ident, $($arg:tt)*) => {{
-        extern crate alloc;
-        use alloc::string::String;
-        Err(BLASError::$errtype(String::from(concat!(
-            file!(), ":", line!(), ": ", "BLASError::", stringify!($errtype), " : ",
-            $($arg),*
-        ))))
-    }};
+    ($errtype:ident) => {
+        Err(BLASError::$errtype($crate::util::BLASErrorInfo::new(concat!(file!(), ":", line!()), "", "")))
+    };
+    ($errtype:ident, $($arg:tt)*) => {
+        Err(BLASError::$errtype($crate::util::BLASErrorInfo::new(
+            concat!(file!(), ":", line!()),
+            concat!($($arg),*),
+            "",
+        )))
+    };
 }
 
 #[macro_export]
 macro_rules! blas_invalid {
     ($word:expr) => {{
-        extern crate alloc;
-        use alloc::string::String;
-        use core::fmt::Write;
-        let mut s = String::from(concat!(file!(), ":", line!(), ": ", "BLASError::InvalidFlag", " : "));
-        write!(s, "{:?} = {:?}", stringify!($word), $word).unwrap();
-        Err(BLASError::InvalidFlag(s))
+        let _ = &$word;
+        Err(BLASError::InvalidFlag($crate::util::BLASErrorInfo::new(
+            concat!(file!(), ":", line!()),
+            concat!("invalid flag `", stringify!($word), "`"),
+            stringify!($word),
+        )))
     }};
 }
 
@@ -134,38 +519,21 @@ macro_rules! blas_invalid {
 
 /* #region macros (warning) */
 
+/// Handle explicit copy of array by current [`CopyPolicy`](crate::util::CopyPolicy).
 #[macro_export]
 macro_rules! blas_warn_layout_clone {
-    ($array:expr) => {{
-        #[cfg(feature = "std")]
-        extern crate std;
-
-        if cfg!(all(feature = "std", feature = "warn_on_copy")) {
-            std::eprintln!(
-                "Warning: Copying array due to non-standard layout, shape={:?}, strides={:?}",
-                $array.shape(),
-                $array.strides()
-            );
-            Result::<(), BLASError>::Ok(())
-        } else if cfg!(feature = "error_on_copy") {
-            blas_raise!(ExplicitCopy)
-        } else {
-            Result::<(), BLASError>::Ok(())
-        }
-    }};
-    ($array:expr, $msg:tt) => {{
-        #[cfg(feature = "std")]
-        extern crate std;
-
-        if cfg!(all(feature = "std", feature = "warn_on_copy")) {
-            std::eprintln!("Warning: {:?}, shape={:?}, strides={:?}", $msg, $array.shape(), $array.strides());
-            Result::<(), BLASError>::Ok(())
-        } else if cfg!(feature = "error_on_copy") {
-            blas_raise!(ExplicitCopy)
-        } else {
-            Result::<(), BLASError>::Ok(())
-        }
-    }};
+    ($array:expr) => {
+        $crate::blas_warn_layout_clone!($array, "Copying array due to non-standard layout")
+    };
+    ($array:expr, $msg:tt) => {
+        $crate::util::on_explicit_copy(
+            $array.shape(),
+            $array.strides(),
+            $msg,
+            $crate::util::elem_size_of_ptr($array.as_ptr()),
+            concat!(file!(), ":", line!()),
+        )
+    };
 }
 
 /* #endregion */
@@ -186,15 +554,16 @@ mod tests {
     fn test_from_try_from_int_error() {
         let error: Result<i32, _> = (1000000000000 as usize).try_into();
         let blas_error: BLASError = error.unwrap_err().into();
-        assert_eq!(blas_error, BLASError::OverflowDimension("TryFromIntError".to_string()));
+        assert_eq!(blas_error.kind(), BLASErrorKind::OverflowDimension);
+        assert_eq!(blas_error.info().unwrap().message, "TryFromIntError");
     }
 
     #[test]
     fn test_blas_assert_macro_with_args() {
         let result = blas_assert!(false, InvalidFlag, "test_condition");
-        if let Err(BLASError::InvalidFlag(msg)) = result {
-            assert!(msg.contains("BLASError::InvalidFlag"));
-            assert!(msg.contains("test_condition"));
+        if let Err(err @ BLASError::InvalidFlag(_)) = result {
+            assert!(err.to_string().contains("BLASError::InvalidFlag"));
+            assert!(err.to_string().contains("test_condition"));
         } else {
             panic!("Expected BLASError::InvalidFlag");
         }
@@ -203,8 +572,8 @@ mod tests {
     #[test]
     fn test_blas_assert_macro_without_args() {
         let result = blas_assert!(false, InvalidFlag);
-        if let Err(BLASError::InvalidFlag(msg)) = result {
-            assert!(msg.contains("BLASError::InvalidFlag"));
+        if let Err(err @ BLASError::InvalidFlag(_)) = result {
+            assert!(err.to_string().contains("BLASError::InvalidFlag"));
         } else {
             panic!("Expected BLASError::InvalidFlag");
         }
@@ -213,9 +582,11 @@ mod tests {
     #[test]
     fn test_blas_assert_eq_macro() {
         let result = blas_assert_eq!(1, 2, InvalidFlag);
-        if let Err(BLASError::InvalidFlag(msg)) = result {
-            assert!(msg.contains("BLASError::InvalidFlag"));
-            assert!(msg.contains(r#""1" = 1 not equal to "2" = 2"#));
+        if let Err(err @ BLASError::InvalidFlag(info)) = result {
+            assert!(err.to_string().contains("BLASError::InvalidFlag"));
+            assert!(err.to_string().contains("1 == 2, actual 1 not equal to expected 2"));
+            assert_eq!(info.actual, Some(BLASDims::scalar(1)));
+            assert_eq!(info.expected, Some(BLASDims::scalar(2)));
         } else {
             panic!("Expected BLASError::InvalidFlag");
         }
@@ -224,8 +595,8 @@ mod tests {
     #[test]
     fn test_blas_raise_macro_without_args() {
         let result: Result<(), BLASError> = blas_raise!(InvalidFlag);
-        if let Err(BLASError::InvalidFlag(msg)) = result {
-            assert!(msg.contains("BLASError::InvalidFlag"));
+        if let Err(err @ BLASError::InvalidFlag(_)) = result {
+            assert!(err.to_string().contains("BLASError::InvalidFlag"));
         } else {
             panic!("Expected BLASError::InvalidFlag");
         }
@@ -234,9 +605,9 @@ mod tests {
     #[test]
     fn test_blas_raise_macro_with_args() {
         let result: Result<(), BLASError> = blas_raise!(InvalidFlag, "test_message");
-        if let Err(BLASError::InvalidFlag(msg)) = result {
-            assert!(msg.contains("BLASError::InvalidFlag"));
-            assert!(msg.contains("test_message"));
+        if let Err(err @ BLASError::InvalidFlag(_)) = result {
+            assert!(err.to_string().contains("BLASError::InvalidFlag"));
+            assert!(err.to_string().contains("test_message"));
         } else {
             panic!("Expected BLASError::InvalidFlag");
         }
@@ -246,11 +617,29 @@ mod tests {
     fn test_blas_invalid_macro() {
         let word = 1;
         let result: Result<(), BLASError> = blas_invalid!(word);
-        if let Err(BLASError::InvalidFlag(msg)) = result {
-            assert!(msg.contains("BLASError::InvalidFlag"));
-            assert!(msg.contains(r#""word" = 1"#));
+        if let Err(err @ BLASError::InvalidFlag(info)) = result {
+            assert!(err.to_string().contains("BLASError::InvalidFlag"));
+            assert_eq!(info.param, "word");
         } else {
             panic!("Expected BLASError::InvalidFlag");
         }
     }
+
+    #[test]
+    fn test_error_info() {
+        // wrapper is inferred from source file, and position is that of Fortran routine
+        let info = BLASErrorInfo::new("src/blas3/gemm.rs:10", "", "b.len_of(Axis(0))");
+        assert_eq!((info.wrapper, info.param, info.position), ("GEMM", "b", Some(9)));
+        let info = BLASErrorInfo::new("src/blas2/gemv.rs:10", "", "&obj.trans");
+        assert_eq!((info.wrapper, info.param, info.position), ("GEMV", "trans", Some(1)));
+        let info = BLASErrorInfo::new("src/util/util_ndarray.rs:10", "", "k_ > 0");
+        assert_eq!((info.wrapper, info.param, info.position), ("", "k_", None));
+
+        let dims = BLASDims::from((3, 4));
+        assert_eq!(alloc::format!("{dims}"), "(3, 4)");
+        assert_eq!(dims.as_slice(), [3, 4]);
+        let err = BLASError::InvalidDim(info.with_dims(dims, BLASDims::from((4, 4))));
+        assert_eq!(err.code().kind, BLASErrorKind::InvalidDim);
+        assert_eq!(err.code().info.expected, Some(BLASDims::from((4, 4))));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::ffi::c_char;
+use crate::util::blas_plan::plan_layout;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BLASLayout {
@@ -24,7 +25,9 @@ pub enum BLASTranspose {
     ConjNoTrans = 114,
 }
 
-pub use BLASTranspose::{ConjTrans as BLASConjTrans, NoTrans as BLASNoTrans, Trans as BLASTrans};
+pub use BLASTranspose::{
+    ConjNoTrans as BLASConjNoTrans, ConjTrans as BLASConjTrans, NoTrans as BLASNoTrans, Trans as BLASTrans,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BLASUpLo {
@@ -100,6 +103,7 @@ impl From<char> for BLASTranspose {
             'N' => BLASNoTrans,
             'T' => BLASTrans,
             'C' => BLASConjTrans,
+            'R' => BLASConjNoTrans,
             _ => Self::Undefined,
         }
     }
@@ -113,6 +117,7 @@ impl TryFrom<BLASTranspose> for char {
             BLASNoTrans => Ok('N'),
             BLASTrans => Ok('T'),
             BLASConjTrans => Ok('C'),
+            BLASConjNoTrans => Ok('R'),
             _ => blas_invalid!(trans),
         }
     }
@@ -126,6 +131,7 @@ impl TryFrom<BLASTranspose> for c_char {
             BLASNoTrans => Ok('N' as c_char),
             BLASTrans => Ok('T' as c_char),
             BLASConjTrans => Ok('C' as c_char),
+            BLASConjNoTrans => Ok('R' as c_char),
             _ => blas_invalid!(trans),
         }
     }
@@ -282,6 +288,30 @@ impl BLASTranspose {
             _ => blas_invalid!(self),
         }
     }
+
+    /// Flip transpose flag for transposed matrix, keeping conjugate (`N <-> T`, `R <-> C`).
+    #[inline]
+    pub fn flip_exact(&self) -> Result<Self, BLASError> {
+        match self {
+            BLASNoTrans => Ok(BLASTrans),
+            BLASTrans => Ok(BLASNoTrans),
+            BLASConjNoTrans => Ok(BLASConjTrans),
+            BLASConjTrans => Ok(BLASConjNoTrans),
+            _ => blas_invalid!(self),
+        }
+    }
+
+    /// Transpose flag for conjugated matrix, keeping transpose (`N <-> R`, `T <-> C`).
+    #[inline]
+    pub fn conj(&self) -> Result<Self, BLASError> {
+        match self {
+            BLASNoTrans => Ok(BLASConjNoTrans),
+            BLASTrans => Ok(BLASConjTrans),
+            BLASConjNoTrans => Ok(BLASNoTrans),
+            BLASConjTrans => Ok(BLASTrans),
+            _ => blas_invalid!(self),
+        }
+    }
 }
 
 unsafe impl Send for BLASLayout {}
@@ -317,6 +347,12 @@ impl BLASLayout {
 }
 
 pub(crate) fn get_layout_row_preferred(by_first: &[Option<BLASLayout>], by_all: &[BLASLayout]) -> BLASLayout {
+    let layout = layout_row_preferred(by_first, by_all);
+    plan_layout(layout);
+    return layout;
+}
+
+fn layout_row_preferred(by_first: &[Option<BLASLayout>], by_all: &[BLASLayout]) -> BLASLayout {
     for x in by_first {
         if let Some(x) = x {
             if x.is_cpref() {
@@ -372,6 +408,8 @@ mod tests {
         assert_eq!(BLASTranspose::from('t'), BLASTrans);
         assert_eq!(BLASTranspose::from('C'), BLASConjTrans);
         assert_eq!(BLASTranspose::from('c'), BLASConjTrans);
+        assert_eq!(BLASTranspose::from('R'), BLASConjNoTrans);
+        assert_eq!(BLASTranspose::from('r'), BLASConjNoTrans);
         assert_eq!(BLASTranspose::from('X'), BLASTranspose::Undefined);
     }
 
@@ -380,6 +418,7 @@ mod tests {
         assert_eq!(char::try_from(BLASNoTrans), Ok('N'));
         assert_eq!(char::try_from(BLASTrans), Ok('T'));
         assert_eq!(char::try_from(BLASConjTrans), Ok('C'));
+        assert_eq!(char::try_from(BLASConjNoTrans), Ok('R'));
         assert!(char::try_from(BLASTranspose::Undefined).is_err());
     }
 
@@ -388,6 +427,7 @@ mod tests {
         assert_eq!(c_char::try_from(BLASNoTrans), Ok('N' as c_char));
         assert_eq!(c_char::try_from(BLASTrans), Ok('T' as c_char));
         assert_eq!(c_char::try_from(BLASConjTrans), Ok('C' as c_char));
+        assert_eq!(c_char::try_from(BLASConjNoTrans), Ok('R' as c_char));
         assert!(c_char::try_from(BLASTranspose::Undefined).is_err());
     }
 
@@ -492,6 +532,24 @@ mod tests {
         assert!(BLASTranspose::Undefined.flip(false).is_err());
     }
 
+    #[test]
+    fn test_blastranspose_flip_exact() {
+        assert_eq!(BLASNoTrans.flip_exact(), Ok(BLASTrans));
+        assert_eq!(BLASTrans.flip_exact(), Ok(BLASNoTrans));
+        assert_eq!(BLASConjNoTrans.flip_exact(), Ok(BLASConjTrans));
+        assert_eq!(BLASConjTrans.flip_exact(), Ok(BLASConjNoTrans));
+        assert!(BLASTranspose::Undefined.flip_exact().is_err());
+    }
+
+    #[test]
+    fn test_blastranspose_conj() {
+        assert_eq!(BLASNoTrans.conj(), Ok(BLASConjNoTrans));
+        assert_eq!(BLASTrans.conj(), Ok(BLASConjTrans));
+        assert_eq!(BLASConjNoTrans.conj(), Ok(BLASNoTrans));
+        assert_eq!(BLASConjTrans.conj(), Ok(BLASTrans));
+        assert!(BLASTranspose::Undefined.conj().is_err());
+    }
+
     #[test]
     fn test_blaslayout_is_cpref() {
         assert!(BLASRowMajor.is_cpref());
@@ -0,0 +1,353 @@
+//! Introspection of BLAS calls and explicit copies, by `explain` of wrapper builders.
+//!
+//! `explain` runs the wrapper as `run` does, including all validation, but BLAS routines are recorded instead
+//! of called. Explicit copies of inputs (and buffers of output) are recorded with their shapes and bytes, but
+//! data is neither copied nor conjugated, and the output is left untouched. Planning state is stored in
+//! thread-local storage, so `explain` requires feature `std`.
+//!
+//! Reusable plans of wrappers (such as [`GEMMPlan`](crate::blas3::gemm::GEMMPlan)) are also based on `explain`:
+//! if the wrapper call is found to be a single BLAS call without any copy of arrays, later calls with arrays of
+//! the same shapes and strides call BLAS directly.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+extern crate alloc;
+
+use crate::ffi::{blas_int, c_char};
+use crate::util::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+use ndarray::prelude::*;
+use ndarray::Data;
+
+/// Report of wrapper call, given by `explain`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BLASPlan {
+    /// Name of wrapper (such as `"GEMM"`).
+    pub wrapper: &'static str,
+    /// Layout chosen by wrapper; row-major problems are flipped to col-major before calling BLAS.
+    ///
+    /// For wrappers of multiple problems (`GEMM_BATCH`), layout of each problem is given.
+    pub layouts: Vec<BLASLayout>,
+    /// Whether conjugate of output is computed (by conjugated inputs) to avoid conjugate copies of inputs.
+    pub conj_output: bool,
+    /// BLAS routines to be called, in order; empty if wrapper returns without calling BLAS.
+    pub calls: Vec<BLASCall>,
+    /// Explicit copies of arrays, in order.
+    pub copies: Vec<BLASCopy>,
+}
+
+/// BLAS routine to be called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BLASCall {
+    /// Name of Fortran routine (such as `"dgemm_"`), or of other kernel (such as `"faer::dgemm"`).
+    pub routine: &'static str,
+    /// Character flags (trans, uplo, side, diag, etc.), as passed to routine.
+    pub flags: Vec<(&'static str, char)>,
+    /// Integer arguments (dimensions, leading dimensions, increments, etc.), as passed to routine.
+    pub dims: Vec<(&'static str, isize)>,
+}
+
+/// Explicit copy of array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BLASCopy {
+    /// Shape of array to be copied.
+    pub shape: Vec<usize>,
+    /// Strides of array to be copied.
+    pub strides: Vec<isize>,
+    /// Reason of copy.
+    pub reason: String,
+    /// Number of bytes to be copied.
+    pub bytes: usize,
+}
+
+impl BLASCall {
+    /// Character flag of name (such as `"transa"`).
+    pub fn flag(&self, name: &str) -> Option<char> {
+        self.flags.iter().find(|(n, _)| *n == name).map(|&(_, f)| f)
+    }
+
+    /// Integer argument of name (such as `"lda"`).
+    pub fn dim(&self, name: &str) -> Option<isize> {
+        self.dims.iter().find(|(n, _)| *n == name).map(|&(_, d)| d)
+    }
+}
+
+impl BLASPlan {
+    /// Total number of bytes to be copied.
+    pub fn copy_bytes(&self) -> usize {
+        self.copies.iter().map(|c| c.bytes).sum()
+    }
+}
+
+/* #region planning state */
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static PLAN: core::cell::RefCell<Option<BLASPlan>> = const { core::cell::RefCell::new(None) };
+}
+
+#[cfg(feature = "std")]
+fn with_plan(f: impl FnOnce(&mut BLASPlan)) -> bool {
+    PLAN.with(|p| match p.borrow_mut().as_mut() {
+        Some(plan) => {
+            f(plan);
+            true
+        },
+        None => false,
+    })
+}
+
+#[cfg(not(feature = "std"))]
+fn with_plan(_f: impl FnOnce(&mut BLASPlan)) -> bool {
+    false
+}
+
+/// Run `f` in planning mode, and return the report.
+///
+/// This is the implementation of `explain` of wrapper builders.
+#[cfg(feature = "std")]
+pub fn explain_with(f: impl FnOnce() -> Result<(), BLASError>) -> Result<BLASPlan, BLASError> {
+    struct Restore(Option<BLASPlan>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PLAN.with(|p| *p.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(PLAN.with(|p| p.borrow_mut().replace(BLASPlan::default())));
+    f()?;
+    return Ok(PLAN.with(|p| p.borrow_mut().take()).unwrap_or_default());
+}
+
+/// Whether wrapper is running by `explain`; data of output should not be modified.
+pub(crate) fn is_planning() -> bool {
+    with_plan(|_| ())
+}
+
+pub(crate) fn plan_wrapper(wrapper: &'static str) {
+    with_plan(|plan| {
+        if plan.wrapper.is_empty() {
+            plan.wrapper = wrapper;
+        }
+    });
+}
+
+pub(crate) fn plan_layout(layout: BLASLayout) {
+    with_plan(|plan| plan.layouts.push(layout));
+}
+
+pub(crate) fn plan_conj_output(conj_output: bool) {
+    with_plan(|plan| plan.conj_output |= conj_output);
+}
+
+pub(crate) fn plan_copy(shape: &[usize], strides: &[isize], reason: &str, elem_size: usize) -> bool {
+    with_plan(|plan| {
+        plan.copies.push(BLASCopy {
+            shape: shape.to_vec(),
+            strides: strides.to_vec(),
+            reason: String::from(reason),
+            bytes: shape.iter().product::<usize>() * elem_size,
+        })
+    })
+}
+
+/// Record BLAS call if planning; in this case, BLAS routine should not be called.
+#[doc(hidden)]
+pub fn plan_call(
+    routine: &'static str,
+    flags: &[(&'static str, c_char)],
+    dims: &[(&'static str, blas_int)],
+) -> bool {
+    with_plan(|plan| {
+        plan.calls.push(BLASCall {
+            routine,
+            flags: flags.iter().map(|&(n, f)| (n, f as u8 as char)).collect(),
+            dims: dims.iter().map(|&(n, d)| (n, d as isize)).collect(),
+        })
+    })
+}
+
+/// Record call of kernel other than BLAS (such as `"matrixmultiply::dgemm"`) if planning; in this case, kernel
+/// should not be called.
+pub(crate) fn plan_kernel_call(
+    routine: &'static str,
+    flags: &[(&'static str, char)],
+    dims: &[(&'static str, isize)],
+) -> bool {
+    with_plan(|plan| plan.calls.push(BLASCall { routine, flags: flags.to_vec(), dims: dims.to_vec() }))
+}
+
+/// Name of faer kernel (such as `"faer::dgemm"`) from name prefixed to Fortran routine (such as
+/// `"faer::dgemm_"`).
+#[doc(hidden)]
+pub fn faer_kernel(name: &'static str) -> &'static str {
+    return name.strip_suffix('_').unwrap_or(name);
+}
+
+/* #endregion */
+
+/* #region reusable plans */
+
+impl BLASCall {
+    pub(crate) fn c_flag(&self, name: &str) -> Result<c_char, BLASError> {
+        match self.flag(name) {
+            Some(f) => Ok(f as u8 as c_char),
+            None => blas_raise!(RuntimeError, "Flag not recorded in BLAS call."),
+        }
+    }
+
+    pub(crate) fn c_dim(&self, name: &str) -> Result<blas_int, BLASError> {
+        match self.dim(name) {
+            Some(d) => Ok(d.try_into()?),
+            None => blas_raise!(RuntimeError, "Dimension not recorded in BLAS call."),
+        }
+    }
+}
+
+/// Shape and strides of array, checked when executing reusable plans.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ArraySignature {
+    shape: Vec<usize>,
+    strides: Vec<isize>,
+}
+
+impl ArraySignature {
+    pub(crate) fn of<S, D>(array: &ArrayBase<S, D>) -> ArraySignature
+    where
+        S: Data,
+        D: Dimension,
+    {
+        ArraySignature { shape: array.shape().to_vec(), strides: array.strides().to_vec() }
+    }
+
+    pub(crate) fn check<S, D>(&self, array: &ArrayBase<S, D>, name: &'static str) -> Result<(), BLASError>
+    where
+        S: Data,
+        D: Dimension,
+    {
+        let location = concat!(file!(), ":", line!());
+        if array.shape() != self.shape.as_slice() {
+            let info = BLASErrorInfo::new(location, "shape differs from plan", name);
+            return Err(BLASError::InvalidDim(
+                info.with_dims(array.shape().into(), self.shape.as_slice().into()),
+            ));
+        }
+        if array.strides() != self.strides.as_slice() {
+            let info = BLASErrorInfo::new(location, "strides differ from plan", name);
+            let info = info.with_dims(array.strides().into(), self.strides.as_slice().into());
+            return Err(BLASError::FailedCheck(info));
+        }
+        return Ok(());
+    }
+
+    /// Check optional array; presence should also be the same as plan.
+    pub(crate) fn check_opt<S, D>(
+        sig: &Option<ArraySignature>,
+        array: Option<&ArrayBase<S, D>>,
+        name: &'static str,
+    ) -> Result<(), BLASError>
+    where
+        S: Data,
+        D: Dimension,
+    {
+        match (sig, array) {
+            (Some(sig), Some(array)) => sig.check(array, name),
+            (None, None) => Ok(()),
+            _ => {
+                let location = concat!(file!(), ":", line!());
+                Err(BLASError::FailedCheck(BLASErrorInfo::new(
+                    location,
+                    "presence of array differs from plan",
+                    name,
+                )))
+            },
+        }
+    }
+}
+
+/// Layout and BLAS call of `f`, if `f` calls BLAS once without any copy or conjugate of arrays.
+///
+/// Calls of stride-native kernels (layout [`BLASLayout::NonContiguous`]) are not regarded as direct. In this case, reusable plan can call BLAS directly. Without feature `std`, this is always `None`, and `f` is
+/// not evaluated.
+pub(crate) fn plan_direct_call(
+    f: impl FnOnce() -> Result<(), BLASError>,
+) -> Result<Option<(BLASLayout, BLASCall)>, BLASError> {
+    #[cfg(feature = "std")]
+    {
+        let BLASPlan { layouts, conj_output, mut calls, copies, .. } = explain_with(f)?;
+        if layouts.len() == 1
+            && layouts[0] != BLASLayout::NonContiguous
+            && !conj_output
+            && calls.len() == 1
+            && copies.is_empty()
+        {
+            return Ok(Some((layouts[0], calls.remove(0))));
+        }
+        return Ok(None);
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = f;
+        return Ok(None);
+    }
+}
+
+/* #endregion */
+
+/* #region macros */
+
+/// Record BLAS call if planning, in implementation of BLAS func traits; evaluates to `true` if recorded.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! blas_plan_call {
+    ($func:ident, [$($flag:ident),*], [$($dim:ident),*]) => {
+        $crate::util::plan_call(
+            stringify!($func),
+            &[$((stringify!($flag), *$flag)),*],
+            &[$((stringify!($dim), *$dim)),*],
+        )
+    };
+    // Level 3 routines computed by faer kernels (crate feature `faer`)
+    (@level3 $func:ident, [$($flag:ident),*], [$($dim:ident),*]) => {
+        $crate::util::plan_call(
+            match cfg!(feature = "faer") {
+                true => $crate::util::faer_kernel(concat!("faer::", stringify!($func))),
+                false => stringify!($func),
+            },
+            &[$((stringify!($flag), *$flag)),*],
+            &[$((stringify!($dim), *$dim)),*],
+        )
+    };
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_explain_with() {
+        assert!(!is_planning());
+        let plan = explain_with(|| {
+            plan_wrapper("GEMM");
+            plan_layout(BLASRowMajor);
+            assert!(plan_call("dgemm_", &[("transa", b'N' as c_char)], &[("m", 3)]));
+            Ok(())
+        })
+        .unwrap();
+        assert!(!is_planning());
+        assert_eq!(plan.wrapper, "GEMM");
+        assert_eq!(plan.layouts, [BLASRowMajor]);
+        assert_eq!(plan.calls[0].flag("transa"), Some('N'));
+        assert_eq!(plan.calls[0].dim("m"), Some(3));
+
+        // error is propagated, and planning state is restored
+        let result = explain_with(|| blas_raise!(InvalidDim));
+        assert!(matches!(result, Err(BLASError::InvalidDim(_))));
+        assert!(!plan_call("dgemm_", &[], &[]));
+    }
+}
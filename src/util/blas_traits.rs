@@ -101,6 +101,16 @@ where
     D: Dimension,
 {
     fn run(self) -> Result<ArrayOut<'c, F, D>, BLASError>;
+
+    /// Validate and plan as [`run`](BLASBuilder::run) does, but without calling BLAS or copying data; see [`BLASPlan`].
+    #[cfg(feature = "std")]
+    fn explain(self) -> Result<BLASPlan, BLASError>
+    where
+        Self: Sized,
+        F: 'c,
+    {
+        explain_with(|| self.run().map(|_| ()))
+    }
 }
 
 // Following test is assisted by DeepSeek
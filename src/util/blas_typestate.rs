@@ -0,0 +1,153 @@
+//! Typestate builders, which check required arguments of wrappers at compile time.
+//!
+//! For each wrapper, a typestate builder (such as `GEMM_Typed`) wraps the `derive_builder` builder (such as
+//! `GEMM_Builder`). Each required argument has a type parameter of state [`Unset`] or [`Set`], and `run` is only
+//! available when all required arguments are [`Set`]. Aliases of the same names as the usual wrappers (such as
+//! `DGEMM`) are given in [`prelude::typed`](crate::prelude::typed).
+//!
+//! ```rust
+//! use blas_array2::prelude::typed::*;
+//! use ndarray::prelude::*;
+//!
+//! let a = Array2::<f64>::ones((3, 4));
+//! let b = Array2::<f64>::ones((4, 5));
+//! let c = DGEMM::default().a(a.view()).b(b.view()).alpha(2.0).run().unwrap().into_owned();
+//! assert_eq!(c[[0, 0]], 8.0);
+//! ```
+//!
+//! Missing required argument is a compile error, instead of [`BLASError::UninitializedField`](crate::util::BLASError)
+//! at runtime:
+//!
+//! ```compile_fail
+//! use blas_array2::prelude::typed::*;
+//! use ndarray::prelude::*;
+//!
+//! let a = Array2::<f64>::ones((3, 4));
+//! let c = DGEMM::default().a(a.view()).run();
+//! ```
+
+/// State of required argument of typestate builders: given.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Set;
+
+/// State of required argument of typestate builders: not given yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unset;
+
+/// States of required arguments that allow `run` of typestate builders.
+#[diagnostic::on_unimplemented(
+    message = "required argument of BLAS wrapper is not set",
+    label = "state of this argument is `Unset`"
+)]
+pub trait IsSet {}
+
+impl IsSet for Set {}
+
+/* #region macros */
+
+/// Generate typestate builder of wrapper.
+///
+/// - `required`: arguments without default, each with name of its state type parameter;
+/// - `optional`: arguments with default;
+/// - `aliases`: generic aliases (`GEMM`), and aliases of concrete float types (`DGEMM = f64`).
+///
+/// This should be invoked in module `typed` of wrapper, so that aliases do not conflict with usual wrappers.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! blas_typestate {
+    (
+        $(#[$meta:meta])*
+        $typed:ident for $builder:ident [$($lt:lifetime),*] [$($F:ident)?] where [$($w:tt)*] => $out:ty;
+        required { $($req:ident: $req_ty:ty as $state:ident),* $(,)? }
+        optional { $($opt:ident: $opt_ty:ty),* $(,)? }
+        aliases { $($aliases:tt)* }
+    ) => {
+        $(#[$meta])*
+        pub struct $typed<$($lt,)* $($F,)? $($state = $crate::util::Unset),*>
+        where
+            $($w)*
+        {
+            builder: $builder<$($lt,)* $($F)?>,
+            _state: core::marker::PhantomData<($($state,)*)>,
+        }
+
+        impl<$($lt,)* $($F)?> Default for $typed<$($lt,)* $($F)?>
+        where
+            $($w)*
+        {
+            fn default() -> Self {
+                Self { builder: $builder::default(), _state: core::marker::PhantomData }
+            }
+        }
+
+        impl<$($lt,)* $($F,)? $($state),*> $typed<$($lt,)* $($F,)? $($state),*>
+        where
+            $($w)*
+        {
+            $(
+                pub fn $opt(self, value: impl Into<$opt_ty>) -> Self {
+                    Self { builder: self.builder.$opt(value.into()), _state: core::marker::PhantomData }
+                }
+            )*
+
+            /// Builder of `derive_builder`, where required arguments are checked at runtime.
+            pub fn into_builder(self) -> $builder<$($lt,)* $($F)?> {
+                self.builder
+            }
+        }
+
+        impl<$($lt,)* $($F,)? $($state: $crate::util::IsSet),*> $typed<$($lt,)* $($F,)? $($state),*>
+        where
+            $($w)*
+        {
+            pub fn run(self) -> Result<$out, $crate::util::BLASError> {
+                self.builder.run()
+            }
+
+            /// Validate and plan as `run` does, but without calling BLAS; see [`BLASPlan`](crate::util::BLASPlan).
+            #[cfg(feature = "std")]
+            pub fn explain(self) -> Result<$crate::util::BLASPlan, $crate::util::BLASError> {
+                self.builder.explain()
+            }
+        }
+
+        $crate::blas_typestate!(
+            @required $typed [$($lt),*] [$($F)?] [$($w)*] []; $($req: $req_ty as $state),*
+        );
+
+        $crate::blas_typestate!(@aliases $typed [$($lt),*] [$($F)?] [$($state),*]; $($aliases)*);
+    };
+
+    // setter of the first remaining required argument, which sets its state to `Set`
+    (
+        @required $typed:ident [$($lt:lifetime),*] [$($F:ident)?] [$($w:tt)*] [$($pre:ident),*];
+        $req:ident: $req_ty:ty as $state:ident $(, $post:ident: $post_ty:ty as $post_state:ident)*
+    ) => {
+        impl<$($lt,)* $($F,)? $($pre,)* $state, $($post_state),*> $typed<$($lt,)* $($F,)? $($pre,)* $state, $($post_state),*>
+        where
+            $($w)*
+        {
+            pub fn $req(self, value: impl Into<$req_ty>) -> $typed<$($lt,)* $($F,)? $($pre,)* $crate::util::Set, $($post_state),*> {
+                $typed { builder: self.builder.$req(value.into()), _state: core::marker::PhantomData }
+            }
+        }
+
+        $crate::blas_typestate!(
+            @required $typed [$($lt),*] [$($F)?] [$($w)*] [$($pre,)* $state]; $($post: $post_ty as $post_state),*
+        );
+    };
+    (@required $typed:ident [$($lt:lifetime),*] [$($F:ident)?] [$($w:tt)*] [$($pre:ident),*];) => {};
+
+    // generic alias (without type), or alias of concrete float type
+    (@aliases $typed:ident [$($lt:lifetime),*] [$($F:ident)?] [$($state:ident),*]; $alias:ident $(, $($rest:tt)*)?) => {
+        pub type $alias<$($lt,)* $($F,)? $($state = $crate::util::Unset),*> = $typed<$($lt,)* $($F,)? $($state),*>;
+        $crate::blas_typestate!(@aliases $typed [$($lt),*] [$($F)?] [$($state),*]; $($($rest)*)?);
+    };
+    (@aliases $typed:ident [$($lt:lifetime),*] [$($F:ident)?] [$($state:ident),*]; $alias:ident = $cty:ty $(, $($rest:tt)*)?) => {
+        pub type $alias<$($lt,)* $($state = $crate::util::Unset),*> = $typed<$($lt,)* $cty, $($state),*>;
+        $crate::blas_typestate!(@aliases $typed [$($lt),*] [$($F)?] [$($state),*]; $($($rest)*)?);
+    };
+    (@aliases $typed:ident [$($lt:lifetime),*] [$($F:ident)?] [$($state:ident),*];) => {};
+}
+
+/* #endregion */
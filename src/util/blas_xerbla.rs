@@ -0,0 +1,90 @@
+//! Catching argument errors reported by BLAS library through `xerbla_`.
+//!
+//! When a parameter passes checks of wrapper but is rejected by BLAS library, reference BLAS and OpenBLAS call
+//! `xerbla_`, which prints message and may terminate the process. With crate feature `xerbla`, this crate
+//! exports its own `xerbla_`, which only records routine name and `INFO` in thread-local storage. Wrappers check
+//! the record after calling BLAS, and return [`BLASError::LibraryArgument`].
+//!
+//! Whether `xerbla_` of this crate takes precedence depends on how BLAS library is linked: this is usually the
+//! case for shared libraries that call `xerbla_` through dynamic symbol (reference BLAS, OpenBLAS, BLIS).
+
+#[cfg(feature = "xerbla")]
+extern crate std;
+
+extern crate alloc;
+
+#[cfg(feature = "xerbla")]
+use crate::ffi::{blas_int, c_char};
+use crate::util::*;
+#[cfg(feature = "xerbla")]
+use alloc::string::String;
+
+/// Maximum length of routine name recorded by `xerbla_`.
+#[cfg(feature = "xerbla")]
+const ROUTINE_LEN: usize = 16;
+
+#[cfg(feature = "xerbla")]
+std::thread_local! {
+    static XERBLA: core::cell::Cell<Option<([u8; ROUTINE_LEN], blas_int)>> = const { core::cell::Cell::new(None) };
+}
+
+/// Error handler called by BLAS routines on illegal argument.
+///
+/// Routine name (Fortran `CHARACTER*(*)`, padded by spaces) and `INFO` are recorded, instead of terminating.
+/// Exported under the symbol naming convention of [`symbol_name`](crate::ffi::symbol_name) (such as
+/// `xerbla_64_`), which is called by BLAS library of the same convention.
+///
+/// # Safety
+///
+/// `srname` should be valid for `len` bytes (or null-terminated), and `info` should be valid pointer.
+#[cfg(feature = "xerbla")]
+#[export_name = crate::ffi::blas_symbol!("xerbla")]
+pub unsafe extern "C" fn xerbla_(srname: *const c_char, info: *const blas_int, len: usize) {
+    let mut routine = [b' '; ROUTINE_LEN];
+    if !srname.is_null() {
+        // length argument may be 32-bit for older compilers; name is also terminated by nul or space
+        for (i, r) in routine.iter_mut().enumerate().take(len.min(ROUTINE_LEN)) {
+            let c = *srname.add(i) as u8;
+            if c == 0 || c == b' ' {
+                break;
+            }
+            *r = c;
+        }
+    }
+    let info = if info.is_null() { 0 } else { *info };
+    XERBLA.with(|x| x.set(Some((routine, info))));
+}
+
+/// Take the record of `xerbla_` on current thread, as routine name and `INFO`.
+///
+/// This is usually not required to be called, since wrappers check it after calling BLAS. It is useful when
+/// BLAS functions in [`ffi`](crate::ffi) are called directly.
+#[cfg(feature = "xerbla")]
+pub fn take_xerbla() -> Option<(String, blas_int)> {
+    let (routine, info) = XERBLA.with(|x| x.take())?;
+    let routine = routine.iter().take_while(|&&c| c != b' ').map(|&c| c as char).collect();
+    return Some((routine, info));
+}
+
+/// Convert record of `xerbla_` on current thread to error, and clear the record.
+///
+/// This is called by wrappers after calling BLAS; always `Ok` without crate feature `xerbla`.
+#[inline]
+pub fn check_xerbla() -> Result<(), BLASError> {
+    #[cfg(feature = "xerbla")]
+    if let Some((routine, info)) = take_xerbla() {
+        return Err(BLASError::LibraryArgument { routine, info });
+    }
+    return Ok(());
+}
+
+/// Check status after calling BLAS: missing symbol of dynamically loaded library (crate feature `dynamic`), and
+/// illegal argument reported by `xerbla_` (crate feature `xerbla`).
+#[inline]
+pub(crate) fn check_blas_call() -> Result<(), BLASError> {
+    #[cfg(feature = "dynamic")]
+    if let Some(symbol) = crate::ffi::dynamic::take_missing_symbol() {
+        return Err(BLASError::MissingSymbol(symbol));
+    }
+    return check_xerbla();
+}
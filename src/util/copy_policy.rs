@@ -0,0 +1,286 @@
+//! Runtime policy on explicit copy (transposition / conjugate) of arrays.
+//!
+//! Effective policy is resolved in order of
+//! - per-call policy (`.copy_policy(...)` of wrapper builders);
+//! - per-thread policy ([`scoped_copy_policy`], requires feature `std`);
+//! - global policy ([`set_copy_policy`]), which defaults to the compile-time features `error_on_copy` or
+//!   `warn_on_copy`.
+//!
+//! Per-call and per-thread policies are stored in thread-local storage, so they are only effective with
+//! feature `std`; without `std`, only global policy is respected.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use crate::util::*;
+use core::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+
+/// Callback when explicit copy is to be performed.
+///
+/// Returning error will abort the wrapper call with that error.
+pub type CopyCallback = fn(&CopyEvent) -> Result<(), BLASError>;
+
+/// Policy on explicit copy of arrays.
+#[derive(Debug, Clone, Copy)]
+pub enum CopyPolicy {
+    /// Perform copy silently.
+    Allow,
+    /// Print warning on stderr (only with feature `std`), then perform copy.
+    Warn,
+    /// Raise [`BLASError::ExplicitCopy`].
+    Error,
+    /// Call user function, which decides whether copy should be performed.
+    Callback(CopyCallback),
+}
+
+/// Information of explicit copy, passed to [`CopyPolicy::Callback`].
+#[derive(Debug, Clone, Copy)]
+pub struct CopyEvent<'a> {
+    /// Name of wrapper (such as `"GEMM"`); empty if copy is not performed in wrapper.
+    pub wrapper: &'static str,
+    /// Shape of array to be copied.
+    pub shape: &'a [usize],
+    /// Strides of array to be copied.
+    pub strides: &'a [isize],
+    /// Reason of copy.
+    pub reason: &'a str,
+}
+
+/* #region global policy */
+
+const POLICY_ALLOW: u8 = 0;
+const POLICY_WARN: u8 = 1;
+const POLICY_ERROR: u8 = 2;
+const POLICY_CALLBACK: u8 = 3;
+
+const POLICY_DEFAULT: u8 = if cfg!(feature = "error_on_copy") {
+    POLICY_ERROR
+} else if cfg!(all(feature = "std", feature = "warn_on_copy")) {
+    POLICY_WARN
+} else {
+    POLICY_ALLOW
+};
+
+static GLOBAL_POLICY: AtomicU8 = AtomicU8::new(POLICY_DEFAULT);
+static GLOBAL_CALLBACK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Set global copy policy.
+pub fn set_copy_policy(policy: CopyPolicy) {
+    let kind = match policy {
+        CopyPolicy::Allow => POLICY_ALLOW,
+        CopyPolicy::Warn => POLICY_WARN,
+        CopyPolicy::Error => POLICY_ERROR,
+        CopyPolicy::Callback(f) => {
+            // callback must be visible before policy kind
+            GLOBAL_CALLBACK.store(f as *mut (), Ordering::Release);
+            POLICY_CALLBACK
+        },
+    };
+    GLOBAL_POLICY.store(kind, Ordering::Release);
+}
+
+/// Global copy policy.
+pub fn global_copy_policy() -> CopyPolicy {
+    match GLOBAL_POLICY.load(Ordering::Acquire) {
+        POLICY_WARN => CopyPolicy::Warn,
+        POLICY_ERROR => CopyPolicy::Error,
+        POLICY_CALLBACK => {
+            let f = GLOBAL_CALLBACK.load(Ordering::Acquire);
+            // SAFETY: pointer is only stored from `CopyCallback` in `set_copy_policy`
+            CopyPolicy::Callback(unsafe { core::mem::transmute::<*mut (), CopyCallback>(f) })
+        },
+        _ => CopyPolicy::Allow,
+    }
+}
+
+/* #endregion */
+
+/* #region thread-local policy */
+
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+struct CopyState {
+    thread: Option<CopyPolicy>,
+    call: Option<CopyPolicy>,
+    wrapper: &'static str,
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static COPY_STATE: core::cell::Cell<CopyState> =
+        const { core::cell::Cell::new(CopyState { thread: None, call: None, wrapper: "" }) };
+}
+
+/// Guard of per-thread copy policy; previous policy is restored when dropped.
+#[cfg(feature = "std")]
+#[must_use = "copy policy is restored when guard is dropped"]
+pub struct CopyPolicyGuard {
+    prev: Option<CopyPolicy>,
+    // guard should be dropped on the thread it is created
+    _marker: core::marker::PhantomData<*const ()>,
+}
+
+/// Set copy policy of current thread, until returned guard is dropped.
+#[cfg(feature = "std")]
+pub fn scoped_copy_policy(policy: CopyPolicy) -> CopyPolicyGuard {
+    let prev = COPY_STATE.with(|s| {
+        let state = s.get();
+        s.set(CopyState { thread: Some(policy), ..state });
+        state.thread
+    });
+    CopyPolicyGuard { prev, _marker: core::marker::PhantomData }
+}
+
+#[cfg(feature = "std")]
+impl Drop for CopyPolicyGuard {
+    fn drop(&mut self) {
+        COPY_STATE.with(|s| s.set(CopyState { thread: self.prev, ..s.get() }));
+    }
+}
+
+/// Context of wrapper call, entered in `run` of wrapper builders.
+///
+/// Per-call policy of outer wrapper call is inherited if `policy` is not given.
+pub(crate) struct CopyContext {
+    #[cfg(feature = "std")]
+    prev: (Option<CopyPolicy>, &'static str),
+}
+
+impl CopyContext {
+    #[cfg(feature = "std")]
+    pub(crate) fn enter(wrapper: &'static str, policy: Option<CopyPolicy>) -> CopyContext {
+        plan_wrapper(wrapper);
+        let prev = COPY_STATE.with(|s| {
+            let state = s.get();
+            s.set(CopyState { call: policy.or(state.call), wrapper, ..state });
+            (state.call, state.wrapper)
+        });
+        CopyContext { prev }
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn enter(wrapper: &'static str, _policy: Option<CopyPolicy>) -> CopyContext {
+        plan_wrapper(wrapper);
+        CopyContext {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for CopyContext {
+    fn drop(&mut self) {
+        let (call, wrapper) = self.prev;
+        COPY_STATE.with(|s| s.set(CopyState { call, wrapper, ..s.get() }));
+    }
+}
+
+/* #endregion */
+
+/* #region policy handling */
+
+/// Effective copy policy of current call.
+pub fn current_copy_policy() -> CopyPolicy {
+    #[cfg(feature = "std")]
+    {
+        let state = COPY_STATE.with(|s| s.get());
+        if let Some(policy) = state.call.or(state.thread) {
+            return policy;
+        }
+    }
+    return global_copy_policy();
+}
+
+pub(crate) fn current_wrapper() -> &'static str {
+    #[cfg(feature = "std")]
+    {
+        return COPY_STATE.with(|s| s.get().wrapper);
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        return "";
+    }
+}
+
+/// Whether copy is rejected by current policy.
+///
+/// [`CopyPolicy::Callback`] is not regarded as rejecting, so that the callback observes copies as they would be
+/// performed.
+pub(crate) fn is_copy_rejected() -> bool {
+    return matches!(current_copy_policy(), CopyPolicy::Error);
+}
+
+/// Handle explicit copy of array by current policy; used by [`blas_warn_layout_clone`].
+#[doc(hidden)]
+pub fn on_explicit_copy(
+    shape: &[usize],
+    strides: &[isize],
+    reason: &'static str,
+    elem_size: usize,
+    location: &'static str,
+) -> Result<(), BLASError> {
+    // copies are recorded instead of handled by policy in `explain`
+    if plan_copy(shape, strides, reason, elem_size) {
+        return Ok(());
+    }
+    let wrapper = current_wrapper();
+    match current_copy_policy() {
+        CopyPolicy::Allow => Ok(()),
+        CopyPolicy::Warn => {
+            #[cfg(feature = "std")]
+            std::eprintln!("Warning: {reason} in {wrapper:?}, shape={shape:?}, strides={strides:?}");
+            Ok(())
+        },
+        CopyPolicy::Error => {
+            let info =
+                BLASErrorInfo { actual: Some(shape.into()), ..BLASErrorInfo::new(location, reason, "") };
+            Err(BLASError::ExplicitCopy(info))
+        },
+        CopyPolicy::Callback(f) => f(&CopyEvent { wrapper, shape, strides, reason }),
+    }
+}
+
+#[doc(hidden)]
+pub fn elem_size_of_ptr<T>(_: *const T) -> usize {
+    core::mem::size_of::<T>()
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global_copy_policy() {
+        fn callback(_: &CopyEvent) -> Result<(), BLASError> {
+            Ok(())
+        }
+
+        let prev = global_copy_policy();
+        set_copy_policy(CopyPolicy::Callback(callback));
+        assert!(
+            matches!(global_copy_policy(), CopyPolicy::Callback(f) if f as usize == callback as CopyCallback as usize)
+        );
+        set_copy_policy(prev);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_scoped_copy_policy() {
+        {
+            let _guard = scoped_copy_policy(CopyPolicy::Error);
+            assert!(matches!(current_copy_policy(), CopyPolicy::Error));
+            {
+                let _guard = scoped_copy_policy(CopyPolicy::Allow);
+                assert!(matches!(current_copy_policy(), CopyPolicy::Allow));
+                let _ctx = CopyContext::enter("GEMM", Some(CopyPolicy::Warn));
+                assert!(matches!(current_copy_policy(), CopyPolicy::Warn));
+                assert_eq!(current_wrapper(), "GEMM");
+            }
+            assert!(matches!(current_copy_policy(), CopyPolicy::Error));
+            assert_eq!(current_wrapper(), "");
+            let result = on_explicit_copy(&[2, 3], &[1, 4], "test", 8, "here");
+            assert!(matches!(result, Err(BLASError::ExplicitCopy(_))));
+        }
+        assert!(COPY_STATE.with(|s| s.get().thread.is_none()));
+    }
+}
@@ -1,11 +1,22 @@
 pub mod blas_error;
 pub mod blas_flags;
+pub mod blas_plan;
 pub mod blas_traits;
+pub mod blas_typestate;
+pub mod blas_xerbla;
+pub mod copy_policy;
 pub mod util_ndarray;
 
 pub use blas_error::*;
 pub use blas_flags::*;
+pub use blas_plan::*;
 pub use blas_traits::*;
+pub use blas_typestate::*;
+pub use blas_xerbla::*;
+pub use copy_policy::*;
 pub use util_ndarray::*;
 
-pub use crate::{blas_assert, blas_assert_eq, blas_invalid, blas_raise, blas_warn_layout_clone};
+pub use crate::{
+    blas_assert, blas_assert_eq, blas_invalid, blas_plan_call, blas_raise, blas_typestate,
+    blas_warn_layout_clone,
+};
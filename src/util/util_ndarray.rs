@@ -1,5 +1,8 @@
 use crate::util::*;
+use core::mem::MaybeUninit;
 use ndarray::prelude::*;
+use ndarray::IntoDimension;
+use num_traits::Zero;
 
 #[derive(Debug)]
 pub enum ArrayOut<'a, F, D>
@@ -37,7 +40,7 @@ where
             Self::ViewMut(arr) => arr.to_owned(),
             Self::Owned(arr) => arr,
             Self::ToBeCloned(mut arr_view, arr_owned) => {
-                arr_view.assign(&arr_owned);
+                assign_buffer(&mut arr_view, &arr_owned);
                 arr_owned
             },
         }
@@ -62,7 +65,7 @@ where
     pub fn clone_to_view_mut(self) -> Self {
         match self {
             ArrayOut::ToBeCloned(mut arr_view, arr_owned) => {
-                arr_view.assign(&arr_owned);
+                assign_buffer(&mut arr_view, &arr_owned);
                 ArrayOut::ViewMut(arr_view)
             },
             _ => self,
@@ -74,12 +77,26 @@ where
             ArrayOut::ViewMut(arr) => ArrayOut::ViewMut(arr.reversed_axes()),
             ArrayOut::Owned(arr) => ArrayOut::Owned(arr.reversed_axes()),
             ArrayOut::ToBeCloned(mut arr_view, arr_owned) => {
-                arr_view.assign(&arr_owned);
+                assign_buffer(&mut arr_view, &arr_owned);
                 ArrayOut::ViewMut(arr_view.reversed_axes())
             },
         }
     }
 
+    pub fn permuted_axes<T>(self, axes: T) -> Self
+    where
+        T: IntoDimension<Dim = D>,
+    {
+        match self {
+            ArrayOut::ViewMut(arr) => ArrayOut::ViewMut(arr.permuted_axes(axes)),
+            ArrayOut::Owned(arr) => ArrayOut::Owned(arr.permuted_axes(axes)),
+            ArrayOut::ToBeCloned(mut arr_view, arr_owned) => {
+                assign_buffer(&mut arr_view, &arr_owned);
+                ArrayOut::ViewMut(arr_view.permuted_axes(axes))
+            },
+        }
+    }
+
     pub fn get_data_mut_ptr(&mut self) -> *mut F {
         match self {
             Self::ViewMut(arr) => arr.as_mut_ptr(),
@@ -89,6 +106,51 @@ where
     }
 }
 
+impl<F> ArrayOut1<'_, F>
+where
+    F: Clone,
+{
+    /// Pointer of vector to be passed to BLAS, which is the element of lowest address for negative stride.
+    pub fn get_base_mut_ptr(&mut self) -> *mut F {
+        let offset = get_vec_base_offset(&self.view());
+        unsafe { self.get_data_mut_ptr().offset(offset) }
+    }
+}
+
+impl<F, D> ArrayOut<'_, F, D>
+where
+    F: BLASFloat,
+    D: Dimension,
+{
+    /// Conjugate output in-place.
+    pub(crate) fn conj_inplace(&mut self) {
+        plan_conj_output(F::is_complex());
+        conj_view_inplace(&mut self.view_mut());
+    }
+}
+
+/// Write buffer of output back to array; skipped in `explain`, where buffer is not computed.
+fn assign_buffer<F, D>(view: &mut ArrayViewMut<F, D>, owned: &Array<F, D>)
+where
+    F: Clone,
+    D: Dimension,
+{
+    if !is_planning() {
+        view.assign(owned);
+    }
+}
+
+/// Conjugate array in-place; skipped in `explain`, where data should not be modified.
+pub(crate) fn conj_view_inplace<F, D>(view: &mut ArrayViewMut<F, D>)
+where
+    F: BLASFloat,
+    D: Dimension,
+{
+    if !is_planning() {
+        view.mapv_inplace(F::conj);
+    }
+}
+
 pub type ArrayOut1<'a, F> = ArrayOut<'a, F, Ix1>;
 pub type ArrayOut2<'a, F> = ArrayOut<'a, F, Ix2>;
 pub type ArrayOut3<'a, F> = ArrayOut<'a, F, Ix3>;
@@ -127,10 +189,23 @@ pub fn get_layout_array2<F>(arr: &ArrayView2<F>) -> BLASLayout {
     }
 }
 
+/// Layout of each matrix in batch (axis 0 of array is batch).
+#[inline]
+pub fn get_layout_batch<F>(arr: &ArrayView3<F>) -> BLASLayout {
+    match arr.len_of(Axis(0)) {
+        0 => BLASLayout::Sequential,
+        _ => get_layout_array2(&arr.index_axis(Axis(0), 0)),
+    }
+}
+
 /* #endregion */
 
 /* #region flip */
 
+/// Obtain F-contiguous matrix, with transpose flag changed accordingly.
+///
+/// Transpose flag `R` (conjugate without transpose) is also accepted if not `hermi`, and conjugate copy of
+/// matrix is performed if it can not be represented by transpose flag.
 pub(crate) fn flip_trans_fpref<'a, F>(
     trans: BLASTranspose,
     view: &'a ArrayView2<F>,
@@ -140,8 +215,15 @@ pub(crate) fn flip_trans_fpref<'a, F>(
 where
     F: BLASFloat,
 {
+    let trans = strip_trivial_conj::<F>(trans, hermi);
     if view.is_fpref() {
-        return Ok((trans, view.to_col_layout()?));
+        match (trans, hermi) {
+            (BLASConjNoTrans, false) => Ok((BLASNoTrans, {
+                blas_warn_layout_clone!(view, "Perform element-wise conjugate to matrix")?;
+                CowArray::from(copy_to_col_layout(view, true))
+            })),
+            _ => Ok((trans, view.to_col_layout()?)),
+        }
     } else {
         match trans {
             BLASNoTrans => Ok((
@@ -150,20 +232,24 @@ where
                     false => view_t.to_col_layout()?,
                     true => {
                         blas_warn_layout_clone!(view_t, "Perform element-wise conjugate to matrix")?;
-                        CowArray::from(view.mapv(F::conj).reversed_axes())
+                        CowArray::from(copy_to_col_layout(view_t, true))
                     },
                 },
             )),
             BLASTrans => Ok((trans.flip(hermi)?, view_t.to_col_layout()?)),
             BLASConjTrans => Ok((trans.flip(hermi)?, {
                 blas_warn_layout_clone!(view_t, "Perform element-wise conjugate to matrix")?;
-                CowArray::from(view.mapv(F::conj).reversed_axes())
+                CowArray::from(copy_to_col_layout(view_t, true))
             })),
+            BLASConjNoTrans if !hermi => Ok((BLASConjTrans, view_t.to_col_layout()?)),
             _ => blas_invalid!(trans),
         }
     }
 }
 
+/// Obtain C-contiguous matrix, with transpose flag changed accordingly.
+///
+/// See also [`flip_trans_fpref`].
 pub(crate) fn flip_trans_cpref<'a, F>(
     trans: BLASTranspose,
     view: &'a ArrayView2<F>,
@@ -173,8 +259,15 @@ pub(crate) fn flip_trans_cpref<'a, F>(
 where
     F: BLASFloat,
 {
+    let trans = strip_trivial_conj::<F>(trans, hermi);
     if view.is_cpref() {
-        return Ok((trans, view.to_row_layout()?));
+        match (trans, hermi) {
+            (BLASConjNoTrans, false) => Ok((BLASNoTrans, {
+                blas_warn_layout_clone!(view, "Perform element-wise conjugate to matrix")?;
+                CowArray::from(copy_to_col_layout(view_t, true).reversed_axes())
+            })),
+            _ => Ok((trans, view.to_row_layout()?)),
+        }
     } else {
         match trans {
             BLASNoTrans => Ok((
@@ -183,14 +276,59 @@ where
                     false => view_t.to_row_layout()?,
                     true => {
                         blas_warn_layout_clone!(view_t, "Perform element-wise conjugate to matrix")?;
-                        CowArray::from(view_t.mapv(F::conj))
+                        CowArray::from(copy_to_col_layout(view, true).reversed_axes())
                     },
                 },
             )),
             BLASTrans => Ok((trans.flip(hermi)?, view_t.to_row_layout()?)),
             BLASConjTrans => Ok((trans.flip(hermi)?, {
                 blas_warn_layout_clone!(view_t, "Perform element-wise conjugate to matrix")?;
-                CowArray::from(view_t.mapv(F::conj))
+                CowArray::from(copy_to_col_layout(view, true).reversed_axes())
+            })),
+            BLASConjNoTrans if !hermi => Ok((BLASConjTrans, view_t.to_row_layout()?)),
+            _ => blas_invalid!(trans),
+        }
+    }
+}
+
+/// Conjugate is trivial for real types, so that `R` and `C` are the same to `N` and `T`.
+fn strip_trivial_conj<F>(trans: BLASTranspose, hermi: bool) -> BLASTranspose
+where
+    F: BLASFloat,
+{
+    match (F::is_complex() || hermi, trans) {
+        (false, BLASConjNoTrans) => BLASNoTrans,
+        (false, BLASConjTrans) => BLASTrans,
+        _ => trans,
+    }
+}
+
+/// Batched version of [`flip_trans_fpref`] (axis 0 of array is batch).
+///
+/// Layout of the whole batch is decided by the first matrix, and negative stride of batch is not allowed.
+pub(crate) fn flip_trans_fpref_batch<'a, F>(
+    trans: BLASTranspose,
+    view: &'a ArrayView3<F>,
+) -> Result<(BLASTranspose, CowArray<'a, F, Ix3>), BLASError>
+where
+    F: BLASFloat,
+{
+    let layout = get_layout_batch(view);
+    if view.stride_of(Axis(0)) < 0 || !(layout.is_fpref() || layout.is_cpref()) {
+        return Ok((trans, CowArray::from(to_col_layout_batch(view)?)));
+    } else if layout.is_fpref() {
+        return Ok((trans, CowArray::from(view)));
+    } else {
+        let view_t = view.view().permuted_axes([0, 2, 1]);
+        match trans {
+            BLASNoTrans | BLASTrans => Ok((trans.flip(false)?, CowArray::from(view_t))),
+            BLASConjTrans => Ok((trans.flip(false)?, {
+                blas_warn_layout_clone!(view_t, "Perform element-wise conjugate to matrix")?;
+                let owned = match is_planning() {
+                    true => Array3::zeros(view.raw_dim()),
+                    false => view.mapv(F::conj),
+                };
+                CowArray::from(owned.permuted_axes([0, 2, 1]))
             })),
             _ => blas_invalid!(trans),
         }
@@ -199,6 +337,70 @@ where
 
 /* #endregion */
 
+/* #region conjugate output */
+
+/// Number of elements that [`flip_trans_fpref`] (`layout` is col-major) or [`flip_trans_cpref`] (`layout` is
+/// row-major) copies only for conjugate.
+///
+/// Copy of non-contiguous matrix is not counted, since it is performed anyway and conjugate is fused into copy.
+pub(crate) fn flip_trans_conj_cost<F>(
+    trans: BLASTranspose,
+    view: &ArrayView2<F>,
+    layout: BLASLayout,
+    hermi: bool,
+) -> usize
+where
+    F: BLASFloat,
+{
+    let (pref, pref_t) = match layout {
+        BLASColMajor => (view.is_fpref(), view.is_cpref()),
+        _ => (view.is_cpref(), view.is_fpref()),
+    };
+    let conj_copy = match (pref, pref_t) {
+        (true, _) => trans == BLASConjNoTrans,
+        (false, true) => trans == BLASConjTrans || (hermi && trans == BLASNoTrans),
+        (false, false) => false,
+    };
+    match conj_copy && F::is_complex() {
+        true => view.len(),
+        false => 0,
+    }
+}
+
+/// Number of elements that [`ToLayoutCowArray2`] copies for `layout`.
+pub(crate) fn to_layout_cost<F>(view: &ArrayView2<F>, layout: BLASLayout) -> usize {
+    let pref = match layout {
+        BLASColMajor => view.is_fpref(),
+        _ => view.is_cpref(),
+    };
+    match pref {
+        true => 0,
+        false => view.len(),
+    }
+}
+
+/// Whether to compute conjugate of output (by conjugated inputs), which avoids some explicit copies of inputs.
+///
+/// `cost` and `cost_conj` are numbers of input elements to be copied without or with conjugate of output. Output
+/// of `len_out` elements is conjugated in-place after BLAS call, and also before if `referenced` by BLAS.
+///
+/// If current [`CopyPolicy`] rejects copies (`Error`), conjugate of output is always preferred if it avoids all
+/// copies of inputs.
+pub(crate) fn prefer_conj_output<F>(cost: usize, cost_conj: usize, len_out: usize, referenced: bool) -> bool
+where
+    F: BLASFloat,
+{
+    if !F::is_complex() {
+        return false;
+    }
+    let cost_out = len_out * (1 + referenced as usize);
+    let conj_out = cost > cost_conj + cost_out || (cost > 0 && cost_conj == 0 && is_copy_rejected());
+    plan_conj_output(conj_out);
+    return conj_out;
+}
+
+/* #endregion */
+
 /* #region contiguous preference */
 
 pub(crate) trait LayoutPref {
@@ -218,6 +420,139 @@ impl<A> LayoutPref for ArrayView2<'_, A> {
 
 /* #endregion */
 
+/* #region blocked copy */
+
+/// Edge length of square tile in cache-blocked copy.
+const COPY_TILE: usize = 32;
+
+/// Cache-blocked element-wise map `dst = f(src)` of matrices of the same shape.
+///
+/// Matrices are traversed tile by tile, so that both reading and writing are local in memory, even if the
+/// contiguous dimensions of source and destination are different (transposition).
+pub(crate) fn map_blocked<A, B>(src: &ArrayView2<A>, dst: &mut ArrayViewMut2<B>, f: impl Fn(&A) -> B) {
+    assert_eq!(src.dim(), dst.dim());
+    let (nrow, ncol) = src.dim();
+    for j0 in (0..ncol).step_by(COPY_TILE) {
+        let j1 = (j0 + COPY_TILE).min(ncol);
+        for i0 in (0..nrow).step_by(COPY_TILE) {
+            let i1 = (i0 + COPY_TILE).min(nrow);
+            for j in j0..j1 {
+                for i in i0..i1 {
+                    // indices are always in bounds of both matrices
+                    unsafe { *dst.uget_mut((i, j)) = f(src.uget((i, j))) };
+                }
+            }
+        }
+    }
+}
+
+/// Copy matrix to newly allocated col-major matrix by element-wise map `f`.
+fn map_to_col_layout<A, B>(view: &ArrayView2<A>, f: impl Fn(&A) -> B) -> Array2<B> {
+    let mut owned = Array2::uninit(view.dim().f());
+    map_blocked(view, &mut owned.view_mut(), |x| MaybeUninit::new(f(x)));
+    // all elements have been written by map_blocked
+    unsafe { owned.assume_init() }
+}
+
+/// Array standing for explicit copy in `explain`, where data is not copied.
+///
+/// Only shape and strides of the copy are used by drivers to record BLAS calls. Memory is zero-initialized by
+/// allocator (which is mapped lazily by the system for large arrays), and is never read or written.
+pub(crate) fn planned_copy<A, Sh, D>(shape: Sh) -> Array<A, D>
+where
+    A: Clone + Zero,
+    Sh: ShapeBuilder<Dim = D>,
+    D: Dimension,
+{
+    Array::zeros(shape)
+}
+
+/// Copy matrix (conjugated if `conj`) to newly allocated col-major matrix.
+///
+/// With feature `matcopy`, vendor `?omatcopy` is called if one dimension of source is contiguous; otherwise
+/// cache-blocked copy is performed.
+pub(crate) fn copy_to_col_layout<F>(view: &ArrayView2<F>, conj: bool) -> Array2<F>
+where
+    F: BLASFloat,
+{
+    if is_planning() {
+        return planned_copy(view.dim().f());
+    }
+    #[cfg(feature = "matcopy")]
+    if let Some(owned) = omatcopy_to_col_layout(view, conj) {
+        return owned;
+    }
+    match conj && F::is_complex() {
+        true => map_to_col_layout(view, |&x| F::conj(x)),
+        false => map_to_col_layout(view, |&x| x),
+    }
+}
+
+/// Copy matrix to newly allocated col-major matrix by vendor `?omatcopy`.
+///
+/// `None` is returned if neither dimension of source is contiguous, if the matrix is too small to be worth
+/// calling BLAS, or if `?omatcopy` is missing in dynamically loaded library (crate feature `dynamic`).
+#[cfg(feature = "matcopy")]
+fn omatcopy_to_col_layout<F>(view: &ArrayView2<F>, conj: bool) -> Option<Array2<F>>
+where
+    F: BLASFloat,
+{
+    use crate::ffi::{self, blas_int, c_char};
+
+    let (nrow, ncol) = view.dim();
+    let [s0, s1] = view.strides().try_into().unwrap();
+    if nrow < 2 || ncol < 2 {
+        return None;
+    }
+
+    // source is regarded as col-major matrix (rows x cols, lda), and transposed if row-major
+    let conj = conj && F::is_complex();
+    let (trans, rows, cols, lda) = if s0 == 1 && s1 >= nrow as isize {
+        (if conj { 'R' } else { 'N' }, nrow, ncol, s1)
+    } else if s1 == 1 && s0 >= ncol as isize {
+        (if conj { 'C' } else { 'T' }, ncol, nrow, s0)
+    } else {
+        return None;
+    };
+    let ordering = 'C' as c_char;
+    let trans = trans as c_char;
+    let rows: blas_int = rows.try_into().ok()?;
+    let cols: blas_int = cols.try_into().ok()?;
+    let lda: blas_int = lda.try_into().ok()?;
+    let ldb: blas_int = nrow.try_into().ok()?;
+
+    let mut owned = Array2::<F>::uninit((nrow, ncol).f());
+    let alpha = F::one();
+    let (alpha, a, b) = (&alpha as *const F, view.as_ptr(), owned.as_mut_ptr() as *mut F);
+    // BLASFloat is only implemented for f32, f64, c32 and c64
+    unsafe {
+        match (F::is_complex(), core::mem::size_of::<F>()) {
+            (false, 4) => {
+                ffi::somatcopy_(&ordering, &trans, &rows, &cols, alpha as _, a as _, &lda, b as _, &ldb)
+            },
+            (false, 8) => {
+                ffi::domatcopy_(&ordering, &trans, &rows, &cols, alpha as _, a as _, &lda, b as _, &ldb)
+            },
+            (true, 8) => {
+                ffi::comatcopy_(&ordering, &trans, &rows, &cols, alpha as _, a as _, &lda, b as _, &ldb)
+            },
+            (true, 16) => {
+                ffi::zomatcopy_(&ordering, &trans, &rows, &cols, alpha as _, a as _, &lda, b as _, &ldb)
+            },
+            _ => return None,
+        }
+        // omatcopy may be missing in dynamically loaded library
+        #[cfg(feature = "dynamic")]
+        if ffi::dynamic::take_missing_symbol().is_some() {
+            return None;
+        }
+        // all elements have been written by omatcopy
+        Some(owned.assume_init())
+    }
+}
+
+/* #endregion */
+
 /* #region warn on clone */
 
 pub trait ToLayoutCowArray2<A> {
@@ -227,14 +562,14 @@ pub trait ToLayoutCowArray2<A> {
 
 impl<A> ToLayoutCowArray2<A> for ArrayView2<'_, A>
 where
-    A: Clone,
+    A: BLASFloat,
 {
     fn to_row_layout(&self) -> Result<CowArray<'_, A, Ix2>, BLASError> {
         if self.is_cpref() {
             Ok(CowArray::from(self))
         } else {
             blas_warn_layout_clone!(self)?;
-            let owned = self.into_owned();
+            let owned = copy_to_col_layout(&self.t(), false).reversed_axes();
             Ok(CowArray::from(owned))
         }
     }
@@ -244,27 +579,183 @@ where
             Ok(CowArray::from(self))
         } else {
             blas_warn_layout_clone!(self)?;
-            let owned = self.t().into_owned().reversed_axes();
+            let owned = copy_to_col_layout(self, false);
             Ok(CowArray::from(owned))
         }
     }
 }
 
+/// Copy batch of matrices (axis 0 of array is batch) to col-major matrices of non-negative batch stride.
+pub(crate) fn to_col_layout_batch<A>(view: &ArrayView3<'_, A>) -> Result<Array3<A>, BLASError>
+where
+    A: Clone + Zero,
+{
+    blas_warn_layout_clone!(view)?;
+    let (nbatch, nrow, ncol) = view.dim();
+    let mut owned = planned_copy((nbatch, ncol, nrow)).permuted_axes([0, 2, 1]);
+    if !is_planning() {
+        for (mut dst, src) in owned.outer_iter_mut().zip(view.outer_iter()) {
+            map_blocked(&src, &mut dst, A::clone);
+        }
+    }
+    Ok(owned)
+}
+
 pub trait ToLayoutCowArray1<A> {
     fn to_seq_layout(&self) -> Result<CowArray<'_, A, Ix1>, BLASError>;
 }
 
 impl<A> ToLayoutCowArray1<A> for ArrayView1<'_, A>
 where
-    A: Clone,
+    A: Clone + Zero,
 {
     fn to_seq_layout(&self) -> Result<CowArray<'_, A, Ix1>, BLASError> {
-        let cow = self.as_standard_layout();
-        if cow.is_owned() {
-            blas_warn_layout_clone!(self)?;
+        if self.is_standard_layout() {
+            return Ok(CowArray::from(self.view()));
+        }
+        blas_warn_layout_clone!(self)?;
+        match is_planning() {
+            true => Ok(CowArray::from(planned_copy(self.raw_dim()))),
+            false => Ok(CowArray::from(self.to_owned())),
         }
-        Ok(cow)
     }
 }
 
 /* #endregion */
+
+/* #region positive stride */
+
+/// Reverse both vectors (without copy) if both strides are negative.
+///
+/// This does not change dot product of two vectors, but may avoid explicit copy in [`to_positive_stride`].
+pub(crate) fn invert_negative_stride_pair<F>(x: &mut ArrayView1<'_, F>, y: &mut ArrayView1<'_, F>) {
+    if x.stride_of(Axis(0)) < 0 && y.stride_of(Axis(0)) < 0 {
+        x.invert_axis(Axis(0));
+        y.invert_axis(Axis(0));
+    }
+}
+
+/// Make stride of vector positive, by explicit copy if required.
+pub(crate) fn to_positive_stride<F>(x: ArrayView1<'_, F>) -> Result<CowArray<'_, F, Ix1>, BLASError>
+where
+    F: BLASFloat,
+{
+    if x.stride_of(Axis(0)) > 0 {
+        Ok(CowArray::from(x))
+    } else {
+        // single element (possibly zero-strided) vector is trivial to copy
+        if x.len() > 1 {
+            blas_warn_layout_clone!(x)?;
+        }
+        match is_planning() {
+            true => Ok(CowArray::from(planned_copy(x.len()))),
+            false => Ok(CowArray::from(Array1::from_iter(x.iter().copied()))),
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region vector increment */
+
+/// Increment of vector to be passed to BLAS.
+///
+/// Increment of vector with less than two elements is not referenced, but zero increment is still rejected
+/// by Level-2 BLAS; so 1 is given in this case.
+pub(crate) fn get_vec_inc<F>(x: &ArrayView1<'_, F>) -> isize {
+    match x.len() {
+        0 | 1 => 1,
+        _ => x.stride_of(Axis(0)),
+    }
+}
+
+/// Offset from logical first element to the element of lowest address.
+///
+/// For negative increment, Fortran BLAS starts from the element of lowest address (`x[(1 - n) * incx]`),
+/// instead of logical first element given by `as_ptr`.
+pub(crate) fn get_vec_base_offset<F>(x: &ArrayView1<'_, F>) -> isize {
+    let inc = get_vec_inc(x);
+    match inc < 0 {
+        true => (x.len() as isize - 1) * inc,
+        false => 0,
+    }
+}
+
+/// Pointer of vector to be passed to BLAS, which is the element of lowest address for negative stride.
+pub(crate) fn get_vec_base_ptr<F>(x: &ArrayView1<'_, F>) -> *const F {
+    unsafe { x.as_ptr().offset(get_vec_base_offset(x)) }
+}
+
+/// Make vector not zero-strided (broadcasted), by explicit copy if required.
+pub(crate) fn to_nonzero_stride<F>(x: ArrayView1<'_, F>) -> Result<CowArray<'_, F, Ix1>, BLASError>
+where
+    F: Clone + Zero,
+{
+    if get_vec_inc(&x) != 0 {
+        Ok(CowArray::from(x))
+    } else {
+        blas_warn_layout_clone!(x)?;
+        match is_planning() {
+            true => Ok(CowArray::from(planned_copy(x.len()))),
+            false => Ok(CowArray::from(Array1::from_iter(x.iter().cloned()))),
+        }
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(nrow: usize, ncol: usize) -> Array2<c64> {
+        Array2::from_shape_fn((nrow, ncol), |(i, j)| c64::new(i as f64, j as f64))
+    }
+
+    #[test]
+    #[cfg_attr(all(not(feature = "std"), feature = "error_on_copy"), ignore = "copy policy is scoped by std")]
+    fn test_to_col_layout() {
+        // copies are tested regardless of default policy (such as `error_on_copy`)
+        #[cfg(feature = "std")]
+        let _guard = scoped_copy_policy(CopyPolicy::Allow);
+        // tile boundary is crossed in both dimensions
+        let a = sample(150, 200);
+        for view in
+            [a.slice(s![3..73, 5..50]), a.slice(s![3..73, 5..50]).reversed_axes(), a.slice(s![..;2, 1..;3])]
+        {
+            let b = view.to_col_layout().unwrap();
+            assert!(b.view().is_fpref());
+            assert_eq!(b, view);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(all(not(feature = "std"), feature = "error_on_copy"), ignore = "copy policy is scoped by std")]
+    fn test_to_row_layout() {
+        // copies are tested regardless of default policy (such as `error_on_copy`)
+        #[cfg(feature = "std")]
+        let _guard = scoped_copy_policy(CopyPolicy::Allow);
+        let a = sample(150, 200).reversed_axes();
+        for view in
+            [a.slice(s![3..73, 5..50]), a.slice(s![3..73, 5..50]).reversed_axes(), a.slice(s![..;2, 1..;3])]
+        {
+            let b = view.to_row_layout().unwrap();
+            assert!(b.view().is_cpref());
+            assert_eq!(b, view);
+        }
+    }
+
+    #[test]
+    fn test_copy_to_col_layout_conj() {
+        let a = sample(150, 200);
+        for view in
+            [a.slice(s![3..73, 5..50]), a.slice(s![3..73, 5..50]).reversed_axes(), a.slice(s![..;2, 1..;3])]
+        {
+            let b = copy_to_col_layout(&view, true);
+            assert!(b.view().is_fpref());
+            assert_eq!(b, view.mapv(|x| x.conj()));
+        }
+        let b = copy_to_col_layout(&a.slice(s![..0, ..]), true);
+        assert_eq!(b.dim(), (0, 200));
+    }
+}
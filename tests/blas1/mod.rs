@@ -1,3 +1,15 @@
 pub mod test_asum;
+pub mod test_axpy;
+pub mod test_copy;
+pub mod test_dotc;
+pub mod test_dotu;
+pub mod test_dsdot;
 pub mod test_iamax;
+pub mod test_negative_stride;
 pub mod test_nrm2;
+pub mod test_rot;
+pub mod test_rotg;
+pub mod test_rotm;
+pub mod test_rotmg;
+pub mod test_scal;
+pub mod test_swap;
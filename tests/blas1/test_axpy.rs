@@ -0,0 +1,52 @@
+use crate::util::*;
+use blas_array2::blas1::axpy::AXPY;
+use blas_array2::util::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let alpha = 1.5;
+            let x = random_array::<f64>(1000);
+            let mut y = random_array::<f64>(1000);
+            let y_origin = y.clone();
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let out = AXPY::default().x(x.slice(x_slc)).y(y.slice_mut(y_slc)).alpha(alpha).run().unwrap();
+            let expected = alpha * &x.slice(x_slc) + y_origin.slice(y_slc);
+            check_same(&out.view(), &expected.view(), 4.0 * f64::EPSILON);
+            check_same(&y.slice(y_slc), &expected.view(), 4.0 * f64::EPSILON);
+        }
+
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let alpha = c64::new(1.5, -0.7);
+            let x = random_array::<c64>(1000);
+            let mut y = random_array::<c64>(1000);
+            let y_origin = y.clone();
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let out = AXPY::default().x(x.slice(x_slc)).y(y.slice_mut(y_slc)).alpha(alpha).run().unwrap();
+            let expected = x.slice(x_slc).mapv(|v| alpha * v) + y_origin.slice(y_slc);
+            check_same(&out.view(), &expected.view(), 4.0 * f64::EPSILON);
+            check_same(&y.slice(y_slc), &expected.view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let x = random_array::<f32>(10);
+        let mut y = random_array::<f32>(11);
+        AXPY::default().x(x.view()).y(y.view_mut()).run().unwrap();
+    }
+}
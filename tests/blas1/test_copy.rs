@@ -0,0 +1,44 @@
+use crate::util::*;
+use blas_array2::blas1::copy::COPY;
+use blas_array2::util::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let x = random_array::<c64>(1000);
+            let mut y = random_array::<c64>(1000);
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let out = COPY::default().x(x.slice(x_slc)).y(y.slice_mut(y_slc)).run().unwrap();
+            assert_eq!(out.view(), x.slice(x_slc));
+            assert_eq!(y.slice(y_slc), x.slice(x_slc));
+        }
+
+        for incx in [1, 2, 3] {
+            let n = 100;
+            let x = random_array::<f32>(1000);
+            let x_slc = slice_1d(n, incx);
+            let mut out = COPY::default().x(x.slice(x_slc)).run().unwrap();
+            assert!(out.is_owned());
+            assert_eq!(out.into_owned(), x.slice(x_slc));
+        }
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let x = random_array::<f64>(10);
+        let mut y = random_array::<f64>(11);
+        COPY::default().x(x.view()).y(y.view_mut()).run().unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+use crate::util::*;
+use approx::*;
+use blas_array2::blas1::dotc::DOTC;
+use blas_array2::util::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let x = random_array::<c64>(1000);
+            let y = random_array::<c64>(1000);
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let out = DOTC::default().x(x.slice(x_slc)).y(y.slice(y_slc)).run().unwrap();
+            let expected = (&x.slice(x_slc).mapv(|v| v.conj()) * &y.slice(y_slc)).sum();
+            assert_relative_eq!(out.re, expected.re, epsilon = 1.0e-10);
+            assert_relative_eq!(out.im, expected.im, epsilon = 1.0e-10);
+        }
+
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let x = random_array::<c32>(1000);
+            let y = random_array::<c32>(1000);
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let out = DOTC::default().x(x.slice(x_slc)).y(y.slice(y_slc)).run().unwrap();
+            let expected = (&x.slice(x_slc).mapv(|v| v.conj()) * &y.slice(y_slc)).sum();
+            assert_relative_eq!(out.re, expected.re, epsilon = 1.0e-4);
+            assert_relative_eq!(out.im, expected.im, epsilon = 1.0e-4);
+        }
+    }
+
+    #[test]
+    fn test_non_positive_stride() {
+        let x = random_array::<c64>(100);
+        let y = random_array::<c64>(100);
+        for (x_view, y_view) in [
+            (x.slice(s![..;-1]), y.slice(s![..;-1])),
+            (x.slice(s![..;-2]), y.slice(s![..50])),
+            (x.slice(s![..50]), y.slice(s![..;-2])),
+        ] {
+            let out = DOTC::default().x(x_view).y(y_view).run().unwrap();
+            let expected = (&x_view.mapv(|v| v.conj()) * &y_view).sum();
+            assert_relative_eq!(out.re, expected.re, epsilon = 1.0e-10);
+            assert_relative_eq!(out.im, expected.im, epsilon = 1.0e-10);
+        }
+    }
+}
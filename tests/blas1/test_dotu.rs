@@ -0,0 +1,70 @@
+use crate::util::*;
+use approx::*;
+use blas_array2::blas1::dotu::DOTU;
+use blas_array2::util::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let x = random_array::<f64>(1000);
+            let y = random_array::<f64>(1000);
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let out = DOTU::default().x(x.slice(x_slc)).y(y.slice(y_slc)).run().unwrap();
+            let expected = (&x.slice(x_slc) * &y.slice(y_slc)).sum();
+            assert_relative_eq!(out, expected, epsilon = 1.0e-10);
+        }
+
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let x = random_array::<c64>(1000);
+            let y = random_array::<c64>(1000);
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let out = DOTU::default().x(x.slice(x_slc)).y(y.slice(y_slc)).run().unwrap();
+            let expected = (&x.slice(x_slc) * &y.slice(y_slc)).sum();
+            assert_relative_eq!(out.re, expected.re, epsilon = 1.0e-10);
+            assert_relative_eq!(out.im, expected.im, epsilon = 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test_complex_non_positive_stride() {
+        let x = random_array::<c32>(100);
+        let y = random_array::<c32>(100);
+        for (x_view, y_view) in [
+            (x.slice(s![..;-1]), y.slice(s![..;-1])),
+            (x.slice(s![..;-2]), y.slice(s![..50])),
+            (x.slice(s![..50]), y.slice(s![..;-2])),
+        ] {
+            let out = DOTU::default().x(x_view).y(y_view).run().unwrap();
+            let expected = (&x_view * &y_view).sum();
+            assert_relative_eq!(out.re, expected.re, epsilon = 1.0e-4);
+            assert_relative_eq!(out.im, expected.im, epsilon = 1.0e-4);
+        }
+
+        let x = Array1::from_elem(1, c32::new(1.0, 2.0));
+        let y = x.broadcast(1).unwrap();
+        let out = DOTU::default().x(x.view()).y(y).run().unwrap();
+        assert_eq!(out, c32::new(-3.0, 4.0));
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let x = random_array::<c64>(10);
+        let y = random_array::<c64>(11);
+        DOTU::default().x(x.view()).y(y.view()).run().unwrap();
+    }
+}
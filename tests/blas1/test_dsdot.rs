@@ -0,0 +1,31 @@
+use crate::util::*;
+use approx::*;
+use blas_array2::blas1::dsdot::{DSDOT, SDSDOT};
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let x = random_array::<f32>(1000);
+            let y = random_array::<f32>(1000);
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let expected = x
+                .slice(x_slc)
+                .iter()
+                .zip(y.slice(y_slc).iter())
+                .map(|(&a, &b)| a as f64 * b as f64)
+                .sum::<f64>();
+
+            let out = DSDOT::default().x(x.slice(x_slc)).y(y.slice(y_slc)).run().unwrap();
+            assert_relative_eq!(out, expected, epsilon = 1.0e-10);
+
+            let out = SDSDOT::default().x(x.slice(x_slc)).y(y.slice(y_slc)).sb(0.5).run().unwrap();
+            assert_relative_eq!(out, (expected + 0.5) as f32, epsilon = 1.0e-4);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::util::*;
+use approx::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    /// Results of negative-strided vectors should be the same to those of contiguous copies.
+    macro_rules! test_macro {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let n = 37;
+                for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+                    let x_slc = slice_1d_neg(n, incx);
+                    let y_slc = slice_1d_neg(n, incy);
+                    let mut x_raw = random_array::<$F>(1000);
+                    let mut y_raw = random_array::<$F>(1000);
+                    let x_origin = x_raw.slice(x_slc).as_standard_layout().into_owned();
+                    let y_origin = y_raw.slice(y_slc).as_standard_layout().into_owned();
+                    let alpha = <$F>::rand();
+
+                    // asum, nrm2, iamax
+                    let out = ASUM::default().x(x_raw.slice(x_slc)).run().unwrap();
+                    let expected = ASUM::default().x(x_origin.view()).run().unwrap();
+                    assert_relative_eq!(out, expected, max_relative = 16.0 * RT::EPSILON);
+                    let out = NRM2::default().x(x_raw.slice(x_slc)).run().unwrap();
+                    let expected = NRM2::default().x(x_origin.view()).run().unwrap();
+                    assert_relative_eq!(out, expected, max_relative = 16.0 * RT::EPSILON);
+                    let out = IAMAX::default().x(x_raw.slice(x_slc)).run().unwrap();
+                    let expected = IAMAX::default().x(x_origin.view()).run().unwrap();
+                    assert_eq!(out, expected);
+
+                    // dotu, with one or both of vectors negative-strided
+                    let expected = DOTU::default().x(x_origin.view()).y(y_origin.view()).run().unwrap();
+                    let out = DOTU::default().x(x_raw.slice(x_slc)).y(y_raw.slice(y_slc)).run().unwrap();
+                    check_same(&arr0(out).view(), &arr0(expected).view(), 4.0 * RT::EPSILON);
+                    let out = DOTU::default().x(x_raw.slice(x_slc)).y(y_origin.view()).run().unwrap();
+                    check_same(&arr0(out).view(), &arr0(expected).view(), 4.0 * RT::EPSILON);
+
+                    // axpy
+                    let mut y_expected = y_origin.clone();
+                    AXPY::default().x(x_origin.view()).y(y_expected.view_mut()).alpha(alpha).run().unwrap();
+                    let y_out = AXPY::default()
+                        .x(x_raw.slice(x_slc))
+                        .y(y_raw.slice_mut(y_slc))
+                        .alpha(alpha)
+                        .run()
+                        .unwrap();
+                    check_same(&y_out.view(), &y_expected.view(), 4.0 * RT::EPSILON);
+                    check_same(&y_raw.slice(y_slc), &y_expected.view(), 4.0 * RT::EPSILON);
+                    y_raw.slice_mut(y_slc).assign(&y_origin);
+
+                    // copy
+                    COPY::default().x(x_raw.slice(x_slc)).y(y_raw.slice_mut(y_slc)).run().unwrap();
+                    check_same(&y_raw.slice(y_slc), &x_origin.view(), 4.0 * RT::EPSILON);
+                    y_raw.slice_mut(y_slc).assign(&y_origin);
+
+                    // scal
+                    SCAL::default().x(x_raw.slice_mut(x_slc)).alpha(alpha).run().unwrap();
+                    check_same(&x_raw.slice(x_slc), &x_origin.mapv(|v| alpha * v).view(), 4.0 * RT::EPSILON);
+                    x_raw.slice_mut(x_slc).assign(&x_origin);
+
+                    // swap
+                    SWAP::default().x(x_raw.slice_mut(x_slc)).y(y_raw.slice_mut(y_slc)).run().unwrap();
+                    check_same(&x_raw.slice(x_slc), &y_origin.view(), 4.0 * RT::EPSILON);
+                    check_same(&y_raw.slice(y_slc), &x_origin.view(), 4.0 * RT::EPSILON);
+                    x_raw.slice_mut(x_slc).assign(&x_origin);
+                    y_raw.slice_mut(y_slc).assign(&y_origin);
+
+                    // rot
+                    let (c, s): (RT, RT) = (0.6, 0.8);
+                    let mut x_expected = x_origin.clone();
+                    let mut y_expected = y_origin.clone();
+                    ROT::default().x(x_expected.view_mut()).y(y_expected.view_mut()).c(c).s(s).run().unwrap();
+                    ROT::default()
+                        .x(x_raw.slice_mut(x_slc))
+                        .y(y_raw.slice_mut(y_slc))
+                        .c(c)
+                        .s(s)
+                        .run()
+                        .unwrap();
+                    check_same(&x_raw.slice(x_slc), &x_expected.view(), 4.0 * RT::EPSILON);
+                    check_same(&y_raw.slice(y_slc), &y_expected.view(), 4.0 * RT::EPSILON);
+                }
+            }
+        };
+    }
+
+    test_macro!(test_f32, f32);
+    test_macro!(test_f64, f64);
+    test_macro!(test_c32, c32);
+    test_macro!(test_c64, c64);
+
+    macro_rules! test_macro_complex {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let n = 37;
+                for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+                    let x_raw = random_array::<$F>(1000);
+                    let y_raw = random_array::<$F>(1000);
+                    let x_slc = slice_1d_neg(n, incx);
+                    let y_slc = slice_1d_neg(n, incy);
+                    let x_origin = x_raw.slice(x_slc).as_standard_layout().into_owned();
+                    let y_origin = y_raw.slice(y_slc).as_standard_layout().into_owned();
+
+                    let expected = DOTC::default().x(x_origin.view()).y(y_origin.view()).run().unwrap();
+                    let out = DOTC::default().x(x_raw.slice(x_slc)).y(y_raw.slice(y_slc)).run().unwrap();
+                    check_same(&arr0(out).view(), &arr0(expected).view(), 4.0 * RT::EPSILON);
+                }
+            }
+        };
+    }
+
+    test_macro_complex!(test_dotc_c32, c32);
+    test_macro_complex!(test_dotc_c64, c64);
+
+    macro_rules! test_macro_real {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                let n = 37;
+                for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+                    let mut x_raw = random_array::<$F>(1000);
+                    let mut y_raw = random_array::<$F>(1000);
+                    let x_slc = slice_1d_neg(n, incx);
+                    let y_slc = slice_1d_neg(n, incy);
+                    let mut x_expected = x_raw.slice(x_slc).as_standard_layout().into_owned();
+                    let mut y_expected = y_raw.slice(y_slc).as_standard_layout().into_owned();
+
+                    let param = RotmParam::Full { h11: 0.5, h21: -0.3, h12: 0.7, h22: 1.2 };
+                    ROTM::default()
+                        .x(x_expected.view_mut())
+                        .y(y_expected.view_mut())
+                        .param(param)
+                        .run()
+                        .unwrap();
+                    ROTM::default()
+                        .x(x_raw.slice_mut(x_slc))
+                        .y(y_raw.slice_mut(y_slc))
+                        .param(param)
+                        .run()
+                        .unwrap();
+                    check_same(&x_raw.slice(x_slc), &x_expected.view(), 4.0 * <$F>::EPSILON);
+                    check_same(&y_raw.slice(y_slc), &y_expected.view(), 4.0 * <$F>::EPSILON);
+                }
+            }
+        };
+    }
+
+    test_macro_real!(test_rotm_f32, f32);
+    test_macro_real!(test_rotm_f64, f64);
+
+    #[test]
+    fn test_dsdot() {
+        let n = 37;
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let x_raw = random_array::<f32>(1000);
+            let y_raw = random_array::<f32>(1000);
+            let x_slc = slice_1d_neg(n, incx);
+            let y_slc = slice_1d_neg(n, incy);
+            let x_origin = x_raw.slice(x_slc).as_standard_layout().into_owned();
+            let y_origin = y_raw.slice(y_slc).as_standard_layout().into_owned();
+
+            let expected = DSDOT::default().x(x_origin.view()).y(y_origin.view()).run().unwrap();
+            let out = DSDOT::default().x(x_raw.slice(x_slc)).y(y_raw.slice(y_slc)).run().unwrap();
+            assert_relative_eq!(out, expected, max_relative = 4.0 * f64::EPSILON);
+            let expected = SDSDOT::default().x(x_origin.view()).y(y_origin.view()).run().unwrap();
+            let out = SDSDOT::default().x(x_raw.slice(x_slc)).y(y_raw.slice(y_slc)).run().unwrap();
+            assert_relative_eq!(out, expected, max_relative = 4.0 * f32::EPSILON);
+        }
+    }
+
+    /// Zero-strided (broadcasted) vectors should be the same to those of contiguous copies.
+    macro_rules! test_macro_broadcast {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let n = 37;
+                let x_raw = random_array::<$F>(1);
+                let x_bcst = x_raw.broadcast(n).unwrap();
+                let x_origin = x_bcst.as_standard_layout().into_owned();
+                let y_origin = random_array::<$F>(n);
+
+                let out = ASUM::default().x(x_bcst).run().unwrap();
+                let expected = ASUM::default().x(x_origin.view()).run().unwrap();
+                assert_relative_eq!(out, expected, max_relative = 4.0 * RT::EPSILON);
+                let out = NRM2::default().x(x_bcst).run().unwrap();
+                let expected = NRM2::default().x(x_origin.view()).run().unwrap();
+                assert_relative_eq!(out, expected, max_relative = 4.0 * RT::EPSILON);
+                let out = IAMAX::default().x(x_bcst).run().unwrap();
+                assert_eq!(out, 0);
+
+                let out = DOTU::default().x(x_bcst).y(y_origin.view()).run().unwrap();
+                let expected = DOTU::default().x(x_origin.view()).y(y_origin.view()).run().unwrap();
+                check_same(&arr0(out).view(), &arr0(expected).view(), 4.0 * RT::EPSILON);
+
+                let mut y_out = y_origin.clone();
+                AXPY::default().x(x_bcst).y(y_out.view_mut()).run().unwrap();
+                check_same(&y_out.view(), &(&y_origin + &x_origin).view(), 4.0 * RT::EPSILON);
+            }
+        };
+    }
+
+    test_macro_broadcast!(test_broadcast_f32, f32);
+    test_macro_broadcast!(test_broadcast_f64, f64);
+    test_macro_broadcast!(test_broadcast_c32, c32);
+    test_macro_broadcast!(test_broadcast_c64, c64);
+}
@@ -0,0 +1,46 @@
+use crate::util::*;
+use blas_array2::blas1::rot::ROT;
+use blas_array2::util::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let (c, s) = (0.6, 0.8);
+            let mut x = random_array::<f64>(1000);
+            let mut y = random_array::<f64>(1000);
+            let x_origin = x.clone();
+            let y_origin = y.clone();
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let (x_out, y_out) =
+                ROT::default().x(x.slice_mut(x_slc)).y(y.slice_mut(y_slc)).c(c).s(s).run().unwrap();
+            let x_expected = c * &x_origin.slice(x_slc) + s * &y_origin.slice(y_slc);
+            let y_expected = c * &y_origin.slice(y_slc) - s * &x_origin.slice(x_slc);
+            check_same(&x_out.view(), &x_expected.view(), 4.0 * f64::EPSILON);
+            check_same(&y_out.view(), &y_expected.view(), 4.0 * f64::EPSILON);
+            check_same(&x.slice(x_slc), &x_expected.view(), 4.0 * f64::EPSILON);
+            check_same(&y.slice(y_slc), &y_expected.view(), 4.0 * f64::EPSILON);
+        }
+
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let (c, s) = (0.6_f32, 0.8_f32);
+            let mut x = random_array::<c32>(1000);
+            let mut y = random_array::<c32>(1000);
+            let x_origin = x.clone();
+            let y_origin = y.clone();
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            ROT::default().x(x.slice_mut(x_slc)).y(y.slice_mut(y_slc)).c(c).s(s).run().unwrap();
+            let x_expected = x_origin.slice(x_slc).mapv(|v| v * c) + y_origin.slice(y_slc).mapv(|v| v * s);
+            let y_expected = y_origin.slice(y_slc).mapv(|v| v * c) - x_origin.slice(x_slc).mapv(|v| v * s);
+            check_same(&x.slice(x_slc), &x_expected.view(), 4.0 * f32::EPSILON);
+            check_same(&y.slice(y_slc), &y_expected.view(), 4.0 * f32::EPSILON);
+        }
+    }
+}
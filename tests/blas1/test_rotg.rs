@@ -0,0 +1,37 @@
+use approx::*;
+use blas_array2::blas1::rotg::ROTG;
+use blas_array2::util::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let (a, b) = (3.0_f64, -4.0_f64);
+        let res = ROTG::default().a(a).b(b).run().unwrap();
+        assert_relative_eq!(res.r.abs(), 5.0, epsilon = 1.0e-12);
+        assert_relative_eq!(res.c * a + res.s * b, res.r, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(-res.s * a + res.c * b, 0.0, epsilon = 1.0e-12);
+
+        let (a, b) = (c64::new(1.0, 2.0), c64::new(-0.5, 3.0));
+        let res = ROTG::default().a(a).b(b).run().unwrap();
+        let r = a * res.c + res.s * b;
+        let z = -res.s.conj() * a + b * res.c;
+        assert_relative_eq!(r.re, res.r.re, epsilon = 1.0e-12);
+        assert_relative_eq!(r.im, res.r.im, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(z.norm(), 0.0, epsilon = 1.0e-12);
+        assert_relative_eq!(res.r.norm(), (a.norm_sqr() + b.norm_sqr()).sqrt(), epsilon = 1.0e-12);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_uninitialized() {
+        ROTG::<f32>::default().a(1.0).run().unwrap();
+    }
+}
@@ -0,0 +1,48 @@
+use crate::util::*;
+use blas_array2::blas1::rotm::{RotmParam, ROTM};
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let params = [
+            RotmParam::Identity,
+            RotmParam::Full { h11: 0.5, h21: -1.5, h12: 2.0, h22: 0.25 },
+            RotmParam::OffDiagonal { h21: -1.5, h12: 2.0 },
+            RotmParam::Diagonal { h11: 0.5, h22: 0.25 },
+        ];
+        for param in params {
+            for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+                let n = 100;
+                let mut x = random_array::<f64>(1000);
+                let mut y = random_array::<f64>(1000);
+                let x_origin = x.clone();
+                let y_origin = y.clone();
+                let x_slc = slice_1d(n, incx);
+                let y_slc = slice_1d(n, incy);
+                ROTM::default().x(x.slice_mut(x_slc)).y(y.slice_mut(y_slc)).param(param).run().unwrap();
+                let [[h11, h12], [h21, h22]] = param.matrix();
+                let x_expected = h11 * &x_origin.slice(x_slc) + h12 * &y_origin.slice(y_slc);
+                let y_expected = h21 * &x_origin.slice(x_slc) + h22 * &y_origin.slice(y_slc);
+                check_same(&x.slice(x_slc), &x_expected.view(), 4.0 * f64::EPSILON);
+                check_same(&y.slice(y_slc), &y_expected.view(), 4.0 * f64::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_param_array() {
+        let params = [
+            RotmParam::Identity,
+            RotmParam::Full { h11: 0.5, h21: -1.5, h12: 2.0, h22: 0.25 },
+            RotmParam::OffDiagonal { h21: -1.5, h12: 2.0 },
+            RotmParam::Diagonal { h11: 0.5, h22: 0.25 },
+        ];
+        for param in params {
+            assert_eq!(RotmParam::from_array(param.to_array()).unwrap(), param);
+        }
+        assert!(RotmParam::from_array([2.0_f32, 0.0, 0.0, 0.0, 0.0]).is_err());
+    }
+}
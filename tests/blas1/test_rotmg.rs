@@ -0,0 +1,36 @@
+use approx::*;
+use blas_array2::blas1::rotmg::{DROTMG, ROTMG};
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (d1, d2, x1, y1) in [(2.0, 3.0, 1.5, -0.5), (0.5, 4.0, 0.1, 2.0), (1.0, 1.0, 0.0, 1.0)] {
+            let res = ROTMG::default().d1(d1).d2(d2).x1(x1).y1(y1).run().unwrap();
+            let [[h11, h12], [h21, h22]] = res.param.matrix();
+            // H (sqrt(d1) x1, sqrt(d2) y1)^T = (sqrt(d1') x1', 0)^T, where H is scaled by sqrt(d) in BLAS
+            assert_abs_diff_eq!(h21 * x1 + h22 * y1, 0.0, epsilon = 1.0e-12);
+            assert_relative_eq!(h11 * x1 + h12 * y1, res.x1, epsilon = 1.0e-12);
+            let lhs = d1 * x1 * x1 + d2 * y1 * y1;
+            assert_relative_eq!(res.d1 * res.x1 * res.x1, lhs, epsilon = 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn test_rescale() {
+        // d1 is rescaled twice by gamsq = 4096^2; H is made explicit only before the first rescale
+        let gamsq = 4096.0_f64 * 4096.0;
+        let res = DROTMG::default().d1(1.0e20).d2(1.0).x1(1.0).y1(1.0).run().unwrap();
+        let param = res.param.to_array();
+        assert_eq!(param[0], -1.0);
+        assert_relative_eq!(param[1], gamsq, max_relative = 1.0e-12);
+        assert_relative_eq!(param[2], -1.0, max_relative = 1.0e-12);
+        assert_relative_eq!(param[3], 1.0e-20 * gamsq, max_relative = 1.0e-12);
+        assert_relative_eq!(param[4], 1.0, max_relative = 1.0e-12);
+        assert_relative_eq!(res.d1, 1.0e20 / (gamsq * gamsq), max_relative = 1.0e-12);
+        assert_relative_eq!(res.d2, 1.0, max_relative = 1.0e-12);
+        assert_relative_eq!(res.x1, gamsq, max_relative = 1.0e-12);
+    }
+}
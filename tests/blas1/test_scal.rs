@@ -0,0 +1,61 @@
+use crate::util::*;
+use blas_array2::blas1::scal::{RSCAL, SCAL};
+use blas_array2::util::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for incx in [1, 2, 3] {
+            let n = 100;
+            let alpha = 1.5;
+            let mut x = random_array::<f32>(1000);
+            let x_origin = x.clone();
+            let x_slc = slice_1d(n, incx);
+            let out = SCAL::default().x(x.slice_mut(x_slc)).alpha(alpha).run().unwrap();
+            let expected = alpha * &x_origin.slice(x_slc);
+            check_same(&out.view(), &expected.view(), 4.0 * f32::EPSILON);
+            check_same(&x.slice(x_slc), &expected.view(), 4.0 * f32::EPSILON);
+        }
+
+        for incx in [1, 2, 3] {
+            let n = 100;
+            let alpha = c32::new(1.5, -0.7);
+            let mut x = random_array::<c32>(1000);
+            let x_origin = x.clone();
+            let x_slc = slice_1d(n, incx);
+            let out = SCAL::default().x(x.slice_mut(x_slc)).alpha(alpha).run().unwrap();
+            let expected = x_origin.slice(x_slc).mapv(|v| alpha * v);
+            check_same(&out.view(), &expected.view(), 4.0 * f32::EPSILON);
+            check_same(&x.slice(x_slc), &expected.view(), 4.0 * f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_real_alpha() {
+        for incx in [1, 2, 3] {
+            let n = 100;
+            let alpha = -2.5;
+            let mut x = random_array::<c64>(1000);
+            let x_origin = x.clone();
+            let x_slc = slice_1d(n, incx);
+            let out = RSCAL::default().x(x.slice_mut(x_slc)).alpha(alpha).run().unwrap();
+            let expected = x_origin.slice(x_slc).mapv(|v| v * alpha);
+            check_same(&out.view(), &expected.view(), 4.0 * f64::EPSILON);
+            check_same(&x.slice(x_slc), &expected.view(), 4.0 * f64::EPSILON);
+        }
+
+        for incx in [1, 2, 3] {
+            let n = 100;
+            let alpha = -2.5;
+            let mut x = random_array::<f64>(1000);
+            let x_origin = x.clone();
+            let x_slc = slice_1d(n, incx);
+            let out = RSCAL::default().x(x.slice_mut(x_slc)).alpha(alpha).run().unwrap();
+            let expected = alpha * &x_origin.slice(x_slc);
+            check_same(&out.view(), &expected.view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::util::*;
+use blas_array2::blas1::swap::SWAP;
+use blas_array2::util::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let mut x = random_array::<f64>(1000);
+            let mut y = random_array::<f64>(1000);
+            let x_origin = x.clone();
+            let y_origin = y.clone();
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            let (x_out, y_out) = SWAP::default().x(x.slice_mut(x_slc)).y(y.slice_mut(y_slc)).run().unwrap();
+            assert_eq!(x_out.view(), y_origin.slice(y_slc));
+            assert_eq!(y_out.view(), x_origin.slice(x_slc));
+            assert_eq!(x.slice(x_slc), y_origin.slice(y_slc));
+            assert_eq!(y.slice(y_slc), x_origin.slice(x_slc));
+        }
+
+        for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+            let n = 100;
+            let mut x = random_array::<c32>(1000);
+            let mut y = random_array::<c32>(1000);
+            let x_origin = x.clone();
+            let y_origin = y.clone();
+            let x_slc = slice_1d(n, incx);
+            let y_slc = slice_1d(n, incy);
+            SWAP::default().x(x.slice_mut(x_slc)).y(y.slice_mut(y_slc)).run().unwrap();
+            assert_eq!(x.slice(x_slc), y_origin.slice(y_slc));
+            assert_eq!(y.slice(y_slc), x_origin.slice(x_slc));
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod test_gbmv;
 pub mod test_gemv;
 pub mod test_ger;
 pub mod test_gerc;
+pub mod test_negative_stride;
 pub mod test_sbmv;
 pub mod test_spmv;
 pub mod test_spr;
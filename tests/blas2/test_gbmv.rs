@@ -1,8 +1,8 @@
+use crate::util::cblas_oracle::*;
 use crate::util::*;
 use approx::*;
 use blas_array2::blas2::gbmv::GBMV;
 use blas_array2::util::*;
-use cblas_sys::*;
 use itertools::iproduct;
 use ndarray::prelude::*;
 use num_complex::*;
@@ -0,0 +1,425 @@
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    /// Results of negative-strided vectors should be the same to those of contiguous copies.
+    macro_rules! test_macro {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let n = 8;
+                let (kl, ku) = (2, 1);
+                for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+                    for layout in ['C', 'R'] {
+                        let x_slc = slice_1d_neg(n, incx);
+                        let y_slc = slice_1d_neg(n, incy);
+                        let a = random_matrix::<$F>(n, n, layout.into());
+                        let ab = random_matrix::<$F>(kl + ku + 1, n, 'C'.into());
+                        let ap = random_array::<$F>(n * (n + 1) / 2);
+                        let mut x_raw = random_array::<$F>(1000);
+                        let mut y_raw = random_array::<$F>(1000);
+                        let x_origin = x_raw.slice(x_slc).as_standard_layout().into_owned();
+                        let y_origin = y_raw.slice(y_slc).as_standard_layout().into_owned();
+                        let alpha = <$F>::rand();
+                        let beta = <$F>::rand();
+                        let eps = 16.0 * RT::EPSILON;
+
+                        // gemv
+                        for trans in ['N', 'T', 'C'] {
+                            let y_expected = GEMV::default()
+                                .a(a.view())
+                                .x(x_origin.view())
+                                .y(y_origin.clone().view_mut())
+                                .alpha(alpha)
+                                .beta(beta)
+                                .trans(trans)
+                                .run()
+                                .unwrap()
+                                .into_owned();
+                            GEMV::default()
+                                .a(a.view())
+                                .x(x_raw.slice(x_slc))
+                                .y(y_raw.slice_mut(y_slc))
+                                .alpha(alpha)
+                                .beta(beta)
+                                .trans(trans)
+                                .run()
+                                .unwrap();
+                            check_same(&y_raw.slice(y_slc), &y_expected.view(), eps);
+                            y_raw.slice_mut(y_slc).assign(&y_origin);
+                        }
+
+                        // gbmv
+                        let y_expected = GBMV::default()
+                            .a(ab.view())
+                            .x(x_origin.view())
+                            .y(y_origin.clone().view_mut())
+                            .m(n)
+                            .kl(kl)
+                            .alpha(alpha)
+                            .beta(beta)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        GBMV::default()
+                            .a(ab.view())
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice_mut(y_slc))
+                            .m(n)
+                            .kl(kl)
+                            .alpha(alpha)
+                            .beta(beta)
+                            .run()
+                            .unwrap();
+                        check_same(&y_raw.slice(y_slc), &y_expected.view(), eps);
+                        y_raw.slice_mut(y_slc).assign(&y_origin);
+
+                        // hemv, hbmv, hpmv
+                        let y_expected = HEMV::default()
+                            .a(a.view())
+                            .x(x_origin.view())
+                            .y(y_origin.clone().view_mut())
+                            .alpha(alpha)
+                            .beta(beta)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        HEMV::default()
+                            .a(a.view())
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice_mut(y_slc))
+                            .alpha(alpha)
+                            .beta(beta)
+                            .run()
+                            .unwrap();
+                        check_same(&y_raw.slice(y_slc), &y_expected.view(), eps);
+                        y_raw.slice_mut(y_slc).assign(&y_origin);
+
+                        let y_expected = HBMV::default()
+                            .a(ab.view())
+                            .x(x_origin.view())
+                            .y(y_origin.clone().view_mut())
+                            .alpha(alpha)
+                            .beta(beta)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        HBMV::default()
+                            .a(ab.view())
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice_mut(y_slc))
+                            .alpha(alpha)
+                            .beta(beta)
+                            .run()
+                            .unwrap();
+                        check_same(&y_raw.slice(y_slc), &y_expected.view(), eps);
+                        y_raw.slice_mut(y_slc).assign(&y_origin);
+
+                        let y_expected = HPMV::default()
+                            .ap(ap.view())
+                            .x(x_origin.view())
+                            .y(y_origin.clone().view_mut())
+                            .alpha(alpha)
+                            .beta(beta)
+                            .layout(layout)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        HPMV::default()
+                            .ap(ap.view())
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice_mut(y_slc))
+                            .alpha(alpha)
+                            .beta(beta)
+                            .layout(layout)
+                            .run()
+                            .unwrap();
+                        check_same(&y_raw.slice(y_slc), &y_expected.view(), eps);
+                        y_raw.slice_mut(y_slc).assign(&y_origin);
+
+                        // ger, her, her2
+                        let a_expected = GER::default()
+                            .x(x_origin.view())
+                            .y(y_origin.view())
+                            .a(a.clone().view_mut())
+                            .alpha(alpha)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        let a_out = GER::default()
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice(y_slc))
+                            .a(a.clone().view_mut())
+                            .alpha(alpha)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        check_same(&a_out.view(), &a_expected.view(), eps);
+
+                        let alpha_real = RT::rand();
+                        let a_expected = HER::default()
+                            .x(x_origin.view())
+                            .a(a.clone().view_mut())
+                            .alpha(alpha_real)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        let a_out = HER::default()
+                            .x(x_raw.slice(x_slc))
+                            .a(a.clone().view_mut())
+                            .alpha(alpha_real)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        check_same(&a_out.view(), &a_expected.view(), eps);
+
+                        let a_expected = HER2::default()
+                            .x(x_origin.view())
+                            .y(y_origin.view())
+                            .a(a.clone().view_mut())
+                            .alpha(alpha)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        let a_out = HER2::default()
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice(y_slc))
+                            .a(a.clone().view_mut())
+                            .alpha(alpha)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        check_same(&a_out.view(), &a_expected.view(), eps);
+
+                        // hpr, hpr2
+                        let ap_expected = HPR::default()
+                            .x(x_origin.view())
+                            .ap(ap.clone().view_mut())
+                            .alpha(alpha_real)
+                            .layout(layout)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        let ap_out = HPR::default()
+                            .x(x_raw.slice(x_slc))
+                            .ap(ap.clone().view_mut())
+                            .alpha(alpha_real)
+                            .layout(layout)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        check_same(&ap_out.view(), &ap_expected.view(), eps);
+
+                        let ap_expected = HPR2::default()
+                            .x(x_origin.view())
+                            .y(y_origin.view())
+                            .ap(ap.clone().view_mut())
+                            .alpha(alpha)
+                            .layout(layout)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        let ap_out = HPR2::default()
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice(y_slc))
+                            .ap(ap.clone().view_mut())
+                            .alpha(alpha)
+                            .layout(layout)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        check_same(&ap_out.view(), &ap_expected.view(), eps);
+
+                        // trmv, tbmv, tpmv, trsv, tbsv, tpsv
+                        for trans in ['N', 'T', 'C'] {
+                            let x_expected = TRMV::default()
+                                .a(a.view())
+                                .x(x_origin.clone().view_mut())
+                                .trans(trans)
+                                .run()
+                                .unwrap()
+                                .into_owned();
+                            TRMV::default().a(a.view()).x(x_raw.slice_mut(x_slc)).trans(trans).run().unwrap();
+                            check_same(&x_raw.slice(x_slc), &x_expected.view(), eps);
+                            x_raw.slice_mut(x_slc).assign(&x_origin);
+
+                            let x_expected = TBMV::default()
+                                .a(ab.slice(s![..ku + 1, ..]))
+                                .x(x_origin.clone().view_mut())
+                                .trans(trans)
+                                .run()
+                                .unwrap()
+                                .into_owned();
+                            TBMV::default()
+                                .a(ab.slice(s![..ku + 1, ..]))
+                                .x(x_raw.slice_mut(x_slc))
+                                .trans(trans)
+                                .run()
+                                .unwrap();
+                            check_same(&x_raw.slice(x_slc), &x_expected.view(), eps);
+                            x_raw.slice_mut(x_slc).assign(&x_origin);
+
+                            let x_expected = TPMV::default()
+                                .ap(ap.view())
+                                .x(x_origin.clone().view_mut())
+                                .trans(trans)
+                                .layout(layout)
+                                .run()
+                                .unwrap()
+                                .into_owned();
+                            TPMV::default()
+                                .ap(ap.view())
+                                .x(x_raw.slice_mut(x_slc))
+                                .trans(trans)
+                                .layout(layout)
+                                .run()
+                                .unwrap();
+                            check_same(&x_raw.slice(x_slc), &x_expected.view(), eps);
+                            x_raw.slice_mut(x_slc).assign(&x_origin);
+
+                            // unit diagonal for well-conditioned triangular solve
+                            let x_expected = TRSV::default()
+                                .a(a.view())
+                                .x(x_origin.clone().view_mut())
+                                .trans(trans)
+                                .diag('U')
+                                .run()
+                                .unwrap()
+                                .into_owned();
+                            TRSV::default()
+                                .a(a.view())
+                                .x(x_raw.slice_mut(x_slc))
+                                .trans(trans)
+                                .diag('U')
+                                .run()
+                                .unwrap();
+                            check_same(&x_raw.slice(x_slc), &x_expected.view(), eps);
+                            x_raw.slice_mut(x_slc).assign(&x_origin);
+
+                            let x_expected = TBSV::default()
+                                .a(ab.slice(s![..ku + 1, ..]))
+                                .x(x_origin.clone().view_mut())
+                                .trans(trans)
+                                .diag('U')
+                                .run()
+                                .unwrap()
+                                .into_owned();
+                            TBSV::default()
+                                .a(ab.slice(s![..ku + 1, ..]))
+                                .x(x_raw.slice_mut(x_slc))
+                                .trans(trans)
+                                .diag('U')
+                                .run()
+                                .unwrap();
+                            check_same(&x_raw.slice(x_slc), &x_expected.view(), eps);
+                            x_raw.slice_mut(x_slc).assign(&x_origin);
+
+                            let x_expected = TPSV::default()
+                                .ap(ap.view())
+                                .x(x_origin.clone().view_mut())
+                                .trans(trans)
+                                .diag('U')
+                                .layout(layout)
+                                .run()
+                                .unwrap()
+                                .into_owned();
+                            TPSV::default()
+                                .ap(ap.view())
+                                .x(x_raw.slice_mut(x_slc))
+                                .trans(trans)
+                                .diag('U')
+                                .layout(layout)
+                                .run()
+                                .unwrap();
+                            check_same(&x_raw.slice(x_slc), &x_expected.view(), eps);
+                            x_raw.slice_mut(x_slc).assign(&x_origin);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    test_macro!(test_f32, f32);
+    test_macro!(test_f64, f64);
+    test_macro!(test_c32, c32);
+    test_macro!(test_c64, c64);
+
+    macro_rules! test_macro_complex {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let n = 8;
+                for (incx, incy) in [(1, 1), (1, 3), (2, 1), (3, 2)] {
+                    for layout in ['C', 'R'] {
+                        let x_slc = slice_1d_neg(n, incx);
+                        let y_slc = slice_1d_neg(n, incy);
+                        let a = random_matrix::<$F>(n, n, layout.into());
+                        let x_raw = random_array::<$F>(1000);
+                        let y_raw = random_array::<$F>(1000);
+                        let x_origin = x_raw.slice(x_slc).as_standard_layout().into_owned();
+                        let y_origin = y_raw.slice(y_slc).as_standard_layout().into_owned();
+                        let alpha = <$F>::rand();
+
+                        let a_expected = GERC::default()
+                            .x(x_origin.view())
+                            .y(y_origin.view())
+                            .a(a.clone().view_mut())
+                            .alpha(alpha)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        let a_out = GERC::default()
+                            .x(x_raw.slice(x_slc))
+                            .y(y_raw.slice(y_slc))
+                            .a(a.clone().view_mut())
+                            .alpha(alpha)
+                            .run()
+                            .unwrap()
+                            .into_owned();
+                        check_same(&a_out.view(), &a_expected.view(), 16.0 * RT::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    test_macro_complex!(test_gerc_c32, c32);
+    test_macro_complex!(test_gerc_c64, c64);
+
+    /// Zero-strided (broadcasted) vectors should be the same to those of contiguous copies.
+    macro_rules! test_macro_broadcast {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let n = 8;
+                let a = random_matrix::<$F>(n, n, 'C'.into());
+                let x_raw = random_array::<$F>(1);
+                let x_bcst = x_raw.broadcast(n).unwrap();
+                let x_origin = x_bcst.as_standard_layout().into_owned();
+                let y_origin = random_array::<$F>(n);
+
+                let y_out = GEMV::default().a(a.view()).x(x_bcst).run().unwrap().into_owned();
+                let y_expected = GEMV::default().a(a.view()).x(x_origin.view()).run().unwrap().into_owned();
+                check_same(&y_out.view(), &y_expected.view(), 16.0 * RT::EPSILON);
+
+                let a_out = GER::default().x(y_origin.view()).y(x_bcst).run().unwrap().into_owned();
+                let a_expected =
+                    GER::default().x(y_origin.view()).y(x_origin.view()).run().unwrap().into_owned();
+                check_same(&a_out.view(), &a_expected.view(), 16.0 * RT::EPSILON);
+            }
+        };
+    }
+
+    test_macro_broadcast!(test_broadcast_f32, f32);
+    test_macro_broadcast!(test_broadcast_f64, f64);
+    test_macro_broadcast!(test_broadcast_c32, c32);
+    test_macro_broadcast!(test_broadcast_c64, c64);
+}
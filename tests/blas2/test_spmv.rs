@@ -1,6 +1,6 @@
+use crate::util::cblas_oracle::*;
 use crate::util::*;
 use blas_array2::prelude::*;
-use cblas_sys::*;
 use itertools::*;
 
 #[cfg(test)]
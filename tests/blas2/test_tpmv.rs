@@ -1,8 +1,8 @@
+use crate::util::cblas_oracle::*;
 use crate::util::*;
 use approx::*;
 use blas_array2::blas2::tpmv::TPMV;
 use blas_array2::prelude::*;
-use cblas_sys::*;
 use itertools::*;
 use ndarray::prelude::*;
 use num_complex::*;
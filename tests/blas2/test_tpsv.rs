@@ -1,7 +1,7 @@
+use crate::util::cblas_oracle::*;
 use crate::util::*;
 use blas_array2::blas2::tpsv::TPSV;
 use blas_array2::prelude::*;
-use cblas_sys::*;
 use itertools::*;
 
 #[cfg(test)]
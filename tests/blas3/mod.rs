@@ -1,4 +1,8 @@
+pub mod test_conj_output;
 pub mod test_gemm;
+pub mod test_gemm_batch;
+pub mod test_gemm_batched;
+pub mod test_gemm_strided;
 pub mod test_symm;
 pub mod test_syr2k;
 pub mod test_syrk;
@@ -8,3 +12,10 @@ pub mod test_trsm;
 #[cfg(feature = "gemmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "gemmt")))]
 pub mod test_gemmt;
+
+#[cfg(feature = "matcopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+pub mod test_imatcopy;
+#[cfg(feature = "matcopy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "matcopy")))]
+pub mod test_omatcopy;
@@ -0,0 +1,267 @@
+//! Cases where conjugate copy of input is avoided by computing conjugate of output.
+//!
+//! Except `HEMM`, these cases should also pass with feature `error_on_copy`.
+
+use crate::util::*;
+use blas_array2::prelude::*;
+use itertools::iproduct;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    fn triangular(a: &ArrayView2<c64>, uplo: char) -> Array2<c64> {
+        let mut a_tri = Array2::zeros(a.dim());
+        tril_assign(&mut a_tri.view_mut(), a, uplo);
+        return a_tri;
+    }
+
+    #[test]
+    fn test_gemm() {
+        // A, B of one layout and C of the other layout, both transposed by `C`
+        for (layout_ab, layout_c) in [('C', 'R'), ('R', 'C')] {
+            let alpha = c64::rand();
+            let beta = c64::rand();
+            let a = random_matrix::<c64>(20, 5, layout_ab.into());
+            let b = random_matrix::<c64>(6, 20, layout_ab.into());
+            let mut c = random_matrix::<c64>(5, 6, layout_c.into());
+            let a_naive = transpose(&a.view(), BLASConjTrans);
+            let b_naive = transpose(&b.view(), BLASConjTrans);
+            let c_naive = alpha * gemm(&a_naive.view(), &b_naive.view()) + beta * &c;
+
+            // inplace
+            let c_out = GEMM::<c64>::default()
+                .a(a.view())
+                .b(b.view())
+                .c(c.view_mut())
+                .alpha(alpha)
+                .beta(beta)
+                .transa('C')
+                .transb('C')
+                .run()
+                .unwrap();
+            assert!(matches!(c_out, ArrayOut2::ViewMut(_)));
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+            // outplace
+            let c_out = GEMM::<c64>::default()
+                .a(a.view())
+                .b(b.view())
+                .alpha(alpha)
+                .transa('C')
+                .transb('C')
+                .layout(layout_c)
+                .run()
+                .unwrap();
+            let c_naive = alpha * gemm(&a_naive.view(), &b_naive.view());
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_herk() {
+        for (trans, uplo) in iproduct!(['N', 'C'], ['L', 'U']) {
+            let alpha = f64::rand();
+            let beta = f64::rand();
+            let a = match trans {
+                'N' => random_matrix::<c64>(5, 20, 'C'.into()),
+                _ => random_matrix::<c64>(20, 5, 'C'.into()),
+            };
+            let mut c = random_matrix::<c64>(5, 5, 'R'.into());
+            let a_h = transpose(&a.view(), BLASConjTrans);
+            let mut c_assign = match trans {
+                'N' => c64::from(alpha) * gemm(&a.view(), &a_h.view()),
+                _ => c64::from(alpha) * gemm(&a_h.view(), &a.view()),
+            };
+            for i in 0..5 {
+                c_assign[[i, i]] += beta * c[[i, i]].re;
+                for j in 0..5 {
+                    if i != j {
+                        c_assign[[i, j]] += beta * c[[i, j]];
+                    }
+                }
+            }
+            let mut c_naive = c.clone();
+            tril_assign(&mut c_naive.view_mut(), &c_assign.view(), uplo);
+
+            HERK::<c64>::default()
+                .a(a.view())
+                .c(c.view_mut())
+                .alpha(alpha)
+                .beta(beta)
+                .trans(trans)
+                .uplo(uplo)
+                .run()
+                .unwrap();
+            check_same(&c.view(), &c_naive.view(), 8.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_her2k() {
+        for (trans, uplo) in iproduct!(['N', 'C'], ['L', 'U']) {
+            let alpha = c64::rand();
+            let beta = f64::rand();
+            let (a, b) = match trans {
+                'N' => (random_matrix::<c64>(5, 20, 'C'.into()), random_matrix::<c64>(5, 20, 'C'.into())),
+                _ => (random_matrix::<c64>(20, 5, 'C'.into()), random_matrix::<c64>(20, 5, 'C'.into())),
+            };
+            let mut c = random_matrix::<c64>(5, 5, 'R'.into());
+            let a_h = transpose(&a.view(), BLASConjTrans);
+            let b_h = transpose(&b.view(), BLASConjTrans);
+            let mut c_assign = match trans {
+                'N' => alpha * gemm(&a.view(), &b_h.view()) + alpha.conj() * gemm(&b.view(), &a_h.view()),
+                _ => alpha * gemm(&a_h.view(), &b.view()) + alpha.conj() * gemm(&b_h.view(), &a.view()),
+            };
+            for i in 0..5 {
+                c_assign[[i, i]] += beta * c[[i, i]].re;
+                for j in 0..5 {
+                    if i != j {
+                        c_assign[[i, j]] += beta * c[[i, j]];
+                    }
+                }
+            }
+            let mut c_naive = c.clone();
+            tril_assign(&mut c_naive.view_mut(), &c_assign.view(), uplo);
+
+            HER2K::<c64>::default()
+                .a(a.view())
+                .b(b.view())
+                .c(c.view_mut())
+                .alpha(alpha)
+                .beta(beta)
+                .trans(trans)
+                .uplo(uplo)
+                .run()
+                .unwrap();
+            check_same(&c.view(), &c_naive.view(), 8.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "error_on_copy"))]
+    fn test_hemm() {
+        // copy of large A is avoided, but conjugate copy of small B is still required
+        for uplo in ['L', 'U'] {
+            let alpha = c64::rand();
+            let beta = c64::rand();
+            let a = random_matrix::<c64>(30, 30, 'C'.into());
+            let b = random_matrix::<c64>(30, 2, 'C'.into());
+            let mut c = random_matrix::<c64>(30, 2, 'R'.into());
+            let a_naive = hermitianize(&a.view(), uplo);
+            let c_naive = alpha * gemm(&a_naive.view(), &b.view()) + beta * &c;
+
+            HEMM::<c64>::default()
+                .a(a.view())
+                .b(b.view())
+                .c(c.view_mut())
+                .alpha(alpha)
+                .beta(beta)
+                .side('L')
+                .uplo(uplo)
+                .run()
+                .unwrap();
+            check_same(&c.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_trmm() {
+        for (side, uplo) in iproduct!(['L', 'R'], ['L', 'U']) {
+            let alpha = c64::rand();
+            // diagonal dominant col-major A
+            let mut a = random_matrix::<c64>(30, 30, 'C'.into());
+            a.diag_mut().mapv_inplace(|x| x + 30.0);
+            let mut b = match side {
+                'L' => random_matrix::<c64>(30, 5, 'R'.into()),
+                _ => random_matrix::<c64>(5, 30, 'R'.into()),
+            };
+            let a_naive = transpose(&triangular(&a.view(), uplo).view(), BLASConjTrans);
+            let b_naive = match side {
+                'L' => alpha * gemm(&a_naive.view(), &b.view()),
+                _ => alpha * gemm(&b.view(), &a_naive.view()),
+            };
+
+            TRMM::<c64>::default()
+                .a(a.view())
+                .b(b.view_mut())
+                .alpha(alpha)
+                .side(side)
+                .uplo(uplo)
+                .transa('C')
+                .run()
+                .unwrap();
+            check_same(&b.view(), &b_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_trsm() {
+        for (side, uplo) in iproduct!(['L', 'R'], ['L', 'U']) {
+            let alpha = c64::rand();
+            // diagonal dominant col-major A
+            let mut a = random_matrix::<c64>(30, 30, 'C'.into());
+            a.diag_mut().mapv_inplace(|x| x + 30.0);
+            let b_origin = match side {
+                'L' => random_matrix::<c64>(30, 5, 'R'.into()),
+                _ => random_matrix::<c64>(5, 30, 'R'.into()),
+            };
+            let mut b = b_origin.clone();
+            let a_naive = transpose(&triangular(&a.view(), uplo).view(), BLASConjTrans);
+
+            TRSM::<c64>::default()
+                .a(a.view())
+                .b(b.view_mut())
+                .alpha(alpha)
+                .side(side)
+                .uplo(uplo)
+                .transa('C')
+                .run()
+                .unwrap();
+            let b_naive = match side {
+                'L' => gemm(&a_naive.view(), &b.view()),
+                _ => gemm(&b.view(), &a_naive.view()),
+            };
+            check_same(&b_naive.view(), &(alpha * &b_origin).view(), 16.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_untouched_on_error() {
+        // output is conjugated only after all checks, so it is left untouched when wrapper fails
+        let a = random_matrix::<c64>(20, 5, 'C'.into());
+        let b = random_matrix::<c64>(6, 20, 'C'.into());
+        let mut c = random_matrix::<c64>(5, 7, 'R'.into());
+        let c_origin = c.clone();
+        let result = ZGEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .c(c.view_mut())
+            .beta(c64::rand())
+            .transa('C')
+            .transb('C')
+            .run();
+        assert!(result.is_err());
+        assert_eq!(c, c_origin);
+
+        let a = random_matrix::<c64>(5, 20, 'C'.into());
+        let mut c = random_matrix::<c64>(5, 6, 'R'.into());
+        let c_origin = c.clone();
+        let result = ZHERK::default().a(a.view()).c(c.view_mut()).beta(0.5).run();
+        assert!(result.is_err());
+        assert_eq!(c, c_origin);
+
+        let a = random_matrix::<c64>(30, 30, 'C'.into());
+        let mut b = random_matrix::<c64>(20, 5, 'R'.into());
+        let b_origin = b.clone();
+        let result = ZTRMM::default().a(a.view()).b(b.view_mut()).side('L').transa('C').run();
+        assert!(result.is_err());
+        assert_eq!(b, b_origin);
+    }
+}
@@ -1,8 +1,8 @@
+use crate::util::cblas_oracle::*;
 use crate::util::*;
 use approx::*;
 use blas_array2::blas3::gemm::GEMM;
 use blas_array2::util::*;
-use cblas_sys::*;
 use ndarray::prelude::*;
 use num_complex::*;
 
@@ -0,0 +1,186 @@
+use crate::util::*;
+use blas_array2::blas3::gemm::GEMM;
+use blas_array2::blas3::gemm_batch::GEMM_BATCH;
+use blas_array2::util::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    macro_rules! test_macro {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                // heterogeneous problems (m, n, k, transa, transb, layout of a, b, c)
+                // repeated problems are placed consecutively, and are expected to be merged into one group
+                let problems = [
+                    (7, 8, 9, 'N', 'N', BLASRowMajor, BLASRowMajor, BLASRowMajor),
+                    (7, 8, 9, 'N', 'N', BLASRowMajor, BLASRowMajor, BLASRowMajor),
+                    (3, 5, 4, 'T', 'N', BLASColMajor, BLASColMajor, BLASColMajor),
+                    (3, 5, 4, 'T', 'N', BLASColMajor, BLASColMajor, BLASColMajor),
+                    (6, 2, 5, 'C', 'T', BLASRowMajor, BLASColMajor, BLASColMajor),
+                    (4, 6, 3, 'N', 'C', BLASColMajor, BLASRowMajor, BLASRowMajor),
+                    (5, 5, 0, 'N', 'N', BLASRowMajor, BLASRowMajor, BLASColMajor),
+                    (0, 3, 4, 'N', 'T', BLASColMajor, BLASColMajor, BLASRowMajor),
+                ];
+                let mut a_list = vec![];
+                let mut b_list = vec![];
+                let mut c_list = vec![];
+                let mut alpha_list = vec![];
+                let mut beta_list = vec![];
+                for &(m, n, k, transa, transb, a_layout, b_layout, c_layout) in problems.iter() {
+                    let (a_rows, a_cols) = if transa == 'N' { (m, k) } else { (k, m) };
+                    let (b_rows, b_cols) = if transb == 'N' { (k, n) } else { (n, k) };
+                    a_list.push(random_matrix::<$F>(a_rows, a_cols, a_layout));
+                    b_list.push(random_matrix::<$F>(b_rows, b_cols, b_layout));
+                    c_list.push(random_matrix::<$F>(m, n, c_layout));
+                    alpha_list.push(<$F>::rand());
+                    beta_list.push(<$F>::rand());
+                }
+
+                // naive reference by GEMM
+                let mut c_naive_list = c_list.clone();
+                for (i, c_naive) in c_naive_list.iter_mut().enumerate() {
+                    let (_, _, _, transa, transb, _, _, _) = problems[i];
+                    GEMM::<$F>::default()
+                        .a(a_list[i].view())
+                        .b(b_list[i].view())
+                        .c(c_naive.view_mut())
+                        .alpha(alpha_list[i])
+                        .beta(beta_list[i])
+                        .transa(transa)
+                        .transb(transb)
+                        .run()
+                        .unwrap();
+                }
+
+                // inplace
+                let mut batch = GEMM_BATCH::<$F>::default();
+                for (i, c) in c_list.iter_mut().enumerate() {
+                    let (_, _, _, transa, transb, _, _, _) = problems[i];
+                    batch = batch.gemm(
+                        GEMM::default()
+                            .a(a_list[i].view())
+                            .b(b_list[i].view())
+                            .c(c.view_mut())
+                            .alpha(alpha_list[i])
+                            .beta(beta_list[i])
+                            .transa(transa)
+                            .transb(transb),
+                    );
+                }
+                let c_out_list = batch.run().unwrap();
+                assert_eq!(c_out_list.len(), problems.len());
+                for i in 0..problems.len() {
+                    let (m, n, _, _, _, _, _, _) = problems[i];
+                    assert_eq!(c_out_list[i].view().dim(), (m, n));
+                    if m * n == 0 {
+                        continue;
+                    }
+                    check_same(&c_out_list[i].view(), &c_naive_list[i].view(), 4.0 * RT::EPSILON);
+                }
+                drop(c_out_list);
+                for i in (0..problems.len()).filter(|&i| c_list[i].len() != 0) {
+                    check_same(&c_list[i].view(), &c_naive_list[i].view(), 4.0 * RT::EPSILON);
+                }
+
+                // outplace
+                let mut batch = GEMM_BATCH::<$F>::default();
+                for i in 0..problems.len() {
+                    let (_, _, _, transa, transb, _, _, _) = problems[i];
+                    batch = batch.gemm(
+                        GEMM::default()
+                            .a(a_list[i].view())
+                            .b(b_list[i].view())
+                            .alpha(alpha_list[i])
+                            .transa(transa)
+                            .transb(transb),
+                    );
+                }
+                let c_out_list = batch.run().unwrap();
+                for (i, c_out) in c_out_list.into_iter().enumerate() {
+                    let (m, n, k, transa, transb, _, _, _) = problems[i];
+                    assert_eq!(c_out.view().dim(), (m, n));
+                    if m * n == 0 {
+                        continue;
+                    } else if k == 0 {
+                        assert!(c_out.view().iter().all(|&v| v == <$F>::from(0.0)));
+                        continue;
+                    }
+                    let c_naive = GEMM::<$F>::default()
+                        .a(a_list[i].view())
+                        .b(b_list[i].view())
+                        .alpha(alpha_list[i])
+                        .transa(transa)
+                        .transb(transb)
+                        .run()
+                        .unwrap()
+                        .into_owned();
+                    check_same(&c_out.view(), &c_naive.view(), 4.0 * RT::EPSILON);
+                }
+            }
+        };
+    }
+
+    test_macro!(test_f32, f32);
+    test_macro!(test_f64, f64);
+    test_macro!(test_c32, c32);
+    test_macro!(test_c64, c64);
+
+    #[test]
+    fn test_sliced() {
+        let a_raw = random_matrix::<f64>(100, 100, BLASRowMajor);
+        let b_raw = random_matrix::<f64>(100, 100, BLASColMajor);
+        let mut c_raw = random_matrix::<f64>(100, 100, BLASRowMajor);
+        let c_origin = c_raw.clone();
+        let a_slc = slice(7, 8, 3, 2);
+        let b_slc = slice(8, 9, 2, 3);
+        let c_slc = slice(7, 9, 1, 1);
+        let c_out = GEMM_BATCH::<f64>::default()
+            .gemm(GEMM::default().a(a_raw.slice(a_slc)).b(b_raw.slice(b_slc)).c(c_raw.slice_mut(c_slc)))
+            .gemm(GEMM::default().a(b_raw.slice(b_slc)).b(a_raw.slice(a_slc)).transa('T').transb('T'))
+            .run()
+            .unwrap();
+
+        let c_naive = gemm(&a_raw.slice(a_slc), &b_raw.slice(b_slc));
+        check_same(&c_out[0].view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        check_same(&c_out[1].view(), &c_naive.t(), 4.0 * f64::EPSILON);
+        drop(c_out);
+
+        let mut c_expected = c_origin.clone();
+        c_expected.slice_mut(c_slc).assign(&c_naive);
+        check_same(&c_raw.view(), &c_expected.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_empty() {
+        let c_out = GEMM_BATCH::<f64>::default().run().unwrap();
+        assert!(c_out.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let a = random_matrix::<f64>(3, 4, BLASRowMajor);
+        let b = random_matrix::<f64>(4, 5, BLASRowMajor);
+        let b_wrong = random_matrix::<f64>(5, 5, BLASRowMajor);
+        GEMM_BATCH::<f64>::default()
+            .gemm(GEMM::default().a(a.view()).b(b.view()))
+            .gemm(GEMM::default().a(a.view()).b(b_wrong.view()))
+            .run()
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_missing_b() {
+        let a = random_matrix::<f64>(3, 4, BLASRowMajor);
+        GEMM_BATCH::<f64>::default().gemm(GEMM::default().a(a.view())).run().unwrap();
+    }
+}
@@ -0,0 +1,170 @@
+use crate::util::*;
+use blas_array2::blas3::gemm::GEMM;
+use blas_array2::blas3::gemm_batched::GEMMBatched;
+use blas_array2::util::*;
+use ndarray::prelude::*;
+
+/// Random batch of matrices; `layout` is layout of each matrix in batch.
+fn random_batch<F>(nbatch: usize, nrow: usize, ncol: usize, layout: char) -> Array3<F>
+where
+    F: TestFloat,
+{
+    let mut batch = match layout {
+        'R' => Array3::zeros((nbatch, nrow, ncol)),
+        'C' => Array3::zeros((nbatch, ncol, nrow)).permuted_axes([0, 2, 1]),
+        _ => panic!("Invalid layout"),
+    };
+    for x in batch.iter_mut() {
+        *x = F::rand();
+    }
+    return batch;
+}
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    macro_rules! test_macro {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let (nbatch, m, n, k) = (5, 7, 8, 9);
+                for (a_layout, b_layout, c_layout) in [
+                    ('R', 'R', 'R'),
+                    ('C', 'C', 'C'),
+                    ('R', 'C', 'C'),
+                    ('C', 'R', 'R'),
+                    ('R', 'R', 'N'),
+                    ('C', 'C', 'N'),
+                    ('R', 'C', 'N'),
+                ] {
+                    for (transa, transb) in [('N', 'N'), ('T', 'N'), ('C', 'T'), ('N', 'C')] {
+                        for (a_batch, b_batch) in [(nbatch, nbatch), (1, nbatch), (nbatch, 1)] {
+                            let alpha = <$F>::rand();
+                            let beta = <$F>::rand();
+                            let (a_rows, a_cols) = if transa == 'N' { (m, k) } else { (k, m) };
+                            let (b_rows, b_cols) = if transb == 'N' { (k, n) } else { (n, k) };
+                            // non-contiguous in batch and matrix rows/cols
+                            let a_raw = random_batch::<$F>(2 * a_batch, 2 * a_rows, a_cols, a_layout);
+                            let b_raw = random_batch::<$F>(a_batch.max(b_batch) + 2, b_rows, b_cols + 3, b_layout);
+                            let a = a_raw.slice(s![..;2, ..;2, ..]);
+                            let b = b_raw.slice(s![1..b_batch + 1, .., 2..b_cols + 2]);
+
+                            // naive reference by GEMM
+                            let c_init = random_batch::<$F>(nbatch, m, n, if c_layout == 'N' { 'R' } else { c_layout });
+                            let mut c_naive = c_init.clone();
+                            for i in 0..nbatch {
+                                let a_i = a.index_axis(Axis(0), if a_batch == 1 { 0 } else { i });
+                                let b_i = b.index_axis(Axis(0), if b_batch == 1 { 0 } else { i });
+                                GEMM::<$F>::default()
+                                    .a(a_i)
+                                    .b(b_i)
+                                    .c(c_naive.index_axis_mut(Axis(0), i))
+                                    .alpha(alpha)
+                                    .beta(if c_layout == 'N' { <$F>::from(0.0) } else { beta })
+                                    .transa(transa)
+                                    .transb(transb)
+                                    .run()
+                                    .unwrap();
+                            }
+
+                            if c_layout == 'N' {
+                                let c_out = GEMMBatched::<$F>::default()
+                                    .a(a)
+                                    .b(b)
+                                    .alpha(alpha)
+                                    .transa(transa)
+                                    .transb(transb)
+                                    .run()
+                                    .unwrap();
+                                assert!(c_out.view().dim() == (nbatch, m, n));
+                                check_same(&c_out.view(), &c_naive.view(), 4.0 * RT::EPSILON);
+                            } else {
+                                let mut c = c_init.clone();
+                                let c_out = GEMMBatched::<$F>::default()
+                                    .a(a)
+                                    .b(b)
+                                    .c(c.view_mut())
+                                    .alpha(alpha)
+                                    .beta(beta)
+                                    .transa(transa)
+                                    .transb(transb)
+                                    .run()
+                                    .unwrap();
+                                assert!(c_out.view().dim() == (nbatch, m, n));
+                                check_same(&c.view(), &c_naive.view(), 4.0 * RT::EPSILON);
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    test_macro!(test_f32, f32);
+    test_macro!(test_f64, f64);
+    test_macro!(test_c32, c32);
+    test_macro!(test_c64, c64);
+
+    #[test]
+    fn test_reversed_batch() {
+        let (nbatch, m, n, k) = (4, 3, 5, 6);
+        let a = random_batch::<f64>(nbatch, m, k, 'C');
+        let b = random_batch::<f64>(nbatch, k, n, 'R');
+        let mut c = Array3::<f64>::zeros((nbatch, m, n));
+        GEMMBatched::<f64>::default()
+            .a(a.slice(s![..;-1, .., ..]))
+            .b(b.slice(s![..;-1, .., ..]))
+            .c(c.slice_mut(s![..;-1, .., ..]))
+            .run()
+            .unwrap();
+        for i in 0..nbatch {
+            let c_naive = GEMM::<f64>::default()
+                .a(a.index_axis(Axis(0), i))
+                .b(b.index_axis(Axis(0), i))
+                .run()
+                .unwrap()
+                .into_owned();
+            check_same(&c.index_axis(Axis(0), i), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_empty_batch() {
+        let a = random_batch::<f64>(0, 3, 4, 'R');
+        let b = random_batch::<f64>(1, 4, 5, 'R');
+        let c_out = GEMMBatched::<f64>::default().a(a.view()).b(b.view()).run().unwrap();
+        assert_eq!(c_out.view().dim(), (0, 3, 5));
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_batch_mismatch() {
+        let a = random_batch::<f64>(3, 3, 4, 'R');
+        let b = random_batch::<f64>(2, 4, 5, 'R');
+        GEMMBatched::<f64>::default().a(a.view()).b(b.view()).run().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let a = random_batch::<f64>(3, 3, 4, 'R');
+        let b = random_batch::<f64>(3, 5, 5, 'R');
+        GEMMBatched::<f64>::default().a(a.view()).b(b.view()).run().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_c_mismatch() {
+        let a = random_batch::<f64>(3, 3, 4, 'R');
+        let b = random_batch::<f64>(1, 4, 5, 'R');
+        let mut c = Array3::<f64>::zeros((2, 3, 5));
+        GEMMBatched::<f64>::default().a(a.view()).b(b.view()).c(c.view_mut()).run().unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+//! GEMM of non-contiguous arrays by stride-native kernel, which never copies arrays.
+
+use crate::util::*;
+use blas_array2::blas3::gemm_strided::STRIDED_FLOPS_PER_COPY;
+use blas_array2::ffi;
+use blas_array2::prelude::*;
+use itertools::iproduct;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    fn naive<F>(a: &ArrayView2<F>, b: &ArrayView2<F>, transa: char, transb: char) -> Array2<F>
+    where
+        F: BLASFloat,
+    {
+        let a = transpose(a, transa.into());
+        let b = transpose(b, transb.into());
+        return gemm(&a.view(), &b.view());
+    }
+
+    #[test]
+    fn test_trans() {
+        // only both or neither of op(A), op(B) can be conjugated without copy
+        let _guard = scoped_copy_policy(CopyPolicy::Error);
+        for (transa, transb) in [('N', 'N'), ('N', 'T'), ('T', 'N'), ('T', 'T'), ('C', 'C')] {
+            let alpha = c64::rand();
+            let beta = c64::rand();
+            let a_raw = random_matrix::<c64>(30, 40, 'R'.into());
+            let b_raw = random_matrix::<c64>(30, 40, 'C'.into());
+            let mut c_raw = random_matrix::<c64>(30, 40, 'R'.into());
+            let a = match transa {
+                'N' => a_raw.slice(s![..;3, ..21;3]),
+                _ => a_raw.slice(s![..21;3, ..;4]),
+            };
+            let b = match transb {
+                'N' => b_raw.slice(s![..21;3, ..;4]),
+                _ => b_raw.slice(s![..;3, ..21;3]),
+            };
+            let c_naive = alpha * naive(&a, &b, transa, transb) + beta * &c_raw.slice(s![..;3, ..20;2]);
+
+            // inplace
+            let c = c_raw.slice_mut(s![..;3, ..20;2]);
+            let c_out = ZGEMM::default()
+                .a(a)
+                .b(b)
+                .c(c)
+                .alpha(alpha)
+                .beta(beta)
+                .transa(transa)
+                .transb(transb)
+                .run()
+                .unwrap();
+            assert!(matches!(c_out, ArrayOut2::ViewMut(_)));
+            check_same(&c_raw.slice(s![..;3, ..20;2]), &c_naive.view(), 4.0 * f64::EPSILON);
+
+            // outplace
+            let c_out = ZGEMM::default().a(a).b(b).transa(transa).transb(transb).run().unwrap();
+            check_same(&c_out.view(), &naive(&a, &b, transa, transb).view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_negative_strides() {
+        let _guard = scoped_copy_policy(CopyPolicy::Error);
+        let a_raw = random_matrix::<f64>(30, 40, 'C'.into());
+        let b_raw = random_matrix::<f64>(30, 40, 'R'.into());
+        let a = a_raw.slice(s![..;-2, 1..;-3]);
+        let b = b_raw.slice(s![..;-5, ..13;-1]);
+        let c_out = DGEMM::default().a(a).b(b).transb('T').run().unwrap();
+        check_same(&c_out.view(), &naive(&a, &b, 'N', 'T').view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_empty() {
+        // k == 0 scales output by beta
+        let a = Array2::<f32>::zeros((6, 0));
+        let b = Array2::<f32>::zeros((0, 4));
+        let mut c_raw = random_matrix::<f32>(12, 8, 'R'.into());
+        let c_naive = 0.5 * &c_raw.slice(s![..;2, ..;2]);
+        SGEMM::default().a(a.view()).b(b.view()).c(c_raw.slice_mut(s![..;2, ..;2])).beta(0.5).run().unwrap();
+        check_same(&c_raw.slice(s![..;2, ..;2]), &c_naive.view(), 4.0 * f32::EPSILON);
+
+        // beta == 0 does not read output
+        c_raw.slice_mut(s![..;2, ..;2]).fill(f32::NAN);
+        SGEMM::default().a(a.view()).b(b.view()).c(c_raw.slice_mut(s![..;2, ..;2])).run().unwrap();
+        assert!(c_raw.slice(s![..;2, ..;2]).iter().all(|&x| x == 0.0));
+    }
+
+    #[test]
+    fn test_selection() {
+        let b = random_matrix::<f64>(24, 128, 'C'.into());
+        for (n, policy) in iproduct!([4, 128], [CopyPolicy::Allow, CopyPolicy::Error]) {
+            let a_raw = random_matrix::<f64>(48, 48, 'R'.into());
+            let a = a_raw.slice(s![..;2, ..;2]);
+            let b = b.slice(s![.., ..n]);
+            let plan = DGEMM::default().a(a).b(b).copy_policy(policy).explain().unwrap();
+            // copy of A (576 elements) dominates unless n is large, or copy may be rejected
+            let strided = n * 576 <= 576 * STRIDED_FLOPS_PER_COPY || matches!(policy, CopyPolicy::Error);
+            assert_eq!(plan.copies.is_empty(), strided);
+            assert_eq!(plan.calls[0].routine == "matrixmultiply::dgemm", strided);
+
+            let c_out = DGEMM::default().a(a).b(b).copy_policy(policy).run().unwrap();
+            check_same(&c_out.view(), &gemm(&a, &b).view(), 4.0 * f64::EPSILON);
+        }
+
+        // contiguous arrays are always computed by BLAS
+        let plan = DGEMM::default().a(b.t()).b(b.view()).copy_policy(CopyPolicy::Error).explain().unwrap();
+        assert_eq!(
+            plan.calls[0].routine,
+            match (ffi::USE_CBLAS, cfg!(feature = "faer")) {
+                (true, _) => "cblas_dgemm",
+                (false, true) => "faer::dgemm",
+                (false, false) => "dgemm_",
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_dim() {
+        let _guard = scoped_copy_policy(CopyPolicy::Error);
+        let a_raw = random_matrix::<f64>(30, 40, 'R'.into());
+        let a = a_raw.slice(s![..;2, ..;3]);
+        let b = random_matrix::<f64>(13, 5, 'R'.into());
+        let err = DGEMM::default().a(a).b(b.view()).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+
+        let b = random_matrix::<f64>(14, 5, 'R'.into());
+        let mut c = random_matrix::<f64>(15, 6, 'R'.into());
+        let err = DGEMM::default().a(a).b(b.view()).c(c.slice_mut(s![.., ..;2])).run().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "matcopy")]
+
+use crate::util::*;
+use blas_array2::blas3::imatcopy::IMATCOPY;
+use blas_array2::util::*;
+use itertools::iproduct;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    macro_rules! test_macro {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let list_trans = ['N', 'T', 'C', 'R'];
+                let list_layout = [BLASRowMajor, BLASColMajor];
+                let list_as0 = [1, 2];
+                let list_as1 = [1, 3];
+
+                // sliced square matrix, or non-transposed rectangular matrix
+                for cfg in iproduct!(list_trans, list_layout, list_as0, list_as1, [7, 8]) {
+                    let (trans, layout, as0, as1, ncol) = cfg;
+                    if ncol != 7 && (trans == 'T' || trans == 'C') {
+                        continue;
+                    }
+                    let alpha = <$F>::rand();
+                    let mut a_raw = random_matrix::<$F>(100, 100, layout);
+                    let a_origin = a_raw.clone();
+                    let a_slc = slice(7, ncol, as0, as1);
+                    let a_naive = transpose(&a_raw.slice(a_slc), trans.into()).mapv(|x| alpha * x);
+
+                    let a_out = IMATCOPY::<$F>::default()
+                        .a(a_raw.slice_mut(a_slc))
+                        .alpha(alpha)
+                        .trans(trans)
+                        .run()
+                        .unwrap();
+                    check_same(&a_out.view(), &a_naive.view(), 4.0 * RT::EPSILON);
+                    drop(a_out);
+                    let mut a_expected = a_origin.clone();
+                    a_expected.slice_mut(a_slc).assign(&a_naive);
+                    check_same(&a_raw.view(), &a_expected.view(), 4.0 * RT::EPSILON);
+                }
+
+                // contiguous rectangular matrix, transposed in-place
+                for cfg in iproduct!(list_trans, list_layout) {
+                    let (trans, layout) = cfg;
+                    let alpha = <$F>::rand();
+                    let mut a = random_matrix::<$F>(5, 9, layout);
+                    let a_naive = transpose(&a.view(), trans.into()).mapv(|x| alpha * x);
+                    let a_ptr = a.as_ptr();
+                    let a_out =
+                        IMATCOPY::<$F>::default().a(a.view_mut()).alpha(alpha).trans(trans).run().unwrap();
+                    assert_eq!(a_out.view().dim(), a_naive.dim());
+                    assert!(a_out.view().is_standard_layout() || a_out.view().t().is_standard_layout());
+                    assert_eq!(a_out.view().as_ptr(), a_ptr);
+                    check_same(&a_out.view(), &a_naive.view(), 4.0 * RT::EPSILON);
+                }
+            }
+        };
+    }
+
+    test_macro!(test_f32, f32);
+    test_macro!(test_f64, f64);
+    test_macro!(test_c32, c32);
+    test_macro!(test_c64, c64);
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_transpose_non_contiguous() {
+        let mut a_raw = random_matrix::<f64>(100, 100, BLASRowMajor);
+        IMATCOPY::<f64>::default().a(a_raw.slice_mut(s![..5, ..9])).trans('T').run().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_trans() {
+        let mut a = random_matrix::<f64>(3, 3, BLASRowMajor);
+        IMATCOPY::<f64>::default().a(a.view_mut()).trans('X').run().unwrap();
+    }
+}
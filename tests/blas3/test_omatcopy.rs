@@ -0,0 +1,98 @@
+#![cfg(feature = "matcopy")]
+
+use crate::util::*;
+use blas_array2::blas3::omatcopy::OMATCOPY;
+use blas_array2::util::*;
+use itertools::iproduct;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    macro_rules! test_macro {
+        ($test_name: ident, $F: ty) => {
+            #[test]
+            fn $test_name() {
+                type RT = <$F as BLASFloat>::RealFloat;
+                let list_trans = ['N', 'T', 'C', 'R'];
+                let list_layout_a = [BLASRowMajor, BLASColMajor];
+                let list_layout_b = [Some(BLASRowMajor), Some(BLASColMajor), None];
+                let list_as0 = [1, 2];
+                let list_as1 = [1, 3];
+
+                for cfg in iproduct!(list_trans, list_layout_a, list_layout_b, list_as0, list_as1) {
+                    let (trans, layout_a, layout_b, as0, as1) = cfg;
+                    let alpha = <$F>::rand();
+                    let a_raw = random_matrix::<$F>(100, 100, layout_a);
+                    let a_slc = slice(7, 8, as0, as1);
+                    let b_naive = transpose(&a_raw.slice(a_slc), trans.into()).mapv(|x| alpha * x);
+
+                    match layout_b {
+                        Some(layout_b) => {
+                            let mut b_raw = random_matrix::<$F>(100, 100, layout_b);
+                            let b_origin = b_raw.clone();
+                            let (bd0, bd1) = b_naive.dim();
+                            let b_slc = slice(bd0, bd1, 2, 1);
+                            let b_out = OMATCOPY::<$F>::default()
+                                .a(a_raw.slice(a_slc))
+                                .b(b_raw.slice_mut(b_slc))
+                                .alpha(alpha)
+                                .trans(trans)
+                                .run()
+                                .unwrap();
+                            check_same(&b_out.view(), &b_naive.view(), 4.0 * RT::EPSILON);
+                            drop(b_out);
+                            let mut b_expected = b_origin.clone();
+                            b_expected.slice_mut(b_slc).assign(&b_naive);
+                            check_same(&b_raw.view(), &b_expected.view(), 4.0 * RT::EPSILON);
+                        },
+                        None => {
+                            let b_out = OMATCOPY::<$F>::default()
+                                .a(a_raw.slice(a_slc))
+                                .alpha(alpha)
+                                .trans(trans)
+                                .run()
+                                .unwrap();
+                            check_same(&b_out.view(), &b_naive.view(), 4.0 * RT::EPSILON);
+                        },
+                    }
+                }
+            }
+        };
+    }
+
+    test_macro!(test_f32, f32);
+    test_macro!(test_f64, f64);
+    test_macro!(test_c32, c32);
+    test_macro!(test_c64, c64);
+
+    #[test]
+    fn test_single_column() {
+        // stride of length-1 axis is zero, leading dimension should be taken from number of rows
+        let a_raw = random_matrix::<c64>(100, 100, BLASColMajor);
+        let a = a_raw.slice(s![..5, 3..4]);
+        let b_out = OMATCOPY::<c64>::default().a(a).trans('C').run().unwrap();
+        check_same(&b_out.view(), &a.t().mapv(|x| x.conj()).view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_dim_mismatch() {
+        let a = random_matrix::<f64>(3, 4, BLASRowMajor);
+        let mut b = random_matrix::<f64>(3, 4, BLASRowMajor);
+        OMATCOPY::<f64>::default().a(a.view()).b(b.view_mut()).trans('T').run().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_trans() {
+        let a = random_matrix::<f64>(3, 4, BLASRowMajor);
+        OMATCOPY::<f64>::default().a(a.view()).trans('X').run().unwrap();
+    }
+}
@@ -0,0 +1,173 @@
+//! Dynamic loading of BLAS library (crate feature `dynamic`).
+//!
+//! Loaded library is global to the process, so this is a separate test binary from `tests/mod.rs` (which is
+//! skipped with this feature). A stub library providing only `ddot_`, `zaxpy_`, `dgemv_` and `dgemm_` is
+//! compiled by C compiler `cc`; test is skipped if it is not available.
+
+#![cfg(feature = "dynamic")]
+
+use blas_array2::ffi::{self, dynamic};
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+use std::path::PathBuf;
+use std::process::Command;
+
+const STUB_SOURCE: &str = r#"
+#include <stdint.h>
+
+#ifdef ILP64
+typedef int64_t blas_int;
+#else
+typedef int32_t blas_int;
+#endif
+
+#ifndef DDOT
+#define DDOT ddot_
+#endif
+#ifndef ZAXPY
+#define ZAXPY zaxpy_
+#endif
+#ifndef DGEMV
+#define DGEMV dgemv_
+#endif
+#ifndef DGEMM
+#define DGEMM dgemm_
+#endif
+
+/* positive increments only */
+double DDOT(const blas_int *n, const double *x, const blas_int *incx, const double *y, const blas_int *incy) {
+    double sum = 0.0;
+    for (blas_int i = 0; i < *n; ++i) {
+        sum += x[i * *incx] * y[i * *incy];
+    }
+    return sum;
+}
+
+/* complex numbers as interleaved (re, im); positive increments only */
+void ZAXPY(const blas_int *n, const double *alpha, const double *x, const blas_int *incx, double *y,
+           const blas_int *incy) {
+    for (blas_int i = 0; i < *n; ++i) {
+        const double *xi = &x[2 * i * *incx];
+        double *yi = &y[2 * i * *incy];
+        yi[0] += alpha[0] * xi[0] - alpha[1] * xi[1];
+        yi[1] += alpha[0] * xi[1] + alpha[1] * xi[0];
+    }
+}
+
+/* naive col-major dgemv; transposition flags other than 'N' are regarded as 'T'; positive increments only */
+void DGEMV(const char *trans, const blas_int *m, const blas_int *n, const double *alpha, const double *a,
+           const blas_int *lda, const double *x, const blas_int *incx, const double *beta, double *y,
+           const blas_int *incy) {
+    int ta = *trans != 'N' && *trans != 'n';
+    blas_int len_y = ta ? *n : *m;
+    blas_int len_x = ta ? *m : *n;
+    for (blas_int i = 0; i < len_y; ++i) {
+        double sum = 0.0;
+        for (blas_int p = 0; p < len_x; ++p) {
+            double aip = ta ? a[p + i * *lda] : a[i + p * *lda];
+            sum += aip * x[p * *incx];
+        }
+        double *yi = &y[i * *incy];
+        *yi = (*beta == 0.0) ? *alpha * sum : *alpha * sum + *beta * *yi;
+    }
+}
+
+/* naive col-major dgemm; transposition flags other than 'N' are regarded as 'T' */
+void DGEMM(const char *transa, const char *transb, const blas_int *m, const blas_int *n, const blas_int *k,
+            const double *alpha, const double *a, const blas_int *lda, const double *b, const blas_int *ldb,
+            const double *beta, double *c, const blas_int *ldc) {
+    int ta = *transa != 'N' && *transa != 'n';
+    int tb = *transb != 'N' && *transb != 'n';
+    for (blas_int j = 0; j < *n; ++j) {
+        for (blas_int i = 0; i < *m; ++i) {
+            double sum = 0.0;
+            for (blas_int p = 0; p < *k; ++p) {
+                double aip = ta ? a[p + i * *lda] : a[i + p * *lda];
+                double bpj = tb ? b[j + p * *ldb] : b[p + j * *ldb];
+                sum += aip * bpj;
+            }
+            double *cij = &c[i + j * *ldc];
+            *cij = (*beta == 0.0) ? *alpha * sum : *alpha * sum + *beta * *cij;
+        }
+    }
+}
+"#;
+
+/// Compile stub library; `None` if C compiler is not available.
+fn build_stub() -> Option<PathBuf> {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let source = dir.join("blas_stub.c");
+    let library = dir.join("libblas_stub.so");
+    std::fs::write(&source, STUB_SOURCE).ok()?;
+    let mut cmd = Command::new("cc");
+    cmd.args(["-shared", "-fPIC", "-O1", "-o"]).arg(&library).arg(&source);
+    if cfg!(feature = "ilp64") {
+        cmd.arg("-DILP64");
+    }
+    for name in ["ddot", "zaxpy", "dgemv", "dgemm"] {
+        cmd.arg(format!("-D{}={}", name.to_uppercase(), ffi::symbol_name(name)));
+    }
+    let status = cmd.status().ok()?;
+    return status.success().then_some(library);
+}
+
+#[test]
+fn test_dynamic_stub() {
+    let Some(stub) = build_stub() else {
+        eprintln!("C compiler is not available, skipped");
+        return;
+    };
+    let stub = stub.to_str().unwrap();
+
+    // failure of loading is error
+    assert!(matches!(dynamic::load("/nonexistent/libblas.so"), Err(BLASError::Miscellaneous(_))));
+    dynamic::load(stub).unwrap();
+    assert_eq!(dynamic::library_path(), Some(stub));
+    assert!(dynamic::table().dgemm_.is_some());
+    assert!(dynamic::table().dsyrk_.is_none());
+
+    // wrapper dispatches through stub library (row-major input is flipped to transposed col-major)
+    let a = Array2::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+    let b = Array2::from_shape_fn((5, 4), |(i, j)| (i + 2 * j) as f64);
+    let c = DGEMM::default().a(a.view()).b(b.view()).transb('T').alpha(2.0).run().unwrap().into_owned();
+    assert_eq!(c, 2.0 * a.dot(&b.t()));
+
+    // missing symbol is error, instead of link failure
+    let err = DSYRK::default().a(a.view()).run().unwrap_err();
+    let dsyrk = ffi::symbol_name("dsyrk");
+    assert!(matches!(err, BLASError::MissingSymbol(symbol) if symbol == dsyrk));
+    assert!(err.to_string().contains(&format!("`{dsyrk}` not found in BLAS library")));
+    assert_eq!(dynamic::take_missing_symbol(), None);
+
+    // output conjugated in-place for BLAS call is restored when the call fails (`zgemm_` is missing)
+    let a_z = Array2::from_shape_fn((4, 3).f(), |(i, j)| c64::new(i as f64, j as f64));
+    let b_z = Array2::from_shape_fn((5, 4).f(), |(i, j)| c64::new(j as f64, (i + 1) as f64));
+    let mut c_z = Array2::from_shape_fn((3, 5), |(i, j)| c64::new(i as f64, (i + j) as f64));
+    let c_origin = c_z.clone();
+    let beta = c64::new(0.5, 1.0);
+    let zgemm = ZGEMM::default().a(a_z.view()).b(b_z.view()).c(c_z.view_mut()).beta(beta);
+    assert!(zgemm.transa('C').transb('C').explain().unwrap().conj_output);
+    let zgemm = ZGEMM::default().a(a_z.view()).b(b_z.view()).c(c_z.view_mut()).beta(beta);
+    let err = zgemm.transa('C').transb('C').run().unwrap_err();
+    assert!(matches!(err, BLASError::MissingSymbol(_)));
+    assert_eq!(c_z, c_origin);
+
+    // Level 1 routine with return value
+    let x = Array1::from_shape_fn(6, |i| i as f64);
+    let y = Array1::from_shape_fn(12, |i| (2 * i + 1) as f64);
+    let y = y.slice(s![..;2]);
+    let result = DDOT::default().x(x.view()).y(y).run().unwrap();
+    assert_eq!(result, x.dot(&y));
+
+    // Level 2 routine (row-major input is flipped to transposed col-major)
+    let x = Array1::from_shape_fn(4, |i| (i + 1) as f64);
+    let y = DGEMV::default().a(a.view()).x(x.view()).alpha(2.0).run().unwrap().into_owned();
+    assert_eq!(y, 2.0 * a.dot(&x));
+
+    // complex routine
+    let x = Array1::from_shape_fn(5, |i| c64::new(i as f64, 1.0));
+    let mut y = Array1::from_shape_fn(5, |i| c64::new(1.0, -(i as f64)));
+    let y_ref = &y + &x.mapv(|v| v * c64::new(0.5, 2.0));
+    ZAXPY::default().x(x.view()).y(y.view_mut()).alpha(c64::new(0.5, 2.0)).run().unwrap();
+    assert_eq!(y, y_ref);
+}
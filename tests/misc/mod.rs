@@ -0,0 +1,9 @@
+pub mod test_copy_policy;
+pub mod test_explain;
+pub mod test_exec_plan;
+pub mod test_typestate;
+pub mod test_func;
+pub mod test_error;
+pub mod test_threading;
+pub mod test_backend;
+pub mod test_cblas;
@@ -0,0 +1,46 @@
+//! Runtime discovery of linked BLAS; vendor is not asserted, since tests may run with any BLAS.
+
+use blas_array2::backend::*;
+use blas_array2::ffi;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_backend_info() {
+        let info = backend_info();
+        println!("{info}");
+        // tests of this crate only run when BLAS is consistent to crate feature `ilp64`
+        assert_eq!(info.is_int_consistent(), Some(true));
+        assert_eq!(info.expected_int_bits, if cfg!(feature = "ilp64") { 64 } else { 32 });
+        if info.vendor != BLASVendor::Unknown {
+            assert!(info.version.is_some());
+        }
+        if cfg!(unix) {
+            assert!(info.gemmt.is_some() && info.matcopy.is_some());
+        }
+        if cfg!(feature = "reference") {
+            assert_eq!(info.vendor, BLASVendor::Reference);
+            assert_eq!((info.gemmt, info.gemm_batch, info.matcopy), (Some(true), Some(true), Some(true)));
+        }
+    }
+
+    #[test]
+    fn test_symbol_name() {
+        // build-time environment variables override crate features
+        if std::env::var_os("BLAS_ARRAY2_SYMBOL_PREFIX").is_some()
+            || std::env::var_os("BLAS_ARRAY2_SYMBOL_SUFFIX").is_some()
+        {
+            return;
+        }
+        let expected = if cfg!(feature = "suffix64_") {
+            "dgemm_64_"
+        } else if cfg!(feature = "suffix_64") {
+            "dgemm_64"
+        } else {
+            "dgemm_"
+        };
+        assert_eq!(ffi::symbol_name("dgemm"), expected);
+    }
+}
@@ -0,0 +1,330 @@
+//! Row-major arrays passed to CBLAS directly (crate feature `cblas`); no conjugate copies should be performed.
+//!
+//! Values of all wrappers dispatched to CBLAS are checked against naive dense computation, for row-major
+//! (CBLAS) and col-major (Fortran BLAS) storage of every `trans`, `uplo` and `diag`.
+//!
+//! Crate feature `cblas` conflicts with `reference`, so these tests run by a separate job, with a BLAS library
+//! that exports `cblas_*` symbols (OpenBLAS, BLIS, MKL) linked by `RUSTFLAGS`:
+//!
+//! ```text
+//! RUSTFLAGS="-lopenblas" cargo test -p blas-array2 --features cblas --tests
+//! ```
+
+#![cfg(feature = "cblas")]
+
+use crate::util::*;
+use blas_array2::prelude::*;
+use itertools::iproduct;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/// Routine name of the only BLAS call in plan, which should neither copy nor conjugate arrays.
+fn single_call(plan: &BLASPlan) -> &'static str {
+    assert!(plan.copies.is_empty());
+    assert!(!plan.conj_output);
+    assert_eq!(plan.calls.len(), 1);
+    let call = &plan.calls[0];
+    assert_eq!(call.flag("layout"), Some('R'));
+    return call.routine;
+}
+
+/// Check that arrays of row-major `layout` are passed to CBLAS routine `routine` directly.
+fn check_dispatch(plan: Result<BLASPlan, BLASError>, layout: char, routine: &str) {
+    if layout == 'R' {
+        assert_eq!(single_call(&plan.unwrap()), routine);
+    }
+}
+
+/// Random square matrix with dominant diagonal, so that triangular solves are well-conditioned.
+fn random_dominant(n: usize) -> Array2<c64> {
+    let mut a = random_matrix::<c64>(n, n, 'R'.into());
+    a.diag_mut().mapv_inplace(|x| x + c64::from(n as f64));
+    return a;
+}
+
+/// Triangle `uplo` of square matrix, with unit diagonal if `diag` is `U`.
+fn triangle(a: &Array2<c64>, uplo: char, diag: char) -> Array2<c64> {
+    return Array2::from_shape_fn(a.dim(), |(i, j)| match (i == j, diag, uplo) {
+        (true, 'U', _) => c64::from(1.0),
+        (_, _, 'U') if i <= j => a[[i, j]],
+        (_, _, 'L') if i >= j => a[[i, j]],
+        _ => c64::zero(),
+    });
+}
+
+/// Band part of matrix with `kl` sub- and `ku` super-diagonals, and its band storage of CBLAS convention in
+/// `layout`: `(m, kl + ku + 1)` in row-major, `(kl + ku + 1, n)` in col-major. Unreferenced elements of storage
+/// are random.
+fn band(a: &Array2<c64>, kl: usize, ku: usize, layout: char) -> (Array2<c64>, Array2<c64>) {
+    let (m, n) = a.dim();
+    let mut ab = match layout {
+        'R' => random_matrix::<c64>(m, kl + ku + 1, 'R'.into()),
+        _ => random_matrix::<c64>(kl + ku + 1, n, 'C'.into()),
+    };
+    let mut a_band = Array2::zeros((m, n));
+    for (i, j) in iproduct!(0..m, 0..n) {
+        if i <= j + kl && j <= i + ku {
+            a_band[[i, j]] = a[[i, j]];
+            match layout {
+                'R' => ab[[i, kl + j - i]] = a[[i, j]],
+                _ => ab[[ku + i - j, j]] = a[[i, j]],
+            }
+        }
+    }
+    return (a_band, ab);
+}
+
+/// Packed storage of triangle `uplo` of square matrix in `layout`; inverse of [`unpack_tril`].
+fn packed(a: &Array2<c64>, uplo: char, layout: char) -> Array1<c64> {
+    let n = a.nrows();
+    // row-major upper (lower) is col-major lower (upper) of transpose
+    let a = match layout {
+        'R' => a.t(),
+        _ => a.view(),
+    };
+    let upper = (uplo == 'U') == (layout == 'C');
+    let ap = (0..n).flat_map(|j| if upper { 0..j + 1 } else { j..n }.map(move |i| (i, j)));
+    return ap.map(|(i, j)| a[[i, j]]).collect();
+}
+
+/// `alpha op(A) x + beta y` by naive computation.
+fn mv(a: &Array2<c64>, trans: char, x: &Array1<c64>, alpha: c64, beta: c64, y: &Array1<c64>) -> Array1<c64> {
+    let a = transpose(&a.view(), trans.into());
+    return gemv(&a.view(), &x.view()) * alpha + y * beta;
+}
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_blas2_routines() {
+        let a = random_matrix::<c64>(8, 8, 'R'.into());
+        let a_band = random_matrix::<c64>(8, 3, 'R'.into());
+        let ap = random_array::<c64>(36);
+        let x = random_array::<c64>(8);
+        let mut x_mut = x.clone();
+
+        let plan = ZGEMV::default().a(a.view()).x(x.view()).trans('C').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_zgemv");
+        let plan = ZGBMV::default().a(a_band.view()).x(x.view()).m(8).kl(1).trans('C').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_zgbmv");
+        let plan = ZHEMV::default().a(a.view()).x(x.view()).uplo('U').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_zhemv");
+        let plan = ZHBMV::default().a(a_band.view()).x(x.view()).uplo('U').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_zhbmv");
+        let plan = ZHPMV::default().ap(ap.view()).x(x.view()).layout('R').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_zhpmv");
+
+        let plan = ZTRMV::default().a(a.view()).x(x_mut.view_mut()).trans('C').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_ztrmv");
+        let plan = ZTBMV::default().a(a_band.view()).x(x_mut.view_mut()).trans('C').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_ztbmv");
+        let plan =
+            ZTPMV::default().ap(ap.view()).x(x_mut.view_mut()).trans('C').layout('R').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_ztpmv");
+        let plan = ZTRSV::default().a(a.view()).x(x_mut.view_mut()).trans('C').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_ztrsv");
+        let plan = ZTBSV::default().a(a_band.view()).x(x_mut.view_mut()).trans('C').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_ztbsv");
+        let plan =
+            ZTPSV::default().ap(ap.view()).x(x_mut.view_mut()).trans('C').layout('R').explain().unwrap();
+        assert_eq!(single_call(&plan), "cblas_ztpsv");
+        assert_eq!(x_mut, x);
+    }
+
+    #[test]
+    fn test_gemv_gbmv() {
+        let (m, n, kl, ku) = (8, 6, 2, 1);
+        let (alpha, beta) = (c64::new(1.5, 0.5), c64::new(0.5, -1.0));
+        for (layout, trans) in iproduct!(['R', 'C'], ['N', 'T', 'C']) {
+            let a = ndarray_to_layout(random_matrix::<c64>(m, n, 'R'.into()), layout);
+            let (len_x, len_y) = if trans == 'N' { (n, m) } else { (m, n) };
+            let x = random_array::<c64>(len_x);
+            let y = random_array::<c64>(len_y);
+
+            let plan = ZGEMV::default().a(a.view()).x(x.view()).trans(trans).explain();
+            check_dispatch(plan, layout, "cblas_zgemv");
+            let mut y_out = y.clone();
+            let gemv = ZGEMV::default().a(a.view()).x(x.view()).y(y_out.view_mut()).trans(trans);
+            gemv.alpha(alpha).beta(beta).run().unwrap();
+            check_same(&y_out.view(), &mv(&a, trans, &x, alpha, beta, &y).view(), 4.0 * f64::EPSILON);
+
+            // builder `m` is number of columns in row-major
+            let (a_band, ab) = band(&a, kl, ku, layout);
+            let m_arg = if layout == 'R' { n } else { m };
+            let plan = ZGBMV::default().a(ab.view()).x(x.view()).m(m_arg).kl(kl).trans(trans).explain();
+            check_dispatch(plan, layout, "cblas_zgbmv");
+            let mut y_out = y.clone();
+            let gbmv = ZGBMV::default().a(ab.view()).x(x.view()).y(y_out.view_mut()).m(m_arg).kl(kl);
+            gbmv.trans(trans).alpha(alpha).beta(beta).run().unwrap();
+            check_same(&y_out.view(), &mv(&a_band, trans, &x, alpha, beta, &y).view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_hemv_hbmv_hpmv() {
+        let (n, k) = (8, 2);
+        let (alpha, beta) = (c64::new(1.5, 0.5), c64::new(0.5, -1.0));
+        for (layout, uplo) in iproduct!(['R', 'C'], ['U', 'L']) {
+            let a = ndarray_to_layout(random_matrix::<c64>(n, n, 'R'.into()), layout);
+            let x = random_array::<c64>(n);
+            let y = random_array::<c64>(n);
+
+            let a_herm = hermitianize(&a.view(), uplo);
+            let plan = ZHEMV::default().a(a.view()).x(x.view()).uplo(uplo).explain();
+            check_dispatch(plan, layout, "cblas_zhemv");
+            let mut y_out = y.clone();
+            let hemv = ZHEMV::default().a(a.view()).x(x.view()).y(y_out.view_mut()).uplo(uplo);
+            hemv.alpha(alpha).beta(beta).run().unwrap();
+            check_same(&y_out.view(), &mv(&a_herm, 'N', &x, alpha, beta, &y).view(), 4.0 * f64::EPSILON);
+
+            let (kl, ku) = if uplo == 'U' { (0, k) } else { (k, 0) };
+            let (a_band, ab) = band(&a, kl, ku, layout);
+            let a_herm = hermitianize(&a_band.view(), uplo);
+            let plan = ZHBMV::default().a(ab.view()).x(x.view()).uplo(uplo).explain();
+            check_dispatch(plan, layout, "cblas_zhbmv");
+            let mut y_out = y.clone();
+            let hbmv = ZHBMV::default().a(ab.view()).x(x.view()).y(y_out.view_mut()).uplo(uplo);
+            hbmv.alpha(alpha).beta(beta).run().unwrap();
+            check_same(&y_out.view(), &mv(&a_herm, 'N', &x, alpha, beta, &y).view(), 4.0 * f64::EPSILON);
+
+            let ap = packed(&a, uplo, layout);
+            let a_herm = hermitianize(&a.view(), uplo);
+            let plan = ZHPMV::default().ap(ap.view()).x(x.view()).uplo(uplo).layout(layout).explain();
+            check_dispatch(plan, layout, "cblas_zhpmv");
+            let mut y_out = y.clone();
+            let hpmv = ZHPMV::default().ap(ap.view()).x(x.view()).y(y_out.view_mut()).uplo(uplo);
+            hpmv.layout(layout).alpha(alpha).beta(beta).run().unwrap();
+            check_same(&y_out.view(), &mv(&a_herm, 'N', &x, alpha, beta, &y).view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_trmv_tbmv_tpmv() {
+        let (n, k) = (8, 2);
+        let (one, zero) = (c64::from(1.0), c64::zero());
+        for (layout, uplo, trans, diag) in iproduct!(['R', 'C'], ['U', 'L'], ['N', 'T', 'C'], ['N', 'U']) {
+            let a = ndarray_to_layout(random_matrix::<c64>(n, n, 'R'.into()), layout);
+            let x = random_array::<c64>(n);
+
+            let a_tri = triangle(&a, uplo, diag);
+            let mut x_out = x.clone();
+            let plan = ZTRMV::default().a(a.view()).x(x_out.view_mut()).uplo(uplo).trans(trans).diag(diag);
+            check_dispatch(plan.explain(), layout, "cblas_ztrmv");
+            ZTRMV::default()
+                .a(a.view())
+                .x(x_out.view_mut())
+                .uplo(uplo)
+                .trans(trans)
+                .diag(diag)
+                .run()
+                .unwrap();
+            check_same(&x_out.view(), &mv(&a_tri, trans, &x, one, zero, &x).view(), 4.0 * f64::EPSILON);
+
+            let (kl, ku) = if uplo == 'U' { (0, k) } else { (k, 0) };
+            let (a_band, ab) = band(&a, kl, ku, layout);
+            let a_tri = triangle(&a_band, uplo, diag);
+            let mut x_out = x.clone();
+            let plan = ZTBMV::default().a(ab.view()).x(x_out.view_mut()).uplo(uplo).trans(trans).diag(diag);
+            check_dispatch(plan.explain(), layout, "cblas_ztbmv");
+            let tbmv = ZTBMV::default().a(ab.view()).x(x_out.view_mut()).uplo(uplo).trans(trans).diag(diag);
+            tbmv.run().unwrap();
+            check_same(&x_out.view(), &mv(&a_tri, trans, &x, one, zero, &x).view(), 4.0 * f64::EPSILON);
+
+            let ap = packed(&a, uplo, layout);
+            let a_tri = triangle(&a, uplo, diag);
+            let mut x_out = x.clone();
+            let plan = ZTPMV::default().ap(ap.view()).x(x_out.view_mut()).uplo(uplo).trans(trans);
+            check_dispatch(plan.diag(diag).layout(layout).explain(), layout, "cblas_ztpmv");
+            let tpmv = ZTPMV::default().ap(ap.view()).x(x_out.view_mut()).uplo(uplo).trans(trans).diag(diag);
+            tpmv.layout(layout).run().unwrap();
+            check_same(&x_out.view(), &mv(&a_tri, trans, &x, one, zero, &x).view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_trsv_tbsv_tpsv() {
+        // solution `x` is checked by `op(A) x = b`
+        let (n, k) = (8, 2);
+        let (one, zero) = (c64::from(1.0), c64::zero());
+        for (layout, uplo, trans, diag) in iproduct!(['R', 'C'], ['U', 'L'], ['N', 'T', 'C'], ['N', 'U']) {
+            let a = ndarray_to_layout(random_dominant(n), layout);
+            let b = random_array::<c64>(n);
+
+            let a_tri = triangle(&a, uplo, diag);
+            let mut x = b.clone();
+            let plan = ZTRSV::default().a(a.view()).x(x.view_mut()).uplo(uplo).trans(trans).diag(diag);
+            check_dispatch(plan.explain(), layout, "cblas_ztrsv");
+            ZTRSV::default().a(a.view()).x(x.view_mut()).uplo(uplo).trans(trans).diag(diag).run().unwrap();
+            check_same(&mv(&a_tri, trans, &x, one, zero, &x).view(), &b.view(), 4.0 * f64::EPSILON);
+
+            let (kl, ku) = if uplo == 'U' { (0, k) } else { (k, 0) };
+            let (a_band, ab) = band(&a, kl, ku, layout);
+            let a_tri = triangle(&a_band, uplo, diag);
+            let mut x = b.clone();
+            let plan = ZTBSV::default().a(ab.view()).x(x.view_mut()).uplo(uplo).trans(trans).diag(diag);
+            check_dispatch(plan.explain(), layout, "cblas_ztbsv");
+            ZTBSV::default().a(ab.view()).x(x.view_mut()).uplo(uplo).trans(trans).diag(diag).run().unwrap();
+            check_same(&mv(&a_tri, trans, &x, one, zero, &x).view(), &b.view(), 4.0 * f64::EPSILON);
+
+            let ap = packed(&a, uplo, layout);
+            let a_tri = triangle(&a, uplo, diag);
+            let mut x = b.clone();
+            let plan = ZTPSV::default().ap(ap.view()).x(x.view_mut()).uplo(uplo).trans(trans);
+            check_dispatch(plan.diag(diag).layout(layout).explain(), layout, "cblas_ztpsv");
+            let tpsv = ZTPSV::default().ap(ap.view()).x(x.view_mut()).uplo(uplo).trans(trans).diag(diag);
+            tpsv.layout(layout).run().unwrap();
+            check_same(&mv(&a_tri, trans, &x, one, zero, &x).view(), &b.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_gemm() {
+        let (m, n, k) = (7, 6, 5);
+        let (alpha, beta) = (c64::new(1.5, 0.5), c64::new(0.5, -1.0));
+        for (layout, transa, transb) in iproduct!(['R', 'C'], ['N', 'T', 'C'], ['N', 'T', 'C']) {
+            let (ma, na) = if transa == 'N' { (m, k) } else { (k, m) };
+            let (mb, nb) = if transb == 'N' { (k, n) } else { (n, k) };
+            let a = ndarray_to_layout(random_matrix::<c64>(ma, na, 'R'.into()), layout);
+            let b = ndarray_to_layout(random_matrix::<c64>(mb, nb, 'R'.into()), layout);
+            let c = ndarray_to_layout(random_matrix::<c64>(m, n, 'R'.into()), layout);
+
+            let plan = ZGEMM::default().a(a.view()).b(b.view()).transa(transa).transb(transb).explain();
+            check_dispatch(plan, layout, "cblas_zgemm");
+            let mut c_out = c.clone();
+            let gemm_obj = ZGEMM::default().a(a.view()).b(b.view()).c(c_out.view_mut());
+            gemm_obj.transa(transa).transb(transb).alpha(alpha).beta(beta).run().unwrap();
+            let a_op = transpose(&a.view(), transa.into());
+            let b_op = transpose(&b.view(), transb.into());
+            let c_naive = gemm(&a_op.view(), &b_op.view()) * alpha + &c * beta;
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_herk() {
+        let (n, k) = (6, 5);
+        let (alpha, beta) = (1.5, 0.5);
+        for (layout, uplo, trans) in iproduct!(['R', 'C'], ['U', 'L'], ['N', 'C']) {
+            let (ma, na) = if trans == 'N' { (n, k) } else { (k, n) };
+            let a = ndarray_to_layout(random_matrix::<c64>(ma, na, 'R'.into()), layout);
+            let c = random_matrix::<c64>(n, n, 'R'.into());
+            let c = ndarray_to_layout(hermitianize(&c.view(), uplo), layout);
+
+            let plan = ZHERK::default().a(a.view()).uplo(uplo).trans(trans).explain();
+            check_dispatch(plan, layout, "cblas_zherk");
+            let mut c_out = c.clone();
+            let herk = ZHERK::default().a(a.view()).c(c_out.view_mut()).uplo(uplo).trans(trans);
+            herk.alpha(alpha).beta(beta).run().unwrap();
+
+            // only triangle `uplo` is updated
+            let a_op = transpose(&a.view(), trans.into());
+            let c_update = gemm(&a_op.view(), &transpose(&a_op.view(), 'C'.into()).view());
+            let c_update = c_update * c64::from(alpha) + &c * c64::from(beta);
+            let mut c_naive = c.clone();
+            tril_assign(&mut c_naive.view_mut(), &c_update.view(), uplo);
+            check_same(&c_out.view(), &c_naive.view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
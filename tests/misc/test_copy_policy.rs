@@ -0,0 +1,142 @@
+//! Runtime copy policy; global policy is not modified here, since tests run in parallel.
+
+use crate::util::*;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    fn report(event: &CopyEvent) -> Result<(), BLASError> {
+        let CopyEvent { wrapper, shape, strides, reason } = event;
+        Err(BLASError::Miscellaneous(format!("{wrapper}|{shape:?}|{strides:?}|{reason}")))
+    }
+
+    #[test]
+    fn test_per_call() {
+        let a_raw = random_matrix::<f64>(8, 12, 'R'.into());
+        let a = a_raw.slice(s![.., ..;2]);
+
+        // copy of non-contiguous A is rejected
+        let err = DSYRK::default().a(a).copy_policy(CopyPolicy::Error).run().unwrap_err();
+        assert!(matches!(&err, BLASError::ExplicitCopy(info) if info.wrapper == "SYRK"));
+
+        // per-call policy overrides per-thread policy
+        let _guard = scoped_copy_policy(CopyPolicy::Error);
+        let c_out = DSYRK::default().a(a).copy_policy(CopyPolicy::Allow).run().unwrap();
+        let c_naive = gemm(&a, &a.t());
+        check_same(&symmetrize(&c_out.view(), 'L').view(), &c_naive.view(), 4.0 * f64::EPSILON);
+
+        // contiguous inputs are not affected
+        let a = a.to_owned();
+        DSYRK::default().a(a.view()).run().unwrap();
+
+        // GEMM of non-contiguous A is computed by stride-native kernel without copy
+        let b = random_matrix::<f64>(6, 5, 'R'.into());
+        let c_out = DGEMM::default().a(a_raw.slice(s![.., ..;2])).b(b.view()).run().unwrap();
+        check_same(&c_out.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+
+        // conjugate of only one input is not supported by stride-native kernel, so copy is rejected
+        let a_raw = random_matrix::<c64>(8, 12, 'R'.into());
+        let b = random_matrix::<c64>(8, 5, 'R'.into());
+        let err = ZGEMM::default()
+            .a(a_raw.slice(s![.., ..;2]))
+            .b(b.view())
+            .transa('C')
+            .copy_policy(CopyPolicy::Error)
+            .run()
+            .unwrap_err();
+        assert!(matches!(&err, BLASError::ExplicitCopy(info) if info.wrapper == "GEMM"));
+    }
+
+    #[test]
+    fn test_per_thread() {
+        let a_raw = random_matrix::<f64>(8, 12, 'R'.into());
+        let a = a_raw.slice(s![.., ..;2]);
+        let x = random_array::<f64>(6);
+        {
+            let _guard = scoped_copy_policy(CopyPolicy::Error);
+            let err = DGEMV::default().a(a).x(x.view()).run().unwrap_err();
+            assert!(matches!(err, BLASError::ExplicitCopy(_)));
+            {
+                let _guard = scoped_copy_policy(CopyPolicy::Allow);
+                DGEMV::default().a(a).x(x.view()).run().unwrap();
+            }
+            // previous policy is restored when guard is dropped
+            let err = DGEMV::default().a(a).x(x.view()).run().unwrap_err();
+            assert!(matches!(err, BLASError::ExplicitCopy(_)));
+        }
+
+        // per-thread policy does not leak to other threads
+        std::thread::scope(|s| {
+            let _guard = scoped_copy_policy(CopyPolicy::Error);
+            s.spawn(|| {
+                let _guard = scoped_copy_policy(CopyPolicy::Allow);
+                DGEMV::default().a(a).x(x.view()).run().unwrap();
+            });
+        });
+    }
+
+    #[test]
+    fn test_callback() {
+        let a_raw = random_matrix::<f64>(8, 12, 'R'.into());
+        let a = a_raw.slice(s![.., ..;2]);
+        let mut b = random_matrix::<f64>(6, 5, 'R'.into());
+
+        let err = DTRMM::default()
+            .a(a.slice(s![..6, ..]))
+            .b(b.view_mut())
+            .copy_policy(CopyPolicy::Callback(report))
+            .run();
+        let msg = match err {
+            Err(BLASError::Miscellaneous(msg)) => msg,
+            _ => panic!("Expected error from callback"),
+        };
+        // copied view may be transposed from input, depending on layout
+        assert!(msg.starts_with("TRMM|[6, 6]|"));
+        assert!(msg.ends_with("|Copying array due to non-standard layout"));
+
+        // conjugate copy is also reported
+        let a = random_matrix::<c64>(5, 5, 'C'.into());
+        let b = random_matrix::<c64>(5, 5, 'C'.into());
+        let err = ZHEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .layout('R')
+            .copy_policy(CopyPolicy::Callback(report))
+            .run()
+            .unwrap_err();
+        assert!(matches!(err, BLASError::Miscellaneous(msg) if msg.starts_with("HEMM|")));
+
+        // callback does not force stride-native GEMM, so copy dominated by computation is reported
+        let b = random_matrix::<f64>(6, 80, 'R'.into());
+        let err = DGEMM::default()
+            .a(a_raw.slice(s![.., ..;2]))
+            .b(b.view())
+            .copy_policy(CopyPolicy::Callback(report))
+            .run()
+            .unwrap_err();
+        assert!(matches!(err, BLASError::Miscellaneous(msg) if msg.starts_with("GEMM|")));
+    }
+
+    #[test]
+    fn test_gemm_batch() {
+        // per-problem policy overrides that of batch
+        let a_raw = random_matrix::<f64>(8, 12, 'R'.into());
+        let a = a_raw.slice(s![.., ..;2]);
+        let b = random_matrix::<f64>(6, 5, 'R'.into());
+        let result = DGEMM_BATCH::default()
+            .gemm(DGEMM::default().a(a).b(b.view()))
+            .gemm(DGEMM::default().a(a).b(b.view()).copy_policy(CopyPolicy::Allow))
+            .copy_policy(CopyPolicy::Error)
+            .run();
+        assert!(matches!(result, Err(BLASError::ExplicitCopy(_))));
+        let c_list = DGEMM_BATCH::default()
+            .gemm(DGEMM::default().a(a).b(b.view()).copy_policy(CopyPolicy::Allow))
+            .copy_policy(CopyPolicy::Error)
+            .run()
+            .unwrap();
+        check_same(&c_list[0].view(), &gemm(&a, &b.view()).view(), 4.0 * f64::EPSILON);
+    }
+}
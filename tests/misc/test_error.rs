@@ -0,0 +1,99 @@
+//! Structured information of errors.
+
+use crate::util::*;
+use blas_array2::prelude::*;
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_gemm_dim() {
+        let a = random_matrix::<f64>(8, 6, 'C'.into());
+        let b = random_matrix::<f64>(7, 5, 'C'.into());
+        let err = DGEMM::default().a(a.view()).b(b.view()).run().unwrap_err();
+        let BLASError::InvalidDim(info) = &err else { panic!("Expected BLASError::InvalidDim") };
+        assert_eq!((info.wrapper, info.param, info.position), ("GEMM", "b", Some(9)));
+        assert_eq!(info.actual, Some(BLASDims::scalar(7)));
+        assert_eq!(info.expected, Some(BLASDims::scalar(6)));
+        assert!(info.location.contains("gemm.rs"));
+        assert!(err.to_string().contains("(in GEMM, argument 9 `b`)"));
+
+        // non-allocating representation
+        let code = err.code();
+        assert_eq!(code.kind, BLASErrorKind::InvalidDim);
+        assert_eq!(code.info, *info);
+        assert_eq!(code.to_string(), err.to_string());
+    }
+
+    #[test]
+    fn test_gemv_dim() {
+        let a = random_matrix::<f64>(8, 6, 'R'.into());
+        let x = random_array::<f64>(6);
+        let mut y = random_array::<f64>(7);
+        let err = DGEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).run().unwrap_err();
+        let info = err.info().unwrap();
+        assert_eq!(err.kind(), BLASErrorKind::InvalidDim);
+        assert_eq!((info.wrapper, info.param, info.position), ("GEMV", "y", Some(10)));
+        assert_eq!((info.actual, info.expected), (Some(BLASDims::scalar(7)), Some(BLASDims::scalar(8))));
+    }
+
+    #[test]
+    fn test_flag() {
+        let a = random_matrix::<f64>(6, 6, 'C'.into());
+        let err = DSYRK::default().a(a.view()).uplo('X').run().unwrap_err();
+        assert_eq!(err.kind(), BLASErrorKind::InvalidFlag);
+        assert_eq!(err.info().unwrap().param, "uplo");
+    }
+
+    #[test]
+    fn test_uninitialized() {
+        let err = DGEMM::default().run().unwrap_err();
+        assert_eq!(err.code().kind, BLASErrorKind::UninitializedField);
+        assert_eq!(err.code().info.param, "a");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "xerbla")]
+mod xerbla {
+    use super::*;
+    use blas_array2::ffi::{self, blas_int};
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_library_argument() {
+        // lda = 1 is rejected by BLAS library (argument 8 of dgemm)
+        let a = random_matrix::<f64>(4, 4, 'C'.into());
+        let mut c = Array2::<f64>::zeros((4, 4).f());
+        let (m, lda, ldc): (blas_int, blas_int, blas_int) = (4, 1, 4);
+        let (alpha, beta) = (1.0, 0.0);
+        unsafe {
+            let trans = b'N' as ffi::c_char;
+            ffi::dgemm_(
+                &trans,
+                &trans,
+                &m,
+                &m,
+                &m,
+                &alpha,
+                a.as_ptr(),
+                &lda,
+                a.as_ptr(),
+                &ldc,
+                &beta,
+                c.as_mut_ptr(),
+                &ldc,
+            );
+        }
+        let err = check_xerbla().unwrap_err();
+        assert_eq!(err, BLASError::LibraryArgument { routine: "DGEMM".into(), info: 8 });
+        let info = err.code().info;
+        assert_eq!((info.wrapper, info.param, info.position), ("GEMM", "lda", Some(8)));
+        assert!(err.to_string().contains("argument 8 of DGEMM `lda`"));
+
+        // record is cleared
+        assert!(check_xerbla().is_ok());
+        assert!(take_xerbla().is_none());
+    }
+}
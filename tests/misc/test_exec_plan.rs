@@ -0,0 +1,251 @@
+//! Reusable plans of wrappers; results should be the same as those of wrappers.
+
+use crate::util::*;
+use blas_array2::ffi;
+use blas_array2::prelude::*;
+use itertools::iproduct;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        for (la, lb, lc) in [('C', 'C', 'C'), ('R', 'R', 'R'), ('R', 'C', 'C'), ('C', 'R', 'R')] {
+            let a = random_matrix::<c64>(7, 5, la.into());
+            let b = random_matrix::<c64>(6, 7, lb.into());
+            let c = random_matrix::<c64>(5, 6, lc.into());
+            let plan = ZGEMM::default()
+                .a(a.view())
+                .b(b.view())
+                .c(c.clone().view_mut())
+                .alpha(c64::new(1.5, 0.5))
+                .beta(c64::new(0.5, -1.0))
+                .transa('T')
+                .transb('C')
+                .plan()
+                .unwrap();
+            for _ in 0..2 {
+                let a = random_matrix::<c64>(7, 5, la.into());
+                let b = random_matrix::<c64>(6, 7, lb.into());
+                let mut c = random_matrix::<c64>(5, 6, lc.into());
+                let mut c_ref = c.clone();
+                let builder = ZGEMM::default().a(a.view()).b(b.view()).c(c_ref.view_mut());
+                builder
+                    .alpha(c64::new(1.5, 0.5))
+                    .beta(c64::new(0.5, -1.0))
+                    .transa('T')
+                    .transb('C')
+                    .run()
+                    .unwrap();
+                let c_out = plan.run(a.view(), b.view(), Some(c.view_mut())).unwrap();
+                assert!(matches!(c_out, ArrayOut2::ViewMut(_)));
+                check_same(&c.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+            }
+        }
+
+        // contiguous inputs of any layout are called directly
+        let a = random_matrix::<c64>(7, 5, 'R'.into());
+        let b = random_matrix::<c64>(6, 7, 'C'.into());
+        assert!(ZGEMM::default().a(a.view()).b(b.view()).transa('T').transb('T').plan().unwrap().is_direct());
+
+        // output allocated by plan
+        let a = random_matrix::<f64>(5, 7, 'R'.into());
+        let b = random_matrix::<f64>(7, 6, 'R'.into());
+        let plan = DGEMM::default().a(a.view()).b(b.view()).plan().unwrap();
+        let c_out = plan.run(a.view(), b.view(), None).unwrap().into_owned();
+        assert!(c_out.is_standard_layout());
+        check_same(&c_out.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+
+        // non-contiguous input falls back to wrapper
+        let a_raw = random_matrix::<f64>(5, 14, 'R'.into());
+        let a = a_raw.slice(s![.., ..;2]);
+        let plan = DGEMM::default().a(a).b(b.view()).plan().unwrap();
+        assert!(!plan.is_direct());
+        let c_out = plan.run(a, b.view(), None).unwrap().into_owned();
+        check_same(&c_out.view(), &gemm(&a, &b.view()).view(), 4.0 * f64::EPSILON);
+
+        // inputs of different lifetimes
+        let b_short = b.clone();
+        let c_out = plan.run(a, b_short.view(), None).unwrap().into_owned();
+        drop(b_short);
+        check_same(&c_out.view(), &gemm(&a, &b.view()).view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_symm_syrk() {
+        let alpha = c64::new(1.5, 0.5);
+        let beta = c64::new(0.5, -1.0);
+        for (la, lb, lc) in [('C', 'C', 'C'), ('R', 'R', 'R'), ('C', 'R', 'R')] {
+            let a = random_matrix::<c64>(5, 5, la.into());
+            let b = random_matrix::<c64>(5, 6, lb.into());
+            let mut c = random_matrix::<c64>(5, 6, lc.into());
+            let mut c_ref = c.clone();
+
+            let plan =
+                ZSYMM::default().a(a.view()).b(b.view()).c(c.view_mut()).alpha(alpha).beta(beta).uplo('U');
+            let plan = plan.plan().unwrap();
+            plan.run(a.view(), b.view(), Some(c.view_mut())).unwrap();
+            let builder =
+                ZSYMM::default().a(a.view()).b(b.view()).c(c_ref.view_mut()).alpha(alpha).beta(beta);
+            builder.uplo('U').run().unwrap();
+            check_same(&c.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+            let plan =
+                ZHEMM::default().a(a.view()).b(b.view()).alpha(alpha).side('L').uplo('L').plan().unwrap();
+            let c_out = plan.run(a.view(), b.view(), None).unwrap().into_owned();
+            let c_ref =
+                ZHEMM::default().a(a.view()).b(b.view()).alpha(alpha).side('L').uplo('L').run().unwrap();
+            check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+            // C is 5 x 5
+            let mut c = random_matrix::<c64>(5, 5, lc.into());
+            let mut c_ref = c.clone();
+            let plan = ZSYR2K::default()
+                .a(b.view())
+                .b(b.view())
+                .c(c.view_mut())
+                .beta(beta)
+                .trans('N')
+                .plan()
+                .unwrap();
+            plan.run(b.view(), b.view(), Some(c.view_mut())).unwrap();
+            ZSYR2K::default()
+                .a(b.view())
+                .b(b.view())
+                .c(c_ref.view_mut())
+                .beta(beta)
+                .trans('N')
+                .run()
+                .unwrap();
+            check_same(&c.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+            let plan = ZHER2K::default().a(b.view()).b(b.view()).uplo('U').plan().unwrap();
+            let c_out = plan.run(b.view(), b.view(), None).unwrap().into_owned();
+            let c_ref = ZHER2K::default().a(b.view()).b(b.view()).uplo('U').run().unwrap();
+            check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+            // C is 6 x 6
+            let plan = ZSYRK::default().a(b.view()).alpha(alpha).trans('T').plan().unwrap();
+            let c_out = plan.run(b.view(), None).unwrap().into_owned();
+            let c_ref = ZSYRK::default().a(b.view()).alpha(alpha).trans('T').run().unwrap();
+            check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+            let plan = ZHERK::default().a(b.view()).alpha(2.0).trans('C').plan().unwrap();
+            let c_out = plan.run(b.view(), None).unwrap().into_owned();
+            let c_ref = ZHERK::default().a(b.view()).alpha(2.0).trans('C').run().unwrap();
+            check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_trsm_trmm() {
+        for (la, lb, side, transa) in iproduct!(['C', 'R'], ['C', 'R'], ['L', 'R'], ['N', 'T', 'C']) {
+            let a = random_matrix::<c64>(5, 5, la.into()) + Array2::<c64>::eye(5) * 10.0;
+            let b_shape = if side == 'L' { (5, 6) } else { (6, 5) };
+            let plan = ZTRSM::default()
+                .a(a.view())
+                .b(random_matrix::<c64>(b_shape.0, b_shape.1, lb.into()).view_mut())
+                .alpha(c64::new(1.5, 0.5))
+                .side(side)
+                .transa(transa)
+                .plan()
+                .unwrap();
+            // conjugate is required for A of the other layout with transa = C
+            assert_eq!(plan.is_direct(), la == lb || transa != 'C');
+            for _ in 0..2 {
+                let mut b = random_matrix::<c64>(b_shape.0, b_shape.1, lb.into());
+                let mut b_ref = b.clone();
+                plan.run(a.view(), b.view_mut()).unwrap();
+                let builder = ZTRSM::default().a(a.view()).b(b_ref.view_mut()).alpha(c64::new(1.5, 0.5));
+                builder.side(side).transa(transa).run().unwrap();
+                check_same(&b.view(), &b_ref.view(), 4.0 * f64::EPSILON);
+            }
+
+            let mut b = random_matrix::<c64>(b_shape.0, b_shape.1, lb.into());
+            let mut b_ref = b.clone();
+            let plan =
+                ZTRMM::default().a(a.view()).b(b.view_mut()).side(side).uplo('U').transa(transa).diag('U');
+            let plan = plan.plan().unwrap();
+            plan.run(a.view(), b.view_mut()).unwrap();
+            ZTRMM::default()
+                .a(a.view())
+                .b(b_ref.view_mut())
+                .side(side)
+                .uplo('U')
+                .transa(transa)
+                .diag('U')
+                .run()
+                .unwrap();
+            check_same(&b.view(), &b_ref.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_gemv() {
+        for (la, trans) in iproduct!(['C', 'R'], ['N', 'T', 'C']) {
+            let a = random_matrix::<c64>(5, 6, la.into());
+            let x = random_array::<c64>(if trans == 'N' { 6 } else { 5 });
+            let plan = ZGEMV::default().a(a.view()).x(x.view()).trans(trans).plan().unwrap();
+            // conjugate of vectors is required for row-major A with trans = C, unless CBLAS is called
+            assert_eq!(plan.is_direct(), ffi::USE_CBLAS || !(la == 'R' && trans == 'C'));
+            let y_out = plan.run(a.view(), x.view(), None).unwrap().into_owned();
+            let y_ref = ZGEMV::default().a(a.view()).x(x.view()).trans(trans).run().unwrap();
+            check_same(&y_out.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+
+            let mut y_raw = random_array::<c64>(if trans == 'N' { 10 } else { 12 });
+            let mut y = y_raw.slice_mut(s![..;2]);
+            let mut y_ref = y.to_owned();
+            let plan = ZGEMV::default()
+                .a(a.view())
+                .x(x.view())
+                .y(y.view_mut())
+                .beta(c64::new(0.5, 1.0))
+                .trans(trans);
+            let plan = plan.plan().unwrap();
+            plan.run(a.view(), x.view(), Some(y.view_mut())).unwrap();
+            let builder =
+                ZGEMV::default().a(a.view()).x(x.view()).y(y_ref.view_mut()).beta(c64::new(0.5, 1.0));
+            builder.trans(trans).run().unwrap();
+            check_same(&y.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "gemmt")]
+    fn test_gemmt() {
+        for (la, lb) in [('C', 'C'), ('R', 'R'), ('R', 'C')] {
+            let a = random_matrix::<f64>(5, 7, la.into());
+            let b = random_matrix::<f64>(7, 5, lb.into());
+            let plan = DGEMMT::default().a(a.view()).b(b.view()).uplo('U').plan().unwrap();
+            let c_out = plan.run(a.view(), b.view(), None).unwrap().into_owned();
+            let c_ref = DGEMMT::default().a(a.view()).b(b.view()).uplo('U').run().unwrap();
+            check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        let a = random_matrix::<f64>(5, 7, 'R'.into());
+        let b = random_matrix::<f64>(7, 6, 'R'.into());
+
+        // invalid problem is rejected when creating plan
+        assert!(DGEMM::default().a(b.view()).b(b.view()).plan().is_err());
+
+        let plan = DGEMM::default().a(a.view()).b(b.view()).plan().unwrap();
+        let a_f = random_matrix::<f64>(5, 7, 'C'.into());
+        let err = plan.run(a_f.view(), b.view(), None).unwrap_err();
+        assert!(matches!(err, BLASError::FailedCheck(_)));
+        let err = plan.run(b.view(), b.view(), None).unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+        let mut c = random_matrix::<f64>(5, 6, 'R'.into());
+        let err = plan.run(a.view(), b.view(), Some(c.view_mut())).unwrap_err();
+        assert!(matches!(err, BLASError::FailedCheck(_)));
+    }
+}
@@ -0,0 +1,167 @@
+//! Plan introspection by `explain`; BLAS routines are not called and output is not modified.
+
+use crate::util::*;
+use blas_array2::ffi;
+use blas_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        // row-major problem is flipped to col-major: C' = B' A' (or passed to CBLAS directly)
+        let a = random_matrix::<f64>(8, 6, 'R'.into());
+        let b = random_matrix::<f64>(6, 5, 'R'.into());
+        let plan = DGEMM::default().a(a.view()).b(b.view()).explain().unwrap();
+        assert_eq!(plan.wrapper, "GEMM");
+        assert_eq!(plan.layouts, [BLASRowMajor]);
+        assert!(!plan.conj_output);
+        assert!(plan.copies.is_empty());
+        assert_eq!(plan.calls.len(), 1);
+        let call = &plan.calls[0];
+        assert_eq!((call.flag("transa"), call.flag("transb")), (Some('N'), Some('N')));
+        if ffi::USE_CBLAS {
+            assert_eq!((call.routine, call.flag("layout")), ("cblas_dgemm", Some('R')));
+            assert_eq!((call.dim("m"), call.dim("n"), call.dim("k")), (Some(8), Some(5), Some(6)));
+            assert_eq!((call.dim("lda"), call.dim("ldb"), call.dim("ldc")), (Some(6), Some(5), Some(5)));
+        } else {
+            assert_eq!(call.routine, if cfg!(feature = "faer") { "faer::dgemm" } else { "dgemm_" });
+            assert_eq!((call.dim("m"), call.dim("n"), call.dim("k")), (Some(5), Some(8), Some(6)));
+            assert_eq!((call.dim("lda"), call.dim("ldb"), call.dim("ldc")), (Some(5), Some(6), Some(5)));
+        }
+
+        // non-contiguous input is read by strides in stride-native kernel, instead of copied
+        let a_raw = random_matrix::<f64>(8, 12, 'R'.into());
+        let a = a_raw.slice(s![.., ..;2]);
+        let plan = DGEMM::default().a(a).b(b.view()).copy_policy(CopyPolicy::Error).explain().unwrap();
+        assert_eq!(plan.layouts, [BLASLayout::NonContiguous]);
+        assert!(plan.copies.is_empty());
+        let call = &plan.calls[0];
+        assert_eq!(call.routine, "matrixmultiply::dgemm");
+        assert_eq!((call.dim("m"), call.dim("n"), call.dim("k")), (Some(8), Some(5), Some(6)));
+        assert_eq!((call.dim("rsa"), call.dim("csa")), (Some(12), Some(2)));
+
+        // copy of non-contiguous input is recorded, instead of rejected by copy policy
+        // (conjugate of only one input is not supported by stride-native kernel)
+        let a_raw_z = random_matrix::<c64>(8, 12, 'R'.into());
+        let a_z = a_raw_z.slice(s![.., ..;2]);
+        let b_z = random_matrix::<c64>(8, 5, 'R'.into());
+        let plan = ZGEMM::default()
+            .a(a_z)
+            .b(b_z.view())
+            .transa('C')
+            .copy_policy(CopyPolicy::Error)
+            .explain()
+            .unwrap();
+        assert_eq!(plan.copies.len(), 1);
+        assert_eq!(plan.copies[0].shape, [6, 8]);
+        assert_eq!(plan.copy_bytes(), 6 * 8 * 16);
+
+        // invalid dimension is reported as `run` does
+        let b = random_matrix::<f64>(5, 5, 'R'.into());
+        let err = DGEMM::default().a(a).b(b.view()).explain().unwrap_err();
+        assert!(matches!(err, BLASError::InvalidDim(_)));
+    }
+
+    #[test]
+    fn test_conj_output() {
+        // col-major A, B transposed by `C`, and row-major C
+        let a = random_matrix::<c64>(20, 5, 'C'.into());
+        let b = random_matrix::<c64>(6, 20, 'C'.into());
+        let mut c = random_matrix::<c64>(5, 6, 'R'.into());
+        let c_origin = c.clone();
+        let plan = ZGEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .c(c.view_mut())
+            .beta(c64::rand())
+            .transa('C')
+            .transb('C')
+            .explain()
+            .unwrap();
+        assert!(plan.conj_output);
+        assert!(plan.copies.is_empty());
+        // conj(C)' = B A, with col-major A, B viewed as row-major
+        assert_eq!(plan.calls[0].flag("transa"), Some('N'));
+        assert_eq!(plan.calls[0].flag("transb"), Some('N'));
+        assert_eq!(c, c_origin);
+    }
+
+    #[test]
+    fn test_output_untouched() {
+        let a = random_matrix::<f64>(8, 6, 'R'.into());
+        let b = random_matrix::<f64>(6, 5, 'C'.into());
+
+        // output of non-standard layout is not written back
+        let mut c_raw = random_matrix::<f64>(8, 10, 'R'.into());
+        let c_origin = c_raw.clone();
+        let c = c_raw.slice_mut(s![.., ..;2]);
+        DGEMM::default().a(a.view()).b(b.view()).c(c).beta(1.0).explain().unwrap();
+        assert_eq!(c_raw, c_origin);
+
+        // copies of input and output are recorded, but neither performed nor written back
+        let a_raw = random_matrix::<c64>(8, 12, 'R'.into());
+        let b = random_matrix::<c64>(8, 5, 'R'.into());
+        let mut c_raw = random_matrix::<c64>(6, 10, 'R'.into());
+        let c_origin = c_raw.clone();
+        let c = c_raw.slice_mut(s![.., ..;2]);
+        let plan = ZGEMM::default()
+            .a(a_raw.slice(s![.., ..;2]))
+            .b(b.view())
+            .c(c)
+            .transa('C')
+            .beta(c64::rand())
+            .explain()
+            .unwrap();
+        assert!(plan.copies.len() >= 2);
+        assert_eq!(c_raw, c_origin);
+
+        // quick return of k == 0 does not scale output
+        let a = Array2::<f64>::zeros((8, 0));
+        let b = Array2::<f64>::zeros((0, 5));
+        let mut c = random_matrix::<f64>(8, 5, 'C'.into());
+        let c_origin = c.clone();
+        let plan = DGEMM::default().a(a.view()).b(b.view()).c(c.view_mut()).beta(2.0).explain().unwrap();
+        assert!(plan.calls.is_empty());
+        assert_eq!(c, c_origin);
+
+        // conjugate of vector is not performed in-place
+        let a = random_matrix::<c64>(6, 6, 'R'.into());
+        let mut x = random_array::<c64>(6);
+        let x_origin = x.clone();
+        let plan = ZTRMV::default().a(a.view()).x(x.view_mut()).trans('C').explain().unwrap();
+        assert_eq!(plan.calls[0].routine, if ffi::USE_CBLAS { "cblas_ztrmv" } else { "ztrmv_" });
+        assert_eq!(x, x_origin);
+    }
+
+    #[test]
+    fn test_blas1_blas2() {
+        let a = random_matrix::<f64>(8, 6, 'R'.into());
+        let x = random_array::<f64>(6);
+        let plan = DGEMV::default().a(a.view()).x(x.view()).explain().unwrap();
+        assert_eq!(plan.layouts, [BLASRowMajor]);
+        let call = &plan.calls[0];
+        if ffi::USE_CBLAS {
+            assert_eq!((call.routine, call.flag("trans")), ("cblas_dgemv", Some('N')));
+            assert_eq!((call.dim("m"), call.dim("n"), call.dim("lda")), (Some(8), Some(6), Some(6)));
+        } else {
+            assert_eq!((call.routine, call.flag("trans")), ("dgemv_", Some('T')));
+            assert_eq!((call.dim("m"), call.dim("n"), call.dim("lda")), (Some(6), Some(8), Some(6)));
+        }
+
+        let x_raw = random_array::<f64>(12);
+        let plan = DNRM2::default().x(x_raw.slice(s![..;2])).explain().unwrap();
+        assert_eq!(plan.wrapper, "NRM2");
+        assert_eq!(plan.calls[0].dim("incx"), Some(2));
+
+        // copy of zero-strided (broadcasted) vector is recorded, instead of performed
+        let x_one = random_array::<f64>(1);
+        let x = x_one.broadcast(6).unwrap();
+        let plan = DGEMV::default().a(a.view()).x(x).copy_policy(CopyPolicy::Error).explain().unwrap();
+        assert_eq!(plan.copies.len(), 1);
+        assert_eq!(plan.copies[0].shape, [6]);
+        assert_eq!(plan.calls[0].dim("incx"), Some(1));
+    }
+}
@@ -0,0 +1,115 @@
+//! Function-style API (`blas_array2::func`), compared to wrapper builders.
+
+use crate::util::*;
+use blas_array2::func;
+use blas_array2::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        let a = random_matrix::<c64>(6, 8, 'R'.into());
+        let b = random_matrix::<c64>(5, 6, 'C'.into());
+        let mut c = random_matrix::<c64>(8, 5, 'C'.into());
+        let c_origin = c.clone();
+        let (alpha, beta) = (c64::new(1.5, 0.5), c64::new(-0.5, 1.0));
+
+        let c_ref = ZGEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .c(c.clone().view_mut())
+            .transa('T')
+            .transb('C')
+            .alpha(alpha)
+            .beta(beta)
+            .run()
+            .unwrap()
+            .into_owned();
+
+        // c is not modified without overwrite_c
+        let kwargs =
+            func::GEMMKwargs { beta, c: Some(c.view_mut()), trans_a: 1, trans_b: 2, ..Default::default() };
+        let mut c_out = func::gemm(alpha, a.view(), b.view(), kwargs).unwrap();
+        assert!(c_out.is_owned());
+        check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+        check_same(&c.view(), &c_origin.view(), 0.0);
+
+        // c is written with overwrite_c
+        let kwargs =
+            func::GEMMKwargs { beta, c: Some(c.view_mut()), trans_a: 1, trans_b: 2, overwrite_c: true };
+        func::gemm(alpha, a.view(), b.view(), kwargs).unwrap();
+        check_same(&c.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_blas3() {
+        let a = random_matrix::<f64>(7, 7, 'R'.into());
+        let b = random_matrix::<f64>(7, 5, 'C'.into());
+
+        let c_out =
+            func::syrk(2.0, b.view(), func::SYRKKwargs { trans: 1, lower: 1, ..Default::default() }).unwrap();
+        let c_ref = DSYRK::default().a(b.view()).alpha(2.0).trans('T').uplo('L').run().unwrap();
+        check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+        let c_out =
+            func::symm(1.0, a.view(), b.view(), func::SYMMKwargs { lower: 1, ..Default::default() }).unwrap();
+        let c_ref = DSYMM::default().a(a.view()).b(b.view()).uplo('L').run().unwrap();
+        check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+        // b is overwritten by solution with overwrite_b
+        let mut x = b.clone();
+        let kwargs = func::TRMMKwargs { lower: 1, trans_a: 1, overwrite_b: true, ..Default::default() };
+        func::trsm(1.0, a.view(), x.view_mut(), kwargs).unwrap();
+        let b_out =
+            func::trmm(1.0, a.view(), x.view_mut(), func::TRMMKwargs { overwrite_b: false, ..kwargs })
+                .unwrap();
+        check_same(&b_out.view(), &b.view(), 1e-8);
+    }
+
+    #[test]
+    fn test_blas2() {
+        let a = random_matrix::<c64>(6, 8, 'C'.into());
+        let x = random_array::<c64>(6);
+        let y_out = func::gemv(
+            c64::new(1.0, 0.0),
+            a.view(),
+            x.view(),
+            func::GEMVKwargs { trans: 2, ..Default::default() },
+        )
+        .unwrap();
+        let y_ref = ZGEMV::default().a(a.view()).x(x.view()).trans('C').run().unwrap();
+        check_same(&y_out.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+
+        let a_out = func::her(0.5, x.view(), func::HERKwargs { lower: 1, ..Default::default() }).unwrap();
+        let a_ref = ZHER::default().x(x.view()).alpha(0.5).uplo('L').run().unwrap();
+        check_same(&a_out.view(), &a_ref.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_blas1() {
+        let x = random_array::<f64>(10);
+        let mut y = random_array::<f64>(10);
+        assert!((func::dot(x.view(), y.view()).unwrap() - x.dot(&y)).abs() < 1e-12);
+        let y_ref = &y + &(2.0 * &x);
+        func::axpy(x.view(), y.view_mut(), 2.0).unwrap();
+        check_same(&y.view(), &y_ref.view(), 4.0 * f64::EPSILON);
+    }
+}
+
+#[cfg(test)]
+mod invalid {
+    use super::*;
+
+    #[test]
+    fn test_codes() {
+        let a = random_matrix::<f64>(6, 6, 'R'.into());
+        let b = random_matrix::<f64>(6, 6, 'R'.into());
+        let result =
+            func::gemm(1.0, a.view(), b.view(), func::GEMMKwargs { trans_a: 3, ..Default::default() });
+        assert!(matches!(result, Err(BLASError::InvalidFlag(_))));
+        let result = func::syrk(1.0, a.view(), func::SYRKKwargs { lower: 2, ..Default::default() });
+        assert!(matches!(result, Err(BLASError::InvalidFlag(_))));
+    }
+}
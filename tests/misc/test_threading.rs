@@ -0,0 +1,47 @@
+//! Threading control of vendors found at runtime; thread count of some vendors is global, so it is only read (and restored) here.
+
+use blas_array2::backend::*;
+use blas_array2::prelude::*;
+use blas_array2::threading::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_scoped_threads() {
+        let prev = get_num_threads();
+        if threading_vendors().is_empty() {
+            assert_eq!(prev, None);
+            // vendor functions not exported (such as BLAS linked statically) are reported
+            assert!(matches!(set_num_threads(1), Err(BLASError::MissingSymbol(_))));
+        } else if let Some(n) = prev {
+            assert!(set_num_threads(n).is_ok());
+        }
+        if cfg!(feature = "faer") {
+            assert!(threading_vendors().contains(&ThreadingVendor::Faer));
+        }
+        // vendor functions are looked up at runtime, as backend discovery does
+        let expected = match backend_info().vendor {
+            BLASVendor::OpenBLAS => Some(ThreadingVendor::OpenBLAS),
+            BLASVendor::MKL => Some(ThreadingVendor::MKL),
+            BLASVendor::BLIS | BLASVendor::AOCL => Some(ThreadingVendor::BLIS),
+            _ => None,
+        };
+        if let Some(vendor) = expected {
+            assert!(threading_vendors().contains(&vendor));
+        }
+        {
+            let _guard = scoped_reproducible();
+            if prev.is_some() {
+                assert_eq!(get_num_threads(), Some(1));
+            }
+            // wrappers run as usual in scope
+            let a = Array2::<f64>::ones((4, 3));
+            let c = DGEMM::default().a(a.view()).b(a.t()).run().unwrap().into_owned();
+            assert_eq!(c, Array2::from_elem((4, 4), 3.0));
+        }
+        assert_eq!(get_num_threads(), prev);
+    }
+}
@@ -0,0 +1,88 @@
+//! Typestate builders; missing required arguments are checked at compile time (see doc of
+//! `blas_array2::util::blas_typestate` for compile-fail example).
+
+use crate::util::*;
+use blas_array2::ffi;
+use blas_array2::prelude::*;
+
+#[cfg(test)]
+mod valid {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        let a = random_matrix::<c64>(8, 6, 'R'.into());
+        let b = random_matrix::<c64>(5, 6, 'C'.into());
+        let c_out = typed::ZGEMM::default()
+            .b(b.view())
+            .a(a.view())
+            .transb('C')
+            .alpha(c64::new(1.5, 0.5))
+            .run()
+            .unwrap()
+            .into_owned();
+        let c_ref = ZGEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .transb('C')
+            .alpha(c64::new(1.5, 0.5))
+            .run()
+            .unwrap()
+            .into_owned();
+        check_same(&c_out.view(), &c_ref.view(), 4.0 * f64::EPSILON);
+
+        // optional output, and generic alias
+        let mut c = random_matrix::<f64>(8, 5, 'C'.into());
+        let a = random_matrix::<f64>(8, 6, 'C'.into());
+        let b = random_matrix::<f64>(6, 5, 'C'.into());
+        typed::GEMM::<f64>::default().a(a.view()).b(b.view()).c(c.view_mut()).beta(0.0).run().unwrap();
+        check_same(&c.view(), &gemm(&a.view(), &b.view()).view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_blas1_blas2() {
+        let x = random_array::<f64>(10);
+        let y = random_array::<f64>(10);
+        let dot = typed::DDOT::default().x(x.view()).y(y.view()).run().unwrap();
+        assert!((dot - x.dot(&y)).abs() < 1e-12);
+        let dot =
+            typed::SDSDOT::default().x(x.mapv(|v| v as f32).view()).y(y.mapv(|v| v as f32).view()).run();
+        assert!(dot.is_ok());
+
+        let mut y = random_array::<f64>(8);
+        let a = random_matrix::<f64>(8, 10, 'R'.into());
+        let y_ref = a.dot(&x) + &y;
+        typed::DGEMV::default().a(a.view()).x(x.view()).y(y.view_mut()).beta(1.0).run().unwrap();
+        check_same(&y.view(), &y_ref.view(), 16.0 * f64::EPSILON);
+
+        // multiple generic aliases of the same wrapper
+        let x = random_array::<f64>(6);
+        let a_ref = typed::DSYR2::default().x(x.view()).y(x.view()).run().unwrap().into_owned();
+        let a_out = DSYR2::default().x(x.view()).y(x.view()).run().unwrap().into_owned();
+        check_same(&a_out.view(), &a_ref.view(), 4.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_into_builder() {
+        // builder of `derive_builder` still checks required arguments at runtime
+        let a = random_matrix::<f64>(8, 6, 'R'.into());
+        let result = typed::DGEMM::default().a(a.view()).into_builder().run();
+        assert!(matches!(result, Err(BLASError::UninitializedField(_))));
+    }
+
+    #[test]
+    fn test_explain() {
+        let a = random_matrix::<f64>(8, 6, 'R'.into());
+        let b = random_matrix::<f64>(6, 5, 'R'.into());
+        let plan = typed::DGEMM::default().a(a.view()).b(b.view()).explain().unwrap();
+        assert_eq!(plan.layouts, [BLASRowMajor]);
+        assert_eq!(
+            plan.calls[0].routine,
+            match (ffi::USE_CBLAS, cfg!(feature = "faer")) {
+                (true, _) => "cblas_dgemm",
+                (false, true) => "faer::dgemm",
+                (false, false) => "dgemm_",
+            }
+        );
+    }
+}
@@ -1,4 +1,12 @@
+//! Tests of wrappers, with linked BLAS library.
+//!
+//! With crate feature `dynamic`, BLAS is not linked but loaded at runtime, so this suite is skipped; dispatch
+//! through the loaded library is tested by `tests/dynamic.rs` instead.
+
+#![cfg(not(feature = "dynamic"))]
+
 pub mod blas1;
 pub mod blas2;
 pub mod blas3;
+pub mod misc;
 pub mod util;
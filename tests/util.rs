@@ -111,6 +111,10 @@ pub fn slice_1d(n: usize, s: usize) -> SliceInfo<[SliceInfoElem; 1], Ix1, Ix1> {
     s![50..(50+n*s);s]
 }
 
+pub fn slice_1d_neg(n: usize, s: usize) -> SliceInfo<[SliceInfoElem; 1], Ix1, Ix1> {
+    s![50..(50+n*s);-(s as isize)]
+}
+
 /* #endregion */
 
 /* #region Basic matrix operations */
@@ -386,3 +390,20 @@ pub fn to_cblas_diag(diag: char) -> CBLAS_DIAG {
 }
 
 /* #endregion */
+
+/* #region cblas oracle */
+
+/// CBLAS functions that tests compare against: `cblas_sys`, or naive implementation with crate feature
+/// `reference` (where CBLAS is not provided).
+pub mod cblas_oracle {
+    #[cfg(not(feature = "reference"))]
+    pub use cblas_sys::*;
+    #[cfg(feature = "reference")]
+    pub use super::oracle::*;
+}
+
+#[cfg(feature = "reference")]
+#[path = "util/oracle.rs"]
+pub mod oracle;
+
+/* #endregion */
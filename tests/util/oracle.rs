@@ -0,0 +1,711 @@
+//! Naive CBLAS oracle of tests, for crate feature `reference` (where CBLAS is not provided).
+//!
+//! Functions have the same names and signatures as those of `cblas_sys` called by tests. Matrices are read from
+//! their storage (general, band or packed, in row-major or col-major layout) into dense arrays, computed by naive
+//! loops, and written back to the same storage.
+
+#![allow(clippy::too_many_arguments)]
+
+use super::*;
+use core::ffi::c_int;
+
+/* #region storage */
+
+/// Offset of element `(i, j)` in storage of matrix; `None` if not stored.
+type Storage = Box<dyn Fn(usize, usize) -> Option<usize>>;
+
+fn layout_char(layout: CBLAS_LAYOUT) -> char {
+    match layout {
+        CBLAS_LAYOUT::CblasRowMajor => 'R',
+        CBLAS_LAYOUT::CblasColMajor => 'C',
+    }
+}
+
+fn trans_char(trans: CBLAS_TRANSPOSE) -> char {
+    match trans {
+        CBLAS_TRANSPOSE::CblasNoTrans => 'N',
+        CBLAS_TRANSPOSE::CblasTrans => 'T',
+        CBLAS_TRANSPOSE::CblasConjTrans => 'C',
+    }
+}
+
+fn uplo_char(uplo: CBLAS_UPLO) -> char {
+    match uplo {
+        CBLAS_UPLO::CblasUpper => 'U',
+        CBLAS_UPLO::CblasLower => 'L',
+    }
+}
+
+fn diag_char(diag: CBLAS_DIAG) -> char {
+    match diag {
+        CBLAS_DIAG::CblasNonUnit => 'N',
+        CBLAS_DIAG::CblasUnit => 'U',
+    }
+}
+
+fn in_triangle(uplo: char, i: usize, j: usize) -> bool {
+    match uplo {
+        'U' => i <= j,
+        'L' => i >= j,
+        _ => panic!("Invalid uplo"),
+    }
+}
+
+fn general(layout: CBLAS_LAYOUT, ld: c_int) -> Storage {
+    let ld = ld as usize;
+    match layout_char(layout) {
+        'R' => Box::new(move |i, j| Some(i * ld + j)),
+        _ => Box::new(move |i, j| Some(i + j * ld)),
+    }
+}
+
+fn triangle(layout: CBLAS_LAYOUT, uplo: char, ld: c_int) -> Storage {
+    let storage = general(layout, ld);
+    Box::new(move |i, j| if in_triangle(uplo, i, j) { storage(i, j) } else { None })
+}
+
+fn band(layout: CBLAS_LAYOUT, kl: c_int, ku: c_int, ld: c_int) -> Storage {
+    let (kl, ku, ld) = (kl as usize, ku as usize, ld as usize);
+    let row_major = layout_char(layout) == 'R';
+    Box::new(move |i, j| {
+        if j > i + ku || i > j + kl {
+            None
+        } else if row_major {
+            Some(i * ld + kl + j - i)
+        } else {
+            Some(ku + i - j + j * ld)
+        }
+    })
+}
+
+/// Band storage of triangular (or symmetric) matrix with `k` off-diagonals.
+fn band_triangle(layout: CBLAS_LAYOUT, uplo: char, k: c_int, ld: c_int) -> Storage {
+    match uplo {
+        'U' => band(layout, 0, k, ld),
+        'L' => band(layout, k, 0, ld),
+        _ => panic!("Invalid uplo"),
+    }
+}
+
+fn packed(layout: CBLAS_LAYOUT, uplo: char, n: c_int) -> Storage {
+    let n = n as usize;
+    let layout = layout_char(layout);
+    Box::new(move |i, j| {
+        if !in_triangle(uplo, i, j) {
+            return None;
+        }
+        match (layout, uplo) {
+            ('C', 'U') => Some(i + j * (j + 1) / 2),
+            ('C', _) => Some(i + (2 * n - j - 1) * j / 2),
+            (_, 'U') => Some(j + (2 * n - i - 1) * i / 2),
+            (_, _) => Some(j + i * (i + 1) / 2),
+        }
+    })
+}
+
+/// Read matrix from storage; elements not stored are zero.
+unsafe fn read<F: BLASFloat>(m: usize, n: usize, a: *const F, storage: &Storage) -> Array2<F> {
+    Array2::from_shape_fn((m, n), |(i, j)| match storage(i, j) {
+        Some(offset) => *a.add(offset),
+        None => F::zero(),
+    })
+}
+
+/// Write stored elements of matrix to storage.
+unsafe fn write<F: BLASFloat>(a_new: &Array2<F>, a: *mut F, storage: &Storage) {
+    for ((i, j), &value) in a_new.indexed_iter() {
+        if let Some(offset) = storage(i, j) {
+            *a.add(offset) = value;
+        }
+    }
+}
+
+fn vec_offset(n: usize, i: usize, inc: c_int) -> usize {
+    if inc > 0 {
+        i * inc as usize
+    } else {
+        (n - 1 - i) * (-inc) as usize
+    }
+}
+
+unsafe fn read_vec<F: BLASFloat>(n: usize, x: *const F, incx: c_int) -> Array1<F> {
+    Array1::from_shape_fn(n, |i| *x.add(vec_offset(n, i, incx)))
+}
+
+unsafe fn write_vec<F: BLASFloat>(x_new: &Array1<F>, x: *mut F, incx: c_int) {
+    let n = x_new.len();
+    for (i, &value) in x_new.iter().enumerate() {
+        *x.add(vec_offset(n, i, incx)) = value;
+    }
+}
+
+/* #endregion */
+
+/* #region naive operations */
+
+fn op<F: BLASFloat>(a: &Array2<F>, trans: char) -> Array2<F> {
+    match trans {
+        'N' => a.clone(),
+        'T' => a.t().to_owned(),
+        'C' => a.t().mapv(F::conj),
+        _ => panic!("Invalid trans"),
+    }
+}
+
+/// Hermitian (symmetric for real types) matrix from its triangle; imaginary part of diagonal is ignored.
+fn hermitian_full<F: BLASFloat>(a: &Array2<F>, uplo: char) -> Array2<F> {
+    let two = F::one() + F::one();
+    Array2::from_shape_fn(a.dim(), |(i, j)| match (i == j, in_triangle(uplo, i, j)) {
+        (true, _) => (a[[i, i]] + F::conj(a[[i, i]])) / two,
+        (false, true) => a[[i, j]],
+        (false, false) => F::conj(a[[j, i]]),
+    })
+}
+
+/// Triangular matrix from its triangle, with unit diagonal if `diag` is `U`.
+fn triangular_full<F: BLASFloat>(a: &Array2<F>, uplo: char, diag: char) -> Array2<F> {
+    Array2::from_shape_fn(a.dim(), |(i, j)| match (i == j && diag == 'U', in_triangle(uplo, i, j)) {
+        (true, _) => F::one(),
+        (false, true) => a[[i, j]],
+        (false, false) => F::zero(),
+    })
+}
+
+/// alpha * a x + beta * y; y is not read if beta is zero.
+fn gemv_update<F: BLASFloat>(alpha: F, a: &Array2<F>, x: &Array1<F>, beta: F, y: &Array1<F>) -> Array1<F> {
+    let ax = gemv(&a.view(), &x.view());
+    match beta == F::zero() {
+        true => ax.mapv(|v| alpha * v),
+        false => Array1::from_shape_fn(ax.len(), |i| alpha * ax[i] + beta * y[i]),
+    }
+}
+
+/// a + alpha x y^T (or y^H if `conj`).
+fn rank1_update<F: BLASFloat>(
+    a: &Array2<F>,
+    alpha: F,
+    x: &Array1<F>,
+    y: &Array1<F>,
+    conj: bool,
+) -> Array2<F> {
+    let y = if conj { y.mapv(F::conj) } else { y.clone() };
+    Array2::from_shape_fn(a.dim(), |(i, j)| a[[i, j]] + alpha * x[i] * y[j])
+}
+
+/// a + alpha x y^H + conj(alpha) y x^H.
+fn rank2_update<F: BLASFloat>(a: &Array2<F>, alpha: F, x: &Array1<F>, y: &Array1<F>) -> Array2<F> {
+    let a = rank1_update(a, alpha, x, y, true);
+    rank1_update(&a, F::conj(alpha), y, x, true)
+}
+
+/// Solve triangular system a x = b by substitution.
+fn trsv<F: BLASFloat>(a: &Array2<F>, lower: bool, b: &Array1<F>) -> Array1<F> {
+    let n = b.len();
+    let mut x = b.clone();
+    let order: Vec<usize> = if lower { (0..n).collect() } else { (0..n).rev().collect() };
+    for i in order {
+        let solved = if lower { 0..i } else { (i + 1)..n };
+        let mut sum = x[i];
+        for j in solved {
+            sum = sum - a[[i, j]] * x[j];
+        }
+        x[i] = sum / a[[i, i]];
+    }
+    return x;
+}
+
+/* #endregion */
+
+/* #region generic routines */
+
+unsafe fn gemm_oracle<F: BLASFloat>(
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: F,
+    a: *const F,
+    lda: c_int,
+    b: *const F,
+    ldb: c_int,
+    beta: F,
+    c: *mut F,
+    ldc: c_int,
+) {
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (transa, transb) = (trans_char(transa), trans_char(transb));
+    let (ma, na) = if transa == 'N' { (m, k) } else { (k, m) };
+    let (mb, nb) = if transb == 'N' { (k, n) } else { (n, k) };
+    let a = op(&read(ma, na, a, &general(layout, lda)), transa);
+    let b = op(&read(mb, nb, b, &general(layout, ldb)), transb);
+    let storage_c = general(layout, ldc);
+    let c_old = read(m, n, c, &storage_c);
+    let ab = gemm(&a.view(), &b.view());
+    let c_new = match beta == F::zero() {
+        true => ab.mapv(|v| alpha * v),
+        false => Array2::from_shape_fn((m, n), |(i, j)| alpha * ab[[i, j]] + beta * c_old[[i, j]]),
+    };
+    write(&c_new, c, &storage_c);
+}
+
+unsafe fn gbmv_oracle<F: BLASFloat>(
+    layout: CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: F,
+    a: *const F,
+    lda: c_int,
+    x: *const F,
+    incx: c_int,
+    beta: F,
+    y: *mut F,
+    incy: c_int,
+) {
+    let trans = trans_char(trans);
+    let a = op(&read(m as usize, n as usize, a, &band(layout, kl, ku, lda)), trans);
+    let (len_y, len_x) = a.dim();
+    let x = read_vec(len_x, x, incx);
+    let y_new = gemv_update(alpha, &a, &x, beta, &read_vec(len_y, y, incy));
+    write_vec(&y_new, y, incy);
+}
+
+/// Hermitian (symmetric for real types) matrix-vector product, with matrix given by its storage.
+unsafe fn hemv_oracle<F: BLASFloat>(
+    uplo: char,
+    n: c_int,
+    alpha: F,
+    a: *const F,
+    storage: &Storage,
+    x: *const F,
+    incx: c_int,
+    beta: F,
+    y: *mut F,
+    incy: c_int,
+) {
+    let n = n as usize;
+    let a = hermitian_full(&read(n, n, a, storage), uplo);
+    let x = read_vec(n, x, incx);
+    let y_new = gemv_update(alpha, &a, &x, beta, &read_vec(n, y, incy));
+    write_vec(&y_new, y, incy);
+}
+
+/// Triangular matrix-vector product (or solve if `solve`), with matrix given by its storage.
+unsafe fn trmv_oracle<F: BLASFloat>(
+    uplo: char,
+    trans: char,
+    diag: char,
+    n: c_int,
+    a: *const F,
+    storage: &Storage,
+    x: *mut F,
+    incx: c_int,
+    solve: bool,
+) {
+    let n = n as usize;
+    let a = op(&triangular_full(&read(n, n, a, storage), uplo, diag), trans);
+    let x_old = read_vec(n, x, incx);
+    let x_new = match solve {
+        true => trsv(&a, (uplo == 'L') == (trans == 'N'), &x_old),
+        false => gemv(&a.view(), &x_old.view()),
+    };
+    write_vec(&x_new, x, incx);
+}
+
+unsafe fn ger_oracle<F: BLASFloat>(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: F,
+    x: *const F,
+    incx: c_int,
+    y: *const F,
+    incy: c_int,
+    a: *mut F,
+    lda: c_int,
+    conj: bool,
+) {
+    let (m, n) = (m as usize, n as usize);
+    let storage = general(layout, lda);
+    let (x, y) = (read_vec(m, x, incx), read_vec(n, y, incy));
+    let a_new = rank1_update(&read(m, n, a, &storage), alpha, &x, &y, conj);
+    write(&a_new, a, &storage);
+}
+
+/// Hermitian (symmetric for real types) rank-1 update, with matrix given by its storage.
+unsafe fn her_oracle<F: BLASFloat>(
+    uplo: char,
+    n: c_int,
+    alpha: F,
+    x: *const F,
+    incx: c_int,
+    a: *mut F,
+    storage: &Storage,
+) {
+    let n = n as usize;
+    let x = read_vec(n, x, incx);
+    let a_old = hermitian_full(&read(n, n, a, storage), uplo);
+    write(&rank1_update(&a_old, alpha, &x, &x, true), a, storage);
+}
+
+/// Hermitian (symmetric for real types) rank-2 update, with matrix given by its storage.
+unsafe fn her2_oracle<F: BLASFloat>(
+    uplo: char,
+    n: c_int,
+    alpha: F,
+    x: *const F,
+    incx: c_int,
+    y: *const F,
+    incy: c_int,
+    a: *mut F,
+    storage: &Storage,
+) {
+    let n = n as usize;
+    let (x, y) = (read_vec(n, x, incx), read_vec(n, y, incy));
+    let a_old = hermitian_full(&read(n, n, a, storage), uplo);
+    write(&rank2_update(&a_old, alpha, &x, &y), a, storage);
+}
+
+/* #endregion */
+
+/* #region CBLAS functions */
+
+pub unsafe fn cblas_dgemm(
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *const f64,
+    ldb: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) {
+    gemm_oracle(layout, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+pub unsafe fn cblas_cgemm(
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: c_int,
+    b: *const c_float_complex,
+    ldb: c_int,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: c_int,
+) {
+    let (alpha, beta) = (*(alpha as *const c32), *(beta as *const c32));
+    let (a, b, c) = (a as *const c32, b as *const c32, c as *mut c32);
+    gemm_oracle(layout, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+pub unsafe fn cblas_cgbmv(
+    layout: CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: c_int,
+    x: *const c_float_complex,
+    incx: c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: c_int,
+) {
+    let (alpha, beta) = (*(alpha as *const c32), *(beta as *const c32));
+    let (a, x, y) = (a as *const c32, x as *const c32, y as *mut c32);
+    gbmv_oracle(layout, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy);
+}
+
+pub unsafe fn cblas_chbmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: c_int,
+    x: *const c_float_complex,
+    incx: c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: c_int,
+) {
+    let uplo = uplo_char(uplo);
+    let (alpha, beta) = (*(alpha as *const c32), *(beta as *const c32));
+    let storage = band_triangle(layout, uplo, k, lda);
+    hemv_oracle(uplo, n, alpha, a as *const c32, &storage, x as *const c32, incx, beta, y as *mut c32, incy);
+}
+
+pub unsafe fn cblas_ssbmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    let uplo = uplo_char(uplo);
+    let storage = band_triangle(layout, uplo, k, lda);
+    hemv_oracle(uplo, n, alpha, a, &storage, x, incx, beta, y, incy);
+}
+
+pub unsafe fn cblas_chpmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_float_complex,
+    ap: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: c_int,
+) {
+    let uplo = uplo_char(uplo);
+    let (alpha, beta) = (*(alpha as *const c32), *(beta as *const c32));
+    let storage = packed(layout, uplo, n);
+    hemv_oracle(uplo, n, alpha, ap as *const c32, &storage, x as *const c32, incx, beta, y as *mut c32, incy);
+}
+
+pub unsafe fn cblas_sspmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    let uplo = uplo_char(uplo);
+    hemv_oracle(uplo, n, alpha, ap, &packed(layout, uplo, n), x, incx, beta, y, incy);
+}
+
+pub unsafe fn cblas_ctbmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const c_float_complex,
+    lda: c_int,
+    x: *mut c_float_complex,
+    incx: c_int,
+) {
+    let (uplo, trans, diag) = (uplo_char(uplo), trans_char(trans), diag_char(diag));
+    let storage = band_triangle(layout, uplo, k, lda);
+    trmv_oracle(uplo, trans, diag, n, a as *const c32, &storage, x as *mut c32, incx, false);
+}
+
+pub unsafe fn cblas_ctbsv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    k: c_int,
+    a: *const c_float_complex,
+    lda: c_int,
+    x: *mut c_float_complex,
+    incx: c_int,
+) {
+    let (uplo, trans, diag) = (uplo_char(uplo), trans_char(trans), diag_char(diag));
+    let storage = band_triangle(layout, uplo, k, lda);
+    trmv_oracle(uplo, trans, diag, n, a as *const c32, &storage, x as *mut c32, incx, true);
+}
+
+pub unsafe fn cblas_ctpmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const c_float_complex,
+    x: *mut c_float_complex,
+    incx: c_int,
+) {
+    let (uplo, trans, diag) = (uplo_char(uplo), trans_char(trans), diag_char(diag));
+    let storage = packed(layout, uplo, n);
+    trmv_oracle(uplo, trans, diag, n, ap as *const c32, &storage, x as *mut c32, incx, false);
+}
+
+pub unsafe fn cblas_ctpsv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    ap: *const c_float_complex,
+    x: *mut c_float_complex,
+    incx: c_int,
+) {
+    let (uplo, trans, diag) = (uplo_char(uplo), trans_char(trans), diag_char(diag));
+    let storage = packed(layout, uplo, n);
+    trmv_oracle(uplo, trans, diag, n, ap as *const c32, &storage, x as *mut c32, incx, true);
+}
+
+pub unsafe fn cblas_sger(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    ger_oracle(layout, m, n, alpha, x, incx, y, incy, a, lda, false);
+}
+
+pub unsafe fn cblas_cgeru(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: c_int,
+    y: *const c_float_complex,
+    incy: c_int,
+    a: *mut c_float_complex,
+    lda: c_int,
+) {
+    let (alpha, x, y, a) = (*(alpha as *const c32), x as *const c32, y as *const c32, a as *mut c32);
+    ger_oracle(layout, m, n, alpha, x, incx, y, incy, a, lda, false);
+}
+
+pub unsafe fn cblas_cgerc(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: c_int,
+    y: *const c_float_complex,
+    incy: c_int,
+    a: *mut c_float_complex,
+    lda: c_int,
+) {
+    let (alpha, x, y, a) = (*(alpha as *const c32), x as *const c32, y as *const c32, a as *mut c32);
+    ger_oracle(layout, m, n, alpha, x, incx, y, incy, a, lda, true);
+}
+
+pub unsafe fn cblas_ssyr(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    let uplo = uplo_char(uplo);
+    her_oracle(uplo, n, alpha, x, incx, a, &triangle(layout, uplo, lda));
+}
+
+pub unsafe fn cblas_cher(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const c_float_complex,
+    incx: c_int,
+    a: *mut c_float_complex,
+    lda: c_int,
+) {
+    let uplo = uplo_char(uplo);
+    let storage = triangle(layout, uplo, lda);
+    her_oracle(uplo, n, c32::new(alpha, 0.0), x as *const c32, incx, a as *mut c32, &storage);
+}
+
+pub unsafe fn cblas_sspr(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    ap: *mut f32,
+) {
+    let uplo = uplo_char(uplo);
+    her_oracle(uplo, n, alpha, x, incx, ap, &packed(layout, uplo, n));
+}
+
+pub unsafe fn cblas_chpr(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: f32,
+    x: *const c_float_complex,
+    incx: c_int,
+    ap: *mut c_float_complex,
+) {
+    let uplo = uplo_char(uplo);
+    let storage = packed(layout, uplo, n);
+    her_oracle(uplo, n, c32::new(alpha, 0.0), x as *const c32, incx, ap as *mut c32, &storage);
+}
+
+pub unsafe fn cblas_cher2(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: c_int,
+    y: *const c_float_complex,
+    incy: c_int,
+    a: *mut c_float_complex,
+    lda: c_int,
+) {
+    let uplo = uplo_char(uplo);
+    let (alpha, x, y, a) = (*(alpha as *const c32), x as *const c32, y as *const c32, a as *mut c32);
+    her2_oracle(uplo, n, alpha, x, incx, y, incy, a, &triangle(layout, uplo, lda));
+}
+
+pub unsafe fn cblas_chpr2(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    n: c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: c_int,
+    y: *const c_float_complex,
+    incy: c_int,
+    ap: *mut c_float_complex,
+) {
+    let uplo = uplo_char(uplo);
+    let (alpha, x, y, ap) = (*(alpha as *const c32), x as *const c32, y as *const c32, ap as *mut c32);
+    her2_oracle(uplo, n, alpha, x, incx, y, incy, ap, &packed(layout, uplo, n));
+}
+
+/* #endregion */